@@ -0,0 +1,834 @@
+use bevy::prelude::*;
+
+use crate::ore::mine_amount;
+use crate::ships::ShipKind;
+use crate::stations::StationKind;
+
+/// Hull fraction at or below which an entity counts as damaged
+pub const DAMAGED_THRESHOLD: f32 = 0.5;
+
+/// Hull fraction at or below which an entity is disabled
+pub const DISABLED_THRESHOLD: f32 = 0.2;
+
+/// Share of a hit that always gets through armor
+const MIN_ARMOR_PENETRATION: f32 = 0.25;
+
+/// Seconds a wreck drifts before breaking up
+pub const WRECK_LIFETIME_SECONDS: f32 = 120.0;
+
+/// Share of a hull's maximum integrity recoverable from its wreck as components
+pub const WRECK_COMPONENT_FRACTION: f32 = 0.05;
+
+/// Units of each resource a salvager pulls from a wreck per second
+pub const SALVAGE_RATE_PER_SECOND: f32 = 2.0;
+
+/// Distance within which a ship can strip a wreck
+pub const SALVAGE_RANGE: f32 = 24.0;
+
+/// Seconds the player's own wreck lingers, long enough to fly back for the cargo
+pub const PLAYER_WRECK_LIFETIME_SECONDS: f32 = 900.0;
+
+/// Fuel an escape pod launches with
+pub const ESCAPE_POD_FUEL: f32 = 8.0;
+
+/// Share of a Security hull a captured raider comes into service with
+pub const CAPTURED_HULL_FRACTION: f32 = 0.25;
+
+/// Ore plated onto a damaged hull per patch
+pub const HULL_PATCH_ORE: u32 = 4;
+
+/// Hull one patch restores
+pub const HULL_PATCH_AMOUNT: f32 = 40.0;
+
+/// Seconds an impact flash stays on screen
+pub const IMPACT_FLASH_SECONDS: f32 = 0.3;
+
+/// How quickly a missile bends toward its target, as a fraction per second
+const MISSILE_TURN_RATE: f32 = 3.0;
+
+/// Structural integrity of a ship or station. Reaching zero destroys it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Hull {
+    pub current: f32,
+    pub max: f32,
+    /// Flat damage shaved off every hit that reaches the hull
+    pub armor: f32,
+    /// Source of the most recent hit, credited if the hull gives out
+    pub last_hit: Option<DamageSource>,
+}
+
+impl Hull {
+    pub fn new(max: f32, armor: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            armor,
+            last_hit: None,
+        }
+    }
+
+    pub fn ratio(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+
+    pub fn condition(&self) -> HullCondition {
+        let ratio = self.ratio();
+        if self.current <= 0.0 {
+            HullCondition::Destroyed
+        } else if ratio <= DISABLED_THRESHOLD {
+            HullCondition::Disabled
+        } else if ratio <= DAMAGED_THRESHOLD {
+            HullCondition::Damaged
+        } else {
+            HullCondition::Intact
+        }
+    }
+
+    /// Restore up to `amount` hull, returns the amount actually repaired
+    pub fn repair(&mut self, amount: f32) -> f32 {
+        let repaired = amount.max(0.0).min(self.max - self.current).max(0.0);
+        self.current += repaired;
+        repaired
+    }
+
+    /// Plate over damage with ore from a hold holding `ore` units. Returns
+    /// the ore used: one patch's worth, or nothing if the hull is whole or
+    /// there is not enough ore.
+    pub fn patch(&mut self, ore: u32) -> u32 {
+        if self.current >= self.max || ore < HULL_PATCH_ORE {
+            return 0;
+        }
+        self.repair(HULL_PATCH_AMOUNT);
+        HULL_PATCH_ORE
+    }
+}
+
+/// Energy barrier that soaks damage before the hull and recharges over time.
+#[derive(Component, Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Shields {
+    pub current: f32,
+    pub max: f32,
+    pub regen_per_second: f32,
+}
+
+impl Shields {
+    pub fn new(max: f32, regen_per_second: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen_per_second,
+        }
+    }
+
+    pub fn regen(&mut self, delta_seconds: f32) {
+        self.current = (self.current + self.regen_per_second * delta_seconds).min(self.max);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HullCondition {
+    Intact,
+    Damaged,
+    Disabled,
+    Destroyed,
+}
+
+/// Who dealt a hit, used for logging and pirate memory
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum DamageSource {
+    Player,
+    Pirate,
+    Security,
+    Station,
+}
+
+/// Request to damage an entity; resolved against its shields, armor and hull.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    pub source: DamageSource,
+}
+
+/// How a single hit was split between shields and hull
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DamageResult {
+    pub absorbed: f32,
+    pub hull_damage: f32,
+}
+
+/// What a wreck used to be
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum WreckKind {
+    Ship(ShipKind),
+    PirateShip,
+    Station(StationKind),
+}
+
+impl WreckKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            WreckKind::Ship(ShipKind::PlayerShip) => "Your Vessel",
+            WreckKind::Ship(_) => "Ship",
+            WreckKind::PirateShip => "Raider",
+            WreckKind::Station(_) => "Station",
+        }
+    }
+}
+
+/// Debris left behind by a destroyed ship or station, holding whatever cargo survived.
+#[derive(Component, Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Wreck {
+    pub kind: WreckKind,
+    pub ore: f32,
+    pub fuel: f32,
+    /// Usable parts stripped from the hull
+    pub components: f32,
+    /// Zone whose intel can be pulled from the wreck's logs, if any
+    pub intel_zone: Option<u32>,
+    pub remaining_seconds: f32,
+}
+
+impl Wreck {
+    pub fn new(kind: WreckKind, ore: f32, fuel: f32, components: f32) -> Self {
+        Self {
+            kind,
+            ore: ore.max(0.0),
+            fuel: fuel.max(0.0),
+            components: components.max(0.0),
+            intel_zone: None,
+            remaining_seconds: WRECK_LIFETIME_SECONDS,
+        }
+    }
+
+    pub fn with_intel(mut self, zone: u32) -> Self {
+        self.intel_zone = Some(zone);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ore <= 0.0 && self.fuel <= 0.0 && self.components <= 0.0 && self.intel_zone.is_none()
+    }
+
+    /// Strip one step of salvage, limited by the room the salvager has.
+    /// Ore and components only leave the wreck as whole units.
+    pub fn salvage(
+        &mut self,
+        delta_seconds: f32,
+        ore_room: u32,
+        fuel_room: f32,
+        component_room: u32,
+    ) -> SalvageYield {
+        let ore_before = self.ore;
+        self.ore -= mine_amount(
+            self.ore,
+            SALVAGE_RATE_PER_SECOND,
+            delta_seconds,
+            ore_room as f32,
+        );
+
+        let fuel = mine_amount(self.fuel, SALVAGE_RATE_PER_SECOND, delta_seconds, fuel_room);
+        self.fuel -= fuel;
+
+        let components_before = self.components;
+        self.components -= mine_amount(
+            self.components,
+            SALVAGE_RATE_PER_SECOND,
+            delta_seconds,
+            component_room as f32,
+        );
+
+        SalvageYield {
+            ore: whole_units_taken(ore_before, self.ore),
+            fuel,
+            components: whole_units_taken(components_before, self.components),
+            intel_zone: self.intel_zone.take(),
+        }
+    }
+}
+
+/// What one salvage step pulled out of a wreck
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SalvageYield {
+    pub ore: u32,
+    pub fuel: f32,
+    pub components: u32,
+    pub intel_zone: Option<u32>,
+}
+
+/// Components recoverable from a hull of the given size
+pub fn wreck_components(hull: &Hull) -> f32 {
+    (hull.max * WRECK_COMPONENT_FRACTION).floor()
+}
+
+/// Whole units that crossed out of a fractional stock between two readings
+fn whole_units_taken(before: f32, after: f32) -> u32 {
+    (before.ceil() - after.max(0.0).ceil()).max(0.0) as u32
+}
+
+/// Apply a hit: shields absorb first, armor blunts what reaches the hull.
+pub fn apply_damage(hull: &mut Hull, shields: Option<&mut Shields>, amount: f32) -> DamageResult {
+    let mut remaining = amount.max(0.0);
+    let mut result = DamageResult::default();
+
+    if let Some(shields) = shields {
+        let absorbed = remaining.min(shields.current.max(0.0));
+        shields.current -= absorbed;
+        remaining -= absorbed;
+        result.absorbed = absorbed;
+    }
+
+    if remaining > 0.0 {
+        let through = (remaining - hull.armor).max(remaining * MIN_ARMOR_PENETRATION);
+        let dealt = through.min(hull.current.max(0.0));
+        hull.current -= dealt;
+        result.hull_damage = dealt;
+    }
+
+    result
+}
+
+pub fn ship_hull(kind: ShipKind) -> Hull {
+    match kind {
+        ShipKind::PlayerShip => Hull::new(100.0, 2.0),
+        ShipKind::Scout => Hull::new(40.0, 0.0),
+        ShipKind::Miner => Hull::new(120.0, 4.0),
+        ShipKind::Security => Hull::new(150.0, 5.0),
+    }
+}
+
+/// A boarded raider refitted as a Security ship, still badly holed
+pub fn captured_hull() -> Hull {
+    let mut hull = ship_hull(ShipKind::Security);
+    hull.current = hull.max * CAPTURED_HULL_FRACTION;
+    hull
+}
+
+pub fn ship_shields(kind: ShipKind) -> Shields {
+    match kind {
+        ShipKind::PlayerShip => Shields::new(50.0, 5.0),
+        ShipKind::Scout => Shields::new(20.0, 3.0),
+        ShipKind::Miner => Shields::new(20.0, 2.0),
+        ShipKind::Security => Shields::new(80.0, 6.0),
+    }
+}
+
+pub fn station_hull(kind: StationKind) -> Hull {
+    match kind {
+        StationKind::MiningOutpost => Hull::new(200.0, 5.0),
+        StationKind::FuelDepot => Hull::new(180.0, 4.0),
+        StationKind::SensorStation => Hull::new(120.0, 2.0),
+        StationKind::Shipyard => Hull::new(400.0, 8.0),
+        StationKind::Refinery => Hull::new(300.0, 6.0),
+        StationKind::Outpost => Hull::new(250.0, 5.0),
+    }
+}
+
+pub fn station_shields(kind: StationKind) -> Shields {
+    match kind {
+        StationKind::MiningOutpost => Shields::new(40.0, 2.0),
+        StationKind::FuelDepot => Shields::new(40.0, 2.0),
+        StationKind::SensorStation => Shields::new(60.0, 4.0),
+        StationKind::Shipyard => Shields::new(100.0, 5.0),
+        StationKind::Refinery => Shields::new(60.0, 3.0),
+        StationKind::Outpost => Shields::new(60.0, 3.0),
+    }
+}
+
+/// An escape pod is unshielded and barely holds together
+pub fn escape_pod_hull() -> Hull {
+    Hull::new(15.0, 0.0)
+}
+
+pub fn pirate_hull() -> Hull {
+    Hull::new(60.0, 1.0)
+}
+
+pub fn pirate_shields() -> Shields {
+    Shields::new(20.0, 2.0)
+}
+
+/// Weapon families, each with its own range, pacing and running cost
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeaponKind {
+    Kinetic,
+    Beam,
+    Missile,
+}
+
+impl WeaponKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            WeaponKind::Kinetic => "Kinetic",
+            WeaponKind::Beam => "Beam",
+            WeaponKind::Missile => "Missile",
+        }
+    }
+}
+
+/// Fixed characteristics shared by every weapon of a kind
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeaponProfile {
+    pub damage: f32,
+    pub range: f32,
+    pub projectile_speed: f32,
+    pub cooldown_seconds: f32,
+    /// Ship fuel burned per shot
+    pub fuel_cost: f32,
+    /// Rounds consumed per shot
+    pub ammo_cost: u32,
+    /// Whether shots steer toward their target
+    pub homing: bool,
+}
+
+pub fn weapon_profile(kind: WeaponKind) -> WeaponProfile {
+    match kind {
+        WeaponKind::Kinetic => WeaponProfile {
+            damage: 12.0,
+            range: 220.0,
+            projectile_speed: 420.0,
+            cooldown_seconds: 0.35,
+            fuel_cost: 0.0,
+            ammo_cost: 1,
+            homing: false,
+        },
+        WeaponKind::Beam => WeaponProfile {
+            damage: 8.0,
+            range: 160.0,
+            projectile_speed: 900.0,
+            cooldown_seconds: 0.2,
+            fuel_cost: 0.5,
+            ammo_cost: 0,
+            homing: false,
+        },
+        WeaponKind::Missile => WeaponProfile {
+            damage: 40.0,
+            range: 320.0,
+            projectile_speed: 180.0,
+            cooldown_seconds: 2.5,
+            fuel_cost: 0.0,
+            ammo_cost: 1,
+            homing: true,
+        },
+    }
+}
+
+/// Why a weapon could not fire
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FireBlocked {
+    Cooldown,
+    NoAmmo,
+    NoFuel,
+}
+
+/// A single mounted weapon and its magazine
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weapon {
+    pub kind: WeaponKind,
+    pub cooldown_remaining: f32,
+    pub ammo: u32,
+}
+
+impl Weapon {
+    pub fn new(kind: WeaponKind, ammo: u32) -> Self {
+        Self {
+            kind,
+            cooldown_remaining: 0.0,
+            ammo,
+        }
+    }
+
+    pub fn tick(&mut self, delta_seconds: f32) {
+        self.cooldown_remaining = (self.cooldown_remaining - delta_seconds).max(0.0);
+    }
+
+    /// Spend ammo and start the cooldown. Fuel is checked here but burned by the caller.
+    pub fn fire(&mut self, available_fuel: f32) -> Result<WeaponProfile, FireBlocked> {
+        let profile = weapon_profile(self.kind);
+
+        if self.cooldown_remaining > 0.0 {
+            return Err(FireBlocked::Cooldown);
+        }
+        if self.ammo < profile.ammo_cost {
+            return Err(FireBlocked::NoAmmo);
+        }
+        if available_fuel < profile.fuel_cost {
+            return Err(FireBlocked::NoFuel);
+        }
+
+        self.ammo -= profile.ammo_cost;
+        self.cooldown_remaining = profile.cooldown_seconds;
+        Ok(profile)
+    }
+
+    /// Short description of the weapon and what it has left to fire
+    pub fn status(&self) -> String {
+        if weapon_profile(self.kind).ammo_cost == 0 {
+            format!("{} (fuel)", self.kind.label())
+        } else {
+            format!("{} ({} rounds)", self.kind.label(), self.ammo)
+        }
+    }
+}
+
+/// Weapons carried by a ship, with the one currently selected for firing.
+#[derive(Component, Debug, Clone)]
+pub struct Armament {
+    pub weapons: Vec<Weapon>,
+    pub selected: usize,
+}
+
+impl Armament {
+    pub fn new(weapons: Vec<Weapon>) -> Self {
+        Self {
+            weapons,
+            selected: 0,
+        }
+    }
+
+    pub fn selected(&self) -> Option<&Weapon> {
+        self.weapons.get(self.selected)
+    }
+
+    pub fn selected_mut(&mut self) -> Option<&mut Weapon> {
+        self.weapons.get_mut(self.selected)
+    }
+
+    /// Switch to the next weapon, wrapping around
+    pub fn cycle(&mut self) -> Option<&Weapon> {
+        if self.weapons.is_empty() {
+            return None;
+        }
+        self.selected = (self.selected + 1) % self.weapons.len();
+        self.selected()
+    }
+}
+
+pub fn player_armament() -> Armament {
+    Armament::new(vec![
+        Weapon::new(WeaponKind::Kinetic, 120),
+        Weapon::new(WeaponKind::Beam, 0),
+        Weapon::new(WeaponKind::Missile, 6),
+    ])
+}
+
+pub fn pirate_armament() -> Armament {
+    Armament::new(vec![Weapon::new(WeaponKind::Kinetic, 200)])
+}
+
+pub fn security_armament() -> Armament {
+    Armament::new(vec![Weapon::new(WeaponKind::Kinetic, 240)])
+}
+
+/// Station point defense runs off the station's own fuel
+pub fn station_armament() -> Armament {
+    Armament::new(vec![Weapon::new(WeaponKind::Beam, 0)])
+}
+
+/// A shot in flight. Moves every fixed tick and damages the first hostile hull it crosses.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Projectile {
+    pub kind: WeaponKind,
+    pub source: DamageSource,
+    /// Entity that fired the shot, never hit by it
+    pub shooter: Entity,
+    pub velocity: Vec2,
+    pub damage: f32,
+    pub remaining_range: f32,
+    /// Homing target, only set for guided weapons
+    pub target: Option<Entity>,
+}
+
+impl Projectile {
+    pub fn launch(
+        profile: WeaponProfile,
+        kind: WeaponKind,
+        source: DamageSource,
+        shooter: Entity,
+        direction: Vec2,
+        target: Option<Entity>,
+    ) -> Self {
+        Self {
+            kind,
+            source,
+            shooter,
+            velocity: direction.normalize_or_zero() * profile.projectile_speed,
+            damage: profile.damage,
+            remaining_range: profile.range,
+            target: if profile.homing { target } else { None },
+        }
+    }
+
+    /// Whether this shot should stop at a hull belonging to a pirate or not
+    pub fn hostile_to(&self, is_pirate: bool) -> bool {
+        match self.source {
+            DamageSource::Pirate => !is_pirate,
+            _ => is_pirate,
+        }
+    }
+}
+
+/// Short-lived flash where a projectile struck something
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ImpactFlash {
+    pub kind: WeaponKind,
+    pub remaining_seconds: f32,
+}
+
+impl ImpactFlash {
+    pub fn new(kind: WeaponKind) -> Self {
+        Self {
+            kind,
+            remaining_seconds: IMPACT_FLASH_SECONDS,
+        }
+    }
+}
+
+/// Bend a missile's velocity toward its target while keeping its speed.
+pub fn homing_velocity(velocity: Vec2, to_target: Vec2, delta_seconds: f32) -> Vec2 {
+    let speed = velocity.length();
+    let desired = to_target.normalize_or_zero() * speed;
+    if desired == Vec2::ZERO {
+        return velocity;
+    }
+
+    let turn = (MISSILE_TURN_RATE * delta_seconds).clamp(0.0, 1.0);
+    velocity.lerp(desired, turn).normalize_or_zero() * speed
+}
+
+/// Fraction along the segment `start..end` where it passes closest to a circle,
+/// if that pass is inside the circle.
+pub fn segment_hit_fraction(start: Vec2, end: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    let fraction = if length_squared <= f32::EPSILON {
+        0.0
+    } else {
+        ((center - start).dot(segment) / length_squared).clamp(0.0, 1.0)
+    };
+
+    let closest = start + segment * fraction;
+    if closest.distance(center) <= radius {
+        Some(fraction)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shields_absorb_before_hull() {
+        let mut hull = Hull::new(100.0, 0.0);
+        let mut shields = Shields::new(30.0, 1.0);
+
+        let result = apply_damage(&mut hull, Some(&mut shields), 20.0);
+        assert_eq!(result.absorbed, 20.0);
+        assert_eq!(result.hull_damage, 0.0);
+        assert_eq!(hull.current, 100.0);
+        assert_eq!(shields.current, 10.0);
+    }
+
+    #[test]
+    fn armor_blunts_hull_damage_but_never_fully() {
+        let mut hull = Hull::new(100.0, 5.0);
+        let result = apply_damage(&mut hull, None, 15.0);
+        assert_eq!(result.hull_damage, 10.0);
+
+        let result = apply_damage(&mut hull, None, 4.0);
+        assert_eq!(result.hull_damage, 1.0);
+    }
+
+    #[test]
+    fn hull_damage_stops_at_zero() {
+        let mut hull = Hull::new(10.0, 0.0);
+        let result = apply_damage(&mut hull, None, 50.0);
+        assert_eq!(result.hull_damage, 10.0);
+        assert_eq!(hull.condition(), HullCondition::Destroyed);
+    }
+
+    #[test]
+    fn hull_condition_follows_thresholds() {
+        let mut hull = Hull::new(100.0, 0.0);
+        assert_eq!(hull.condition(), HullCondition::Intact);
+        hull.current = 50.0;
+        assert_eq!(hull.condition(), HullCondition::Damaged);
+        hull.current = 20.0;
+        assert_eq!(hull.condition(), HullCondition::Disabled);
+    }
+
+    #[test]
+    fn hull_repair_caps_at_max() {
+        let mut hull = Hull::new(100.0, 0.0);
+        hull.current = 90.0;
+        assert_eq!(hull.repair(25.0), 10.0);
+        assert_eq!(hull.current, 100.0);
+    }
+
+    #[test]
+    fn hull_patch_spends_ore_only_on_damage() {
+        let mut hull = Hull::new(100.0, 0.0);
+        assert_eq!(hull.patch(10), 0);
+
+        hull.current = 30.0;
+        assert_eq!(hull.patch(HULL_PATCH_ORE - 1), 0);
+        assert_eq!(hull.patch(10), HULL_PATCH_ORE);
+        assert_eq!(hull.current, 30.0 + HULL_PATCH_AMOUNT);
+    }
+
+    #[test]
+    fn shields_regen_caps_at_max() {
+        let mut shields = Shields::new(20.0, 4.0);
+        shields.current = 15.0;
+        shields.regen(2.0);
+        assert_eq!(shields.current, 20.0);
+    }
+
+    #[test]
+    fn captured_raiders_start_badly_damaged() {
+        let hull = captured_hull();
+        assert_eq!(hull.max, ship_hull(ShipKind::Security).max);
+        assert_eq!(hull.condition(), HullCondition::Damaged);
+    }
+
+    #[test]
+    fn heavier_hulls_carry_more_armor() {
+        assert!(ship_hull(ShipKind::Security).armor > ship_hull(ShipKind::Scout).armor);
+        assert!(
+            station_hull(StationKind::Shipyard).max > station_hull(StationKind::SensorStation).max
+        );
+    }
+
+    #[test]
+    fn salvage_hands_out_whole_units_and_intel_once() {
+        let mut wreck = Wreck::new(WreckKind::PirateShip, 2.0, 1.0, 1.0).with_intel(4);
+
+        let first = wreck.salvage(0.25, 10, 10.0, 10);
+        assert_eq!(first.ore, 0);
+        assert_eq!(first.intel_zone, Some(4));
+        assert!((first.fuel - 0.5).abs() < 0.001);
+
+        let second = wreck.salvage(0.25, 10, 10.0, 10);
+        assert_eq!(second.ore, 1);
+        assert_eq!(second.components, 1);
+        assert_eq!(second.intel_zone, None);
+
+        wreck.salvage(1.0, 10, 10.0, 10);
+        assert!(wreck.is_empty());
+    }
+
+    #[test]
+    fn salvage_respects_cargo_room() {
+        let mut wreck = Wreck::new(WreckKind::Ship(ShipKind::Miner), 5.0, 0.0, 0.0);
+        let step = wreck.salvage(1.0, 0, 0.0, 0);
+        assert_eq!(step.ore, 0);
+        assert_eq!(wreck.ore, 5.0);
+    }
+
+    #[test]
+    fn salvage_leaves_parts_that_do_not_fit() {
+        let mut wreck = Wreck::new(WreckKind::PirateShip, 0.0, 0.0, 5.0);
+        let step = wreck.salvage(1.0, 10, 10.0, 1);
+        assert_eq!(step.components, 1);
+        assert_eq!(wreck.components, 4.0);
+    }
+
+    #[test]
+    fn weapon_fire_spends_ammo_and_starts_cooldown() {
+        let mut weapon = Weapon::new(WeaponKind::Kinetic, 1);
+        assert!(weapon.fire(0.0).is_ok());
+        assert_eq!(weapon.ammo, 0);
+        assert_eq!(weapon.fire(0.0), Err(FireBlocked::Cooldown));
+
+        weapon.tick(1.0);
+        assert_eq!(weapon.fire(0.0), Err(FireBlocked::NoAmmo));
+    }
+
+    #[test]
+    fn beams_need_fuel_not_ammo() {
+        let mut weapon = Weapon::new(WeaponKind::Beam, 0);
+        assert_eq!(weapon.fire(0.1), Err(FireBlocked::NoFuel));
+        let profile = weapon.fire(5.0).expect("beam fires with fuel");
+        assert!(profile.fuel_cost > 0.0);
+    }
+
+    #[test]
+    fn weapon_status_shows_rounds_or_fuel() {
+        assert_eq!(
+            Weapon::new(WeaponKind::Missile, 4).status(),
+            "Missile (4 rounds)"
+        );
+        assert_eq!(Weapon::new(WeaponKind::Beam, 0).status(), "Beam (fuel)");
+    }
+
+    #[test]
+    fn armament_cycle_wraps() {
+        let mut armament = player_armament();
+        assert_eq!(armament.cycle().map(|w| w.kind), Some(WeaponKind::Beam));
+        assert_eq!(armament.cycle().map(|w| w.kind), Some(WeaponKind::Missile));
+        assert_eq!(armament.cycle().map(|w| w.kind), Some(WeaponKind::Kinetic));
+    }
+
+    #[test]
+    fn only_guided_weapons_keep_a_target() {
+        let shooter = Entity::from_bits(1);
+        let target = Some(Entity::from_bits(2));
+        let shot = Projectile::launch(
+            weapon_profile(WeaponKind::Kinetic),
+            WeaponKind::Kinetic,
+            DamageSource::Player,
+            shooter,
+            Vec2::X,
+            target,
+        );
+        assert_eq!(shot.target, None);
+
+        let missile = Projectile::launch(
+            weapon_profile(WeaponKind::Missile),
+            WeaponKind::Missile,
+            DamageSource::Player,
+            shooter,
+            Vec2::X,
+            target,
+        );
+        assert_eq!(missile.target, target);
+    }
+
+    #[test]
+    fn projectiles_only_stop_at_the_other_side() {
+        let shot = Projectile::launch(
+            weapon_profile(WeaponKind::Kinetic),
+            WeaponKind::Kinetic,
+            DamageSource::Pirate,
+            Entity::from_bits(1),
+            Vec2::X,
+            None,
+        );
+        assert!(shot.hostile_to(false));
+        assert!(!shot.hostile_to(true));
+    }
+
+    #[test]
+    fn homing_turns_without_changing_speed() {
+        let velocity = homing_velocity(Vec2::new(100.0, 0.0), Vec2::new(0.0, 50.0), 0.1);
+        assert!(velocity.y > 0.0);
+        assert!((velocity.length() - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn segment_hit_fraction_catches_fast_shots() {
+        let start = Vec2::new(0.0, 0.0);
+        let end = Vec2::new(100.0, 0.0);
+        let hit = segment_hit_fraction(start, end, Vec2::new(50.0, 4.0), 5.0);
+        assert!(hit.is_some_and(|fraction| (fraction - 0.5).abs() < 0.001));
+        assert_eq!(
+            segment_hit_fraction(start, end, Vec2::new(50.0, 20.0), 5.0),
+            None
+        );
+    }
+}
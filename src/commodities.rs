@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+/// Goods hauled, stored, processed and traded across the sector
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum Commodity {
+    RawOre,
+    FuelOre,
+    Alloys,
+    Components,
+    RareIsotopes,
+}
+
+/// Every commodity, in display order
+pub const COMMODITIES: [Commodity; 5] = [
+    Commodity::RawOre,
+    Commodity::FuelOre,
+    Commodity::Alloys,
+    Commodity::Components,
+    Commodity::RareIsotopes,
+];
+
+/// Registry entry for a commodity
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CommoditySpec {
+    pub name: &'static str,
+    /// Hold mass one unit takes up
+    pub mass: f32,
+    /// List price in credits per unit
+    pub base_price: u32,
+}
+
+impl Commodity {
+    pub fn spec(self) -> CommoditySpec {
+        let (name, mass, base_price) = match self {
+            Commodity::RawOre => ("Raw ore", 1.0, 2),
+            Commodity::FuelOre => ("Fuel ore", 1.0, 3),
+            Commodity::Alloys => ("Alloys", 2.0, 12),
+            Commodity::Components => ("Components", 0.5, 20),
+            Commodity::RareIsotopes => ("Rare isotopes", 0.25, 60),
+        };
+        CommoditySpec {
+            name,
+            mass,
+            base_price,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        self.spec().name
+    }
+
+    pub fn mass(self) -> f32 {
+        self.spec().mass
+    }
+}
+
+/// A hold of commodities bounded by total mass
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Inventory {
+    items: BTreeMap<Commodity, f32>,
+    pub mass_capacity: f32,
+}
+
+impl Inventory {
+    pub fn new(mass_capacity: f32) -> Self {
+        Self {
+            items: BTreeMap::new(),
+            mass_capacity,
+        }
+    }
+
+    pub fn amount(&self, commodity: Commodity) -> f32 {
+        self.items.get(&commodity).copied().unwrap_or(0.0)
+    }
+
+    /// Whole units of a commodity held
+    pub fn whole(&self, commodity: Commodity) -> u32 {
+        self.amount(commodity).max(0.0).floor() as u32
+    }
+
+    /// Mass of everything held
+    pub fn mass(&self) -> f32 {
+        self.items
+            .iter()
+            .map(|(commodity, amount)| commodity.mass() * amount)
+            .sum()
+    }
+
+    pub fn free_mass(&self) -> f32 {
+        (self.mass_capacity - self.mass()).max(0.0)
+    }
+
+    /// Units of a commodity that still fit
+    pub fn room_for(&self, commodity: Commodity) -> f32 {
+        self.free_mass() / commodity.mass()
+    }
+
+    /// Units of a commodity the hold could take if it were empty
+    pub fn capacity_for(&self, commodity: Commodity) -> f32 {
+        self.mass_capacity / commodity.mass()
+    }
+
+    /// Put up to `amount` in the hold. Returns the amount stored.
+    pub fn add(&mut self, commodity: Commodity, amount: f32) -> f32 {
+        let stored = amount.clamp(0.0, self.room_for(commodity));
+        if stored > 0.0 {
+            *self.items.entry(commodity).or_insert(0.0) += stored;
+        }
+        stored
+    }
+
+    /// Take up to `amount` out of the hold. Returns the amount taken.
+    pub fn remove(&mut self, commodity: Commodity, amount: f32) -> f32 {
+        let held = self.amount(commodity);
+        let taken = amount.clamp(0.0, held);
+        if held - taken <= f32::EPSILON {
+            self.items.remove(&commodity);
+        } else {
+            self.items.insert(commodity, held - taken);
+        }
+        taken
+    }
+
+    /// Commodities held, in registry order
+    pub fn iter(&self) -> impl Iterator<Item = (Commodity, f32)> + '_ {
+        self.items
+            .iter()
+            .map(|(commodity, amount)| (*commodity, *amount))
+    }
+
+    /// One-line listing such as `Raw ore 12, Alloys 3` of the whole units
+    /// held, leaving out `skip`. `None` when nothing else is held.
+    pub fn summary(&self, skip: &[Commodity]) -> Option<String> {
+        let parts: Vec<String> = self
+            .iter()
+            .filter(|(commodity, amount)| *amount >= 1.0 && !skip.contains(commodity))
+            .map(|(commodity, amount)| format!("{} {}", commodity.name(), amount.floor()))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Commodity, Inventory, COMMODITIES};
+
+    #[test]
+    fn registry_lists_every_commodity_once() {
+        for (index, commodity) in COMMODITIES.iter().enumerate() {
+            assert!(!COMMODITIES[index + 1..].contains(commodity));
+            assert!(commodity.mass() > 0.0);
+            assert!(commodity.spec().base_price > 0);
+        }
+    }
+
+    #[test]
+    fn raw_ore_is_one_mass_per_unit() {
+        let hold = Inventory::new(50.0);
+        assert_eq!(hold.room_for(Commodity::RawOre), 50.0);
+    }
+
+    #[test]
+    fn add_respects_mass_capacity() {
+        let mut hold = Inventory::new(10.0);
+        assert_eq!(hold.add(Commodity::Alloys, 3.0), 3.0);
+        // 6 mass used by alloys leaves room for 4 ore
+        assert_eq!(hold.add(Commodity::RawOre, 10.0), 4.0);
+        assert_eq!(hold.free_mass(), 0.0);
+    }
+
+    #[test]
+    fn light_goods_pack_densely() {
+        let mut hold = Inventory::new(10.0);
+        assert_eq!(hold.add(Commodity::RareIsotopes, 100.0), 40.0);
+    }
+
+    #[test]
+    fn remove_drops_emptied_entries() {
+        let mut hold = Inventory::new(50.0);
+        hold.add(Commodity::RawOre, 5.0);
+        assert_eq!(hold.remove(Commodity::RawOre, 8.0), 5.0);
+        assert_eq!(hold.iter().count(), 0);
+        assert_eq!(hold.remove(Commodity::FuelOre, 1.0), 0.0);
+    }
+
+    #[test]
+    fn whole_rounds_down() {
+        let mut hold = Inventory::new(50.0);
+        hold.add(Commodity::RawOre, 7.8);
+        assert_eq!(hold.whole(Commodity::RawOre), 7);
+    }
+
+    #[test]
+    fn summary_lists_whole_units_in_registry_order() {
+        let mut hold = Inventory::new(50.0);
+        assert_eq!(hold.summary(&[]), None);
+        hold.add(Commodity::Alloys, 2.0);
+        hold.add(Commodity::RawOre, 12.4);
+        hold.add(Commodity::Components, 0.5);
+        assert_eq!(hold.summary(&[]).as_deref(), Some("Raw ore 12, Alloys 2"));
+        assert_eq!(
+            hold.summary(&[Commodity::RawOre]).as_deref(),
+            Some("Alloys 2")
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use bevy::prelude::*;
+
+use crate::stations::{CrisisStage, CrisisType, StationKind};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Seconds a fresh crisis (or one the player just intervened in) is shielded
+/// from escalation
+pub const CRISIS_GRACE_SECONDS: f32 = 30.0;
+
+/// Seconds of sustained, heavier pressure before a crisis escalates a stage
+pub const CRISIS_ESCALATION_SECONDS: f32 = 45.0;
+
+/// Seconds of relief before a crisis steps down a stage
+pub const CRISIS_RECOVERY_SECONDS: f32 = 20.0;
+
+/// Seconds a station can run unattended before its automation starts drifting
+pub const DRIFT_STRAIN_SECONDS: f32 = 300.0;
+
+/// Seconds unattended before drift turns into outright failure
+pub const DRIFT_FAIL_SECONDS: f32 = 600.0;
+
+/// How close the player or a fleet ship must come to count as a visit
+pub const DRIFT_VISIT_RANGE: f32 = 40.0;
+
+/// Stored ore a drifting station mis-routes per minute, per stage
+pub const DRIFT_MISROUTED_ORE_PER_MINUTE: f32 = 2.0;
+
+/// Mis-routed ore that piles up before it is jettisoned as a wreck
+pub const DRIFT_SPILL_ORE: f32 = 5.0;
+
+/// How far from the station jettisoned ore comes to rest
+pub const DRIFT_SPILL_OFFSET: f32 = 30.0;
+
+/// Player stations within this distance of an independent Outpost provoke it
+pub const FLASHPOINT_RANGE: f32 = 320.0;
+
+/// Routes at or below this risk carry pirate pressure out of a lawless zone
+pub const CASCADE_ROUTE_RISK: f32 = 0.3;
+
+// =============================================================================
+// Crisis Lifecycle
+// =============================================================================
+
+/// Something the player can do to ease a crisis
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CrisisResponse {
+    DeliverFuel,
+    DriveOffRaiders,
+    RepairHull,
+    VisitStation,
+    TradeAtOutpost,
+    WithdrawExpansion,
+    DeliverOre,
+}
+
+impl CrisisResponse {
+    pub fn label(self) -> &'static str {
+        match self {
+            CrisisResponse::DeliverFuel => "deliver fuel",
+            CrisisResponse::DriveOffRaiders => "drive off raiders",
+            CrisisResponse::RepairHull => "repair the hull",
+            CrisisResponse::VisitStation => "visit or send a ship",
+            CrisisResponse::TradeAtOutpost => "trade at the outpost",
+            CrisisResponse::WithdrawExpansion => "scale back nearby stations",
+            CrisisResponse::DeliverOre => "deliver ore",
+        }
+    }
+}
+
+/// Player actions that de-escalate a crisis of this type
+pub fn crisis_responses(crisis_type: CrisisType) -> &'static [CrisisResponse] {
+    match crisis_type {
+        CrisisType::FuelShortage => &[CrisisResponse::DeliverFuel],
+        CrisisType::PirateHarassment => &[CrisisResponse::DriveOffRaiders],
+        CrisisType::HullBreach => &[CrisisResponse::RepairHull],
+        CrisisType::AutomationDrift => &[CrisisResponse::VisitStation],
+        CrisisType::PoliticalFlashpoint => &[
+            CrisisResponse::TradeAtOutpost,
+            CrisisResponse::WithdrawExpansion,
+        ],
+        CrisisType::SupplyShortage => &[CrisisResponse::DeliverOre],
+    }
+}
+
+/// Pressure a provider passes on to the stations depending on it: outright
+/// failure starves them, a failing crisis strains them.
+pub fn cascade_stage(
+    provider_failed: bool,
+    provider_stage: Option<CrisisStage>,
+) -> Option<CrisisStage> {
+    if provider_failed {
+        Some(CrisisStage::Failing)
+    } else if provider_stage == Some(CrisisStage::Failing) {
+        Some(CrisisStage::Strained)
+    } else {
+        None
+    }
+}
+
+/// Drift pressure on a station left unattended for this long
+pub fn drift_pressure(unvisited_seconds: f32) -> Option<CrisisStage> {
+    if unvisited_seconds >= DRIFT_FAIL_SECONDS {
+        Some(CrisisStage::Failing)
+    } else if unvisited_seconds >= DRIFT_STRAIN_SECONDS {
+        Some(CrisisStage::Strained)
+    } else {
+        None
+    }
+}
+
+/// Fuel burn multiplier for a station whose drifting automation leaks fuel
+pub fn drift_fuel_leak(stage: Option<CrisisStage>) -> f32 {
+    match stage {
+        Some(CrisisStage::Strained) => 1.5,
+        Some(CrisisStage::Failing) => 2.5,
+        Some(CrisisStage::Stable | CrisisStage::Resolved) | None => 1.0,
+    }
+}
+
+/// Protest pressure on an Outpost from the player stations built near it
+pub fn flashpoint_pressure(nearby_player_stations: u32) -> Option<CrisisStage> {
+    match nearby_player_stations {
+        0 => None,
+        1 => Some(CrisisStage::Strained),
+        _ => Some(CrisisStage::Failing),
+    }
+}
+
+/// How a crisis ended
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum CrisisResolution {
+    /// Pressure eased and the station recovered
+    Stabilized,
+    /// The player walked away from or decommissioned the station
+    Abandoned,
+    /// The station was destroyed while the crisis was open
+    CollapsedIntoWreck,
+    /// The player restarted the failed station, starting it over clean
+    Restarted,
+}
+
+impl CrisisResolution {
+    pub fn label(self) -> &'static str {
+        match self {
+            CrisisResolution::Stabilized => "stabilized",
+            CrisisResolution::Abandoned => "abandoned",
+            CrisisResolution::CollapsedIntoWreck => "collapsed into a wreck",
+            CrisisResolution::Restarted => "reset by a restart",
+        }
+    }
+}
+
+/// An open crisis at a station. Producers only report pressure; the crisis
+/// itself decides when to escalate or recover.
+#[derive(Component, Clone, Debug)]
+pub struct Crisis {
+    pub station: Entity,
+    pub station_kind: StationKind,
+    pub crisis_type: CrisisType,
+    pub stage: CrisisStage,
+    pub peak_stage: CrisisStage,
+    pub onset_tick: u64,
+    pub elapsed_seconds: f32,
+    /// Remaining seconds during which the crisis cannot escalate
+    pub grace_seconds: f32,
+    /// Seconds spent under heavier pressure than the current stage
+    pub escalation_seconds: f32,
+    /// Seconds spent under lighter pressure than the current stage
+    pub recovery_seconds: f32,
+}
+
+impl Crisis {
+    /// Open a crisis. Every crisis starts Strained with a grace period,
+    /// however bad things already are.
+    pub fn new(
+        station: Entity,
+        station_kind: StationKind,
+        crisis_type: CrisisType,
+        onset_tick: u64,
+    ) -> Self {
+        Self {
+            station,
+            station_kind,
+            crisis_type,
+            stage: CrisisStage::Strained,
+            peak_stage: CrisisStage::Strained,
+            onset_tick,
+            elapsed_seconds: 0.0,
+            grace_seconds: CRISIS_GRACE_SECONDS,
+            escalation_seconds: 0.0,
+            recovery_seconds: 0.0,
+        }
+    }
+
+    /// Advance the crisis clocks against the pressure currently reported for
+    /// its type. Returns the new stage if it changed.
+    pub fn advance(
+        &mut self,
+        delta_seconds: f32,
+        pressure: Option<CrisisStage>,
+    ) -> Option<CrisisStage> {
+        self.elapsed_seconds += delta_seconds;
+        self.grace_seconds = (self.grace_seconds - delta_seconds).max(0.0);
+
+        let pressure = pressure.map_or(0, |stage| stage.severity());
+        let current = self.stage.severity();
+
+        if pressure > current {
+            self.recovery_seconds = 0.0;
+
+            // A stabilizing crisis relapses as soon as pressure returns
+            if self.stage == CrisisStage::Stable {
+                self.escalation_seconds = 0.0;
+                return self.move_to(CrisisStage::Strained);
+            }
+
+            if self.grace_seconds > 0.0 {
+                return None;
+            }
+
+            self.escalation_seconds += delta_seconds;
+            if self.escalation_seconds < CRISIS_ESCALATION_SECONDS {
+                return None;
+            }
+            self.escalation_seconds = 0.0;
+            return self.move_to(CrisisStage::Failing);
+        }
+
+        self.escalation_seconds = 0.0;
+        if pressure == current && self.stage != CrisisStage::Stable {
+            self.recovery_seconds = 0.0;
+            return None;
+        }
+
+        self.recovery_seconds += delta_seconds;
+        if self.recovery_seconds < CRISIS_RECOVERY_SECONDS {
+            return None;
+        }
+        self.recovery_seconds = 0.0;
+
+        let next = match self.stage {
+            CrisisStage::Failing => CrisisStage::Strained,
+            CrisisStage::Strained => CrisisStage::Stable,
+            CrisisStage::Stable | CrisisStage::Resolved => CrisisStage::Resolved,
+        };
+        self.move_to(next)
+    }
+
+    /// Player intervention: knocks a failing crisis back to Strained and
+    /// buys another grace period. Returns true if the stage dropped.
+    pub fn intervene(&mut self) -> bool {
+        self.grace_seconds = CRISIS_GRACE_SECONDS;
+        self.escalation_seconds = 0.0;
+
+        if self.stage == CrisisStage::Failing {
+            self.stage = CrisisStage::Strained;
+            self.recovery_seconds = 0.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.stage == CrisisStage::Resolved
+    }
+
+    /// Close the crisis into a history record
+    pub fn record(&self, resolved_tick: u64, resolution: CrisisResolution) -> CrisisRecord {
+        CrisisRecord {
+            crisis_type: self.crisis_type,
+            station_kind: self.station_kind,
+            onset_tick: self.onset_tick,
+            resolved_tick,
+            peak_stage: self.peak_stage,
+            resolution,
+        }
+    }
+
+    fn move_to(&mut self, stage: CrisisStage) -> Option<CrisisStage> {
+        if stage == self.stage {
+            return None;
+        }
+        self.stage = stage;
+        if stage.severity() > self.peak_stage.severity() {
+            self.peak_stage = stage;
+        }
+        Some(stage)
+    }
+}
+
+// =============================================================================
+// History
+// =============================================================================
+
+/// A crisis that has ended
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CrisisRecord {
+    pub crisis_type: CrisisType,
+    pub station_kind: StationKind,
+    pub onset_tick: u64,
+    pub resolved_tick: u64,
+    pub peak_stage: CrisisStage,
+    pub resolution: CrisisResolution,
+}
+
+/// Every crisis resolved this run, kept for the end-of-run summary
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CrisisHistory {
+    pub records: Vec<CrisisRecord>,
+}
+
+impl CrisisHistory {
+    pub fn push(&mut self, record: CrisisRecord) {
+        self.records.push(record);
+    }
+
+    pub fn count(&self, resolution: CrisisResolution) -> usize {
+        self.records
+            .iter()
+            .filter(|record| record.resolution == resolution)
+            .count()
+    }
+
+    /// One-line tally of outcomes, e.g. for the run-over screen
+    pub fn summary(&self) -> String {
+        format!(
+            "Crises: {} stabilized, {} abandoned, {} restarted, {} lost",
+            self.count(CrisisResolution::Stabilized),
+            self.count(CrisisResolution::Abandoned),
+            self.count(CrisisResolution::Restarted),
+            self.count(CrisisResolution::CollapsedIntoWreck)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fuel_crisis() -> Crisis {
+        Crisis::new(
+            Entity::PLACEHOLDER,
+            StationKind::MiningOutpost,
+            CrisisType::FuelShortage,
+            7,
+        )
+    }
+
+    fn run(crisis: &mut Crisis, seconds: f32, pressure: Option<CrisisStage>) {
+        let steps = (seconds / 0.5).ceil() as u32;
+        for _ in 0..steps {
+            crisis.advance(0.5, pressure);
+        }
+    }
+
+    #[test]
+    fn grace_period_holds_off_escalation() {
+        let mut crisis = fuel_crisis();
+        run(
+            &mut crisis,
+            CRISIS_GRACE_SECONDS - 1.0,
+            Some(CrisisStage::Failing),
+        );
+        assert_eq!(crisis.stage, CrisisStage::Strained);
+
+        run(
+            &mut crisis,
+            CRISIS_ESCALATION_SECONDS + 1.0,
+            Some(CrisisStage::Failing),
+        );
+        assert_eq!(crisis.stage, CrisisStage::Failing);
+        assert_eq!(crisis.peak_stage, CrisisStage::Failing);
+    }
+
+    #[test]
+    fn relief_steps_down_to_resolved() {
+        let mut crisis = fuel_crisis();
+        crisis.stage = CrisisStage::Failing;
+
+        run(&mut crisis, CRISIS_RECOVERY_SECONDS, None);
+        assert_eq!(crisis.stage, CrisisStage::Strained);
+        run(&mut crisis, CRISIS_RECOVERY_SECONDS, None);
+        assert_eq!(crisis.stage, CrisisStage::Stable);
+
+        // Pressure returning while stabilizing is an immediate relapse
+        crisis.advance(0.5, Some(CrisisStage::Strained));
+        assert_eq!(crisis.stage, CrisisStage::Strained);
+
+        run(&mut crisis, CRISIS_RECOVERY_SECONDS, None);
+        assert_eq!(crisis.stage, CrisisStage::Stable);
+        assert!(!crisis.is_resolved());
+        run(&mut crisis, CRISIS_RECOVERY_SECONDS, None);
+        assert!(crisis.is_resolved());
+    }
+
+    #[test]
+    fn intervention_drops_a_stage_and_restores_grace() {
+        let mut crisis = fuel_crisis();
+        crisis.stage = CrisisStage::Failing;
+        crisis.grace_seconds = 0.0;
+
+        assert!(crisis.intervene());
+        assert_eq!(crisis.stage, CrisisStage::Strained);
+        assert_eq!(crisis.grace_seconds, CRISIS_GRACE_SECONDS);
+        assert!(!crisis.intervene());
+    }
+
+    #[test]
+    fn drift_and_flashpoint_pressure_thresholds() {
+        assert_eq!(drift_pressure(DRIFT_STRAIN_SECONDS - 1.0), None);
+        assert_eq!(
+            drift_pressure(DRIFT_STRAIN_SECONDS),
+            Some(CrisisStage::Strained)
+        );
+        assert_eq!(
+            drift_pressure(DRIFT_FAIL_SECONDS),
+            Some(CrisisStage::Failing)
+        );
+        assert!(drift_fuel_leak(Some(CrisisStage::Failing)) > drift_fuel_leak(None));
+
+        assert_eq!(flashpoint_pressure(0), None);
+        assert_eq!(flashpoint_pressure(1), Some(CrisisStage::Strained));
+        assert_eq!(flashpoint_pressure(3), Some(CrisisStage::Failing));
+    }
+
+    #[test]
+    fn cascades_weaken_as_they_spread() {
+        assert_eq!(cascade_stage(true, None), Some(CrisisStage::Failing));
+        assert_eq!(
+            cascade_stage(false, Some(CrisisStage::Failing)),
+            Some(CrisisStage::Strained)
+        );
+        assert_eq!(cascade_stage(false, Some(CrisisStage::Strained)), None);
+        assert_eq!(cascade_stage(false, None), None);
+    }
+
+    #[test]
+    fn history_tallies_resolutions() {
+        let crisis = fuel_crisis();
+        let mut history = CrisisHistory::default();
+        history.push(crisis.record(40, CrisisResolution::Stabilized));
+        history.push(crisis.record(90, CrisisResolution::CollapsedIntoWreck));
+        history.push(crisis.record(120, CrisisResolution::Restarted));
+
+        assert_eq!(history.count(CrisisResolution::Stabilized), 1);
+        assert_eq!(history.records[1].onset_tick, 7);
+        assert_eq!(
+            history.summary(),
+            "Crises: 1 stabilized, 0 abandoned, 1 restarted, 1 lost"
+        );
+    }
+}
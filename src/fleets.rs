@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use std::collections::HashSet;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Hash, Default, serde::Serialize, serde::Deserialize,
+)]
 pub enum RiskTolerance {
     Cautious,
     #[default]
@@ -48,6 +50,7 @@ pub enum ContactType {
     Ship,
     PirateShip,
     PirateBase,
+    Wreck,
 }
 
 /// A contact discovered by the scout
@@ -89,6 +92,17 @@ pub const SCAN_DURATION_SECONDS: f32 = 5.0;
 /// Distance required to identify a contact
 pub const IDENTIFY_RANGE: f32 = 150.0;
 
+/// How far from its post a patrolling ship will chase a raider
+pub const PATROL_CHASE_RADIUS: f32 = 320.0;
+
+/// Standing orders for a Security ship: guard a post in its zone and run
+/// down raiders that stray near it
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct PatrolOrder {
+    /// The point the ship guards and falls back to
+    pub post: Vec2,
+}
+
 #[derive(Component, Debug)]
 pub struct ScoutBehavior {
     pub risk: RiskTolerance,
@@ -117,7 +131,6 @@ pub struct ScoutBehavior {
     pub pirates_detected: u32,
 }
 
-#[allow(dead_code)]
 pub fn risk_threshold(risk: RiskTolerance) -> f32 {
     match risk {
         RiskTolerance::Cautious => 0.35,
@@ -300,6 +313,21 @@ impl ScoutBehavior {
         self.gates_to_explore.first().copied()
     }
 
+    /// Get the safest gate to explore whose jump risk is within the scout's tolerance.
+    /// Gates with equal risk keep their discovery order.
+    pub fn next_gate_within_tolerance(
+        &self,
+        jump_risk: impl Fn(u32) -> f32,
+    ) -> Option<(Entity, u32)> {
+        let threshold = risk_threshold(self.risk);
+        self.gates_to_explore
+            .iter()
+            .map(|(gate, destination)| (*gate, *destination, jump_risk(*destination)))
+            .filter(|(_, _, risk)| *risk <= threshold)
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(gate, destination, _)| (gate, destination))
+    }
+
     /// Remove a gate from the exploration list (after using it or if destination was visited)
     pub fn remove_gate(&mut self, gate_entity: Entity) {
         self.gates_to_explore.retain(|(e, _)| *e != gate_entity);
@@ -925,4 +953,27 @@ mod tests {
         assert_eq!(scout.current_contact_index, 0);
         assert_eq!(scout.pirates_detected, 0);
     }
+
+    #[test]
+    fn next_gate_within_tolerance_prefers_safest_route() {
+        let mut scout = ScoutBehavior::new(100, RiskTolerance::Balanced);
+        let risky = Entity::from_bits(1);
+        let safe = Entity::from_bits(2);
+        scout.discover_gate(risky, 200);
+        scout.discover_gate(safe, 300);
+
+        let next = scout.next_gate_within_tolerance(|zone| if zone == 200 { 0.5 } else { 0.1 });
+        assert_eq!(next, Some((safe, 300)));
+    }
+
+    #[test]
+    fn next_gate_within_tolerance_skips_routes_over_threshold() {
+        let mut scout = ScoutBehavior::new(100, RiskTolerance::Cautious);
+        scout.discover_gate(Entity::from_bits(1), 200);
+
+        assert_eq!(scout.next_gate_within_tolerance(|_| 0.5), None);
+
+        scout.risk = RiskTolerance::Bold;
+        assert!(scout.next_gate_within_tolerance(|_| 0.5).is_some());
+    }
 }
@@ -0,0 +1,334 @@
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::commodities::Commodity;
+
+/// Why credits, ore or fuel changed hands
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum LedgerCategory {
+    /// Buying and selling at Outposts and on the black market
+    Trade,
+    /// Stations, hulls and production jobs paid for, and what they produce
+    Construction,
+    /// Cancelled orders, abandoned stations and salvaged wrecks
+    Refund,
+    /// Spoils taken from pirates
+    Bounty,
+    /// Repairs, restarts and other running costs of the player's stations
+    Upkeep,
+    /// Supplies the player moves between their own ship and their own stations
+    Transfer,
+}
+
+/// Every category, in display order
+pub const LEDGER_CATEGORIES: [LedgerCategory; 6] = [
+    LedgerCategory::Trade,
+    LedgerCategory::Construction,
+    LedgerCategory::Refund,
+    LedgerCategory::Bounty,
+    LedgerCategory::Upkeep,
+    LedgerCategory::Transfer,
+];
+
+impl LedgerCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            LedgerCategory::Trade => "Trade",
+            LedgerCategory::Construction => "Construction",
+            LedgerCategory::Refund => "Refund",
+            LedgerCategory::Bounty => "Bounty",
+            LedgerCategory::Upkeep => "Upkeep",
+            LedgerCategory::Transfer => "Transfer",
+        }
+    }
+}
+
+/// One transaction, signed from the player's side: positive amounts came in,
+/// negative amounts went out
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LedgerEntry {
+    pub tick: u64,
+    pub counterparty: String,
+    pub category: LedgerCategory,
+    #[serde(default)]
+    pub credits: i64,
+    #[serde(default)]
+    pub ore: f32,
+    #[serde(default)]
+    pub fuel: f32,
+    /// Goods other than raw ore that changed hands
+    #[serde(default)]
+    pub goods: BTreeMap<Commodity, f32>,
+}
+
+impl LedgerEntry {
+    pub fn new(tick: u64, category: LedgerCategory, counterparty: impl Into<String>) -> Self {
+        Self {
+            tick,
+            counterparty: counterparty.into(),
+            category,
+            credits: 0,
+            ore: 0.0,
+            fuel: 0.0,
+            goods: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_credits(mut self, credits: i64) -> Self {
+        self.credits = credits;
+        self
+    }
+
+    pub fn with_ore(mut self, ore: f32) -> Self {
+        self.ore = ore;
+        self
+    }
+
+    pub fn with_fuel(mut self, fuel: f32) -> Self {
+        self.fuel = fuel;
+        self
+    }
+
+    /// Add a commodity that changed hands; raw ore counts towards `ore`
+    pub fn with_goods(mut self, commodity: Commodity, amount: f32) -> Self {
+        if commodity == Commodity::RawOre {
+            self.ore += amount;
+        } else if amount != 0.0 {
+            *self.goods.entry(commodity).or_default() += amount;
+        }
+        self
+    }
+
+    fn is_empty(&self) -> bool {
+        self.credits == 0
+            && self.ore == 0.0
+            && self.fuel == 0.0
+            && self.goods.values().all(|amount| *amount == 0.0)
+    }
+
+    /// Short description such as `-25 cr, +10 fuel`
+    pub fn amounts(&self) -> String {
+        let mut parts = Vec::new();
+        if self.credits != 0 {
+            parts.push(format!("{:+} cr", self.credits));
+        }
+        if self.ore != 0.0 {
+            parts.push(format!("{:+.0} ore", self.ore));
+        }
+        if self.fuel != 0.0 {
+            parts.push(format!("{:+.0} fuel", self.fuel));
+        }
+        for (commodity, amount) in &self.goods {
+            parts.push(format!(
+                "{:+.0} {}",
+                amount,
+                commodity.name().to_lowercase()
+            ));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Entries the ledger keeps itemised; older ones are rolled into run totals
+pub const LEDGER_ENTRY_LIMIT: usize = 1_000;
+
+/// Amounts received and spent of one resource
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Flow {
+    pub income: f32,
+    pub expense: f32,
+}
+
+impl Flow {
+    fn add(&mut self, amount: f32) {
+        if amount > 0.0 {
+            self.income += amount;
+        } else {
+            self.expense -= amount;
+        }
+    }
+
+    pub fn net(&self) -> f32 {
+        self.income - self.expense
+    }
+
+    fn merge(&mut self, other: &Flow) {
+        self.income += other.income;
+        self.expense += other.expense;
+    }
+}
+
+/// Income and expense of credits, ore and fuel over a stretch of entries
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LedgerTotals {
+    pub credits: Flow,
+    pub ore: Flow,
+    pub fuel: Flow,
+}
+
+impl LedgerTotals {
+    fn add(&mut self, entry: &LedgerEntry) {
+        self.credits.add(entry.credits as f32);
+        self.ore.add(entry.ore);
+        self.fuel.add(entry.fuel);
+    }
+
+    fn merge(&mut self, other: &LedgerTotals) {
+        self.credits.merge(&other.credits);
+        self.ore.merge(&other.ore);
+        self.fuel.merge(&other.fuel);
+    }
+}
+
+/// Every credit, ore and fuel transaction the player made this run
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Ledger {
+    pub entries: Vec<LedgerEntry>,
+    /// Per-category totals of the entries rolled off the front past
+    /// `LEDGER_ENTRY_LIMIT`; they only count towards the whole run
+    #[serde(default)]
+    pub archived: BTreeMap<LedgerCategory, LedgerTotals>,
+}
+
+impl Ledger {
+    /// Record a transaction; ones that moved nothing are dropped
+    pub fn record(&mut self, entry: LedgerEntry) {
+        if entry.is_empty() {
+            return;
+        }
+        self.entries.push(entry);
+        let overflow = self.entries.len().saturating_sub(LEDGER_ENTRY_LIMIT);
+        for old in self.entries.drain(..overflow) {
+            self.archived.entry(old.category).or_default().add(&old);
+        }
+    }
+
+    /// Totals of every entry at or after `since_tick`
+    pub fn totals(&self, since_tick: u64) -> LedgerTotals {
+        let mut totals = LedgerTotals::default();
+        for category in self.by_category(since_tick).values() {
+            totals.merge(category);
+        }
+        totals
+    }
+
+    /// Totals per category of every entry at or after `since_tick`
+    pub fn by_category(&self, since_tick: u64) -> BTreeMap<LedgerCategory, LedgerTotals> {
+        let mut totals = if since_tick == 0 {
+            self.archived.clone()
+        } else {
+            BTreeMap::new()
+        };
+        for entry in self.entries.iter().filter(|entry| entry.tick >= since_tick) {
+            totals.entry(entry.category).or_default().add(entry);
+        }
+        totals
+    }
+
+    /// The most recent entries, newest first
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = &LedgerEntry> {
+        self.entries.iter().rev().take(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ledger, LedgerCategory, LedgerEntry, LEDGER_ENTRY_LIMIT};
+    use crate::commodities::Commodity;
+
+    fn sample() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(
+            LedgerEntry::new(10, LedgerCategory::Trade, "Outpost-1")
+                .with_credits(-5)
+                .with_fuel(10.0),
+        );
+        ledger.record(
+            LedgerEntry::new(50, LedgerCategory::Trade, "Outpost-1")
+                .with_credits(20)
+                .with_ore(-10.0),
+        );
+        ledger.record(
+            LedgerEntry::new(60, LedgerCategory::Construction, "Station-Shipyard-2")
+                .with_credits(-30)
+                .with_ore(-15.0),
+        );
+        ledger
+    }
+
+    #[test]
+    fn empty_transactions_are_not_recorded() {
+        let mut ledger = Ledger::default();
+        ledger.record(LedgerEntry::new(1, LedgerCategory::Upkeep, "Depot"));
+        assert!(ledger.entries.is_empty());
+    }
+
+    #[test]
+    fn totals_split_income_from_expense_within_the_window() {
+        let ledger = sample();
+        let run = ledger.totals(0);
+        assert_eq!(run.credits.income, 20.0);
+        assert_eq!(run.credits.expense, 35.0);
+        assert_eq!(run.credits.net(), -15.0);
+        assert_eq!(run.ore.expense, 25.0);
+        assert_eq!(run.fuel.income, 10.0);
+
+        let recent = ledger.totals(50);
+        assert_eq!(recent.credits.expense, 30.0);
+        assert_eq!(recent.fuel.income, 0.0);
+    }
+
+    #[test]
+    fn by_category_groups_entries() {
+        let totals = sample().by_category(0);
+        assert_eq!(totals[&LedgerCategory::Trade].credits.net(), 15.0);
+        assert_eq!(totals[&LedgerCategory::Construction].ore.expense, 15.0);
+        assert!(!totals.contains_key(&LedgerCategory::Bounty));
+    }
+
+    #[test]
+    fn goods_are_recorded_alongside_ore() {
+        let mut ledger = Ledger::default();
+        ledger.record(
+            LedgerEntry::new(5, LedgerCategory::Transfer, "Refinery-1")
+                .with_goods(Commodity::RawOre, -4.0)
+                .with_goods(Commodity::FuelOre, -6.0),
+        );
+        ledger.record(
+            LedgerEntry::new(6, LedgerCategory::Transfer, "Refinery-1")
+                .with_goods(Commodity::Alloys, 0.0),
+        );
+
+        assert_eq!(ledger.entries.len(), 1);
+        assert_eq!(ledger.entries[0].ore, -4.0);
+        assert_eq!(ledger.entries[0].amounts(), "-4 ore, -6 fuel ore");
+    }
+
+    #[test]
+    fn old_entries_roll_into_run_totals_past_the_limit() {
+        let mut ledger = Ledger::default();
+        let recorded = LEDGER_ENTRY_LIMIT as u64 + 2;
+        for tick in 0..recorded {
+            ledger
+                .record(LedgerEntry::new(tick, LedgerCategory::Trade, "Outpost-1").with_credits(1));
+        }
+
+        assert_eq!(ledger.entries.len(), LEDGER_ENTRY_LIMIT);
+        assert_eq!(ledger.entries[0].tick, 2);
+        assert_eq!(ledger.totals(0).credits.income, recorded as f32);
+        assert_eq!(
+            ledger.by_category(0)[&LedgerCategory::Trade].credits.income,
+            recorded as f32
+        );
+        assert_eq!(ledger.totals(2).credits.income, LEDGER_ENTRY_LIMIT as f32);
+    }
+
+    #[test]
+    fn recent_lists_newest_first_with_amounts() {
+        let ledger = sample();
+        let amounts: Vec<String> = ledger.recent(2).map(|entry| entry.amounts()).collect();
+        assert_eq!(amounts, vec!["-30 cr, -15 ore", "+20 cr, -10 ore"]);
+    }
+}
@@ -1,8 +1,12 @@
 use bevy::prelude::*;
 
+mod combat;
+mod commodities;
 mod compat;
+mod crises;
 mod factions;
 mod fleets;
+mod ledger;
 mod ore;
 mod pirates;
 mod plugins;
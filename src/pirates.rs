@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::stations::StationKind;
+use crate::world::RouteEdge;
 
 #[derive(Component, Debug)]
 pub struct PirateBase {
@@ -7,28 +11,390 @@ pub struct PirateBase {
 }
 
 /// Pirate ship behavior state
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum PirateShipBehavior {
     /// Normal roaming/harassing behavior
     #[default]
     Roaming,
+    /// Decided to dock, flying to the nearest Outpost in its zone
+    HeadingToOutpost,
     /// Docked at an Outpost for resupply (flavor only)
     DockedAtOutpost { ticks_remaining: u64 },
+    /// Hold is full, hauling loot back to the nearest base
+    ReturningToBase,
+    /// Leaving a fortified zone through the gate toward `destination_zone`
+    Relocating { destination_zone: u32 },
+    /// Hull breached; drifting until repaired, boarded or destroyed
+    Disabled,
 }
 
 #[derive(Component, Debug)]
 pub struct PirateShip {
     pub speed: f32,
     pub behavior: PirateShipBehavior,
+    /// Tick before which the pirate won't decide to dock at an Outpost again
+    pub next_dock_tick: u64,
+    /// Targets that gave up loot on the last raid pulse; each engagement counts once
+    pub engaged: Vec<Entity>,
+}
+
+impl PirateShip {
+    pub fn new(speed: f32) -> Self {
+        Self {
+            speed,
+            behavior: PirateShipBehavior::default(),
+            next_dock_tick: 0,
+            engaged: Vec::new(),
+        }
+    }
+}
+
+/// Maximum combined ore and fuel a raider can carry
+pub const PIRATE_LOOT_CAPACITY: f32 = 30.0;
+
+/// Share of the hold a raider fills before it heads home to unload
+pub const PIRATE_RETURN_FRACTION: f32 = 0.8;
+
+/// Launch ticks shaved off a base's schedule per unit of delivered loot
+pub const LOOT_LAUNCH_TICKS_PER_UNIT: f32 = 4.0;
+
+/// Fraction of a raider's haul recovered when it is intercepted
+pub const INTERCEPT_RECOVERY_FRACTION: f32 = 0.5;
+
+/// Ore stripped from a raider's frame when the player scraps it, on top of its hold
+pub const SCRAP_FRAME_ORE: u32 = 12;
+
+/// Marks a boarded raider whose crew has already given up what they know
+#[derive(Component, Debug, Default)]
+pub struct Interrogated;
+
+/// Resources stolen by a raider and not yet delivered to a base
+#[derive(
+    Component, Debug, Default, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+pub struct PirateLoot {
+    pub ore: f32,
+    pub fuel: f32,
+}
+
+impl PirateLoot {
+    pub fn total(&self) -> f32 {
+        self.ore + self.fuel
+    }
+
+    pub fn free_space(&self) -> f32 {
+        (PIRATE_LOOT_CAPACITY - self.total()).max(0.0)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.free_space() <= 0.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() <= 0.0
+    }
+
+    /// Whether the haul is big enough to be worth flying home
+    pub fn should_return(&self) -> bool {
+        self.total() >= PIRATE_LOOT_CAPACITY * PIRATE_RETURN_FRACTION
+    }
+
+    /// Stow up to `amount` ore, returns the amount actually taken
+    pub fn stow_ore(&mut self, amount: f32) -> f32 {
+        let taken = amount.max(0.0).min(self.free_space());
+        self.ore += taken;
+        taken
+    }
+
+    /// Stow up to `amount` fuel, returns the amount actually taken
+    pub fn stow_fuel(&mut self, amount: f32) -> f32 {
+        let taken = amount.max(0.0).min(self.free_space());
+        self.fuel += taken;
+        taken
+    }
+
+    /// Empty the hold, returning what was carried
+    pub fn unload(&mut self) -> PirateLoot {
+        std::mem::take(self)
+    }
+}
+
+/// What destroyed a pirate ship
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PirateLossCause {
+    Player,
+    SecurityFleet,
+    StationDefense,
+}
+
+/// Kinds of target pirates have successfully raided
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum RaidTargetType {
+    MiningOutpost,
+    FuelDepot,
+    SensorStation,
+    Shipyard,
+    Refinery,
+    Ship,
+}
+
+impl RaidTargetType {
+    /// Map a station kind to its raid target type; neutral Outposts are never raided.
+    pub fn from_station_kind(kind: StationKind) -> Option<Self> {
+        match kind {
+            StationKind::MiningOutpost => Some(Self::MiningOutpost),
+            StationKind::FuelDepot => Some(Self::FuelDepot),
+            StationKind::SensorStation => Some(Self::SensorStation),
+            StationKind::Shipyard => Some(Self::Shipyard),
+            StationKind::Refinery => Some(Self::Refinery),
+            StationKind::Outpost => None,
+        }
+    }
+}
+
+/// Pirate losses in a single zone, split by cause
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ZoneLosses {
+    pub player: u32,
+    pub security: u32,
+    pub station: u32,
+}
+
+impl ZoneLosses {
+    /// Weighted danger; organised defenses scare pirates more than a lone pilot.
+    pub fn danger(&self) -> f32 {
+        self.player as f32 * 1.0 + self.security as f32 * 1.5 + self.station as f32 * 2.0
+    }
+}
+
+/// How much zone danger inflates the effective distance to a target
+const DANGER_DISTANCE_WEIGHT: f32 = 0.25;
+
+/// Sector-wide pirate memory of where they bled and what paid off
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PirateMemory {
+    pub losses: BTreeMap<u32, ZoneLosses>,
+    pub successes: BTreeMap<RaidTargetType, u32>,
+}
+
+impl PirateMemory {
+    pub fn record_loss(&mut self, zone: u32, cause: PirateLossCause) {
+        let entry = self.losses.entry(zone).or_default();
+        match cause {
+            PirateLossCause::Player => entry.player += 1,
+            PirateLossCause::SecurityFleet => entry.security += 1,
+            PirateLossCause::StationDefense => entry.station += 1,
+        }
+    }
+
+    pub fn record_success(&mut self, target: RaidTargetType) {
+        *self.successes.entry(target).or_insert(0) += 1;
+    }
+
+    pub fn zone_danger(&self, zone: u32) -> f32 {
+        self.losses.get(&zone).map_or(0.0, |losses| losses.danger())
+    }
+
+    /// Appeal of a target type in [1, 2]; types that paid off before are preferred.
+    pub fn target_appeal(&self, target: RaidTargetType) -> f32 {
+        let total: u32 = self.successes.values().sum();
+        if total == 0 {
+            return 1.0;
+        }
+        let hits = self.successes.get(&target).copied().unwrap_or(0);
+        1.0 + hits as f32 / total as f32
+    }
+
+    /// Distance inflated by the remembered danger of the zone it leads into.
+    pub fn zone_score(&self, distance: f32, zone: Option<u32>) -> f32 {
+        let danger = zone.map_or(0.0, |zone| self.zone_danger(zone));
+        distance * (1.0 + danger * DANGER_DISTANCE_WEIGHT)
+    }
+
+    /// Effective cost of pursuing a target; lower is more attractive.
+    pub fn target_score(&self, distance: f32, zone: Option<u32>, target: RaidTargetType) -> f32 {
+        self.zone_score(distance, zone) / self.target_appeal(target)
+    }
+
+    /// Zones sorted by descending danger, limited to `count`
+    pub fn most_dangerous_zones(&self, count: usize) -> Vec<(u32, f32)> {
+        let mut zones: Vec<(u32, f32)> = self
+            .losses
+            .iter()
+            .map(|(zone, losses)| (*zone, losses.danger()))
+            .collect();
+        zones.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        zones.truncate(count);
+        zones
+    }
+}
+
+/// Pick the gate destination with the lowest remembered danger, if it beats the current zone.
+pub fn choose_relocation_zone(
+    memory: &PirateMemory,
+    current_zone: u32,
+    destinations: &[u32],
+) -> Option<u32> {
+    let current = memory.zone_danger(current_zone);
+    let mut best: Option<(u32, f32)> = None;
+
+    for zone in destinations {
+        let danger = memory.zone_danger(*zone);
+        if danger >= current {
+            continue;
+        }
+        match best {
+            Some((_, best_danger)) if best_danger <= danger => {}
+            _ => best = Some((*zone, danger)),
+        }
+    }
+
+    best.map(|(zone, _)| zone)
+}
+
+/// Pirate assets and opposition counted in one zone for a control update
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZonePresence {
+    pub bases: u32,
+    pub docked: u32,
+    pub roaming: u32,
+    pub security: u32,
+}
+
+/// Control gained per base per control update
+const CONTROL_PER_BASE: f32 = 0.02;
+/// Control gained per docked pirate per control update
+const CONTROL_PER_DOCKED: f32 = 0.01;
+/// Control gained per roaming pirate per control update
+const CONTROL_PER_ROAMING: f32 = 0.005;
+/// Control lost per security patrol per control update
+const CONTROL_PER_SECURITY: f32 = 0.02;
+/// Control lost every update regardless, so abandoned territory fades
+const CONTROL_DECAY: f32 = 0.003;
+
+/// Control gained when a raid in the zone pays off
+pub const RAID_CONTROL_GAIN: f32 = 0.01;
+/// Control lost when a pirate is killed in the zone
+pub const KILL_CONTROL_LOSS: f32 = 0.05;
+
+/// Level above which pirates jam intel refresh in a zone
+pub const INTEL_BLOCK_CONTROL: f32 = 0.6;
+/// Level above which neutral outposts side with the pirates
+pub const HOSTILE_OUTPOST_CONTROL: f32 = 0.75;
+/// Level above which the player can no longer break ground in a zone
+pub const CONSTRUCTION_BLOCK_CONTROL: f32 = 0.5;
+/// Extra route risk at full control of either endpoint
+const CONTROL_ROUTE_RISK: f32 = 0.4;
+
+/// Net change in a zone's control for one update
+pub fn pirate_control_delta(presence: &ZonePresence) -> f32 {
+    presence.bases as f32 * CONTROL_PER_BASE
+        + presence.docked as f32 * CONTROL_PER_DOCKED
+        + presence.roaming as f32 * CONTROL_PER_ROAMING
+        - presence.security as f32 * CONTROL_PER_SECURITY
+        - CONTROL_DECAY
+}
+
+/// Per-zone pirate control in [0, 1]
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PirateControl {
+    pub zones: BTreeMap<u32, f32>,
+}
+
+impl PirateControl {
+    pub fn level(&self, zone: u32) -> f32 {
+        self.zones.get(&zone).copied().unwrap_or(0.0)
+    }
+
+    /// Shift a zone's control, clamped to [0, 1]; zones at zero are forgotten.
+    pub fn adjust(&mut self, zone: u32, delta: f32) {
+        let level = (self.level(zone) + delta).clamp(0.0, 1.0);
+        if level <= 0.0 {
+            self.zones.remove(&zone);
+        } else {
+            self.zones.insert(zone, level);
+        }
+    }
+
+    pub fn blocks_intel(&self, zone: u32) -> bool {
+        self.level(zone) >= INTEL_BLOCK_CONTROL
+    }
+
+    pub fn outposts_hostile(&self, zone: u32) -> bool {
+        self.level(zone) >= HOSTILE_OUTPOST_CONTROL
+    }
+
+    pub fn blocks_construction(&self, zone: u32) -> bool {
+        self.level(zone) >= CONSTRUCTION_BLOCK_CONTROL
+    }
+
+    /// Route risk raised by the more contested of its two endpoints
+    pub fn effective_route_risk(&self, route: &RouteEdge) -> f32 {
+        let control = self.level(route.from).max(self.level(route.to));
+        (route.risk + control * CONTROL_ROUTE_RISK).clamp(0.0, 1.0)
+    }
 }
 
 pub fn schedule_next_launch(current_tick: u64, interval: u64) -> u64 {
     current_tick.saturating_add(interval)
 }
 
+/// Pull a base's next launch earlier by the value of delivered loot, never before `current_tick`.
+pub fn accelerate_launch(next_launch_tick: u64, current_tick: u64, loot: &PirateLoot) -> u64 {
+    let reduction = (loot.total() * LOOT_LAUNCH_TICKS_PER_UNIT).max(0.0) as u64;
+    next_launch_tick.saturating_sub(reduction).max(current_tick)
+}
+
+/// Portion of a haul returned to whoever intercepts the raider
+pub fn intercept_recovery(loot: &PirateLoot) -> PirateLoot {
+    PirateLoot {
+        ore: loot.ore * INTERCEPT_RECOVERY_FRACTION,
+        fuel: loot.fuel * INTERCEPT_RECOVERY_FRACTION,
+    }
+}
+
+/// Only raiders knocked out of the fight can be boarded
+pub fn is_boardable(pirate: &PirateShip) -> bool {
+    pirate.behavior == PirateShipBehavior::Disabled
+}
+
+/// Ore the player gets for scrapping a boarded raider: its frame plus whatever it stole
+pub fn scrap_yield(loot: Option<&PirateLoot>) -> u32 {
+    SCRAP_FRAME_ORE + loot.map_or(0, |loot| loot.ore.max(0.0).floor() as u32)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{schedule_next_launch, PirateShipBehavior};
+    use super::{
+        accelerate_launch, choose_relocation_zone, intercept_recovery, is_boardable,
+        pirate_control_delta, schedule_next_launch, scrap_yield, PirateControl, PirateLoot,
+        PirateLossCause, PirateMemory, PirateShip, PirateShipBehavior, RaidTargetType,
+        ZonePresence, PIRATE_LOOT_CAPACITY, PIRATE_RETURN_FRACTION, SCRAP_FRAME_ORE,
+    };
+    use crate::stations::StationKind;
+    use crate::world::RouteEdge;
+
+    #[test]
+    fn only_disabled_raiders_are_boardable() {
+        let mut pirate = PirateShip::new(60.0);
+        assert!(!is_boardable(&pirate));
+
+        pirate.behavior = PirateShipBehavior::Disabled;
+        assert!(is_boardable(&pirate));
+    }
+
+    #[test]
+    fn scrapping_adds_stolen_ore_to_the_frame() {
+        assert_eq!(scrap_yield(None), SCRAP_FRAME_ORE);
+
+        let loot = PirateLoot {
+            ore: 7.6,
+            fuel: 5.0,
+        };
+        assert_eq!(scrap_yield(Some(&loot)), SCRAP_FRAME_ORE + 7);
+    }
 
     #[test]
     fn schedule_next_launch_advances_by_interval() {
@@ -62,4 +428,189 @@ mod tests {
             PirateShipBehavior::DockedAtOutpost { .. }
         ));
     }
+
+    #[test]
+    fn pirate_loot_stow_respects_capacity() {
+        let mut loot = PirateLoot::default();
+        assert_eq!(loot.stow_ore(20.0), 20.0);
+        assert_eq!(loot.stow_fuel(20.0), PIRATE_LOOT_CAPACITY - 20.0);
+        assert!(loot.is_full());
+        assert_eq!(loot.stow_ore(5.0), 0.0);
+    }
+
+    #[test]
+    fn raiders_head_home_before_the_hold_is_full() {
+        let mut loot = PirateLoot::default();
+        loot.stow_ore(PIRATE_LOOT_CAPACITY * PIRATE_RETURN_FRACTION - 1.0);
+        assert!(!loot.should_return());
+
+        loot.stow_fuel(1.0);
+        assert!(loot.should_return());
+        assert!(!loot.is_full());
+    }
+
+    #[test]
+    fn pirate_loot_unload_empties_hold() {
+        let mut loot = PirateLoot {
+            ore: 6.0,
+            fuel: 4.0,
+        };
+        let carried = loot.unload();
+        assert_eq!(carried.total(), 10.0);
+        assert!(loot.is_empty());
+    }
+
+    #[test]
+    fn accelerate_launch_scales_with_loot() {
+        let loot = PirateLoot {
+            ore: 10.0,
+            fuel: 5.0,
+        };
+        assert_eq!(accelerate_launch(500, 100, &loot), 440);
+    }
+
+    #[test]
+    fn accelerate_launch_never_before_current_tick() {
+        let loot = PirateLoot {
+            ore: 30.0,
+            fuel: 0.0,
+        };
+        assert_eq!(accelerate_launch(120, 100, &loot), 100);
+    }
+
+    #[test]
+    fn intercept_recovery_returns_half() {
+        let loot = PirateLoot {
+            ore: 10.0,
+            fuel: 4.0,
+        };
+        let recovered = intercept_recovery(&loot);
+        assert_eq!(recovered.ore, 5.0);
+        assert_eq!(recovered.fuel, 2.0);
+    }
+
+    #[test]
+    fn pirate_memory_weights_losses_by_cause() {
+        let mut memory = PirateMemory::default();
+        memory.record_loss(4, PirateLossCause::Player);
+        memory.record_loss(4, PirateLossCause::SecurityFleet);
+        memory.record_loss(4, PirateLossCause::StationDefense);
+        assert_eq!(memory.zone_danger(4), 4.5);
+        assert_eq!(memory.zone_danger(9), 0.0);
+    }
+
+    #[test]
+    fn pirate_memory_prefers_profitable_targets() {
+        let mut memory = PirateMemory::default();
+        assert_eq!(memory.target_appeal(RaidTargetType::Ship), 1.0);
+
+        memory.record_success(RaidTargetType::Refinery);
+        memory.record_success(RaidTargetType::Refinery);
+        memory.record_success(RaidTargetType::Ship);
+
+        let refinery = memory.target_score(100.0, None, RaidTargetType::Refinery);
+        let shipyard = memory.target_score(100.0, None, RaidTargetType::Shipyard);
+        assert!(refinery < shipyard);
+    }
+
+    #[test]
+    fn pirate_memory_avoids_fortified_zones() {
+        let mut memory = PirateMemory::default();
+        memory.record_loss(1, PirateLossCause::StationDefense);
+        memory.record_loss(1, PirateLossCause::StationDefense);
+
+        let fortified = memory.target_score(100.0, Some(1), RaidTargetType::Refinery);
+        let soft = memory.target_score(140.0, Some(2), RaidTargetType::Refinery);
+        assert!(soft < fortified);
+    }
+
+    #[test]
+    fn most_dangerous_zones_sorted_descending() {
+        let mut memory = PirateMemory::default();
+        memory.record_loss(1, PirateLossCause::Player);
+        memory.record_loss(2, PirateLossCause::StationDefense);
+        memory.record_loss(3, PirateLossCause::SecurityFleet);
+
+        let zones = memory.most_dangerous_zones(2);
+        assert_eq!(zones, vec![(2, 2.0), (3, 1.5)]);
+    }
+
+    #[test]
+    fn choose_relocation_zone_picks_softest_neighbour() {
+        let mut memory = PirateMemory::default();
+        memory.record_loss(1, PirateLossCause::StationDefense);
+        memory.record_loss(1, PirateLossCause::StationDefense);
+        memory.record_loss(2, PirateLossCause::Player);
+
+        assert_eq!(choose_relocation_zone(&memory, 1, &[2, 3]), Some(3));
+        assert_eq!(choose_relocation_zone(&memory, 3, &[1, 2]), None);
+    }
+
+    #[test]
+    fn raid_target_type_skips_outposts() {
+        assert_eq!(
+            RaidTargetType::from_station_kind(StationKind::Outpost),
+            None
+        );
+        assert_eq!(
+            RaidTargetType::from_station_kind(StationKind::Refinery),
+            Some(RaidTargetType::Refinery)
+        );
+    }
+
+    #[test]
+    fn pirate_control_delta_grows_with_bases_and_shrinks_with_security() {
+        let held = ZonePresence {
+            bases: 1,
+            docked: 1,
+            roaming: 2,
+            security: 0,
+        };
+        assert!(pirate_control_delta(&held) > 0.0);
+
+        let patrolled = ZonePresence {
+            roaming: 2,
+            security: 1,
+            ..Default::default()
+        };
+        assert!(pirate_control_delta(&patrolled) < 0.0);
+    }
+
+    #[test]
+    fn pirate_control_adjust_clamps_and_forgets_empty_zones() {
+        let mut control = PirateControl::default();
+        control.adjust(3, 2.0);
+        assert_eq!(control.level(3), 1.0);
+
+        control.adjust(3, -5.0);
+        assert_eq!(control.level(3), 0.0);
+        assert!(control.zones.is_empty());
+    }
+
+    #[test]
+    fn pirate_control_thresholds_gate_intel_and_outposts() {
+        let mut control = PirateControl::default();
+        control.adjust(1, 0.65);
+        assert!(control.blocks_intel(1));
+        assert!(!control.outposts_hostile(1));
+
+        control.adjust(1, 0.2);
+        assert!(control.outposts_hostile(1));
+    }
+
+    #[test]
+    fn effective_route_risk_uses_most_contested_endpoint() {
+        let mut control = PirateControl::default();
+        control.adjust(2, 0.5);
+        let route = RouteEdge {
+            from: 1,
+            to: 2,
+            distance: 100.0,
+            risk: 0.3,
+        };
+        assert!((control.effective_route_risk(&route) - 0.5).abs() < 1e-6);
+
+        control.adjust(1, 1.0);
+        assert!((control.effective_route_risk(&route) - 0.7).abs() < 1e-6);
+    }
 }
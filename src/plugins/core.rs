@@ -92,6 +92,11 @@ pub struct DebugWindow {
     pub open: bool,
 }
 
+#[derive(Resource, Debug, Default)]
+pub struct LedgerScreen {
+    pub open: bool,
+}
+
 #[derive(Resource, Debug, Default)]
 pub struct RunTimer {
     pub elapsed_seconds: f32,
@@ -109,6 +114,46 @@ impl RunTimer {
     }
 }
 
+/// Why a run came to an end
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum RunEndReason {
+    /// Ship lost with no player shipyard left to build a replacement
+    Stranded,
+    /// The escape pod itself was destroyed
+    PodDestroyed,
+}
+
+impl RunEndReason {
+    pub fn describe(self) -> &'static str {
+        match self {
+            RunEndReason::Stranded => "ship lost with no shipyard to rebuild at",
+            RunEndReason::PodDestroyed => "escape pod destroyed",
+        }
+    }
+}
+
+/// Tracks player losses and whether the run has ended
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RunStatus {
+    pub ships_lost: u32,
+    pub ended: Option<RunEndReason>,
+}
+
+impl RunStatus {
+    pub fn is_over(&self) -> bool {
+        self.ended.is_some()
+    }
+
+    /// End the run. Returns false if it had already ended; the first reason sticks.
+    pub fn end(&mut self, reason: RunEndReason) -> bool {
+        if self.ended.is_some() {
+            return false;
+        }
+        self.ended = Some(reason);
+        true
+    }
+}
+
 #[derive(Resource, Debug, Clone)]
 pub struct InputBindings {
     pub move_up: KeyCode,
@@ -142,6 +187,7 @@ pub struct InputBindings {
     pub spawn_refinery: KeyCode,
     pub spawn_shipyard: KeyCode,
     pub spawn_outpost: KeyCode,
+    pub spawn_sensor: KeyCode,
     pub spawn_ship: KeyCode,
     pub spawn_pirate: KeyCode,
     pub reveal_all: KeyCode,
@@ -149,6 +195,8 @@ pub struct InputBindings {
     pub center_camera: KeyCode,
     pub cycle_target: KeyCode,
     pub navigate: KeyCode,
+    pub cycle_weapon: KeyCode,
+    pub toggle_ledger: KeyCode,
 }
 
 impl Default for InputBindings {
@@ -185,6 +233,7 @@ impl Default for InputBindings {
             spawn_refinery: KeyCode::Digit1,
             spawn_shipyard: KeyCode::Digit2,
             spawn_outpost: KeyCode::Digit3,
+            spawn_sensor: KeyCode::Digit4,
             spawn_ship: KeyCode::KeyS,
             spawn_pirate: KeyCode::KeyP,
             reveal_all: KeyCode::KeyU,
@@ -192,6 +241,8 @@ impl Default for InputBindings {
             center_camera: KeyCode::KeyH,
             cycle_target: KeyCode::Tab,
             navigate: KeyCode::KeyN,
+            cycle_weapon: KeyCode::KeyQ,
+            toggle_ledger: KeyCode::KeyL,
         }
     }
 }
@@ -219,7 +270,9 @@ impl Plugin for CorePlugin {
             .init_resource::<ViewMode>()
             .init_resource::<EventLog>()
             .init_resource::<RunTimer>()
+            .init_resource::<RunStatus>()
             .init_resource::<DebugWindow>()
+            .init_resource::<LedgerScreen>()
             .add_systems(OnEnter(GameState::Boot), log_enter_boot)
             .add_systems(OnEnter(GameState::Boot), transition_to_loading)
             .add_systems(OnEnter(GameState::Loading), setup_loading_screen)
@@ -230,9 +283,11 @@ impl Plugin for CorePlugin {
                 Update,
                 (
                     handle_pause_toggle,
+                    freeze_finished_run.after(handle_pause_toggle),
                     handle_tick_rate_input,
                     handle_view_toggle,
                     handle_debug_toggle,
+                    handle_ledger_toggle,
                     update_run_timer.run_if(in_state(GameState::InGame)),
                 ),
             )
@@ -369,7 +424,27 @@ fn handle_debug_toggle(
     }
 }
 
-fn update_run_timer(time: Res<Time>, mut timer: ResMut<RunTimer>) {
+fn handle_ledger_toggle(
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<InputBindings>,
+    mut ledger_screen: ResMut<LedgerScreen>,
+) {
+    if input.just_pressed(bindings.toggle_ledger) {
+        ledger_screen.open = !ledger_screen.open;
+    }
+}
+
+/// A finished run stays frozen, whatever the pause key says
+fn freeze_finished_run(status: Res<RunStatus>, mut config: ResMut<SimConfig>) {
+    if status.is_over() && !config.paused {
+        config.paused = true;
+    }
+}
+
+fn update_run_timer(time: Res<Time>, status: Res<RunStatus>, mut timer: ResMut<RunTimer>) {
+    if status.is_over() {
+        return;
+    }
     timer.elapsed_seconds += time.delta_secs();
 }
 
@@ -501,6 +576,33 @@ mod tests {
         assert_eq!(fixed_time.timestep().as_secs_f32(), 1.0);
     }
 
+    #[test]
+    fn run_status_keeps_first_end_reason() {
+        let mut status = RunStatus::default();
+        assert!(!status.is_over());
+        assert!(status.end(RunEndReason::Stranded));
+        assert!(!status.end(RunEndReason::PodDestroyed));
+        assert_eq!(status.ended, Some(RunEndReason::Stranded));
+    }
+
+    #[test]
+    fn finished_run_cannot_be_unpaused() {
+        let mut world = World::default();
+        world.insert_resource(SimConfig::default());
+        world.insert_resource(RunStatus {
+            ships_lost: 1,
+            ended: Some(RunEndReason::PodDestroyed),
+        });
+
+        let mut system_state: SystemState<(Res<RunStatus>, ResMut<SimConfig>)> =
+            SystemState::new(&mut world);
+        let (status, config) = system_state.get_mut(&mut world);
+        freeze_finished_run(status, config);
+        system_state.apply(&mut world);
+
+        assert!(world.resource::<SimConfig>().paused);
+    }
+
     #[test]
     fn spawn_pirate_binding_is_key_p() {
         let bindings = InputBindings::default();
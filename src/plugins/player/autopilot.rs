@@ -2,8 +2,11 @@
 
 use bevy::prelude::*;
 
+use crate::pirates::PirateControl;
 use crate::plugins::core::{EventLog, InputBindings};
+use crate::plugins::sim::jump_route_risk;
 use crate::ships::{Ship, ShipState, Velocity};
+use crate::world::{JumpGate, Sector, ZoneId};
 
 use super::components::{AutopilotState, NearbyTargets, PlayerControl};
 use super::movement::{
@@ -20,6 +23,7 @@ const AUTOPILOT_POSITION_TOLERANCE: f32 = 3.0; // How close to dock position
 const AUTOPILOT_ARRIVAL_SPEED: f32 = 3.0; // Max speed to be considered stopped
 const AUTOPILOT_ROTATION_TOLERANCE: f32 = 0.1; // radians (~6 degrees)
 const AUTOPILOT_BRAKE_SAFETY_FACTOR: f32 = 1.5;
+const AUTOPILOT_JUMP_RISK_WARNING: f32 = 0.5; // Warn before approaching gates this risky
 
 // =============================================================================
 // Run Conditions
@@ -37,12 +41,16 @@ pub fn autopilot_not_engaged(autopilot: Res<AutopilotState>) -> bool {
 // Systems
 // =============================================================================
 
+#[allow(clippy::too_many_arguments)]
 pub fn autopilot_input_system(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
     targets: Res<NearbyTargets>,
+    sector: Res<Sector>,
+    control: Res<PirateControl>,
     mut autopilot: ResMut<AutopilotState>,
     mut log: ResMut<EventLog>,
+    gates: Query<(&JumpGate, &ZoneId)>,
 ) {
     // Check for manual override (any movement key disengages autopilot)
     if autopilot.engaged {
@@ -77,7 +85,28 @@ pub fn autopilot_input_system(
             if let Some((entity, _pos, label)) = targets.entities.get(targets.selected_index) {
                 autopilot.engaged = true;
                 autopilot.target_entity = Some(*entity);
-                log.push(format!("Autopilot engaged: {}", label));
+
+                // Gates are plotted with the risk of the jump beyond them
+                match gates.get(*entity) {
+                    Ok((gate, gate_zone)) => {
+                        let risk =
+                            jump_route_risk(&sector, &control, gate_zone.0, gate.destination_zone);
+                        log.push(format!(
+                            "Autopilot engaged: {} (jump risk {:.0}%)",
+                            label,
+                            risk * 100.0
+                        ));
+                        if risk >= AUTOPILOT_JUMP_RISK_WARNING {
+                            log.push(format!(
+                                "Autopilot warning: route to zone {} is dangerous",
+                                gate.destination_zone
+                            ));
+                        }
+                    }
+                    Err(_) => {
+                        log.push(format!("Autopilot engaged: {}", label));
+                    }
+                }
             } else {
                 log.push("Autopilot: target not found".to_string());
             }
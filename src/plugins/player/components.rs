@@ -2,6 +2,9 @@
 
 use bevy::prelude::*;
 
+use crate::combat::WreckKind;
+use crate::stations::CONSTRUCTION_SITE_SLOTS;
+
 // =============================================================================
 // Components
 // =============================================================================
@@ -10,6 +13,11 @@ use bevy::prelude::*;
 #[derive(Component, Debug, Default)]
 pub struct PlayerControl;
 
+/// The player's ship was destroyed and they are flying the escape pod.
+/// Removed when a shipyard fits a replacement hull.
+#[derive(Component, Debug, Default)]
+pub struct EscapePod;
+
 // =============================================================================
 // Resources
 // =============================================================================
@@ -32,10 +40,72 @@ pub struct AutopilotState {
     pub target_entity: Option<Entity>,
 }
 
+/// The system node whose construction menu is open, and which of the sites
+/// around it the player has picked.
+#[derive(Resource, Debug, Default)]
+pub struct ConstructionMenu {
+    pub node: Option<Entity>,
+    pub slot: usize,
+}
+
+impl ConstructionMenu {
+    /// Whether the menu is open at some node
+    pub fn is_open(&self) -> bool {
+        self.node.is_some()
+    }
+
+    /// Open the menu at a node, starting from its first site
+    pub fn open(&mut self, node: Entity) {
+        self.node = Some(node);
+        self.slot = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.node = None;
+    }
+
+    /// Move on to the next site round the node
+    pub fn next_site(&mut self) {
+        self.slot = (self.slot + 1) % CONSTRUCTION_SITE_SLOTS;
+    }
+}
+
+/// Construction site the build menu is previewing, and whether it is buildable.
+#[derive(Resource, Debug, Default)]
+pub struct ConstructionPreview {
+    pub site: Option<(Vec2, bool)>,
+}
+
+/// What the player has stripped from the wreck they are working on, reported
+/// once when the session ends.
+#[derive(Resource, Debug, Default)]
+pub struct SalvageSession {
+    pub wreck: Option<(Entity, WreckKind)>,
+    pub ore: u32,
+    pub fuel: f32,
+    pub parts: u32,
+}
+
+impl SalvageSession {
+    /// Whether this session is working the given wreck
+    pub fn is_on(&self, wreck: Entity) -> bool {
+        self.wreck.is_some_and(|(entity, _)| entity == wreck)
+    }
+
+    /// Close the session, handing back the wreck it worked and its haul
+    pub fn finish(&mut self) -> Option<(WreckKind, u32, f32, u32)> {
+        let (_, kind) = self.wreck?;
+        let haul = (kind, self.ore, self.fuel, self.parts);
+        *self = Self::default();
+        Some(haul)
+    }
+}
+
 /// Tracks whether the player is docked at a station.
 #[derive(Resource, Default)]
 pub struct DockingState {
-    /// Entity of the station the player is docked at (if any)
+    /// Entity of the station the player is docked at (if any); also a boarded
+    /// raider
     pub docked_at: Option<Entity>,
 }
 
@@ -89,4 +159,35 @@ mod tests {
         assert!(!state.is_docked());
         assert!(state.docked_at.is_none());
     }
+
+    #[test]
+    fn construction_menu_cycles_sites_and_reopens_at_the_first() {
+        let mut menu = ConstructionMenu::default();
+        let node = Entity::from_bits(7);
+        menu.open(node);
+        for _ in 0..CONSTRUCTION_SITE_SLOTS + 1 {
+            menu.next_site();
+        }
+        assert_eq!(menu.slot, 1);
+
+        menu.close();
+        assert!(!menu.is_open());
+        menu.open(node);
+        assert_eq!(menu.slot, 0);
+    }
+
+    #[test]
+    fn salvage_session_finishes_once() {
+        let wreck = Entity::from_bits(42);
+        let mut session = SalvageSession {
+            wreck: Some((wreck, WreckKind::PirateShip)),
+            ore: 3,
+            fuel: 1.5,
+            parts: 2,
+        };
+        assert!(session.is_on(wreck));
+        assert_eq!(session.finish(), Some((WreckKind::PirateShip, 3, 1.5, 2)));
+        assert!(session.finish().is_none());
+        assert!(!session.is_on(wreck));
+    }
 }
@@ -1,11 +1,14 @@
-//! Player docking systems for station interactions.
+//! Player docking systems for station interactions and boarding disabled raiders.
 
 use bevy::prelude::*;
 
-use crate::plugins::core::InputBindings;
-use crate::stations::{Station, StationKind};
+use crate::factions::Faction;
+use crate::pirates::{is_boardable, PirateShip};
+use crate::plugins::core::{EventLog, InputBindings};
+use crate::stations::{is_npc_station, Station, StationKind, StationState};
 
-use super::components::{DockingState, PlayerControl};
+use super::components::{ConstructionMenu, DockingState, PlayerControl};
+use super::movement::shift_pressed;
 
 /// Range at which player can dock at a station
 pub const DOCKING_RANGE: f32 = 22.0;
@@ -14,13 +17,17 @@ pub const DOCKING_RANGE: f32 = 22.0;
 // Systems
 // =============================================================================
 
-/// Handles docking at Shipyard and Refinery stations when player presses interact.
+/// Handles docking at Shipyard, Refinery, FuelDepot, MiningOutpost and Outpost stations when player presses interact.
+/// Failed stations, or any of the player's stations with Shift held, can be docked to give
+/// station orders. Disabled pirate ships in range can be boarded the same way.
 pub fn player_dock_station(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
     mut docking: ResMut<DockingState>,
+    mut log: ResMut<EventLog>,
     player_query: Query<&Transform, With<PlayerControl>>,
-    stations: Query<(Entity, &Transform, &Station)>,
+    stations: Query<(Entity, &Transform, &Station, Option<&Faction>)>,
+    pirates: Query<(Entity, &Transform, &PirateShip)>,
 ) {
     // Only trigger on key press, not when already docked
     if !input.just_pressed(bindings.interact) {
@@ -42,12 +49,12 @@ pub fn player_dock_station(
         player_transform.translation.y,
     );
 
-    // Find closest dockable station (Shipyard or Refinery)
-    let mut closest: Option<(Entity, f32)> = None;
+    // Find closest dockable station
+    let mut closest: Option<(Entity, f32, bool)> = None;
+    let station_orders = shift_pressed(&input);
 
-    for (entity, transform, station) in stations.iter() {
-        // Only Shipyard and Refinery are dockable
-        if !matches!(station.kind, StationKind::Shipyard | StationKind::Refinery) {
+    for (entity, transform, station, faction) in stations.iter() {
+        if !can_dock(station, station_orders) {
             continue;
         }
 
@@ -55,12 +62,40 @@ pub fn player_dock_station(
         let dist = station_pos.distance(player_pos);
 
         if dist <= DOCKING_RANGE && (closest.is_none() || dist < closest.unwrap().1) {
-            closest = Some((entity, dist));
+            let hostile = faction.is_some_and(|faction| *faction == Faction::Pirate);
+            closest = Some((entity, dist, hostile));
         }
     }
 
-    if let Some((station_entity, _)) = closest {
-        docking.dock(station_entity);
+    // Drifting raiders can be boarded if nothing closer is dockable
+    let mut boarding = false;
+    for (entity, transform, pirate) in pirates.iter() {
+        if !is_boardable(pirate) {
+            continue;
+        }
+
+        let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let dist = pirate_pos.distance(player_pos);
+
+        if dist <= DOCKING_RANGE && (closest.is_none() || dist < closest.unwrap().1) {
+            closest = Some((entity, dist, false));
+            boarding = true;
+        }
+    }
+
+    match closest {
+        Some((pirate_entity, _, _)) if boarding => {
+            docking.dock(pirate_entity);
+            log.push("Boarded disabled raider".to_string());
+        }
+        // Outposts that have fallen to pirates turn the player away
+        Some((_, _, true)) => {
+            log.push("Docking refused: outpost under pirate control".to_string());
+        }
+        Some((station_entity, _, false)) => {
+            docking.dock(station_entity);
+        }
+        None => {}
     }
 }
 
@@ -76,6 +111,30 @@ pub fn player_undock(input: Res<ButtonInput<KeyCode>>, mut docking: ResMut<Docki
     }
 }
 
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Station kinds with a docking menu
+fn is_dockable(kind: StationKind) -> bool {
+    matches!(
+        kind,
+        StationKind::Shipyard
+            | StationKind::Refinery
+            | StationKind::FuelDepot
+            | StationKind::MiningOutpost
+            | StationKind::Outpost
+    )
+}
+
+/// Stations without a menu of their own still take station orders: always once
+/// they have failed, otherwise only when asked for (Shift + interact)
+fn can_dock(station: &Station, station_orders: bool) -> bool {
+    is_dockable(station.kind)
+        || (!is_npc_station(station.kind)
+            && (station_orders || station.state == StationState::Failed))
+}
+
 // =============================================================================
 // Run Conditions
 // =============================================================================
@@ -90,6 +149,16 @@ pub fn player_not_docked(docking: Res<DockingState>) -> bool {
     !docking.is_docked()
 }
 
+/// Run condition: a construction menu is open
+pub fn construction_menu_open(menu: Res<ConstructionMenu>) -> bool {
+    menu.is_open()
+}
+
+/// Run condition: no construction menu is open
+pub fn construction_menu_closed(menu: Res<ConstructionMenu>) -> bool {
+    !menu.is_open()
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -102,4 +171,28 @@ mod tests {
     fn docking_range_is_22_units() {
         assert!((DOCKING_RANGE - 22.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn outposts_are_dockable_but_sensor_stations_are_not() {
+        assert!(is_dockable(StationKind::Outpost));
+        assert!(is_dockable(StationKind::Shipyard));
+        assert!(is_dockable(StationKind::FuelDepot));
+        assert!(is_dockable(StationKind::MiningOutpost));
+        assert!(!is_dockable(StationKind::SensorStation));
+    }
+
+    #[test]
+    fn failed_or_ordered_stations_can_be_docked() {
+        let mut station = Station {
+            kind: StationKind::SensorStation,
+            state: StationState::Operational,
+            fuel: 10.0,
+            fuel_capacity: 30.0,
+        };
+        assert!(!can_dock(&station, false));
+        assert!(can_dock(&station, true));
+
+        station.state = StationState::Failed;
+        assert!(can_dock(&station, false));
+    }
 }
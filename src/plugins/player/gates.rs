@@ -2,13 +2,18 @@
 
 use bevy::prelude::*;
 
+use crate::compat::SpatialBundle;
+use crate::factions::Faction;
 use crate::fleets::ScoutBehavior;
+use crate::pirates::{PirateControl, PirateLoot, PirateShip};
 use crate::plugins::core::{EventLog, InputBindings};
-use crate::plugins::sim::SimTickCount;
-use crate::ships::Ship;
+use crate::plugins::sim::{jump_route_risk, SimTickCount};
+use crate::plugins::worldgen::WorldSeed;
+use crate::ships::{Ship, ShipId};
 use crate::world::{
-    Identified, JumpGate, JumpTransition, SystemIntel, SystemNode, ZoneId, JUMP_GATE_FUEL_COST,
-    JUMP_TRANSITION_SECONDS,
+    jump_rolls, resolve_jump, zone_modifier_of, Identified, JumpGate, JumpOutcome, JumpTransition,
+    Sector, SystemIntel, SystemNode, ZoneId, JUMP_FUEL_LOSS, JUMP_GATE_FUEL_COST,
+    JUMP_OFF_GATE_DISTANCE, JUMP_TRANSITION_SECONDS,
 };
 
 use super::components::PlayerControl;
@@ -19,6 +24,9 @@ use super::components::PlayerControl;
 
 const JUMP_GATE_ACTIVATION_RANGE: f32 = 25.0;
 
+/// How far from an intercepted ship the waiting pirate appears
+const INTERCEPTOR_SPAWN_OFFSET: f32 = 60.0;
+
 // =============================================================================
 // Run Conditions
 // =============================================================================
@@ -35,9 +43,12 @@ pub fn not_in_jump_transition(
 // Systems
 // =============================================================================
 
+#[allow(clippy::too_many_arguments)]
 pub fn player_activate_jump_gate(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
+    sector: Res<Sector>,
+    control: Res<PirateControl>,
     mut commands: Commands,
     mut log: ResMut<EventLog>,
     mut player_query: Query<(Entity, &Transform, &mut Ship, &ZoneId), With<PlayerControl>>,
@@ -96,14 +107,24 @@ pub fn player_activate_jump_gate(
         remaining_seconds: JUMP_TRANSITION_SECONDS,
     });
 
-    log.push(format!("Jumping to zone {}...", gate.destination_zone));
+    let risk = jump_route_risk(&sector, &control, player_zone.0, gate.destination_zone);
+    log.push(format!(
+        "Jumping to zone {}... (risk {:.0}%)",
+        gate.destination_zone,
+        risk * 100.0
+    ));
 }
 
 /// Process jump transitions for all ships. Reveals zones only for player-owned ships.
+/// Ships roll against the route's jump risk on arrival; pirates jump unopposed.
+#[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 pub fn process_jump_transition(
     time: Res<Time<Fixed>>,
     ticks: Res<SimTickCount>,
+    seed: Res<WorldSeed>,
+    sector: Res<Sector>,
+    control: Res<PirateControl>,
     mut commands: Commands,
     mut log: ResMut<EventLog>,
     mut jumping_ships: Query<(
@@ -111,14 +132,24 @@ pub fn process_jump_transition(
         &mut ZoneId,
         &mut Transform,
         &mut JumpTransition,
+        Option<&mut Ship>,
+        Option<&ShipId>,
         Option<&PlayerControl>,
         Option<&ScoutBehavior>,
     )>,
     mut intel_query: Query<(&SystemNode, &mut SystemIntel)>,
     gates: Query<(Entity, &Transform, &JumpGate, Option<&ZoneId>), Without<JumpTransition>>,
 ) {
-    for (entity, mut zone_id, mut ship_transform, mut transition, player_ctrl, scout) in
-        jumping_ships.iter_mut()
+    for (
+        entity,
+        mut zone_id,
+        mut ship_transform,
+        mut transition,
+        ship,
+        ship_id,
+        player_ctrl,
+        scout,
+    ) in jumping_ships.iter_mut()
     {
         transition.remaining_seconds -= time.delta_secs();
 
@@ -163,21 +194,116 @@ pub fn process_jump_transition(
                 }
             }
 
+            // Only ships roll against the route; pirates know the lanes. Rolls are
+            // keyed on the seed, tick and ship id, all saved, so a loaded run sees
+            // the same outcomes.
+            let outcome = match ship {
+                Some(mut ship) => {
+                    let risk = jump_route_risk(&sector, &control, source_zone, destination);
+                    let (hazard_roll, kind_roll) = jump_rolls(
+                        seed.value,
+                        ticks.tick,
+                        ship_id.map_or(0, |id| id.0),
+                        source_zone,
+                        destination,
+                    );
+                    let outcome = resolve_jump(
+                        risk,
+                        zone_modifier_of(&sector, destination),
+                        control.level(destination),
+                        hazard_roll,
+                        kind_roll,
+                    );
+                    apply_jump_outcome(
+                        &mut commands,
+                        outcome,
+                        destination,
+                        kind_roll,
+                        &mut ship,
+                        &mut ship_transform,
+                    );
+                    outcome
+                }
+                None => JumpOutcome::Clean,
+            };
+
             // Log arrival for player ship only
             if player_ctrl.is_some() {
                 log.push(format!("Arrived at zone {}", destination));
             }
+
+            if is_player_owned {
+                let who = if player_ctrl.is_some() {
+                    "Jump"
+                } else {
+                    "Scout jump"
+                };
+                if let Some(report) = jump_outcome_report(outcome, destination) {
+                    log.push(format!("{}: {}", who, report));
+                }
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn apply_jump_outcome(
+    commands: &mut Commands,
+    outcome: JumpOutcome,
+    destination: u32,
+    kind_roll: f32,
+    ship: &mut Ship,
+    transform: &mut Transform,
+) {
+    match outcome {
+        JumpOutcome::Clean => {}
+        JumpOutcome::OffGate => {
+            // Scatter in a direction derived from the same roll, so it stays deterministic
+            let angle = kind_roll * std::f32::consts::TAU;
+            transform.translation.x += angle.cos() * JUMP_OFF_GATE_DISTANCE;
+            transform.translation.y += angle.sin() * JUMP_OFF_GATE_DISTANCE;
+        }
+        JumpOutcome::Intercepted => {
+            let mut ambush = *transform;
+            ambush.translation.x += INTERCEPTOR_SPAWN_OFFSET;
+            commands.spawn((
+                PirateShip::new(70.0),
+                PirateLoot::default(),
+                Faction::Pirate,
+                ZoneId(destination),
+                Name::new("Pirate-Interceptor"),
+                SpatialBundle::from_transform(ambush),
+            ));
+        }
+        JumpOutcome::FuelLoss => {
+            ship.fuel = (ship.fuel - JUMP_FUEL_LOSS).max(0.0);
         }
     }
 }
 
+fn jump_outcome_report(outcome: JumpOutcome, destination: u32) -> Option<String> {
+    match outcome {
+        JumpOutcome::Clean => None,
+        JumpOutcome::OffGate => Some(format!("drifted off-gate in zone {}", destination)),
+        JumpOutcome::Intercepted => Some(format!("intercepted by pirates in zone {}", destination)),
+        JumpOutcome::FuelLoss => Some(format!(
+            "turbulence en route to zone {} burned {:.0} fuel",
+            destination, JUMP_FUEL_LOSS
+        )),
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
 
 #[cfg(test)]
 mod tests {
-    use crate::world::{JumpTransition, JUMP_GATE_FUEL_COST, JUMP_TRANSITION_SECONDS};
+    use super::jump_outcome_report;
+    use crate::world::{JumpOutcome, JumpTransition, JUMP_GATE_FUEL_COST, JUMP_TRANSITION_SECONDS};
 
     #[test]
     fn can_activate_gate_with_enough_fuel() {
@@ -202,4 +328,15 @@ mod tests {
         transition.remaining_seconds -= JUMP_TRANSITION_SECONDS;
         assert!(transition.remaining_seconds <= 0.0);
     }
+
+    #[test]
+    fn clean_jumps_are_not_reported() {
+        assert!(jump_outcome_report(JumpOutcome::Clean, 4).is_none());
+    }
+
+    #[test]
+    fn failed_jumps_name_the_destination() {
+        let report = jump_outcome_report(JumpOutcome::Intercepted, 4);
+        assert_eq!(report.as_deref(), Some("intercepted by pirates in zone 4"));
+    }
 }
@@ -1,19 +1,31 @@
 //! Player interaction systems: mining, building, refueling, combat.
 
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 
+use crate::combat::{
+    Armament, DamageSource, FireBlocked, Hull, Projectile, Wreck, WreckKind, SALVAGE_RANGE,
+};
+use crate::commodities::Commodity;
 use crate::compat::SpatialBundle;
+use crate::crises::Crisis;
+use crate::factions::Faction;
+use crate::ledger::{Ledger, LedgerCategory, LedgerEntry};
 use crate::ore::{mine_amount, OreKind, OreNode};
 use crate::pirates::{PirateBase, PirateShip};
 use crate::plugins::core::{EventLog, InputBindings};
+use crate::plugins::sim::{reveal_salvaged_intel, SimTickCount};
 use crate::ships::{Cargo, Ship};
 use crate::stations::{
-    station_build_time_seconds, station_fuel_capacity, station_ore_capacity, Station, StationBuild,
-    StationCrisisLog, StationKind, StationProduction, StationState,
+    station_build_time_seconds, station_fuel_capacity, station_ore_capacity, station_recipes,
+    ConstructionSite, CrisisType, FuelDepotPolicy, SensorSweep, Station, StationBuild,
+    StationCrises, StationCrisisLog, StationKind, StationMaintenance, StationProduction,
+    StationState,
 };
-use crate::world::{Identified, JumpGate, SystemNode, ZoneId};
+use crate::world::{Identified, JumpGate, SystemIntel, SystemNode, ZoneId};
 
-use super::components::PlayerControl;
+use super::components::{ConstructionMenu, NearbyTargets, PlayerControl, SalvageSession};
 
 // =============================================================================
 // Systems
@@ -85,7 +97,9 @@ pub fn player_mining(
                 amount
             }
             OreKind::FuelOre => {
-                let free_capacity = (ship.fuel_capacity - ship.fuel).max(0.0);
+                // Once the tank is full, fuel ore goes into the hold for refineries
+                let tank_room = (ship.fuel_capacity - ship.fuel).max(0.0);
+                let free_capacity = tank_room + cargo.hold.room_for(Commodity::FuelOre);
                 let amount = mine_amount(
                     ore.remaining,
                     ore.rate_per_second,
@@ -94,7 +108,9 @@ pub fn player_mining(
                 );
                 if amount > 0.0 {
                     ore.remaining -= amount;
-                    ship.fuel += amount;
+                    let fuel = amount.min(tank_room);
+                    ship.fuel += fuel;
+                    cargo.hold.add(Commodity::FuelOre, amount - fuel);
                 }
                 amount
             }
@@ -111,104 +127,79 @@ pub fn player_mining(
     }
 }
 
-pub fn player_build_outpost(
+/// How close to a system node the player has to be to open its construction menu
+const CONSTRUCTION_RANGE: f32 = 26.0;
+
+/// How close to a station the player has to be to hand over fuel and ore
+const STATION_TRANSFER_RANGE: f32 = 22.0;
+
+/// Open the construction menu at the system node the player is flying by,
+/// unless a station is close enough that the press is a transfer instead
+pub fn player_open_construction(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
-    mut commands: Commands,
-    mut log: ResMut<EventLog>,
-    mut player_query: Query<(&Transform, &mut Cargo), With<PlayerControl>>,
-    nodes: Query<&SystemNode>,
+    mut menu: ResMut<ConstructionMenu>,
+    player_query: Query<&Transform, With<PlayerControl>>,
+    nodes: Query<(Entity, &SystemNode)>,
     stations: Query<&Transform, With<Station>>,
 ) {
     if !input.just_pressed(bindings.interact) {
         return;
     }
 
-    let (player_transform, mut cargo) = match player_query.single_mut() {
-        Ok(value) => value,
-        Err(_) => {
-            return;
-        }
-    };
-
-    let cost = 18;
-    if !can_build_outpost(cargo.ore, cost) {
+    let Ok(player_transform) = player_query.single() else {
         return;
-    }
+    };
 
     let player_pos = Vec2::new(
         player_transform.translation.x,
         player_transform.translation.y,
     );
-
-    let mut target_node = None;
-    let mut best_dist = 0.0;
-    let build_range = 26.0;
-
-    for node in nodes.iter() {
-        let dist = node.position.distance(player_pos);
-        if dist <= build_range && (target_node.is_none() || dist < best_dist) {
-            target_node = Some(node);
-            best_dist = dist;
-        }
+    let station_positions: Vec<Vec2> = stations
+        .iter()
+        .map(|transform| Vec2::new(transform.translation.x, transform.translation.y))
+        .collect();
+    if closest_in_range(player_pos, &station_positions, STATION_TRANSFER_RANGE).is_some() {
+        return;
     }
-
-    let node = match target_node {
-        Some(node) => node,
-        None => {
-            return;
-        }
-    };
-
-    for station_transform in stations.iter() {
-        let station_pos = Vec2::new(
-            station_transform.translation.x,
-            station_transform.translation.y,
-        );
-        if station_pos.distance(node.position) <= 18.0 {
-            return;
-        }
+    let (entities, positions): (Vec<Entity>, Vec<Vec2>) = nodes
+        .iter()
+        .map(|(entity, node)| (entity, node.position))
+        .unzip();
+    if let Some(index) = closest_in_range(player_pos, &positions, CONSTRUCTION_RANGE) {
+        menu.open(entities[index]);
     }
+}
 
-    let kind = StationKind::MiningOutpost;
-    let capacity = station_fuel_capacity(kind);
-    let build_time = station_build_time_seconds(kind);
-    let ore_capacity = station_ore_capacity(kind);
-
-    commands.spawn((
-        Station {
-            kind,
-            state: StationState::Deploying,
-            fuel: capacity * 0.5,
-            fuel_capacity: capacity,
-        },
-        StationBuild {
-            remaining_seconds: build_time,
-        },
-        StationProduction {
-            ore: 0.0,
-            ore_capacity,
-        },
-        StationCrisisLog::default(),
-        ZoneId(node.id),
-        Name::new(format!("Station-{}", node.id)),
-        SpatialBundle::from_transform(Transform::from_xyz(
-            node.position.x + 12.0,
-            node.position.y + 8.0,
-            0.5,
-        )),
-    ));
-
-    cargo.remove_ore(cost);
-    log.push(format!("Outpost deployed at zone {}", node.id));
+/// Escape closes the construction menu
+pub fn player_close_construction(
+    input: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<ConstructionMenu>,
+) {
+    if input.just_pressed(KeyCode::Escape) {
+        menu.close();
+    }
 }
 
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
 pub fn player_refuel_station(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
+    ticks: Res<SimTickCount>,
     mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
     mut player_query: Query<(&Transform, &mut Ship, &mut Cargo), With<PlayerControl>>,
-    mut stations: Query<(&Transform, &mut Station, Option<&mut StationProduction>)>,
+    mut stations: Query<(
+        &Transform,
+        &mut Station,
+        Option<&Name>,
+        Option<&mut StationProduction>,
+        Option<&StationCrises>,
+        Option<&mut ConstructionSite>,
+        Option<&mut Hull>,
+    )>,
+    mut crises: Query<&mut Crisis>,
 ) {
     if !input.just_pressed(bindings.interact) {
         return;
@@ -225,17 +216,71 @@ pub fn player_refuel_station(
         player_transform.translation.x,
         player_transform.translation.y,
     );
-    let range = 22.0;
+    let range = STATION_TRANSFER_RANGE;
     let fuel_transfer = 10.0;
     let ore_transfer = 8.0;
     let mut refueled = false;
     let mut supplied_ore = false;
+    let mut supplied_goods = false;
+    let mut patched = false;
+    let mut eased = false;
 
-    for (_transform, mut station, production_opt) in stations.iter_mut() {
+    for (_transform, mut station, name, production_opt, station_crises, site, hull) in
+        stations.iter_mut()
+    {
         let station_pos = Vec2::new(_transform.translation.x, _transform.translation.y);
         if station_pos.distance(player_pos) > range {
             continue;
         }
+        let name = name.map_or(station.kind.label(), |name| name.as_str());
+
+        // Construction sites take what they still need to finish building
+        if let Some(mut site) = site {
+            let fuel = site.fuel.deliver(ship.fuel.min(fuel_transfer));
+            ship.fuel -= fuel;
+            let units = cargo
+                .ore()
+                .min(ore_transfer as u32)
+                .min(site.ore.wanted() as u32);
+            cargo.remove_ore(units);
+            site.ore.deliver(units as f32);
+            ledger.record(
+                LedgerEntry::new(ticks.tick, LedgerCategory::Construction, name)
+                    .with_ore(-(units as f32))
+                    .with_fuel(-fuel),
+            );
+            if fuel > 0.0 || units > 0 {
+                log.push(format!(
+                    "Delivered {} ore and {:.0} fuel to construction ({})",
+                    units,
+                    fuel,
+                    site.progress_line()
+                ));
+            }
+            break;
+        }
+
+        // Depots hand fuel out rather than take it; deliveries go through their menu
+        if station.kind == StationKind::FuelDepot {
+            continue;
+        }
+
+        // A breached hull gets plated over before any ore goes into the hold
+        if let Some(mut hull) = hull {
+            let used = hull.patch(cargo.ore());
+            if used > 0 {
+                cargo.remove_ore(used);
+                ledger.record(
+                    LedgerEntry::new(ticks.tick, LedgerCategory::Upkeep, name)
+                        .with_ore(-(used as f32)),
+                );
+                patched = true;
+            }
+        }
+
+        let fuel_before = ship.fuel;
+        let ore_before = cargo.ore();
+        let mut goods_moved = Vec::new();
 
         if ship.fuel > 0.0 {
             let (new_ship_fuel, new_station_fuel, did_refuel) = transfer_fuel(
@@ -249,18 +294,60 @@ pub fn player_refuel_station(
             refueled = did_refuel;
         }
 
-        if cargo.ore > 0 {
-            if let Some(mut production) = production_opt {
-                let available = (cargo.ore as f32).min(ore_transfer);
-                let free_capacity = (production.ore_capacity - production.ore).max(0.0);
-                let transferred = available.min(free_capacity);
-
-                if transferred > 0.0 {
-                    cargo.remove_ore(transferred as u32);
-                    production.ore += transferred;
+        if let Some(mut production) = production_opt {
+            // Mining outposts make their own ore; their output is withdrawn from the docking menu
+            if cargo.ore() > 0 && station.kind != StationKind::MiningOutpost {
+                let units = (cargo.ore() as f32)
+                    .min(ore_transfer)
+                    .min(production.free_space())
+                    .floor() as u32;
+                if units > 0 {
+                    cargo.remove_ore(units);
+                    production.deposit(units as f32);
                     supplied_ore = true;
                 }
             }
+
+            // Refineries and shipyards also take in what their recipes work on
+            for recipe in station_recipes(station.kind) {
+                let (input, _) = recipe.spec().input;
+                if input == Commodity::RawOre {
+                    continue;
+                }
+                let room = production.hold.room_for(input).floor() as u32;
+                let units = cargo.remove_whole(input, room);
+                if units > 0 {
+                    production.hold.add(input, units as f32);
+                    goods_moved.push((input, units));
+                    supplied_goods = true;
+                }
+            }
+        }
+
+        let mut transfer = LedgerEntry::new(ticks.tick, LedgerCategory::Transfer, name)
+            .with_ore(cargo.ore() as f32 - ore_before as f32)
+            .with_fuel(ship.fuel - fuel_before);
+        for (commodity, units) in goods_moved {
+            transfer = transfer.with_goods(commodity, -(units as f32));
+        }
+        ledger.record(transfer);
+
+        // An emergency delivery or hull patch knocks a crisis back and buys time
+        let deliveries = [
+            (refueled, CrisisType::FuelShortage),
+            (supplied_ore, CrisisType::SupplyShortage),
+            (patched, CrisisType::HullBreach),
+        ];
+        for (delivered, crisis_type) in deliveries {
+            if !delivered {
+                continue;
+            }
+            let shortage = station_crises
+                .and_then(|station_crises| station_crises.get(crisis_type))
+                .and_then(|open| crises.get_mut(open.entity).ok());
+            if let Some(mut crisis) = shortage {
+                eased |= crisis.intervene();
+            }
         }
 
         break;
@@ -273,44 +360,268 @@ pub fn player_refuel_station(
     } else if supplied_ore {
         log.push("Transferred ore to station".to_string());
     }
+    if supplied_goods {
+        log.push("Delivered goods to the station's hold".to_string());
+    }
+    if patched {
+        log.push("Patched the station's hull".to_string());
+    }
+    if eased {
+        log.push("That eased the station's crisis".to_string());
+    }
 }
 
+/// Strip the nearest wreck while interact is held: ore and parts go to the
+/// hold, fuel to the tank, and any flight log reveals a pirate den. The haul
+/// is logged and entered in the ledger once per wreck, when the key is
+/// released or the wreck is bare.
+#[allow(clippy::too_many_arguments)]
+pub fn player_salvage(
+    time: Res<Time<Fixed>>,
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<InputBindings>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut session: ResMut<SalvageSession>,
+    mut player_query: Query<(&Transform, &mut Cargo, &mut Ship), With<PlayerControl>>,
+    mut wrecks: Query<(Entity, &Transform, &mut Wreck)>,
+    mut intel: Query<(&SystemNode, &mut SystemIntel)>,
+) {
+    if !input.pressed(bindings.interact) {
+        end_salvage_session(&mut session, &mut log, &mut ledger, ticks.tick);
+        return;
+    }
+
+    let Ok((player_transform, mut cargo, mut ship)) = player_query.single_mut() else {
+        return;
+    };
+
+    let player_pos = Vec2::new(
+        player_transform.translation.x,
+        player_transform.translation.y,
+    );
+    let mut closest = None;
+    let mut closest_dist = 0.0;
+
+    for (entity, transform, wreck) in wrecks.iter() {
+        if wreck.is_empty() {
+            continue;
+        }
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let dist = pos.distance(player_pos);
+        if dist <= SALVAGE_RANGE && (closest.is_none() || dist < closest_dist) {
+            closest = Some(entity);
+            closest_dist = dist;
+        }
+    }
+
+    let Some(target_entity) = closest else {
+        end_salvage_session(&mut session, &mut log, &mut ledger, ticks.tick);
+        return;
+    };
+    let Ok((_entity, _transform, mut wreck)) = wrecks.get_mut(target_entity) else {
+        return;
+    };
+
+    if !session.is_on(target_entity) {
+        end_salvage_session(&mut session, &mut log, &mut ledger, ticks.tick);
+        session.wreck = Some((target_entity, wreck.kind));
+    }
+
+    let fuel_room = (ship.fuel_capacity - ship.fuel).max(0.0);
+    let taken = wreck.salvage(
+        time.delta_secs(),
+        cargo.ore_free_space(),
+        fuel_room,
+        cargo.components_free_space(),
+    );
+    let ore = cargo.add_ore(taken.ore);
+    ship.fuel += taken.fuel;
+    // Ore and parts share the hold, so parts squeezed out stay on the wreck
+    let parts = cargo.add_whole(Commodity::Components, taken.components);
+    wreck.components += (taken.components - parts) as f32;
+
+    session.ore += ore;
+    session.fuel += taken.fuel;
+    session.parts += parts;
+
+    if let Some(zone) = taken.intel_zone {
+        reveal_salvaged_intel(&mut intel, zone, ticks.tick);
+        log.push(format!("Salvaged flight log: zone {}", zone));
+    }
+    if wreck.is_empty() {
+        end_salvage_session(&mut session, &mut log, &mut ledger, ticks.tick);
+        log.push(format!("{} wreck stripped", wreck.kind.label()));
+    }
+}
+
+/// Report what the current salvage session recovered, if anything, as one
+/// log line and one ledger entry
+fn end_salvage_session(
+    session: &mut SalvageSession,
+    log: &mut EventLog,
+    ledger: &mut Ledger,
+    tick: u64,
+) {
+    let Some((kind, ore, fuel, parts)) = session.finish() else {
+        return;
+    };
+
+    // Raiders' spoils are bounty; anything else is recovering what was lost
+    let category = match kind {
+        WreckKind::PirateShip => LedgerCategory::Bounty,
+        _ => LedgerCategory::Refund,
+    };
+    ledger.record(
+        LedgerEntry::new(tick, category, format!("{} wreck", kind.label()))
+            .with_ore(ore as f32)
+            .with_fuel(fuel),
+    );
+
+    if ore > 0 || fuel >= 1.0 || parts > 0 {
+        log.push(format!(
+            "Salvaged {} ore, {:.0} fuel, {} parts from {} wreck",
+            ore,
+            fuel,
+            parts,
+            kind.label()
+        ));
+    }
+}
+
+/// How close to the cursor a pirate must be for a missile to lock onto it
+const AIM_ASSIST_RADIUS: f32 = 30.0;
+
+/// How far ahead of the ship a projectile appears
+const MUZZLE_OFFSET: f32 = 12.0;
+
+/// Fire the selected weapon while the mouse is held. Shots go at the locked
+/// target if one is selected, otherwise toward the cursor.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 pub fn player_fire(
     input: Res<ButtonInput<MouseButton>>,
     mut commands: Commands,
     mut log: ResMut<EventLog>,
-    player_query: Query<&Transform, With<PlayerControl>>,
+    targets: Res<NearbyTargets>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut player_query: Query<
+        (Entity, &Transform, &mut Ship, &mut Armament, &ZoneId),
+        With<PlayerControl>,
+    >,
     pirates: Query<(Entity, &Transform), With<PirateShip>>,
 ) {
-    if !input.just_pressed(MouseButton::Left) {
+    if !input.pressed(MouseButton::Left) {
         return;
     }
 
-    let player_transform = match player_query.single() {
-        Ok(value) => value,
-        Err(_) => {
-            return;
-        }
-    };
+    let (player_entity, player_transform, mut ship, mut armament, zone) =
+        match player_query.single_mut() {
+            Ok(value) => value,
+            Err(_) => {
+                return;
+            }
+        };
 
     let origin = Vec2::new(
         player_transform.translation.x,
         player_transform.translation.y,
     );
-    let range = 24.0;
-    let mut targets = Vec::new();
-    let mut entities = Vec::new();
 
-    for (entity, transform) in pirates.iter() {
-        targets.push(Vec2::new(transform.translation.x, transform.translation.y));
-        entities.push(entity);
+    let locked = if targets.manually_selected {
+        targets
+            .entities
+            .get(targets.selected_index)
+            .map(|(entity, position, _)| (*position, Some(*entity)))
+    } else {
+        None
+    };
+
+    let (aim, target) = match locked {
+        Some(value) => value,
+        None => {
+            let Some(cursor) = cursor_world_position(&windows, &cameras) else {
+                return;
+            };
+
+            let mut positions = Vec::new();
+            let mut entities = Vec::new();
+            for (entity, transform) in pirates.iter() {
+                positions.push(Vec2::new(transform.translation.x, transform.translation.y));
+                entities.push(entity);
+            }
+            let target = closest_in_range(cursor, &positions, AIM_ASSIST_RADIUS)
+                .and_then(|index| entities.get(index).copied());
+            (cursor, target)
+        }
+    };
+
+    let direction = (aim - origin).normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return;
     }
 
-    if let Some(index) = closest_in_range(origin, &targets, range) {
-        if let Some(target) = entities.get(index) {
-            commands.entity(*target).despawn();
-            log.push("Pirate ship destroyed".to_string());
+    let Some(weapon) = armament.selected_mut() else {
+        return;
+    };
+    let kind = weapon.kind;
+
+    let profile = match weapon.fire(ship.fuel) {
+        Ok(profile) => profile,
+        Err(FireBlocked::Cooldown) => {
+            return;
         }
+        Err(FireBlocked::NoAmmo) => {
+            if input.just_pressed(MouseButton::Left) {
+                log.push(format!("{} out of ammo", kind.label()));
+            }
+            return;
+        }
+        Err(FireBlocked::NoFuel) => {
+            if input.just_pressed(MouseButton::Left) {
+                log.push(format!("Not enough fuel to fire {}", kind.label()));
+            }
+            return;
+        }
+    };
+
+    ship.fuel -= profile.fuel_cost;
+
+    let muzzle = origin + direction * MUZZLE_OFFSET;
+    commands.spawn((
+        Projectile::launch(
+            profile,
+            kind,
+            DamageSource::Player,
+            player_entity,
+            direction,
+            target,
+        ),
+        Name::new("Projectile"),
+        ZoneId(zone.0),
+        SpatialBundle::from_transform(Transform::from_xyz(muzzle.x, muzzle.y, 0.6)),
+    ));
+}
+
+/// Switch to the next weapon on the player's ship.
+pub fn player_cycle_weapon(
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<InputBindings>,
+    mut log: ResMut<EventLog>,
+    mut player_query: Query<&mut Armament, With<PlayerControl>>,
+) {
+    if !input.just_pressed(bindings.cycle_weapon) {
+        return;
+    }
+
+    let Ok(mut armament) = player_query.single_mut() else {
+        return;
+    };
+
+    if let Some(weapon) = armament.cycle() {
+        log.push(format!("Weapon: {}", weapon.status()));
     }
 }
 
@@ -358,8 +669,50 @@ pub fn player_identify_nearby(
 // Helper Functions
 // =============================================================================
 
-pub fn can_build_outpost(ore: u32, cost: u32) -> bool {
-    ore >= cost
+/// Spawn one of the player's stations, deploying, at `position` in `node`'s zone
+pub fn spawn_player_station<'a>(
+    commands: &'a mut Commands,
+    kind: StationKind,
+    node: &SystemNode,
+    position: Vec2,
+    fuel: f32,
+) -> EntityCommands<'a> {
+    let fuel_capacity = station_fuel_capacity(kind);
+    let mut entity_commands = commands.spawn((
+        Station {
+            kind,
+            state: StationState::Deploying,
+            fuel: fuel.min(fuel_capacity),
+            fuel_capacity,
+        },
+        Faction::Player,
+        StationBuild {
+            remaining_seconds: station_build_time_seconds(kind),
+        },
+        StationCrises::default(),
+        StationMaintenance::default(),
+        StationCrisisLog::default(),
+        ZoneId(node.id),
+        Name::new(format!("Station-{:?}-{}", kind, node.id)),
+        SpatialBundle::from_transform(Transform::from_xyz(position.x, position.y, 0.5)),
+    ));
+
+    match kind {
+        StationKind::FuelDepot => {
+            entity_commands.insert(FuelDepotPolicy::default());
+        }
+        StationKind::SensorStation => {
+            entity_commands.insert(SensorSweep::default());
+        }
+        _ => {}
+    }
+
+    let ore_capacity = station_ore_capacity(kind);
+    if ore_capacity > 0.0 {
+        entity_commands.insert(StationProduction::new(ore_capacity));
+    }
+
+    entity_commands
 }
 
 pub fn transfer_fuel(
@@ -382,6 +735,16 @@ pub fn transfer_fuel(
     (ship_fuel - transfer, station_fuel + transfer, true)
 }
 
+fn cursor_world_position(
+    windows: &Query<&Window, With<PrimaryWindow>>,
+    cameras: &Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+) -> Option<Vec2> {
+    let window = windows.single().ok()?;
+    let cursor = window.cursor_position()?;
+    let (camera, camera_transform) = cameras.single().ok()?;
+    camera.viewport_to_world_2d(camera_transform, cursor).ok()
+}
+
 pub fn closest_in_range(origin: Vec2, targets: &[Vec2], range: f32) -> Option<usize> {
     let mut closest = None;
     let mut best_dist = 0.0;
@@ -403,12 +766,6 @@ pub fn closest_in_range(origin: Vec2, targets: &[Vec2], range: f32) -> Option<us
 mod tests {
     use super::*;
 
-    #[test]
-    fn can_build_outpost_requires_enough_ore() {
-        assert!(can_build_outpost(18, 18));
-        assert!(!can_build_outpost(10, 18));
-    }
-
     #[test]
     fn transfer_fuel_respects_capacity() {
         let (ship, station, did) = transfer_fuel(5.0, 8.0, 10.0, 5.0);
@@ -424,4 +781,27 @@ mod tests {
         let index = closest_in_range(origin, &targets, 12.0);
         assert_eq!(index, Some(1));
     }
+
+    #[test]
+    fn salvage_session_books_one_ledger_entry() {
+        let mut session = SalvageSession {
+            wreck: Some((Entity::from_bits(7), WreckKind::PirateShip)),
+            ore: 4,
+            fuel: 6.0,
+            parts: 1,
+        };
+        let mut log = EventLog::default();
+        let mut ledger = Ledger::default();
+
+        end_salvage_session(&mut session, &mut log, &mut ledger, 30);
+        end_salvage_session(&mut session, &mut log, &mut ledger, 31);
+
+        assert_eq!(ledger.entries.len(), 1);
+        assert_eq!(ledger.entries[0].category, LedgerCategory::Bounty);
+        assert_eq!(ledger.entries[0].ore, 4.0);
+        assert_eq!(
+            log.entries().to_vec(),
+            vec!["Salvaged 4 ore, 6 fuel, 1 parts from Raider wreck".to_string()]
+        );
+    }
 }
@@ -21,8 +21,12 @@ use bevy::prelude::*;
 use crate::plugins::core::SimConfig;
 
 // Re-export public types
-pub use components::{AutopilotState, DockingState, NearbyTargets, PlayerControl};
+pub use components::{
+    AutopilotState, ConstructionMenu, ConstructionPreview, DockingState, EscapePod, NearbyTargets,
+    PlayerControl, SalvageSession,
+};
 pub use gates::process_jump_transition;
+pub use interactions::spawn_player_station;
 #[allow(unused_imports)]
 pub use targeting::{filter_entities_by_zone, find_zone_for_position};
 
@@ -37,14 +41,23 @@ impl Plugin for PlayerPlugin {
         app.init_resource::<NearbyTargets>()
             .init_resource::<AutopilotState>()
             .init_resource::<DockingState>()
+            .init_resource::<ConstructionMenu>()
+            .init_resource::<ConstructionPreview>()
+            .init_resource::<SalvageSession>()
             // Continuous simulation systems (FixedUpdate)
             .add_systems(
                 FixedUpdate,
                 (
                     movement::player_movement
                         .run_if(autopilot::autopilot_not_engaged)
-                        .run_if(docking::player_not_docked),
-                    interactions::player_mining.run_if(docking::player_not_docked),
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
+                    interactions::player_mining
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
+                    interactions::player_salvage
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
                     interactions::player_identify_nearby,
                     gates::process_jump_transition,
                 )
@@ -60,6 +73,7 @@ impl Plugin for PlayerPlugin {
                     .run_if(sim_not_paused)
                     .run_if(autopilot::autopilot_engaged)
                     .run_if(docking::player_not_docked)
+                    .run_if(docking::construction_menu_closed)
                     .after(targeting::scan_nearby_entities),
             )
             // One-shot input systems (Update) - just_pressed must run in Update
@@ -69,23 +83,31 @@ impl Plugin for PlayerPlugin {
                     targeting::handle_tactical_selection,
                     autopilot::autopilot_input_system,
                     docking::player_undock.run_if(docking::player_is_docked),
+                    interactions::player_close_construction.run_if(docking::construction_menu_open),
                     // One-shot interactions that use just_pressed
                     gates::player_activate_jump_gate
                         .run_if(sim_not_paused)
                         .run_if(gates::not_in_jump_transition)
-                        .run_if(docking::player_not_docked),
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
                     docking::player_dock_station
                         .run_if(sim_not_paused)
-                        .run_if(docking::player_not_docked),
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
                     interactions::player_fire
                         .run_if(sim_not_paused)
-                        .run_if(docking::player_not_docked),
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
+                    interactions::player_cycle_weapon,
                     interactions::player_refuel_station
                         .run_if(sim_not_paused)
-                        .run_if(docking::player_not_docked),
-                    interactions::player_build_outpost
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
+                    interactions::player_open_construction
+                        .after(docking::player_dock_station)
                         .run_if(sim_not_paused)
-                        .run_if(docking::player_not_docked),
+                        .run_if(docking::player_not_docked)
+                        .run_if(docking::construction_menu_closed),
                 )
                     .run_if(targeting::view_is_world),
             );
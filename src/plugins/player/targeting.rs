@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 
+use crate::combat::Wreck;
 use crate::ore::OreNode;
 use crate::pirates::{PirateBase, PirateShip};
 use crate::plugins::core::{InputBindings, ViewMode};
@@ -61,6 +62,7 @@ pub fn scan_nearby_entities(
         Option<&ZoneId>,
         Option<&Identified>,
     )>,
+    wrecks: Query<(Entity, &Transform, &Wreck, Option<&ZoneId>)>,
 ) {
     // Verify player exists and has a zone
     let Ok((player_transform, player_zone)) = player_query.single() else {
@@ -144,6 +146,15 @@ pub fn scan_nearby_entities(
         }
     }
 
+    // Scan all wrecks in player's zone (debris is obvious on sight)
+    for (entity, transform, wreck, zone) in wrecks.iter() {
+        if zone.is_some_and(|z| z.0 == player_zone.0) {
+            let pos = Vec2::new(transform.translation.x, transform.translation.y);
+            let label = format!("[#] Wreck ({})", wreck.kind.label());
+            targets.entities.push((entity, pos, label));
+        }
+    }
+
     // Scan all jump gates in player's zone
     for (entity, transform, gate, zone, identified) in jump_gates.iter() {
         if zone.is_some_and(|z| z.0 == player_zone.0) {
@@ -2,9 +2,10 @@
 
 use bevy::prelude::*;
 
+use crate::pirates::PirateControl;
 use crate::plugins::core::InputBindings;
 use crate::plugins::sim::{advance_intel_layer, refresh_intel};
-use crate::world::SystemIntel;
+use crate::world::{SystemIntel, SystemNode};
 
 use super::components::{shift_pressed, NodeVisual};
 use super::map::{IntelRefreshCooldown, RenderToggles};
@@ -78,8 +79,9 @@ pub fn handle_intel_refresh(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
     ticks: Res<crate::plugins::sim::SimTickCount>,
+    control: Res<PirateControl>,
     mut cooldown: ResMut<IntelRefreshCooldown>,
-    mut intel_query: Query<&mut SystemIntel>,
+    mut intel_query: Query<(&SystemNode, &mut SystemIntel)>,
 ) {
     if !shift_pressed(&input) || !input.just_pressed(bindings.refresh_intel) {
         return;
@@ -89,11 +91,17 @@ pub fn handle_intel_refresh(
         return;
     }
 
-    for mut intel in intel_query.iter_mut() {
+    let mut jammed = 0;
+    for (node, mut intel) in intel_query.iter_mut() {
+        // Pirate-held zones jam the refresh
+        if control.blocks_intel(node.id) {
+            jammed += 1;
+            continue;
+        }
         refresh_intel(&mut intel, ticks.tick);
     }
     cooldown.next_allowed_tick = ticks.tick.saturating_add(cooldown.cooldown_ticks);
-    info!("Intel refreshed");
+    info!("Intel refreshed ({} zones jammed by pirates)", jammed);
 }
 
 pub fn handle_intel_advance(
@@ -1,8 +1,11 @@
-//! Visual effects: focus marker, tactical navigation, home beacon, current zone marker.
+//! Visual effects: focus marker, construction preview, tactical navigation, home beacon,
+//! current zone marker, projectile tracers, impacts and wreck debris.
 
 use bevy::prelude::*;
 
-use crate::plugins::player::{NearbyTargets, PlayerControl};
+use crate::combat::{ImpactFlash, Projectile, WeaponKind, Wreck, WreckKind, IMPACT_FLASH_SECONDS};
+use crate::plugins::player::{ConstructionPreview, NearbyTargets, PlayerControl};
+use crate::ships::ShipKind;
 use crate::world::{Sector, SystemIntel, ZoneId};
 
 use super::components::find_node_position;
@@ -25,6 +28,14 @@ pub struct HomeBeaconEnabled {
 const BEACON_ARROW_OFFSET: f32 = 40.0;
 const BEACON_ARROW_LENGTH: f32 = 40.0;
 const BEACON_TIP_SIZE: f32 = 8.0;
+const IMPACT_MAX_RADIUS: f32 = 10.0;
+/// Plate offsets (in units of wreck size) and angles for wreck debris
+const WRECK_DEBRIS: [((f32, f32), f32); 4] = [
+    ((-0.6, 0.3), 0.4),
+    ((0.5, 0.5), 2.1),
+    ((0.2, -0.6), 1.2),
+    ((-0.3, -0.2), 2.8),
+];
 
 // =============================================================================
 // Systems
@@ -54,6 +65,34 @@ pub fn draw_focus_marker(mut gizmos: Gizmos, marker: Res<FocusMarker>) {
     gizmos.circle_2d(position, size * 0.6, Color::srgba(0.7, 0.85, 0.95, 0.35));
 }
 
+/// Outline the construction site the build menu is pointing at: green when the
+/// station can go there, red when the site is blocked
+pub fn draw_construction_preview(mut gizmos: Gizmos, preview: Res<ConstructionPreview>) {
+    let Some((site, valid)) = preview.site else {
+        return;
+    };
+
+    let color = if valid {
+        Color::srgba(0.3, 1.0, 0.4, 0.8)
+    } else {
+        Color::srgba(1.0, 0.3, 0.25, 0.8)
+    };
+    let size = 12.0;
+
+    gizmos.circle_2d(site, size, color);
+    gizmos.line_2d(
+        site + Vec2::new(-size * 0.5, 0.0),
+        site + Vec2::new(size * 0.5, 0.0),
+        color,
+    );
+    gizmos.line_2d(
+        site + Vec2::new(0.0, -size * 0.5),
+        site + Vec2::new(0.0, size * 0.5),
+        color,
+    );
+    gizmos.circle_2d(site, size * 1.5, color.with_alpha(0.35));
+}
+
 pub fn draw_tactical_navigation(
     mut gizmos: Gizmos,
     targets: Res<NearbyTargets>,
@@ -349,3 +388,105 @@ pub fn draw_current_zone_marker(
         marker_color,
     );
 }
+
+/// Draws a short tracer behind every projectile in the player's zone.
+pub fn draw_projectile_tracers(
+    mut gizmos: Gizmos,
+    player_query: Query<&ZoneId, With<PlayerControl>>,
+    projectiles: Query<(&Transform, &Projectile, Option<&ZoneId>)>,
+) {
+    let player_zone = match player_query.single() {
+        Ok(zone) => zone.0,
+        Err(_) => return,
+    };
+
+    for (transform, projectile, zone) in projectiles.iter() {
+        if zone.map(|zone| zone.0) != Some(player_zone) {
+            continue;
+        }
+
+        let position = Vec2::new(transform.translation.x, transform.translation.y);
+        let direction = projectile.velocity.normalize_or_zero();
+        let (length, color) = tracer_style(projectile.kind);
+        gizmos.line_2d(position - direction * length, position, color);
+
+        if projectile.kind == WeaponKind::Missile {
+            gizmos.circle_2d(position, 2.5, color);
+        }
+    }
+}
+
+/// Draws an expanding ring where projectiles struck something.
+pub fn draw_impacts(
+    mut gizmos: Gizmos,
+    player_query: Query<&ZoneId, With<PlayerControl>>,
+    flashes: Query<(&Transform, &ImpactFlash, Option<&ZoneId>)>,
+) {
+    let player_zone = match player_query.single() {
+        Ok(zone) => zone.0,
+        Err(_) => return,
+    };
+
+    for (transform, flash, zone) in flashes.iter() {
+        if zone.map(|zone| zone.0) != Some(player_zone) {
+            continue;
+        }
+
+        let progress = 1.0 - (flash.remaining_seconds / IMPACT_FLASH_SECONDS).clamp(0.0, 1.0);
+        let position = Vec2::new(transform.translation.x, transform.translation.y);
+        let (_, color) = tracer_style(flash.kind);
+        gizmos.circle_2d(
+            position,
+            2.0 + IMPACT_MAX_RADIUS * progress,
+            color.with_alpha(1.0 - progress),
+        );
+    }
+}
+
+/// Draws wrecks as a scatter of broken hull plates.
+pub fn draw_wrecks(
+    mut gizmos: Gizmos,
+    player_query: Query<&ZoneId, With<PlayerControl>>,
+    wrecks: Query<(&Transform, &Wreck, Option<&ZoneId>)>,
+) {
+    let player_zone = match player_query.single() {
+        Ok(zone) => zone.0,
+        Err(_) => return,
+    };
+
+    for (transform, wreck, zone) in wrecks.iter() {
+        if zone.map(|zone| zone.0) != Some(player_zone) {
+            continue;
+        }
+
+        let position = Vec2::new(transform.translation.x, transform.translation.y);
+        let (size, color) = wreck_style(wreck.kind);
+        for (offset, angle) in WRECK_DEBRIS {
+            let center = position + Vec2::from(offset) * size;
+            let half = Vec2::from_angle(angle) * size * 0.35;
+            gizmos.line_2d(center - half, center + half, color);
+        }
+        gizmos.circle_2d(position, size * 1.2, color.with_alpha(0.2));
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn wreck_style(kind: WreckKind) -> (f32, Color) {
+    match kind {
+        WreckKind::Ship(ShipKind::PlayerShip) => (8.0, Color::srgba(0.5, 0.85, 1.0, 0.8)),
+        WreckKind::Ship(_) => (7.0, Color::srgba(0.65, 0.7, 0.75, 0.8)),
+        WreckKind::PirateShip => (7.0, Color::srgba(0.85, 0.45, 0.4, 0.8)),
+        WreckKind::Station(_) => (16.0, Color::srgba(0.7, 0.65, 0.55, 0.8)),
+    }
+}
+
+fn tracer_style(kind: WeaponKind) -> (f32, Color) {
+    match kind {
+        WeaponKind::Kinetic => (10.0, Color::srgba(1.0, 0.9, 0.4, 0.9)),
+        WeaponKind::Beam => (28.0, Color::srgba(0.4, 0.9, 1.0, 0.9)),
+        WeaponKind::Missile => (6.0, Color::srgba(1.0, 0.55, 0.2, 0.9)),
+    }
+}
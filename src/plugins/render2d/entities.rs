@@ -174,7 +174,7 @@ pub fn update_station_labels(
     player_query: Query<&ZoneId, With<PlayerControl>>,
     stations: Query<(
         &Station,
-        Option<&crate::stations::StationCrisis>,
+        Option<&crate::stations::StationCrises>,
         &Transform,
         Option<&ZoneId>,
     )>,
@@ -196,7 +196,7 @@ pub fn update_station_labels(
     // Get player zone for filtering
     let player_zone = player_query.single().map(|z| z.0).ok();
 
-    for (station, crisis, transform, zone) in stations.iter() {
+    for (station, crises, transform, zone) in stations.iter() {
         // Skip stations not in player's zone
         if let Some(pz) = player_zone {
             let entity_zone = zone.map(|z| z.0);
@@ -205,7 +205,8 @@ pub fn update_station_labels(
             }
         }
 
-        let crisis_icon = if crisis.is_some() { "!" } else { "" };
+        // One mark per concurrent crisis
+        let crisis_icon = "!".repeat(crises.map_or(0, |crises| crises.len()));
         let label = format!("{}{}", station_kind_short(station.kind), crisis_icon);
         let pos = Vec2::new(transform.translation.x, transform.translation.y + 10.0);
 
@@ -6,6 +6,7 @@ use bevy::window::PrimaryWindow;
 use std::path::Path;
 
 use crate::compat::{SpriteBundle, TextBundle, TextStyle};
+use crate::pirates::PirateControl;
 use crate::plugins::core::FogConfig;
 use crate::plugins::ui::{HoveredNode, MapUi};
 use crate::stations::{Station, StationKind};
@@ -98,6 +99,16 @@ impl FocusMarker {
     }
 }
 
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Pirate control below this is not drawn
+const CONTROL_OVERLAY_MIN_LEVEL: f32 = 0.05;
+const CONTROL_OVERLAY_INNER_RADIUS: f32 = 26.0;
+const CONTROL_OVERLAY_SPREAD: f32 = 40.0;
+const CONTROL_OVERLAY_RING_STEP: f32 = 3.0;
+
 // =============================================================================
 // Systems
 // =============================================================================
@@ -217,9 +228,42 @@ pub fn draw_intel_rings(
     }
 }
 
+/// Tints revealed nodes by pirate control so contested territory reads at a glance.
+pub fn draw_pirate_control_overlay(
+    mut gizmos: Gizmos,
+    control: Res<PirateControl>,
+    toggles: Res<RenderToggles>,
+    nodes: Query<(&SystemNode, &SystemIntel)>,
+) {
+    if !toggles.show_nodes {
+        return;
+    }
+
+    for (node, intel) in nodes.iter() {
+        if !intel.revealed {
+            continue;
+        }
+
+        let level = control.level(node.id);
+        if level < CONTROL_OVERLAY_MIN_LEVEL {
+            continue;
+        }
+
+        // Stacked rings approximate a filled disc whose reach and opacity grow with control
+        let color = control_overlay_color(level);
+        let outer = CONTROL_OVERLAY_INNER_RADIUS + level * CONTROL_OVERLAY_SPREAD;
+        let mut radius = CONTROL_OVERLAY_INNER_RADIUS;
+        while radius <= outer {
+            gizmos.circle_2d(node.position, radius, color);
+            radius += CONTROL_OVERLAY_RING_STEP;
+        }
+    }
+}
+
 pub fn draw_routes(
     mut gizmos: Gizmos,
     sector: Res<Sector>,
+    control: Res<PirateControl>,
     toggles: Res<RenderToggles>,
     nodes: Query<(&SystemNode, &SystemIntel)>,
 ) {
@@ -243,17 +287,19 @@ pub fn draw_routes(
         let end = find_node_position(&sector.nodes, route.to);
 
         if let (Some(start), Some(end)) = (start, end) {
-            let color = risk_color(route.risk);
+            let color = risk_color(control.effective_route_risk(route));
             gizmos.line_2d(start, end, color);
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_route_labels(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     toggles: Res<RenderToggles>,
     sector: Res<Sector>,
+    control: Res<PirateControl>,
     nodes: Query<(&SystemNode, &SystemIntel)>,
     labels: Query<Entity, With<RouteLabel>>,
     cameras: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
@@ -299,10 +345,11 @@ pub fn update_route_labels(
 
         if let (Some(start), Some(end)) = (start, end) {
             let mid = (start + end) * 0.5;
-            let label = format!("{:.0} r{:.2}", route.distance, route.risk);
+            let risk = control.effective_route_risk(route);
+            let label = format!("{:.0} r{:.2}", route.distance, risk);
             if let Ok(screen) = camera.world_to_viewport(camera_transform, mid.extend(0.0)) {
                 let position = Vec2::new(screen.x + 6.0, screen.y - 10.0);
-                let label_color = risk_color(route.risk);
+                let label_color = risk_color(risk);
                 commands.spawn((
                     RouteLabel,
                     MapUi,
@@ -600,6 +647,16 @@ pub fn update_station_map_labels(
     }
 }
 
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Pirate territory tint: a dim red wash that deepens as control grows.
+fn control_overlay_color(level: f32) -> Color {
+    let t = level.clamp(0.0, 1.0);
+    Color::srgba(0.85, 0.15 + 0.1 * (1.0 - t), 0.2, 0.05 + 0.2 * t)
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -652,4 +709,12 @@ mod tests {
             "blue component not between"
         );
     }
+
+    #[test]
+    fn control_overlay_color_deepens_with_control() {
+        let light = Srgba::from(control_overlay_color(0.1));
+        let heavy = Srgba::from(control_overlay_color(0.9));
+        assert!(heavy.alpha > light.alpha);
+        assert!(heavy.green < light.green);
+    }
 }
@@ -77,6 +77,7 @@ impl Plugin for Render2DPlugin {
                     map::sync_node_visuals,
                     map::update_node_visuals,
                     map::draw_intel_rings,
+                    map::draw_pirate_control_overlay,
                     map::draw_routes,
                     map::update_route_labels,
                     map::update_node_labels,
@@ -135,8 +136,12 @@ impl Plugin for Render2DPlugin {
                     ),
                     (
                         effects::draw_focus_marker,
+                        effects::draw_construction_preview,
                         effects::draw_tactical_navigation,
                         effects::draw_home_beacon,
+                        effects::draw_projectile_tracers,
+                        effects::draw_impacts,
+                        effects::draw_wrecks,
                         sync_view_entities,
                     ),
                 )
@@ -1,14 +1,33 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
+use crate::combat::{captured_hull, escape_pod_hull, Hull, Projectile, Shields, Wreck, WreckKind};
+use crate::commodities::Inventory;
 use crate::compat::SpatialBundle;
+use crate::crises::{Crisis, CrisisHistory, CRISIS_GRACE_SECONDS};
+use crate::ledger::Ledger;
 
-use crate::plugins::core::{EventLog, GameState, InputBindings};
-use crate::ships::{ship_default_role, Fleet, FleetRole, Ship, ShipFuelAlert, ShipKind, ShipState};
+use crate::factions::Faction;
+use crate::fleets::PatrolOrder;
+use crate::pirates::{PirateControl, PirateLoot, PirateMemory, PirateShip, PirateShipBehavior};
+use crate::plugins::core::{EventLog, GameState, InputBindings, RunStatus};
+use crate::plugins::player::{find_zone_for_position, EscapePod, PlayerControl};
+use crate::plugins::sim::{BoundaryWarningState, SimTickCount};
+use crate::plugins::worldgen::WorldSeed;
+use crate::ships::{
+    cargo_capacity, ship_default_role, Captured, Cargo, Credits, Fleet, FleetRole, Ship,
+    ShipFuelAlert, ShipId, ShipIds, ShipKind, ShipState, Velocity,
+};
 use crate::stations::{
-    CrisisStage, CrisisType, Station, StationBuild, StationCrisis, StationCrisisLog, StationKind,
-    StationState,
+    station_ore_capacity, ConstructionSite, CrisisStage, CrisisType, Decommissioned,
+    FuelDepotPolicy, MarketIntel, OutpostMarket, OutpostTrade, RecipeKind, RefineryQueue,
+    RefineryStorage, SensorSweep, ShipyardQueue, ShipyardStorage, Station, StationBuild,
+    StationCrises, StationCrisisLog, StationKind, StationMaintenance, StationProduction,
+    StationRecipe, StationState,
+};
+use crate::world::{
+    KnowledgeLayer, RouteEdge, Sector, SystemIntel, SystemNode, ZoneId, ZoneModifier,
 };
-use crate::world::{KnowledgeLayer, RouteEdge, Sector, SystemIntel, SystemNode, ZoneModifier};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
@@ -34,6 +53,32 @@ struct SaveSector {
     stations: Vec<SaveStation>,
     #[serde(default)]
     ships: Vec<SaveShip>,
+    #[serde(default)]
+    pirate_memory: PirateMemory,
+    #[serde(default)]
+    pirate_control: PirateControl,
+    #[serde(default)]
+    crisis_history: CrisisHistory,
+    #[serde(default)]
+    market_intel: MarketIntel,
+    #[serde(default)]
+    ledger: Ledger,
+    #[serde(default)]
+    ship_ids: ShipIds,
+    /// Raiders and their holds; saves made before raiders were kept leave the live ones be
+    #[serde(default)]
+    pirates: Option<Vec<SavePirate>>,
+    #[serde(default)]
+    run_status: RunStatus,
+    /// Debris still holding salvage, the player's lost cargo included
+    #[serde(default)]
+    wrecks: Vec<SaveWreck>,
+    /// Sim clock and world seed that gate jump rolls are keyed on; older
+    /// saves keep the running values
+    #[serde(default)]
+    tick: Option<u64>,
+    #[serde(default)]
+    seed: Option<u64>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -67,14 +112,129 @@ struct SaveStation {
     fuel_capacity: f32,
     #[serde(default)]
     build_remaining: f32,
+    /// Open crises with their clocks, so resolved ones keep their true onset and peak
     #[serde(default)]
-    crisis_type: Option<CrisisType>,
+    open_crises: Vec<SaveCrisis>,
+    /// Reserve a FuelDepot keeps back for itself
+    #[serde(default)]
+    depot_reserve: Option<f32>,
+    /// Goods waiting in the station's production hold
+    #[serde(default)]
+    hold: Option<Inventory>,
+    /// Recipe the station was running
+    #[serde(default)]
+    recipe: Option<RecipeKind>,
+    /// Ore-only hold from saves made before stations stored other goods
+    #[serde(default, skip_serializing)]
+    ore: f32,
+    /// Materials delivered to and built into a station the player ordered
+    #[serde(default)]
+    construction: Option<ConstructionSite>,
+    /// An Outpost's prices and standing with the player
     #[serde(default)]
+    outpost_trade: Option<OutpostTrade>,
+    /// An Outpost's stock and demand per good
+    #[serde(default)]
+    outpost_market: Option<OutpostMarket>,
+    /// Shut down and waiting for its stock to be moved
+    #[serde(default)]
+    decommissioned: bool,
+    /// Zone the station sits in; older saves place it by its nearest node
+    #[serde(default)]
+    zone: Option<u32>,
+    /// A Shipyard's build queue, replacement hulls included
+    #[serde(default)]
+    shipyard_queue: Option<ShipyardQueue>,
+    /// Finished hulls waiting in a Shipyard's hangar
+    #[serde(default)]
+    shipyard_storage: Option<ShipyardStorage>,
+    /// A Refinery's ore batches and stock target
+    #[serde(default)]
+    refinery_queue: Option<RefineryQueue>,
+    /// Fuel a Refinery has made and not yet handed out
+    #[serde(default)]
+    refinery_storage: Option<RefineryStorage>,
+    /// Damage taken; older saves refit stations with fresh hulls
+    #[serde(default)]
+    hull: Option<Hull>,
+    #[serde(default)]
+    shields: Option<Shields>,
+    /// Stage-only crises from saves made before crisis clocks were kept
+    #[serde(default, skip_serializing)]
+    crises: Vec<(CrisisType, CrisisStage)>,
+    /// Single-crisis fields from saves made before stations tracked several at once
+    #[serde(default, skip_serializing)]
+    crisis_type: Option<CrisisType>,
+    #[serde(default, skip_serializing)]
     crisis_stage: Option<CrisisStage>,
 }
 
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SaveCrisis {
+    crisis_type: CrisisType,
+    stage: CrisisStage,
+    peak_stage: CrisisStage,
+    onset_tick: u64,
+    #[serde(default)]
+    elapsed_seconds: f32,
+    #[serde(default)]
+    grace_seconds: f32,
+    #[serde(default)]
+    escalation_seconds: f32,
+    #[serde(default)]
+    recovery_seconds: f32,
+}
+
+impl SaveCrisis {
+    fn from_crisis(crisis: &Crisis) -> Self {
+        Self {
+            crisis_type: crisis.crisis_type,
+            stage: crisis.stage,
+            peak_stage: crisis.peak_stage,
+            onset_tick: crisis.onset_tick,
+            elapsed_seconds: crisis.elapsed_seconds,
+            grace_seconds: crisis.grace_seconds,
+            escalation_seconds: crisis.escalation_seconds,
+            recovery_seconds: crisis.recovery_seconds,
+        }
+    }
+
+    /// A crisis from an older save that only kept its stage; its clocks start
+    /// over at `tick` with a fresh grace period
+    fn legacy(crisis_type: CrisisType, stage: CrisisStage, tick: u64) -> Self {
+        Self {
+            crisis_type,
+            stage,
+            peak_stage: stage,
+            onset_tick: tick,
+            elapsed_seconds: 0.0,
+            grace_seconds: CRISIS_GRACE_SECONDS,
+            escalation_seconds: 0.0,
+            recovery_seconds: 0.0,
+        }
+    }
+
+    fn to_crisis(&self, station: Entity, station_kind: StationKind) -> Crisis {
+        Crisis {
+            station,
+            station_kind,
+            crisis_type: self.crisis_type,
+            stage: self.stage,
+            peak_stage: self.peak_stage,
+            onset_tick: self.onset_tick,
+            elapsed_seconds: self.elapsed_seconds,
+            grace_seconds: self.grace_seconds,
+            escalation_seconds: self.escalation_seconds,
+            recovery_seconds: self.recovery_seconds,
+        }
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 struct SaveShip {
+    /// Run-stable id; saves made before ships had one get a fresh id on load
+    #[serde(default)]
+    id: Option<ShipId>,
     kind: ShipKind,
     state: ShipState,
     #[serde(default)]
@@ -83,14 +243,79 @@ struct SaveShip {
     y: f32,
     fuel: f32,
     fuel_capacity: f32,
+    #[serde(default)]
+    zone: Option<u32>,
+    /// The player lost their ship and is flying the escape pod
+    #[serde(default)]
+    escape_pod: bool,
+    /// A raider the player boarded and pressed into service
+    #[serde(default)]
+    captured: bool,
+    /// Post held by a Security ship on patrol
+    #[serde(default)]
+    patrol: Option<(f32, f32)>,
+    /// Goods in the ship's cargo hold
+    #[serde(default)]
+    hold: Option<Inventory>,
+    /// Fuel carried as cargo rather than in the tank
+    #[serde(default)]
+    cargo_fuel: f32,
+    /// The player's balance; older saves start over at the opening balance
+    #[serde(default)]
+    credits: Option<u32>,
+    /// Damage taken; older saves refit ships with fresh hulls
+    #[serde(default)]
+    hull: Option<Hull>,
+    #[serde(default)]
+    shields: Option<Shields>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SavePirate {
+    x: f32,
+    y: f32,
+    #[serde(default)]
+    zone: Option<u32>,
+    speed: f32,
+    #[serde(default)]
+    loot: PirateLoot,
+    /// What the raider was up to; a Disabled one can still be boarded
+    #[serde(default)]
+    behavior: PirateShipBehavior,
+    /// Damage taken; older saves refit raiders with fresh hulls
+    #[serde(default)]
+    hull: Option<Hull>,
+    #[serde(default)]
+    shields: Option<Shields>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SaveWreck {
+    x: f32,
+    y: f32,
+    #[serde(default)]
+    zone: Option<u32>,
+    wreck: Wreck,
 }
 
 impl SaveSector {
+    #[allow(clippy::too_many_arguments)]
     fn from_sector(
         sector: &Sector,
         intel_map: &HashMap<u32, &SystemIntel>,
         stations: &[SaveStation],
         ships: &[SaveShip],
+        pirate_memory: &PirateMemory,
+        pirate_control: &PirateControl,
+        crisis_history: &CrisisHistory,
+        market_intel: &MarketIntel,
+        ledger: &Ledger,
+        ship_ids: &ShipIds,
+        pirates: &[SavePirate],
+        run_status: &RunStatus,
+        wrecks: &[SaveWreck],
+        tick: u64,
+        seed: u64,
     ) -> Self {
         let nodes = sector
             .nodes
@@ -132,22 +357,70 @@ impl SaveSector {
             intel,
             stations: stations.to_vec(),
             ships: ships.to_vec(),
+            pirate_memory: pirate_memory.clone(),
+            pirate_control: pirate_control.clone(),
+            crisis_history: crisis_history.clone(),
+            market_intel: market_intel.clone(),
+            ledger: ledger.clone(),
+            ship_ids: ship_ids.clone(),
+            pirates: Some(pirates.to_vec()),
+            run_status: run_status.clone(),
+            wrecks: wrecks.to_vec(),
+            tick: Some(tick),
+            seed: Some(seed),
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 fn handle_save_request(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
     sector: Res<Sector>,
+    records: RunRecords,
     intel_query: Query<(&SystemNode, &SystemIntel)>,
     station_query: Query<(
         &Station,
         &Transform,
         Option<&StationBuild>,
-        Option<&StationCrisis>,
+        Option<&StationCrises>,
+        Option<&FuelDepotPolicy>,
+        Option<&StationProduction>,
+        Option<&ConstructionSite>,
+        Option<&StationRecipe>,
+        Option<&OutpostTrade>,
+        Option<&OutpostMarket>,
+        Has<Decommissioned>,
+        Option<&ZoneId>,
+        (Option<&ShipyardQueue>, Option<&ShipyardStorage>),
+        (Option<&RefineryQueue>, Option<&RefineryStorage>),
+        (Option<&Hull>, Option<&Shields>),
     )>,
-    ship_query: Query<(&Ship, &Transform, Option<&Fleet>)>,
+    ship_query: Query<(
+        &Ship,
+        &Transform,
+        Option<&Fleet>,
+        Option<&ShipId>,
+        Option<&ZoneId>,
+        Has<EscapePod>,
+        Has<Captured>,
+        Option<&PatrolOrder>,
+        Option<&Cargo>,
+        Option<&Credits>,
+        Option<&Hull>,
+        Option<&Shields>,
+    )>,
+    crisis_query: Query<&Crisis>,
+    pirate_query: Query<(
+        &PirateShip,
+        &PirateLoot,
+        &Transform,
+        Option<&ZoneId>,
+        Option<&Hull>,
+        Option<&Shields>,
+    )>,
+    wreck_query: Query<(&Wreck, &Transform, Option<&ZoneId>)>,
     mut log: ResMut<EventLog>,
 ) {
     if input.just_pressed(bindings.save) {
@@ -159,33 +432,145 @@ fn handle_save_request(
 
         let stations = station_query
             .iter()
-            .map(|(station, transform, build, crisis)| SaveStation {
-                kind: station.kind,
-                state: station.state,
-                x: transform.translation.x,
-                y: transform.translation.y,
-                fuel: station.fuel,
-                fuel_capacity: station.fuel_capacity,
-                build_remaining: build.map_or(0.0, |build| build.remaining_seconds),
-                crisis_type: crisis.map(|crisis| crisis.crisis_type),
-                crisis_stage: crisis.map(|crisis| crisis.stage),
-            })
+            .map(
+                |(
+                    station,
+                    transform,
+                    build,
+                    crises,
+                    depot,
+                    production,
+                    site,
+                    recipe,
+                    trade,
+                    market,
+                    decommissioned,
+                    zone,
+                    (shipyard_queue, shipyard_storage),
+                    (refinery_queue, refinery_storage),
+                    (hull, shields),
+                )| {
+                    SaveStation {
+                        kind: station.kind,
+                        state: station.state,
+                        x: transform.translation.x,
+                        y: transform.translation.y,
+                        fuel: station.fuel,
+                        fuel_capacity: station.fuel_capacity,
+                        build_remaining: build.map_or(0.0, |build| build.remaining_seconds),
+                        open_crises: crises.map_or_else(Vec::new, |crises| {
+                            crises
+                                .iter()
+                                .filter_map(|(_, active)| crisis_query.get(active.entity).ok())
+                                .map(SaveCrisis::from_crisis)
+                                .collect()
+                        }),
+                        depot_reserve: depot.map(|depot| depot.reserve_fraction),
+                        hold: production.map(|production| production.hold.clone()),
+                        recipe: recipe.and_then(|recipe| recipe.active),
+                        ore: 0.0,
+                        construction: site.copied(),
+                        outpost_trade: trade.copied(),
+                        outpost_market: market.cloned(),
+                        decommissioned,
+                        zone: zone.map(|zone| zone.0),
+                        shipyard_queue: shipyard_queue.cloned(),
+                        shipyard_storage: shipyard_storage.cloned(),
+                        refinery_queue: refinery_queue.cloned(),
+                        refinery_storage: refinery_storage.cloned(),
+                        hull: hull.copied(),
+                        shields: shields.copied(),
+                        crises: Vec::new(),
+                        crisis_type: None,
+                        crisis_stage: None,
+                    }
+                },
+            )
             .collect::<Vec<_>>();
 
         let ships = ship_query
             .iter()
-            .map(|(ship, transform, fleet)| SaveShip {
-                kind: ship.kind,
-                state: ship.state,
-                role: fleet.map_or(ship_default_role(ship.kind), |fleet| fleet.role),
+            .map(
+                |(
+                    ship,
+                    transform,
+                    fleet,
+                    id,
+                    zone,
+                    escape_pod,
+                    captured,
+                    patrol,
+                    cargo,
+                    credits,
+                    hull,
+                    shields,
+                )| {
+                    SaveShip {
+                        id: id.copied(),
+                        kind: ship.kind,
+                        state: ship.state,
+                        role: fleet.map_or(ship_default_role(ship.kind), |fleet| fleet.role),
+                        x: transform.translation.x,
+                        y: transform.translation.y,
+                        fuel: ship.fuel,
+                        fuel_capacity: ship.fuel_capacity,
+                        zone: zone.map(|zone| zone.0),
+                        escape_pod,
+                        captured,
+                        patrol: patrol.map(|order| (order.post.x, order.post.y)),
+                        hold: cargo.map(|cargo| cargo.hold.clone()),
+                        cargo_fuel: cargo.map_or(0.0, |cargo| cargo.fuel),
+                        credits: credits.map(|credits| credits.amount),
+                        hull: hull.copied(),
+                        shields: shields.copied(),
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let pirates = pirate_query
+            .iter()
+            .map(
+                |(pirate, loot, transform, zone, hull, shields)| SavePirate {
+                    x: transform.translation.x,
+                    y: transform.translation.y,
+                    zone: zone.map(|zone| zone.0),
+                    speed: pirate.speed,
+                    loot: *loot,
+                    behavior: pirate.behavior,
+                    hull: hull.copied(),
+                    shields: shields.copied(),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let wrecks = wreck_query
+            .iter()
+            .map(|(wreck, transform, zone)| SaveWreck {
                 x: transform.translation.x,
                 y: transform.translation.y,
-                fuel: ship.fuel,
-                fuel_capacity: ship.fuel_capacity,
+                zone: zone.map(|zone| zone.0),
+                wreck: *wreck,
             })
             .collect::<Vec<_>>();
 
-        let payload = SaveSector::from_sector(&sector, &intel_map, &stations, &ships);
+        let payload = SaveSector::from_sector(
+            &sector,
+            &intel_map,
+            &stations,
+            &ships,
+            &records.pirate_memory,
+            &records.pirate_control,
+            &records.crisis_history,
+            &records.market_intel,
+            &records.ledger,
+            &records.ship_ids,
+            &pirates,
+            &records.run_status,
+            &wrecks,
+            records.ticks.tick,
+            records.seed.value,
+        );
         let config = ron::ser::PrettyConfig::default();
         let modifier_summary = summarize_modifiers(&sector);
 
@@ -284,15 +669,48 @@ const SAMPLE_RON: &str = r#"
 
 const SAVE_PATH: &str = "saves/sector.ron";
 
+/// Run-wide records written into a save
+#[derive(SystemParam)]
+struct RunRecords<'w> {
+    pirate_memory: Res<'w, PirateMemory>,
+    pirate_control: Res<'w, PirateControl>,
+    crisis_history: Res<'w, CrisisHistory>,
+    market_intel: Res<'w, MarketIntel>,
+    ledger: Res<'w, Ledger>,
+    ship_ids: Res<'w, ShipIds>,
+    run_status: Res<'w, RunStatus>,
+    ticks: Res<'w, SimTickCount>,
+    seed: Res<'w, WorldSeed>,
+}
+
+/// Live entities a load replaces
+#[derive(SystemParam)]
+struct SectorEntities<'w, 's> {
+    nodes: Query<'w, 's, Entity, With<SystemNode>>,
+    stations: Query<'w, 's, Entity, With<Station>>,
+    ships: Query<'w, 's, Entity, With<Ship>>,
+    crises: Query<'w, 's, Entity, With<Crisis>>,
+    pirates: Query<'w, 's, Entity, With<PirateShip>>,
+    wrecks: Query<'w, 's, Entity, With<Wreck>>,
+    projectiles: Query<'w, 's, Entity, With<Projectile>>,
+}
+
 #[allow(clippy::too_many_arguments)]
 fn handle_load_request(
     input: Res<ButtonInput<KeyCode>>,
     bindings: Res<InputBindings>,
     mut commands: Commands,
     mut sector: ResMut<Sector>,
-    nodes: Query<Entity, With<SystemNode>>,
-    stations: Query<Entity, With<Station>>,
-    ships: Query<Entity, With<Ship>>,
+    mut pirate_memory: ResMut<PirateMemory>,
+    mut pirate_control: ResMut<PirateControl>,
+    mut crisis_history: ResMut<CrisisHistory>,
+    mut market_intel: ResMut<MarketIntel>,
+    mut ledger: ResMut<Ledger>,
+    mut ship_ids: ResMut<ShipIds>,
+    mut run_status: ResMut<RunStatus>,
+    mut ticks: ResMut<SimTickCount>,
+    mut seed: ResMut<WorldSeed>,
+    existing: SectorEntities,
     mut log: ResMut<EventLog>,
 ) {
     if input.just_pressed(bindings.load) {
@@ -301,10 +719,17 @@ fn handle_load_request(
                 apply_loaded_sector(
                     &mut commands,
                     &mut sector,
+                    &mut pirate_memory,
+                    &mut pirate_control,
+                    &mut crisis_history,
+                    &mut market_intel,
+                    &mut ledger,
+                    &mut ship_ids,
+                    &mut run_status,
+                    &mut ticks,
+                    &mut seed,
                     &loaded,
-                    &nodes,
-                    &stations,
-                    &ships,
+                    &existing,
                 );
                 info!(
                     "Loaded sector from {} (nodes: {}, routes: {})",
@@ -324,10 +749,17 @@ fn handle_load_request(
                     apply_loaded_sector(
                         &mut commands,
                         &mut sector,
+                        &mut pirate_memory,
+                        &mut pirate_control,
+                        &mut crisis_history,
+                        &mut market_intel,
+                        &mut ledger,
+                        &mut ship_ids,
+                        &mut run_status,
+                        &mut ticks,
+                        &mut seed,
                         &loaded,
-                        &nodes,
-                        &stations,
-                        &ships,
+                        &existing,
                     );
                     info!(
                         "Loaded stub sector (nodes: {}, routes: {})",
@@ -353,6 +785,15 @@ fn handle_load_request(
     }
 }
 
+/// Refill an empty hold with what the ship carried when it was saved
+fn restore_cargo(mut cargo: Cargo, ship: &SaveShip) -> Cargo {
+    for (commodity, amount) in ship.hold.iter().flat_map(Inventory::iter) {
+        cargo.hold.add(commodity, amount);
+    }
+    cargo.fuel = ship.cargo_fuel.min(cargo.fuel_capacity);
+    cargo
+}
+
 fn load_sector_from_file() -> Result<Option<SaveSector>, String> {
     let path = Path::new(SAVE_PATH);
 
@@ -383,21 +824,36 @@ fn write_save_file(contents: &str) -> Result<(), String> {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn apply_loaded_sector(
     commands: &mut Commands,
     sector: &mut Sector,
+    pirate_memory: &mut PirateMemory,
+    pirate_control: &mut PirateControl,
+    crisis_history: &mut CrisisHistory,
+    market_intel: &mut MarketIntel,
+    ledger: &mut Ledger,
+    ship_ids: &mut ShipIds,
+    run_status: &mut RunStatus,
+    ticks: &mut SimTickCount,
+    seed: &mut WorldSeed,
     loaded: &SaveSector,
-    nodes: &Query<Entity, With<SystemNode>>,
-    stations: &Query<Entity, With<Station>>,
-    ships: &Query<Entity, With<Ship>>,
+    existing: &SectorEntities,
 ) {
-    for entity in nodes.iter() {
+    for entity in existing.nodes.iter() {
+        commands.entity(entity).despawn();
+    }
+    for entity in existing.stations.iter() {
         commands.entity(entity).despawn();
     }
-    for entity in stations.iter() {
+    for entity in existing.ships.iter() {
         commands.entity(entity).despawn();
     }
-    for entity in ships.iter() {
+    for entity in existing.crises.iter() {
+        commands.entity(entity).despawn();
+    }
+    // Shots in flight were aimed at entities that are going away
+    for entity in existing.wrecks.iter().chain(existing.projectiles.iter()) {
         commands.entity(entity).despawn();
     }
 
@@ -443,6 +899,20 @@ fn apply_loaded_sector(
     }
 
     sector.routes = loaded.routes.clone();
+    *pirate_memory = loaded.pirate_memory.clone();
+    *pirate_control = loaded.pirate_control.clone();
+    *crisis_history = loaded.crisis_history.clone();
+    *market_intel = loaded.market_intel.clone();
+    *ledger = loaded.ledger.clone();
+    *ship_ids = loaded.ship_ids.clone();
+    *run_status = loaded.run_status.clone();
+    if let Some(tick) = loaded.tick {
+        ticks.tick = tick;
+    }
+    if let Some(value) = loaded.seed {
+        seed.value = value;
+    }
+    let tick = ticks.tick;
 
     for station in &loaded.stations {
         let mut entity_commands = commands.spawn((
@@ -452,6 +922,8 @@ fn apply_loaded_sector(
                 fuel: station.fuel,
                 fuel_capacity: station.fuel_capacity,
             },
+            StationCrises::default(),
+            StationMaintenance::default(),
             StationCrisisLog::default(),
             Name::new(format!("Station-{:?}-{:?}", station.kind, station.state)),
             SpatialBundle::from_transform(Transform::from_xyz(station.x, station.y, 0.5)),
@@ -461,39 +933,341 @@ fn apply_loaded_sector(
             entity_commands.insert(StationBuild {
                 remaining_seconds: station.build_remaining,
             });
+            if let Some(site) = station.construction {
+                entity_commands.insert(site);
+            }
         }
 
-        if let (Some(crisis_type), Some(crisis_stage)) = (station.crisis_type, station.crisis_stage)
-        {
-            entity_commands.insert(StationCrisis {
-                crisis_type,
-                stage: crisis_stage,
+        if station.kind == StationKind::FuelDepot {
+            let policy = station
+                .depot_reserve
+                .map_or_else(FuelDepotPolicy::default, |reserve_fraction| {
+                    FuelDepotPolicy { reserve_fraction }
+                });
+            entity_commands.insert(policy);
+        }
+        if station.kind == StationKind::SensorStation {
+            entity_commands.insert(SensorSweep::default());
+        }
+        if station.kind == StationKind::Outpost {
+            entity_commands.insert((
+                Faction::Independent,
+                station.outpost_trade.unwrap_or_default(),
+                station.outpost_market.clone().unwrap_or_default(),
+            ));
+        } else {
+            entity_commands.insert(Faction::Player);
+        }
+        let ore_capacity = station_ore_capacity(station.kind);
+        if ore_capacity > 0.0 {
+            let mut production = StationProduction::new(ore_capacity).with_ore(station.ore);
+            for (commodity, amount) in station.hold.iter().flat_map(Inventory::iter) {
+                production.hold.add(commodity, amount);
+            }
+            entity_commands.insert(production);
+        }
+        if let Some(recipe) = station.recipe {
+            entity_commands.insert(StationRecipe {
+                active: Some(recipe),
+                elapsed_seconds: 0.0,
             });
         }
+        if station.decommissioned {
+            entity_commands.insert(Decommissioned);
+        }
+        let zone = station
+            .zone
+            .or_else(|| find_zone_for_position(&sector.nodes, Vec2::new(station.x, station.y)));
+        if let Some(zone) = zone {
+            entity_commands.insert(ZoneId(zone));
+        }
+        if let Some(queue) = &station.shipyard_queue {
+            entity_commands.insert(queue.clone());
+        }
+        if let Some(storage) = &station.shipyard_storage {
+            entity_commands.insert(storage.clone());
+        }
+        if let Some(queue) = &station.refinery_queue {
+            entity_commands.insert(queue.clone());
+        }
+        if let Some(storage) = &station.refinery_storage {
+            entity_commands.insert(storage.clone());
+        }
+        if let Some(hull) = station.hull {
+            entity_commands.insert(hull);
+        }
+        if let Some(shields) = station.shields {
+            entity_commands.insert(shields);
+        }
+
+        // Open crises pick up where they left off, clocks and all
+        let station_entity = entity_commands.id();
+        for saved in load_station_crises(station, tick) {
+            commands.spawn((
+                saved.to_crisis(station_entity, station.kind),
+                Name::new("Crisis"),
+            ));
+        }
     }
 
     for ship in &loaded.ships {
-        commands.spawn((
+        let mut entity_commands = commands.spawn((
             Ship {
                 kind: ship.kind,
                 state: ship.state,
                 fuel: ship.fuel,
                 fuel_capacity: ship.fuel_capacity,
             },
-            Fleet { role: ship.role },
             ShipFuelAlert::default(),
-            Name::new(format!("Ship-{:?}-{:?}", ship.kind, ship.state)),
             SpatialBundle::from_transform(Transform::from_xyz(ship.x, ship.y, 0.4)),
         ));
+        if ship.kind == ShipKind::PlayerShip {
+            entity_commands.insert((
+                restore_cargo(Cargo::default(), ship),
+                ship.credits
+                    .map_or_else(Credits::default, |amount| Credits { amount }),
+                Velocity::default(),
+                Faction::Player,
+                PlayerControl,
+                BoundaryWarningState::default(),
+                Name::new("Ship-Player"),
+            ));
+            if ship.escape_pod {
+                entity_commands.insert((EscapePod, escape_pod_hull()));
+            }
+        } else {
+            // Haulers and salvagers need a hold to work from
+            entity_commands.insert((
+                Fleet { role: ship.role },
+                restore_cargo(Cargo::with_capacity(cargo_capacity(ship.kind) as u32), ship),
+                Name::new(format!("Ship-{:?}-{:?}", ship.kind, ship.state)),
+            ));
+            if ship.captured {
+                entity_commands.insert((Captured, Faction::Player, captured_hull()));
+            }
+            // Security ships from older saves guard wherever they were left
+            if ship.role == FleetRole::Security {
+                let (x, y) = ship.patrol.unwrap_or((ship.x, ship.y));
+                entity_commands.insert(PatrolOrder {
+                    post: Vec2::new(x, y),
+                });
+            }
+        }
+        // Saved damage wins over the pod and prize hulls fitted above
+        if let Some(hull) = ship.hull {
+            entity_commands.insert(hull);
+        }
+        if let Some(shields) = ship.shields {
+            entity_commands.insert(shields);
+        }
+        if let Some(zone) = ship.zone {
+            entity_commands.insert(ZoneId(zone));
+        }
+        if let Some(id) = ship.id {
+            ship_ids.reserve(id);
+            entity_commands.insert(id);
+        }
+    }
+
+    if let Some(pirates) = &loaded.pirates {
+        for entity in existing.pirates.iter() {
+            commands.entity(entity).despawn();
+        }
+        for pirate in pirates {
+            let mut ship = PirateShip::new(pirate.speed);
+            ship.behavior = pirate.behavior;
+            let mut entity_commands = commands.spawn((
+                ship,
+                pirate.loot,
+                Faction::Pirate,
+                Name::new("Pirate-Ship"),
+                SpatialBundle::from_transform(Transform::from_xyz(pirate.x, pirate.y, 0.4)),
+            ));
+            if let Some(zone) = pirate.zone {
+                entity_commands.insert(ZoneId(zone));
+            }
+            if let Some(hull) = pirate.hull {
+                entity_commands.insert(hull);
+            }
+            if let Some(shields) = pirate.shields {
+                entity_commands.insert(shields);
+            }
+        }
+    }
+
+    for saved in &loaded.wrecks {
+        let name = if saved.wreck.kind == WreckKind::Ship(ShipKind::PlayerShip) {
+            "Wreck-Player"
+        } else {
+            "Wreck"
+        };
+        let mut entity_commands = commands.spawn((
+            saved.wreck,
+            Name::new(name),
+            SpatialBundle::from_transform(Transform::from_xyz(saved.x, saved.y, 0.4)),
+        ));
+        if let Some(zone) = saved.zone {
+            entity_commands.insert(ZoneId(zone));
+        }
+    }
+}
+
+/// A station's open crises, including the stage-only crises older saves
+/// recorded, which restart their clocks at `tick`
+fn load_station_crises(station: &SaveStation, tick: u64) -> Vec<SaveCrisis> {
+    let mut crises: std::collections::BTreeMap<CrisisType, SaveCrisis> = station
+        .crises
+        .iter()
+        .map(|&(crisis_type, stage)| (crisis_type, SaveCrisis::legacy(crisis_type, stage, tick)))
+        .collect();
+    if let (Some(crisis_type), Some(stage)) = (station.crisis_type, station.crisis_stage) {
+        crises.insert(crisis_type, SaveCrisis::legacy(crisis_type, stage, tick));
     }
+    for crisis in &station.open_crises {
+        crises.insert(crisis.crisis_type, crisis.clone());
+    }
+    crises.into_values().collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commodities::Commodity;
+    use crate::ledger::{LedgerCategory, LedgerEntry};
+    use crate::stations::MarketConditions;
+    use bevy::ecs::system::SystemState;
     use ron::de::from_str;
     use std::collections::HashMap;
 
+    #[test]
+    fn legacy_single_crisis_loads_alongside_new_list() {
+        let station: SaveStation = from_str(
+            "(kind: MiningOutpost, state: Strained, x: 0.0, y: 0.0, fuel: 2.0, \
+             fuel_capacity: 30.0, crises: [(PirateHarassment, Failing)], \
+             crisis_type: Some(FuelShortage), crisis_stage: Some(Strained))",
+        )
+        .expect("station parses");
+
+        let crises = load_station_crises(&station, 200);
+        let stages: Vec<(CrisisType, CrisisStage)> = crises
+            .iter()
+            .map(|crisis| (crisis.crisis_type, crisis.stage))
+            .collect();
+        assert_eq!(
+            stages,
+            vec![
+                (CrisisType::FuelShortage, CrisisStage::Strained),
+                (CrisisType::PirateHarassment, CrisisStage::Failing),
+            ]
+        );
+        assert!(crises.iter().all(|crisis| crisis.onset_tick == 200));
+        assert!(crises
+            .iter()
+            .all(|crisis| crisis.grace_seconds == CRISIS_GRACE_SECONDS));
+    }
+
+    #[test]
+    fn open_crisis_clocks_survive_a_round_trip() {
+        let mut crisis = Crisis::new(
+            Entity::PLACEHOLDER,
+            StationKind::Refinery,
+            CrisisType::HullBreach,
+            30,
+        );
+        crisis.stage = CrisisStage::Strained;
+        crisis.peak_stage = CrisisStage::Failing;
+        crisis.elapsed_seconds = 75.0;
+        crisis.grace_seconds = 0.0;
+        crisis.recovery_seconds = 12.5;
+
+        let serialized =
+            ron::ser::to_string(&SaveCrisis::from_crisis(&crisis)).expect("crisis serializes");
+        let saved: SaveCrisis = from_str(&serialized).expect("crisis parses");
+        let station: SaveStation = from_str(&format!(
+            "(kind: Refinery, state: Strained, x: 0.0, y: 0.0, fuel: 2.0, \
+             fuel_capacity: 60.0, open_crises: [{}])",
+            serialized
+        ))
+        .expect("station parses");
+        assert_eq!(load_station_crises(&station, 500), vec![saved.clone()]);
+
+        let loaded = saved.to_crisis(Entity::PLACEHOLDER, StationKind::Refinery);
+        assert_eq!(loaded.onset_tick, 30);
+        assert_eq!(loaded.peak_stage, CrisisStage::Failing);
+        assert_eq!(loaded.elapsed_seconds, 75.0);
+        assert_eq!(loaded.grace_seconds, 0.0);
+        assert_eq!(loaded.recovery_seconds, 12.5);
+    }
+
+    #[test]
+    fn station_hold_loads_goods_and_legacy_ore() {
+        let station: SaveStation = from_str(
+            "(kind: Refinery, state: Operational, x: 0.0, y: 0.0, fuel: 2.0, \
+             fuel_capacity: 60.0, hold: Some((items: {RawOre: 12.0, Alloys: 3.0}, \
+             mass_capacity: 80.0)), recipe: Some(AlloySmelting))",
+        )
+        .expect("station parses");
+        let hold = station.hold.expect("hold parses");
+        assert_eq!(hold.amount(Commodity::RawOre), 12.0);
+        assert_eq!(hold.amount(Commodity::Alloys), 3.0);
+        assert_eq!(station.recipe, Some(RecipeKind::AlloySmelting));
+
+        let legacy: SaveStation = from_str(
+            "(kind: Refinery, state: Operational, x: 0.0, y: 0.0, fuel: 2.0, \
+             fuel_capacity: 60.0, ore: 7.0)",
+        )
+        .expect("legacy station parses");
+        assert!(legacy.hold.is_none());
+        assert_eq!(legacy.ore, 7.0);
+    }
+
+    #[test]
+    fn outpost_trade_and_market_survive_a_round_trip() {
+        let mut trade = OutpostTrade::default();
+        trade.record_pirate_sale(10.0);
+        trade.docked_pirates = 2;
+        let mut market = OutpostMarket::default();
+        assert!(market.sell(crate::stations::MarketGood::Fuel, 80.0));
+
+        let station = SaveStation {
+            kind: StationKind::Outpost,
+            state: StationState::Operational,
+            x: 0.0,
+            y: 0.0,
+            fuel: 0.0,
+            fuel_capacity: 0.0,
+            build_remaining: 0.0,
+            open_crises: Vec::new(),
+            depot_reserve: None,
+            hold: None,
+            recipe: None,
+            ore: 0.0,
+            construction: None,
+            outpost_trade: Some(trade),
+            outpost_market: Some(market.clone()),
+            decommissioned: false,
+            zone: Some(4),
+            shipyard_queue: None,
+            shipyard_storage: None,
+            refinery_queue: None,
+            refinery_storage: None,
+            hull: None,
+            shields: None,
+            crises: Vec::new(),
+            crisis_type: None,
+            crisis_stage: None,
+        };
+        let serialized = ron::ser::to_string(&station).expect("station serializes");
+        let loaded: SaveStation = from_str(&serialized).expect("station parses");
+
+        let loaded_trade = loaded.outpost_trade.expect("trade restored");
+        assert_eq!(loaded_trade.price_multiplier, trade.price_multiplier);
+        assert_eq!(loaded_trade.player_standing, trade.player_standing);
+        // Docked pirates are recounted once the sim runs again
+        assert_eq!(loaded_trade.docked_pirates, 0);
+        assert_eq!(loaded.outpost_market, Some(market));
+    }
+
     #[test]
     fn summarize_modifiers_counts_expected_values() {
         let sector = Sector {
@@ -537,7 +1311,23 @@ mod tests {
             routes: Vec::new(),
         };
         let intel_map: HashMap<u32, &SystemIntel> = HashMap::new();
-        let payload = SaveSector::from_sector(&sector, &intel_map, &[], &[]);
+        let payload = SaveSector::from_sector(
+            &sector,
+            &intel_map,
+            &[],
+            &[],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
 
         assert_eq!(payload.intel.len(), 1);
         let intel = &payload.intel[0];
@@ -563,4 +1353,651 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn pirate_state_round_trips_through_ron() {
+        let mut memory = PirateMemory::default();
+        memory.record_loss(7, crate::pirates::PirateLossCause::Player);
+        memory.record_success(crate::pirates::RaidTargetType::Refinery);
+
+        let sector = Sector::default();
+        let intel_map: HashMap<u32, &SystemIntel> = HashMap::new();
+        let mut control = PirateControl::default();
+        control.adjust(7, 0.4);
+        let mut history = CrisisHistory::default();
+        history.push(crate::crises::CrisisRecord {
+            crisis_type: CrisisType::HullBreach,
+            station_kind: StationKind::Refinery,
+            onset_tick: 30,
+            resolved_tick: 90,
+            peak_stage: CrisisStage::Failing,
+            resolution: crate::crises::CrisisResolution::Abandoned,
+        });
+
+        let mut market_intel = MarketIntel::default();
+        market_intel.record(
+            7,
+            40,
+            OutpostMarket::default().quotes(&MarketConditions::default()),
+        );
+
+        let mut ledger = Ledger::default();
+        ledger.record(
+            LedgerEntry::new(55, LedgerCategory::Trade, "Outpost-2")
+                .with_credits(-12)
+                .with_fuel(25.0),
+        );
+
+        let ships = [SaveShip {
+            id: Some(ShipId(3)),
+            kind: ShipKind::Scout,
+            state: ShipState::Idle,
+            role: FleetRole::Scout,
+            x: 0.0,
+            y: 0.0,
+            fuel: 10.0,
+            fuel_capacity: 30.0,
+            zone: Some(7),
+            escape_pod: false,
+            captured: false,
+            patrol: None,
+            hold: None,
+            cargo_fuel: 0.0,
+            credits: None,
+            hull: None,
+            shields: None,
+        }];
+        let ship_ids = ShipIds { next: 4 };
+        let pirates = [SavePirate {
+            x: 40.0,
+            y: -12.0,
+            zone: Some(7),
+            speed: 70.0,
+            loot: PirateLoot {
+                ore: 9.0,
+                fuel: 4.5,
+            },
+            behavior: PirateShipBehavior::ReturningToBase,
+            hull: None,
+            shields: None,
+        }];
+
+        let payload = SaveSector::from_sector(
+            &sector,
+            &intel_map,
+            &[],
+            &ships,
+            &memory,
+            &control,
+            &history,
+            &market_intel,
+            &ledger,
+            &ship_ids,
+            &pirates,
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
+        let serialized = match ron::ser::to_string(&payload) {
+            Ok(serialized) => serialized,
+            Err(error) => panic!("serialize failed: {}", error),
+        };
+
+        match from_str::<SaveSector>(&serialized) {
+            Ok(loaded) => {
+                assert_eq!(loaded.pirate_memory, memory);
+                assert_eq!(loaded.pirate_control, control);
+                assert_eq!(loaded.crisis_history, history);
+                assert_eq!(loaded.market_intel, market_intel);
+                assert_eq!(loaded.ledger, ledger);
+                assert_eq!(loaded.ships[0].id, Some(ShipId(3)));
+                assert_eq!(loaded.ship_ids, ship_ids);
+                assert_eq!(loaded.pirates, Some(pirates.to_vec()));
+            }
+            Err(error) => panic!("deserialize failed: {}", error),
+        }
+    }
+
+    #[test]
+    fn escape_pod_and_run_status_survive_a_round_trip() {
+        let pod = SaveShip {
+            id: Some(ShipId(1)),
+            kind: ShipKind::PlayerShip,
+            state: ShipState::Idle,
+            role: FleetRole::Security,
+            x: 12.0,
+            y: 4.0,
+            fuel: 8.0,
+            fuel_capacity: 40.0,
+            zone: Some(2),
+            escape_pod: true,
+            captured: false,
+            patrol: None,
+            hold: None,
+            cargo_fuel: 0.0,
+            credits: Some(83),
+            hull: None,
+            shields: None,
+        };
+        let mut run_status = RunStatus {
+            ships_lost: 2,
+            ended: None,
+        };
+        run_status.end(crate::plugins::core::RunEndReason::Stranded);
+
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[],
+            &[pod],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &run_status,
+            &[],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+
+        assert_eq!(loaded.run_status, run_status);
+        assert!(loaded.ships[0].escape_pod);
+        assert_eq!(loaded.ships[0].zone, Some(2));
+
+        let mut world = load_into_world(&loaded);
+        let mut query = world.query_filtered::<&Credits, With<PlayerControl>>();
+        assert_eq!(query.single(&world).expect("player restored").amount, 83);
+    }
+
+    #[test]
+    fn captured_raiders_keep_their_marker_and_patrol_post() {
+        let raider = SaveShip {
+            id: Some(ShipId(5)),
+            kind: ShipKind::Security,
+            state: ShipState::Executing,
+            role: FleetRole::Security,
+            x: 30.0,
+            y: -6.0,
+            fuel: 20.0,
+            fuel_capacity: 60.0,
+            zone: Some(4),
+            escape_pod: false,
+            captured: true,
+            patrol: Some((25.0, -10.0)),
+            hold: None,
+            cargo_fuel: 0.0,
+            credits: None,
+            hull: None,
+            shields: None,
+        };
+
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[],
+            &[raider],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+
+        assert!(loaded.ships[0].captured);
+        assert_eq!(loaded.ships[0].patrol, Some((25.0, -10.0)));
+    }
+
+    #[test]
+    fn sample_ron_defaults_pirate_memory() {
+        match from_str::<SaveSector>(SAMPLE_RON) {
+            Ok(payload) => {
+                assert_eq!(payload.pirate_memory, PirateMemory::default());
+                assert!(payload.pirates.is_none());
+                assert_eq!(payload.run_status, RunStatus::default());
+                assert!(payload.ships.iter().all(|ship| !ship.escape_pod));
+                assert!(payload.ships.iter().all(|ship| !ship.captured));
+            }
+            Err(error) => panic!("SAMPLE_RON parse failed: {}", error),
+        }
+    }
+
+    /// Spawn a parsed save into an empty world the way a load does
+    fn load_into_world(loaded: &SaveSector) -> World {
+        let mut world = World::default();
+        let mut system_state: SystemState<(Commands, SectorEntities)> =
+            SystemState::new(&mut world);
+        let (mut commands, existing) = system_state.get_mut(&mut world);
+        apply_loaded_sector(
+            &mut commands,
+            &mut Sector::default(),
+            &mut PirateMemory::default(),
+            &mut PirateControl::default(),
+            &mut CrisisHistory::default(),
+            &mut MarketIntel::default(),
+            &mut Ledger::default(),
+            &mut ShipIds::default(),
+            &mut RunStatus::default(),
+            &mut SimTickCount::default(),
+            &mut WorldSeed::default(),
+            loaded,
+            &existing,
+        );
+        system_state.apply(&mut world);
+        world
+    }
+
+    #[test]
+    fn fleet_ships_reload_with_their_hold_and_zone() {
+        let mut hold = Cargo::with_capacity(cargo_capacity(ShipKind::Miner) as u32).hold;
+        hold.add(Commodity::RawOre, 6.0);
+        let miner = SaveShip {
+            id: Some(ShipId(2)),
+            kind: ShipKind::Miner,
+            state: ShipState::InTransit,
+            role: FleetRole::Mining,
+            x: 10.0,
+            y: 5.0,
+            fuel: 30.0,
+            fuel_capacity: 40.0,
+            zone: Some(3),
+            escape_pod: false,
+            captured: false,
+            patrol: None,
+            hold: Some(hold),
+            cargo_fuel: 4.0,
+            credits: None,
+            hull: None,
+            shields: None,
+        };
+
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[],
+            &[miner],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+        let mut world = load_into_world(&loaded);
+
+        // Everything `haul_station_output` needs to pick the ship up again
+        let mut query = world.query_filtered::<(&Cargo, &ZoneId), With<Fleet>>();
+        let (cargo, zone) = query.single(&world).expect("miner restored");
+        assert_eq!(cargo.ore(), 6);
+        assert_eq!(cargo.fuel, 4.0);
+        assert_eq!(zone.0, 3);
+    }
+
+    #[test]
+    fn disabled_raiders_reload_damaged_and_boardable() {
+        let mut hull = crate::combat::pirate_hull();
+        hull.current = 6.0;
+        hull.last_hit = Some(crate::combat::DamageSource::Player);
+        let mut shields = crate::combat::pirate_shields();
+        shields.current = 0.0;
+        let raider = SavePirate {
+            x: 40.0,
+            y: -12.0,
+            zone: Some(4),
+            speed: 70.0,
+            loot: PirateLoot {
+                ore: 9.0,
+                fuel: 0.0,
+            },
+            behavior: PirateShipBehavior::Disabled,
+            hull: Some(hull),
+            shields: Some(shields),
+        };
+
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[],
+            &[],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[raider],
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+        let mut world = load_into_world(&loaded);
+
+        // Boarding looks for a Disabled raider, and its hull must not reset
+        let mut query = world.query::<(&PirateShip, &Hull, &Shields)>();
+        let (pirate, loaded_hull, loaded_shields) = query.single(&world).expect("raider restored");
+        assert_eq!(pirate.behavior, PirateShipBehavior::Disabled);
+        assert_eq!(*loaded_hull, hull);
+        assert_eq!(*loaded_shields, shields);
+    }
+
+    #[test]
+    fn loading_replaces_wrecks_and_clears_shots_in_flight() {
+        let mut player_wreck = Wreck::new(WreckKind::Ship(ShipKind::PlayerShip), 12.0, 20.0, 3.0);
+        player_wreck.remaining_seconds = 400.0;
+        let saved = SaveWreck {
+            x: 5.0,
+            y: 5.0,
+            zone: Some(3),
+            wreck: player_wreck,
+        };
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[],
+            &[],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[saved],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+
+        let mut world = World::default();
+        world.spawn(Wreck::new(WreckKind::PirateShip, 4.0, 0.0, 1.0));
+        world.spawn(Projectile {
+            kind: crate::combat::WeaponKind::Kinetic,
+            source: crate::combat::DamageSource::Pirate,
+            shooter: Entity::PLACEHOLDER,
+            velocity: Vec2::X,
+            damage: 5.0,
+            remaining_range: 100.0,
+            target: None,
+        });
+        let mut system_state: SystemState<(Commands, SectorEntities)> =
+            SystemState::new(&mut world);
+        let (mut commands, existing) = system_state.get_mut(&mut world);
+        apply_loaded_sector(
+            &mut commands,
+            &mut Sector::default(),
+            &mut PirateMemory::default(),
+            &mut PirateControl::default(),
+            &mut CrisisHistory::default(),
+            &mut MarketIntel::default(),
+            &mut Ledger::default(),
+            &mut ShipIds::default(),
+            &mut RunStatus::default(),
+            &mut SimTickCount::default(),
+            &mut WorldSeed::default(),
+            &loaded,
+            &existing,
+        );
+        system_state.apply(&mut world);
+
+        let mut projectiles = world.query::<&Projectile>();
+        assert_eq!(projectiles.iter(&world).count(), 0);
+        let mut wrecks = world.query::<(&Wreck, &ZoneId)>();
+        let (wreck, zone) = wrecks.single(&world).expect("player wreck restored");
+        assert_eq!(*wreck, player_wreck);
+        assert_eq!(zone.0, 3);
+    }
+
+    #[test]
+    fn sim_clock_and_seed_survive_a_round_trip() {
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[],
+            &[],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[],
+            4_200,
+            77,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+
+        let mut world = World::default();
+        let mut ticks = SimTickCount { tick: 9_000 };
+        let mut seed = WorldSeed { value: 1 };
+        let mut system_state: SystemState<(Commands, SectorEntities)> =
+            SystemState::new(&mut world);
+        let (mut commands, existing) = system_state.get_mut(&mut world);
+        apply_loaded_sector(
+            &mut commands,
+            &mut Sector::default(),
+            &mut PirateMemory::default(),
+            &mut PirateControl::default(),
+            &mut CrisisHistory::default(),
+            &mut MarketIntel::default(),
+            &mut Ledger::default(),
+            &mut ShipIds::default(),
+            &mut RunStatus::default(),
+            &mut ticks,
+            &mut seed,
+            &loaded,
+            &existing,
+        );
+
+        // Jump rolls are keyed on both, so the loaded run rolls the same way
+        assert_eq!(ticks.tick, 4_200);
+        assert_eq!(seed.value, 77);
+    }
+
+    /// A working station with nothing but its kind and zone filled in
+    fn bare_station(kind: StationKind, zone: u32) -> SaveStation {
+        SaveStation {
+            kind,
+            state: StationState::Operational,
+            x: 0.0,
+            y: 0.0,
+            fuel: 20.0,
+            fuel_capacity: 50.0,
+            build_remaining: 0.0,
+            open_crises: Vec::new(),
+            depot_reserve: None,
+            hold: None,
+            recipe: None,
+            ore: 0.0,
+            construction: None,
+            outpost_trade: None,
+            outpost_market: None,
+            decommissioned: false,
+            zone: Some(zone),
+            shipyard_queue: None,
+            shipyard_storage: None,
+            refinery_queue: None,
+            refinery_storage: None,
+            hull: None,
+            shields: None,
+            crises: Vec::new(),
+            crisis_type: None,
+            crisis_stage: None,
+        }
+    }
+
+    /// Save the stations to RON and load them back into a fresh world
+    fn round_trip_stations(stations: &[SaveStation]) -> World {
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            stations,
+            &[],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+        load_into_world(&loaded)
+    }
+
+    #[test]
+    fn station_and_prize_ship_damage_survives_a_round_trip() {
+        let mut station_hull = crate::combat::station_hull(StationKind::Refinery);
+        station_hull.current = 35.0;
+        let mut station_shields = crate::combat::station_shields(StationKind::Refinery);
+        station_shields.current = 2.0;
+        let refinery = SaveStation {
+            hull: Some(station_hull),
+            shields: Some(station_shields),
+            ..bare_station(StationKind::Refinery, 1)
+        };
+        let mut prize_hull = captured_hull();
+        prize_hull.current = 9.0;
+        let prize = SaveShip {
+            id: Some(ShipId(5)),
+            kind: ShipKind::Security,
+            state: ShipState::Idle,
+            role: FleetRole::Security,
+            x: 0.0,
+            y: 0.0,
+            fuel: 10.0,
+            fuel_capacity: 40.0,
+            zone: Some(1),
+            escape_pod: false,
+            captured: true,
+            patrol: None,
+            hold: None,
+            cargo_fuel: 0.0,
+            credits: None,
+            hull: Some(prize_hull),
+            shields: None,
+        };
+
+        let payload = SaveSector::from_sector(
+            &Sector::default(),
+            &HashMap::new(),
+            &[refinery],
+            &[prize],
+            &PirateMemory::default(),
+            &PirateControl::default(),
+            &CrisisHistory::default(),
+            &MarketIntel::default(),
+            &Ledger::default(),
+            &ShipIds::default(),
+            &[],
+            &RunStatus::default(),
+            &[],
+            0,
+            0,
+        );
+        let serialized = ron::ser::to_string(&payload).expect("sector serializes");
+        let loaded: SaveSector = from_str(&serialized).expect("sector parses");
+        let mut world = load_into_world(&loaded);
+
+        let mut stations = world.query_filtered::<(&Hull, &Shields), With<Station>>();
+        let (hull, shields) = stations.single(&world).expect("refinery restored");
+        assert_eq!(*hull, station_hull);
+        assert_eq!(*shields, station_shields);
+        let mut ships = world.query_filtered::<&Hull, With<Captured>>();
+        let hull = ships.single(&world).expect("prize restored");
+        assert_eq!(*hull, prize_hull);
+    }
+
+    #[test]
+    fn shipyard_queue_and_hangar_survive_a_round_trip() {
+        use crate::fleets::RiskTolerance;
+        use crate::stations::ShipyardJob;
+
+        let mut queue = ShipyardQueue {
+            scout_risk: RiskTolerance::Bold,
+            ..ShipyardQueue::default()
+        };
+        let mut replacement = ShipyardJob::new(ShipKind::PlayerShip);
+        replacement.tick(30.0);
+        queue.push(replacement);
+        queue.push(ShipyardJob::new(ShipKind::Scout));
+        let mut storage = ShipyardStorage::new();
+        storage.add_ship(ShipKind::Miner);
+
+        let yard = SaveStation {
+            shipyard_queue: Some(queue),
+            shipyard_storage: Some(storage),
+            ..bare_station(StationKind::Shipyard, 2)
+        };
+        let mut world = round_trip_stations(&[yard]);
+
+        // Everything `shipyard_job_progress` needs to pick the yard up again
+        let mut query = world.query::<(&ZoneId, &ShipyardQueue, &ShipyardStorage)>();
+        let (zone, queue, storage) = query.single(&world).expect("shipyard restored");
+        assert_eq!(zone.0, 2);
+        assert!(queue.has_replacement_hull());
+        assert_eq!(queue.jobs[0].remaining_seconds, 60.0);
+        assert_eq!(queue.jobs[1].kind, ShipKind::Scout);
+        assert_eq!(queue.scout_risk, RiskTolerance::Bold);
+        assert_eq!(storage.hangar, vec![ShipKind::Miner]);
+    }
+
+    #[test]
+    fn refinery_batches_and_stored_fuel_survive_a_round_trip() {
+        use crate::stations::RefineryJob;
+
+        let mut queue = RefineryQueue::default();
+        let mut batch = RefineryJob::new(10);
+        batch.process(4.0);
+        queue.push(batch);
+        queue.cycle_target();
+        let mut storage = RefineryStorage::new();
+        storage.add_fuel(12.0);
+
+        let refinery = SaveStation {
+            refinery_queue: Some(queue),
+            refinery_storage: Some(storage),
+            ..bare_station(StationKind::Refinery, 5)
+        };
+        let mut world = round_trip_stations(&[refinery]);
+
+        let mut query = world.query::<(&ZoneId, &RefineryQueue, &RefineryStorage)>();
+        let (zone, queue, storage) = query.single(&world).expect("refinery restored");
+        assert_eq!(zone.0, 5);
+        assert_eq!(queue.jobs[0].unrefined_ore(), 6);
+        assert_eq!(queue.target_fraction, 0.75);
+        assert_eq!(storage.fuel, 12.0);
+    }
 }
@@ -0,0 +1,1442 @@
+//! Hull, shield, weapon and damage resolution systems.
+
+use bevy::ecs::message::{MessageReader, MessageWriter};
+use bevy::prelude::*;
+
+use crate::combat::{
+    apply_damage, escape_pod_hull, homing_velocity, pirate_armament, pirate_hull, pirate_shields,
+    player_armament, security_armament, segment_hit_fraction, ship_hull, ship_shields,
+    station_armament, station_hull, station_shields, wreck_components, Armament, DamageEvent,
+    DamageSource, Hull, HullCondition, ImpactFlash, Projectile, Shields, Wreck, WreckKind,
+    ESCAPE_POD_FUEL, PLAYER_WRECK_LIFETIME_SECONDS, SALVAGE_RANGE,
+};
+use crate::commodities::{Commodity, Inventory};
+use crate::compat::SpatialBundle;
+use crate::crises::Crisis;
+use crate::factions::Faction;
+use crate::fleets::{PatrolOrder, PATROL_CHASE_RADIUS};
+use crate::ledger::{Ledger, LedgerCategory, LedgerEntry};
+use crate::ore::Asteroid;
+use crate::pirates::{
+    intercept_recovery, PirateBase, PirateControl, PirateLoot, PirateLossCause, PirateMemory,
+    PirateShip, PirateShipBehavior, KILL_CONTROL_LOSS,
+};
+use crate::plugins::core::{EventLog, RunEndReason, RunStatus};
+use crate::plugins::player::{EscapePod, PlayerControl};
+use crate::ships::{Cargo, Fleet, FleetRole, MinerTask, Ship, ShipKind, ShipState};
+use crate::stations::{
+    CrisisStage, CrisisType, Station, StationBuild, StationCrises, StationKind, StationProduction,
+    StationState,
+};
+use crate::world::{SystemIntel, SystemNode, ZoneId};
+
+use super::intel::reveal_salvaged_intel;
+use super::SimTickCount;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Hull repaired per second by stations that still have power
+const STATION_REPAIR_PER_SECOND: f32 = 0.5;
+
+/// Share of a destroyed station's fuel that survives in the wreck
+const STATION_WRECK_FUEL_FRACTION: f32 = 0.5;
+
+/// Collision radius of ships and pirates
+const SHIP_HIT_RADIUS: f32 = 10.0;
+
+/// Collision radius of stations
+const STATION_HIT_RADIUS: f32 = 24.0;
+
+/// Collision radius of a full-size asteroid, scaled by its size
+const ASTEROID_HIT_RADIUS: f32 = 14.0;
+
+/// Distance at which pirates open fire on the player
+const PIRATE_ENGAGE_RANGE: f32 = 160.0;
+
+/// Distance at which security ships open fire on pirates
+const SECURITY_ENGAGE_RANGE: f32 = 180.0;
+
+/// Distance at which station point defense opens fire on pirates
+const STATION_DEFENSE_RANGE: f32 = 150.0;
+
+/// Stations this close to a raider taken down by the player's side are
+/// relieved of its harassment
+const HARASSMENT_RELIEF_RANGE: f32 = 200.0;
+
+/// Cruise speed of Security ships on patrol
+const SECURITY_PATROL_SPEED: f32 = 60.0;
+
+/// How close a patrolling ship closes on a raider before holding to fire
+const SECURITY_STAND_OFF: f32 = 90.0;
+
+/// How far ahead of the shooter a projectile appears
+const MUZZLE_OFFSET: f32 = 12.0;
+
+/// How far mining ships will divert to pick over a wreck
+const AI_SALVAGE_SEEK_RANGE: f32 = 300.0;
+
+/// Cruise speed of mining ships heading to a wreck
+const AI_SALVAGE_SPEED: f32 = 40.0;
+
+// =============================================================================
+// Systems
+// =============================================================================
+
+/// Give every ship, station and pirate a hull and shields matching its kind.
+/// Covers all spawn paths, including entities restored from a save.
+#[allow(clippy::type_complexity)]
+pub fn equip_hulls(
+    mut commands: Commands,
+    unequipped: Query<
+        (Entity, Option<&Ship>, Option<&Station>, Has<PirateShip>),
+        (
+            Without<Hull>,
+            Or<(With<Ship>, With<Station>, With<PirateShip>)>,
+        ),
+    >,
+) {
+    for (entity, ship, station, is_pirate) in unequipped.iter() {
+        let (hull, shields) = if let Some(ship) = ship {
+            (ship_hull(ship.kind), ship_shields(ship.kind))
+        } else if let Some(station) = station {
+            (station_hull(station.kind), station_shields(station.kind))
+        } else if is_pirate {
+            (pirate_hull(), pirate_shields())
+        } else {
+            continue;
+        };
+
+        commands.entity(entity).insert((hull, shields));
+    }
+}
+
+/// Shields recharge everywhere; powered stations also patch their hulls.
+pub fn regen_defenses(
+    time: Res<Time<Fixed>>,
+    mut shields: Query<&mut Shields>,
+    mut stations: Query<(&Station, &mut Hull)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for mut shield in shields.iter_mut() {
+        shield.regen(delta_seconds);
+    }
+
+    for (station, mut hull) in stations.iter_mut() {
+        if matches!(station.state, StationState::Failed) {
+            continue;
+        }
+        hull.repair(STATION_REPAIR_PER_SECOND * delta_seconds);
+    }
+}
+
+/// Arm the player, pirates, security ships and stations. Runs every tick so
+/// loaded saves are covered too. Escape pods stay unarmed until a replacement
+/// hull is fitted, and Outposts are neutral ground.
+#[allow(clippy::type_complexity)]
+pub fn equip_weapons(
+    mut commands: Commands,
+    unarmed: Query<
+        (
+            Entity,
+            Has<PlayerControl>,
+            Has<PirateShip>,
+            Option<&Fleet>,
+            Option<&Station>,
+        ),
+        (
+            Without<Armament>,
+            Without<EscapePod>,
+            Or<(
+                With<PlayerControl>,
+                With<PirateShip>,
+                With<Fleet>,
+                With<Station>,
+            )>,
+        ),
+    >,
+) {
+    for (entity, is_player, is_pirate, fleet, station) in unarmed.iter() {
+        let armament = if is_player {
+            player_armament()
+        } else if is_pirate {
+            pirate_armament()
+        } else if fleet.is_some_and(|fleet| fleet.role == FleetRole::Security) {
+            security_armament()
+        } else if station.is_some_and(|station| station.kind != StationKind::Outpost) {
+            station_armament()
+        } else {
+            continue;
+        };
+        commands.entity(entity).insert(armament);
+    }
+}
+
+/// Count down weapon cooldowns
+pub fn weapon_cooldowns(time: Res<Time<Fixed>>, mut armaments: Query<&mut Armament>) {
+    let delta_seconds = time.delta_secs();
+
+    for mut armament in armaments.iter_mut() {
+        for weapon in armament.weapons.iter_mut() {
+            weapon.tick(delta_seconds);
+        }
+    }
+}
+
+/// Pirates that can still fly shoot back at the player when in the same zone.
+#[allow(clippy::type_complexity)]
+pub fn pirate_fire(
+    mut commands: Commands,
+    players: Query<(&Transform, &ZoneId), (With<PlayerControl>, Without<PirateShip>)>,
+    mut pirates: Query<(
+        Entity,
+        &Transform,
+        &PirateShip,
+        &mut Armament,
+        Option<&ZoneId>,
+    )>,
+) {
+    let Ok((player_transform, player_zone)) = players.single() else {
+        return;
+    };
+
+    let player_pos = Vec2::new(
+        player_transform.translation.x,
+        player_transform.translation.y,
+    );
+
+    for (entity, transform, pirate, mut armament, zone) in pirates.iter_mut() {
+        // Outposts are neutral ground and wrecks can't aim
+        if matches!(
+            pirate.behavior,
+            PirateShipBehavior::Disabled | PirateShipBehavior::DockedAtOutpost { .. }
+        ) {
+            continue;
+        }
+        if zone.map(|zone| zone.0) != Some(player_zone.0) {
+            continue;
+        }
+
+        let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
+        if pirate_pos.distance(player_pos) > PIRATE_ENGAGE_RANGE {
+            continue;
+        }
+
+        let Some(weapon) = armament.selected_mut() else {
+            continue;
+        };
+        let kind = weapon.kind;
+        let Ok(profile) = weapon.fire(0.0) else {
+            continue;
+        };
+
+        let direction = (player_pos - pirate_pos).normalize_or_zero();
+        let origin = pirate_pos + direction * MUZZLE_OFFSET;
+        commands.spawn((
+            Projectile::launch(profile, kind, DamageSource::Pirate, entity, direction, None),
+            Name::new("Projectile"),
+            ZoneId(player_zone.0),
+            SpatialBundle::from_transform(Transform::from_xyz(origin.x, origin.y, 0.6)),
+        ));
+    }
+}
+
+/// Security ships on patrol close on raiders near their post, then fall
+/// back to it once the zone is quiet.
+#[allow(clippy::type_complexity)]
+pub fn security_patrol(
+    time: Res<Time<Fixed>>,
+    pirates: Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+    mut ships: Query<
+        (
+            &mut Ship,
+            &mut Transform,
+            &Fleet,
+            &PatrolOrder,
+            Option<&ZoneId>,
+        ),
+        (Without<PlayerControl>, Without<PirateShip>),
+    >,
+) {
+    let delta_seconds = time.delta_secs();
+    let targets = pirate_targets(&pirates);
+
+    for (mut ship, mut transform, fleet, order, zone) in ships.iter_mut() {
+        if fleet.role != FleetRole::Security
+            || matches!(ship.state, ShipState::Disabled | ShipState::Returning)
+        {
+            continue;
+        }
+
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let zone = zone.map(|zone| zone.0);
+        let (goal, stand_off, state) =
+            match nearest_target(order.post, zone, PATROL_CHASE_RADIUS, &targets) {
+                Some(target) => (target, SECURITY_STAND_OFF, ShipState::Executing),
+                None => (order.post, 1.0, ShipState::Idle),
+            };
+
+        if pos.distance(goal) <= stand_off {
+            ship.state = state;
+            continue;
+        }
+        let step = (goal - pos).normalize_or_zero() * SECURITY_PATROL_SPEED * delta_seconds;
+        transform.translation.x += step.x;
+        transform.translation.y += step.y;
+        ship.state = ShipState::InTransit;
+    }
+}
+
+/// Security ships shoot the nearest raider still flying in their zone.
+#[allow(clippy::type_complexity)]
+pub fn security_fire(
+    mut commands: Commands,
+    pirates: Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+    mut ships: Query<
+        (Entity, &Transform, &Fleet, &mut Armament, Option<&ZoneId>),
+        (Without<PlayerControl>, Without<PirateShip>),
+    >,
+) {
+    let targets = pirate_targets(&pirates);
+
+    for (entity, transform, fleet, mut armament, zone) in ships.iter_mut() {
+        if fleet.role != FleetRole::Security {
+            continue;
+        }
+
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let zone = zone.map(|zone| zone.0);
+        let Some(target) = nearest_target(pos, zone, SECURITY_ENGAGE_RANGE, &targets) else {
+            continue;
+        };
+        let Some(weapon) = armament.selected_mut() else {
+            continue;
+        };
+        let kind = weapon.kind;
+        let Ok(profile) = weapon.fire(0.0) else {
+            continue;
+        };
+
+        let direction = (target - pos).normalize_or_zero();
+        let shot = Projectile::launch(
+            profile,
+            kind,
+            DamageSource::Security,
+            entity,
+            direction,
+            None,
+        );
+        spawn_projectile(&mut commands, shot, pos + direction * MUZZLE_OFFSET, zone);
+    }
+}
+
+/// Powered stations fire point defense at raiders in range, paying for each
+/// shot from their own fuel.
+#[allow(clippy::type_complexity)]
+pub fn station_defense_fire(
+    mut commands: Commands,
+    pirates: Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+    mut stations: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Station,
+            &mut Armament,
+            Option<&ZoneId>,
+        ),
+        (Without<StationBuild>, Without<PirateShip>),
+    >,
+) {
+    let targets = pirate_targets(&pirates);
+
+    for (entity, transform, mut station, mut armament, zone) in stations.iter_mut() {
+        if matches!(station.state, StationState::Failed) {
+            continue;
+        }
+
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let zone = zone.map(|zone| zone.0);
+        let Some(target) = nearest_target(pos, zone, STATION_DEFENSE_RANGE, &targets) else {
+            continue;
+        };
+        let Some(weapon) = armament.selected_mut() else {
+            continue;
+        };
+        let kind = weapon.kind;
+        let Ok(profile) = weapon.fire(station.fuel) else {
+            continue;
+        };
+        station.fuel = (station.fuel - profile.fuel_cost).max(0.0);
+
+        let direction = (target - pos).normalize_or_zero();
+        let shot = Projectile::launch(
+            profile,
+            kind,
+            DamageSource::Station,
+            entity,
+            direction,
+            None,
+        );
+        spawn_projectile(&mut commands, shot, pos + direction * MUZZLE_OFFSET, zone);
+    }
+}
+
+/// Fly projectiles forward, sweeping each step so fast shots cannot skip past a hull.
+/// Asteroids stop everything; hulls only stop shots from the other side.
+#[allow(clippy::type_complexity)]
+pub fn move_projectiles(
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+    mut damage: MessageWriter<DamageEvent>,
+    mut projectiles: Query<(Entity, &mut Projectile, &mut Transform, Option<&ZoneId>)>,
+    hulls: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&ZoneId>,
+            Has<PirateShip>,
+            Has<Station>,
+        ),
+        (With<Hull>, Without<Projectile>),
+    >,
+    asteroids: Query<(&Transform, &Asteroid, Option<&ZoneId>), Without<Projectile>>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut projectile, mut transform, zone) in projectiles.iter_mut() {
+        let zone = zone.map(|zone| zone.0);
+        let start = Vec2::new(transform.translation.x, transform.translation.y);
+
+        if let Some(target) = projectile.target {
+            match hulls.get(target) {
+                Ok((_, target_transform, _, _, _)) => {
+                    let target_pos = Vec2::new(
+                        target_transform.translation.x,
+                        target_transform.translation.y,
+                    );
+                    projectile.velocity =
+                        homing_velocity(projectile.velocity, target_pos - start, delta_seconds);
+                }
+                Err(_) => projectile.target = None,
+            }
+        }
+
+        let mut step = projectile.velocity * delta_seconds;
+        let step_length = step.length();
+        if step_length > projectile.remaining_range && step_length > 0.0 {
+            step *= projectile.remaining_range / step_length;
+        }
+        let end = start + step;
+
+        let mut hit: Option<(f32, Option<Entity>)> = None;
+        let mut consider = |fraction: f32, target: Option<Entity>| {
+            if hit.is_none_or(|(best, _)| fraction < best) {
+                hit = Some((fraction, target));
+            }
+        };
+
+        for (target, target_transform, target_zone, is_pirate, is_station) in hulls.iter() {
+            if target == projectile.shooter || target_zone.map(|zone| zone.0) != zone {
+                continue;
+            }
+            if !projectile.hostile_to(is_pirate) {
+                continue;
+            }
+
+            let center = Vec2::new(
+                target_transform.translation.x,
+                target_transform.translation.y,
+            );
+            let radius = if is_station {
+                STATION_HIT_RADIUS
+            } else {
+                SHIP_HIT_RADIUS
+            };
+            if let Some(fraction) = segment_hit_fraction(start, end, center, radius) {
+                consider(fraction, Some(target));
+            }
+        }
+
+        for (asteroid_transform, asteroid, asteroid_zone) in asteroids.iter() {
+            if asteroid_zone.map(|zone| zone.0) != zone {
+                continue;
+            }
+            let center = Vec2::new(
+                asteroid_transform.translation.x,
+                asteroid_transform.translation.y,
+            );
+            let radius = ASTEROID_HIT_RADIUS * asteroid.size;
+            if let Some(fraction) = segment_hit_fraction(start, end, center, radius) {
+                consider(fraction, None);
+            }
+        }
+
+        if let Some((fraction, target)) = hit {
+            if let Some(target) = target {
+                damage.write(DamageEvent {
+                    target,
+                    amount: projectile.damage,
+                    source: projectile.source,
+                });
+            }
+
+            let point = start + step * fraction;
+            let mut flash = commands.spawn((
+                ImpactFlash::new(projectile.kind),
+                SpatialBundle::from_transform(Transform::from_xyz(point.x, point.y, 0.6)),
+            ));
+            if let Some(zone) = zone {
+                flash.insert(ZoneId(zone));
+            }
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.x = end.x;
+        transform.translation.y = end.y;
+        projectile.remaining_range -= step.length();
+        if projectile.remaining_range <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Impact flashes fade out quickly
+pub fn impact_decay(
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+    mut flashes: Query<(Entity, &mut ImpactFlash)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut flash) in flashes.iter_mut() {
+        flash.remaining_seconds -= delta_seconds;
+        if flash.remaining_seconds <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Resolve queued hits against shields, armor and hull.
+pub fn apply_damage_events(
+    mut events: MessageReader<DamageEvent>,
+    mut targets: Query<(&mut Hull, Option<&mut Shields>)>,
+) {
+    for event in events.read() {
+        let Ok((mut hull, shields)) = targets.get_mut(event.target) else {
+            continue;
+        };
+
+        if hull.current <= 0.0 {
+            continue;
+        }
+
+        let result = apply_damage(
+            &mut hull,
+            shields.map(|shields| shields.into_inner()),
+            event.amount,
+        );
+        if result.hull_damage > 0.0 || result.absorbed > 0.0 {
+            hull.last_hit = Some(event.source);
+        }
+    }
+}
+
+/// Hull thresholds drive ship states; the player hears about each step down.
+pub fn ship_damage_state(
+    mut log: ResMut<EventLog>,
+    mut ships: Query<(&Hull, &mut Ship, Has<PlayerControl>), Changed<Hull>>,
+) {
+    for (hull, mut ship, is_player) in ships.iter_mut() {
+        let previous = ship.state;
+
+        match hull.condition() {
+            HullCondition::Disabled | HullCondition::Destroyed => {
+                ship.state = ShipState::Disabled;
+            }
+            HullCondition::Damaged => {
+                if !matches!(ship.state, ShipState::Disabled | ShipState::Returning) {
+                    ship.state = ShipState::Damaged;
+                }
+            }
+            HullCondition::Intact => {
+                if matches!(ship.state, ShipState::Damaged) {
+                    ship.state = ShipState::Idle;
+                }
+            }
+        }
+
+        if is_player && ship.state != previous {
+            match ship.state {
+                ShipState::Damaged => log.push(format!(
+                    "Hull damaged: {:.0}% integrity",
+                    hull.ratio() * 100.0
+                )),
+                ShipState::Disabled => log.push("Ship disabled: hull breached".to_string()),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Pirates whose hull drops past the disabled threshold stop flying.
+/// Raiders knocked out of the fight drift disabled. Taking one down near a
+/// harassed station drives its raiders off, easing the crisis there.
+#[allow(clippy::type_complexity)]
+pub fn pirate_damage_state(
+    mut pirates: Query<(&Hull, &mut PirateShip, &Transform, Option<&ZoneId>), Changed<Hull>>,
+    stations: Query<(&Transform, &StationCrises, Option<&ZoneId>), With<Station>>,
+    mut crises: Query<&mut Crisis>,
+) {
+    for (hull, mut pirate, transform, zone) in pirates.iter_mut() {
+        if !matches!(
+            hull.condition(),
+            HullCondition::Disabled | HullCondition::Destroyed
+        ) || pirate.behavior == PirateShipBehavior::Disabled
+        {
+            continue;
+        }
+        pirate.behavior = PirateShipBehavior::Disabled;
+
+        if hull.last_hit.and_then(loss_cause).is_none() {
+            continue;
+        }
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        for (station_transform, station_crises, station_zone) in stations.iter() {
+            let station_pos = Vec2::new(
+                station_transform.translation.x,
+                station_transform.translation.y,
+            );
+            if station_zone.map(|zone| zone.0) != zone.map(|zone| zone.0)
+                || station_pos.distance(pos) > HARASSMENT_RELIEF_RANGE
+            {
+                continue;
+            }
+            let harassment = station_crises
+                .get(CrisisType::PirateHarassment)
+                .and_then(|open| crises.get_mut(open.entity).ok());
+            if let Some(mut crisis) = harassment {
+                crisis.intervene();
+            }
+        }
+    }
+}
+
+/// Damaged station hulls put breach pressure on a station, worse as integrity drops.
+pub fn station_hull_crisis(mut stations: Query<(&Hull, &mut StationCrises), With<Station>>) {
+    for (hull, mut crises) in stations.iter_mut() {
+        match hull_crisis_stage(hull.condition()) {
+            Some(stage) => {
+                crises.report(CrisisType::HullBreach, stage);
+            }
+            None => {
+                if crises.direct_pressure(CrisisType::HullBreach).is_some() {
+                    crises.relieve(CrisisType::HullBreach);
+                }
+            }
+        }
+    }
+}
+
+/// Replace anything whose hull gave out with a wreck holding part of its cargo.
+/// The player's ship is never removed here; it stays disabled instead.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn destroy_wrecked(
+    mut commands: Commands,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut memory: ResMut<PirateMemory>,
+    mut control: ResMut<PirateControl>,
+    mut player_cargo: Query<&mut Cargo, With<PlayerControl>>,
+    bases: Query<(&Transform, &ZoneId), With<PirateBase>>,
+    destroyed: Query<
+        (
+            Entity,
+            &Hull,
+            &Transform,
+            Option<&ZoneId>,
+            Option<&Ship>,
+            Option<&Cargo>,
+            Option<&Station>,
+            Option<&StationProduction>,
+            Option<&PirateLoot>,
+            Has<PirateShip>,
+        ),
+        (Changed<Hull>, Without<PlayerControl>),
+    >,
+) {
+    for (entity, hull, transform, zone, ship, cargo, station, production, loot, is_pirate) in
+        destroyed.iter()
+    {
+        if hull.condition() != HullCondition::Destroyed {
+            continue;
+        }
+
+        let zone = zone.map(|zone| zone.0);
+        let position = Vec2::new(transform.translation.x, transform.translation.y);
+        let mut components = wreck_components(hull);
+        let mut intel_zone = None;
+        let (kind, ore, fuel) = if is_pirate {
+            let mut remaining = loot.copied().unwrap_or_default();
+
+            if let Some(zone) = zone {
+                if let Some(cause) = hull.last_hit.and_then(loss_cause) {
+                    memory.record_loss(zone, cause);
+                }
+                control.adjust(zone, -KILL_CONTROL_LOSS);
+            }
+            log.push("Pirate ship destroyed".to_string());
+
+            // Player kills pull half the haul straight into the hold
+            if hull.last_hit == Some(DamageSource::Player) && !remaining.is_empty() {
+                if let Ok(mut cargo) = player_cargo.single_mut() {
+                    let recovered = intercept_recovery(&remaining);
+                    let ore = cargo.add_ore(recovered.ore.floor() as u32);
+                    let fuel = cargo.add_fuel(recovered.fuel);
+                    remaining.ore -= ore as f32;
+                    remaining.fuel -= fuel;
+                    if ore > 0 || fuel > 0.0 {
+                        ledger.record(
+                            LedgerEntry::new(ticks.tick, LedgerCategory::Bounty, "Raider")
+                                .with_ore(ore as f32)
+                                .with_fuel(fuel),
+                        );
+                        log.push(format!(
+                            "Recovered {} ore, {:.0} fuel from raider",
+                            ore, fuel
+                        ));
+                    }
+                }
+            }
+
+            // The raider's flight log points back to the closest den
+            intel_zone = nearest_base_zone(position, &bases);
+
+            (WreckKind::PirateShip, remaining.ore, remaining.fuel)
+        } else if let Some(ship) = ship {
+            log.push(format!("Ship {:?} destroyed", ship.kind));
+            let ore = cargo.map_or(0.0, |cargo| cargo.ore() as f32);
+            components += cargo.map_or(0.0, |cargo| cargo.components() as f32);
+            (WreckKind::Ship(ship.kind), ore, ship.fuel)
+        } else if let Some(station) = station {
+            log.push(format!("Station {:?} destroyed", station.kind));
+            let ore = production.map_or(0.0, |production| production.ore());
+            let fuel = station.fuel * STATION_WRECK_FUEL_FRACTION;
+            (WreckKind::Station(station.kind), ore, fuel)
+        } else {
+            continue;
+        };
+
+        commands.entity(entity).despawn();
+
+        let mut salvage = Wreck::new(kind, ore, fuel, components);
+        if let Some(zone) = intel_zone {
+            salvage = salvage.with_intel(zone);
+        }
+        let mut wreck = commands.spawn((
+            salvage,
+            Name::new("Wreck"),
+            SpatialBundle::from_transform(*transform),
+        ));
+        if let Some(zone) = zone {
+            wreck.insert(ZoneId(zone));
+        }
+    }
+}
+
+/// The player's ship is never simply removed: its cargo stays behind in a wreck
+/// and the player carries on in an escape pod. Losing the pod ends the run.
+#[allow(clippy::type_complexity)]
+pub fn eject_escape_pod(
+    mut commands: Commands,
+    mut log: ResMut<EventLog>,
+    mut status: ResMut<RunStatus>,
+    mut players: Query<
+        (
+            Entity,
+            &mut Hull,
+            &mut Ship,
+            &mut Cargo,
+            &Transform,
+            &ZoneId,
+            Has<EscapePod>,
+        ),
+        (With<PlayerControl>, Changed<Hull>),
+    >,
+) {
+    let Ok((entity, mut hull, mut ship, mut cargo, transform, zone, in_pod)) = players.single_mut()
+    else {
+        return;
+    };
+
+    if hull.condition() != HullCondition::Destroyed {
+        return;
+    }
+
+    if in_pod {
+        if status.end(RunEndReason::PodDestroyed) {
+            log.push(format!(
+                "Run over: {}",
+                RunEndReason::PodDestroyed.describe()
+            ));
+        }
+        return;
+    }
+
+    status.ships_lost += 1;
+
+    let pod_fuel = ESCAPE_POD_FUEL.min(ship.fuel_capacity);
+    let mut wreck = Wreck::new(
+        WreckKind::Ship(ShipKind::PlayerShip),
+        cargo.ore() as f32,
+        cargo.fuel + (ship.fuel - pod_fuel).max(0.0),
+        wreck_components(&hull) + cargo.components() as f32,
+    );
+    wreck.remaining_seconds = PLAYER_WRECK_LIFETIME_SECONDS;
+    commands.spawn((
+        wreck,
+        Name::new("Wreck-Player"),
+        ZoneId(zone.0),
+        SpatialBundle::from_transform(*transform),
+    ));
+
+    // Whatever else was in the hold goes down with the ship
+    cargo.hold = Inventory::new(cargo.hold.mass_capacity);
+    cargo.fuel = 0.0;
+    ship.fuel = pod_fuel;
+    ship.state = ShipState::Idle;
+    *hull = escape_pod_hull();
+    commands
+        .entity(entity)
+        .insert(EscapePod)
+        .remove::<(Shields, Armament)>();
+
+    log.push("Ship destroyed! Escape pod launched, cargo left in the wreck".to_string());
+}
+
+/// A player stuck in an escape pod with no shipyard left to reach has lost the run.
+pub fn evaluate_stranded_pod(
+    mut log: ResMut<EventLog>,
+    mut status: ResMut<RunStatus>,
+    pods: Query<(), (With<PlayerControl>, With<EscapePod>)>,
+    stations: Query<(&Station, Option<&Faction>)>,
+) {
+    if pods.is_empty() || status.is_over() {
+        return;
+    }
+
+    if stations
+        .iter()
+        .any(|(station, faction)| is_rebuild_site(station, faction))
+    {
+        return;
+    }
+
+    if status.end(RunEndReason::Stranded) {
+        log.push(format!("Run over: {}", RunEndReason::Stranded.describe()));
+    }
+}
+
+/// Player mining ships pick over wrecks near them, pulling cargo, parts and logs.
+/// A ship on a hauling run is left to `haul_station_output` until it unloads.
+#[allow(clippy::type_complexity)]
+pub fn ai_salvage(
+    time: Res<Time<Fixed>>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ships: Query<
+        (
+            &mut Ship,
+            &mut Cargo,
+            &mut Transform,
+            &Fleet,
+            &mut MinerTask,
+            &ZoneId,
+        ),
+        (Without<PlayerControl>, Without<Wreck>),
+    >,
+    mut wrecks: Query<(Entity, &Transform, &mut Wreck, &ZoneId), Without<Ship>>,
+    mut intel: Query<(&SystemNode, &mut SystemIntel)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut ship, mut cargo, mut transform, fleet, mut task, zone) in ships.iter_mut() {
+        // Haulers finish their run before they are free to salvage
+        if fleet.role != FleetRole::Mining || *task == MinerTask::Hauling {
+            continue;
+        }
+        if matches!(ship.state, ShipState::Disabled | ShipState::Returning)
+            || cargo.ore_free_space() == 0
+        {
+            *task = MinerTask::Idle;
+            continue;
+        }
+
+        let ship_pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let mut closest: Option<(Entity, Vec2, f32)> = None;
+        for (entity, wreck_transform, wreck, wreck_zone) in wrecks.iter() {
+            if wreck_zone.0 != zone.0 || wreck.is_empty() {
+                continue;
+            }
+            let pos = Vec2::new(wreck_transform.translation.x, wreck_transform.translation.y);
+            let dist = pos.distance(ship_pos);
+            if dist <= AI_SALVAGE_SEEK_RANGE && closest.is_none_or(|(_, _, best)| dist < best) {
+                closest = Some((entity, pos, dist));
+            }
+        }
+
+        let Some((wreck_entity, wreck_pos, dist)) = closest else {
+            *task = MinerTask::Idle;
+            continue;
+        };
+        *task = MinerTask::Salvaging;
+
+        if dist > SALVAGE_RANGE {
+            let step =
+                (wreck_pos - ship_pos).normalize_or_zero() * AI_SALVAGE_SPEED * delta_seconds;
+            transform.translation.x += step.x;
+            transform.translation.y += step.y;
+            ship.state = ShipState::InTransit;
+            continue;
+        }
+
+        let Ok((_, _, mut wreck, _)) = wrecks.get_mut(wreck_entity) else {
+            continue;
+        };
+
+        let fuel_room = (ship.fuel_capacity - ship.fuel).max(0.0);
+        let taken = wreck.salvage(
+            delta_seconds,
+            cargo.ore_free_space(),
+            fuel_room,
+            cargo.components_free_space(),
+        );
+        cargo.add_ore(taken.ore);
+        ship.fuel += taken.fuel;
+        // Ore and parts share the hold, so parts squeezed out stay on the wreck
+        let parts = cargo.add_whole(Commodity::Components, taken.components);
+        wreck.components += (taken.components - parts) as f32;
+        ship.state = ShipState::Executing;
+
+        if let Some(intel_zone) = taken.intel_zone {
+            reveal_salvaged_intel(&mut intel, intel_zone, ticks.tick);
+            log.push(format!(
+                "Salvage crew recovered a flight log pointing to zone {}",
+                intel_zone
+            ));
+        }
+    }
+}
+
+/// Wrecks break up after a while, or once they have been stripped bare
+pub fn wreck_decay(
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+    mut wrecks: Query<(Entity, &mut Wreck)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut wreck) in wrecks.iter_mut() {
+        wreck.remaining_seconds -= delta_seconds;
+        if wreck.remaining_seconds <= 0.0 || wreck.is_empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Raiders worth shooting at: still flying and not docked on neutral ground
+fn pirate_targets(
+    pirates: &Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+) -> Vec<(Vec2, Option<u32>)> {
+    pirates
+        .iter()
+        .filter(|(_, pirate, _)| {
+            !matches!(
+                pirate.behavior,
+                PirateShipBehavior::Disabled | PirateShipBehavior::DockedAtOutpost { .. }
+            )
+        })
+        .map(|(transform, _, zone)| {
+            (
+                Vec2::new(transform.translation.x, transform.translation.y),
+                zone.map(|zone| zone.0),
+            )
+        })
+        .collect()
+}
+
+/// Closest target in `zone` within `range` of `origin`
+fn nearest_target(
+    origin: Vec2,
+    zone: Option<u32>,
+    range: f32,
+    targets: &[(Vec2, Option<u32>)],
+) -> Option<Vec2> {
+    targets
+        .iter()
+        .filter(|(pos, target_zone)| *target_zone == zone && origin.distance(*pos) <= range)
+        .map(|(pos, _)| *pos)
+        .min_by(|a, b| origin.distance(*a).total_cmp(&origin.distance(*b)))
+}
+
+fn spawn_projectile(
+    commands: &mut Commands,
+    projectile: Projectile,
+    origin: Vec2,
+    zone: Option<u32>,
+) {
+    let mut shot = commands.spawn((
+        projectile,
+        Name::new("Projectile"),
+        SpatialBundle::from_transform(Transform::from_xyz(origin.x, origin.y, 0.6)),
+    ));
+    if let Some(zone) = zone {
+        shot.insert(ZoneId(zone));
+    }
+}
+
+fn nearest_base_zone(
+    position: Vec2,
+    bases: &Query<(&Transform, &ZoneId), With<PirateBase>>,
+) -> Option<u32> {
+    let mut nearest: Option<(u32, f32)> = None;
+    for (transform, zone) in bases.iter() {
+        let dist = Vec2::new(transform.translation.x, transform.translation.y).distance(position);
+        if nearest.is_none_or(|(_, best)| dist < best) {
+            nearest = Some((zone.0, dist));
+        }
+    }
+    nearest.map(|(zone, _)| zone)
+}
+
+fn hull_crisis_stage(condition: HullCondition) -> Option<CrisisStage> {
+    match condition {
+        HullCondition::Intact => None,
+        HullCondition::Damaged => Some(CrisisStage::Strained),
+        HullCondition::Disabled | HullCondition::Destroyed => Some(CrisisStage::Failing),
+    }
+}
+
+fn is_rebuild_site(station: &Station, faction: Option<&Faction>) -> bool {
+    station.kind == StationKind::Shipyard
+        && station.state != StationState::Failed
+        && faction.is_none_or(|faction| *faction == Faction::Player)
+}
+
+fn loss_cause(source: DamageSource) -> Option<PirateLossCause> {
+    match source {
+        DamageSource::Player => Some(PirateLossCause::Player),
+        DamageSource::Security => Some(PirateLossCause::SecurityFleet),
+        DamageSource::Station => Some(PirateLossCause::StationDefense),
+        DamageSource::Pirate => None,
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::SystemState;
+
+    #[test]
+    fn hull_crisis_stage_tracks_condition() {
+        assert_eq!(hull_crisis_stage(HullCondition::Intact), None);
+        assert_eq!(
+            hull_crisis_stage(HullCondition::Damaged),
+            Some(CrisisStage::Strained)
+        );
+        assert_eq!(
+            hull_crisis_stage(HullCondition::Disabled),
+            Some(CrisisStage::Failing)
+        );
+    }
+
+    #[test]
+    fn pirate_kills_by_pirates_are_not_remembered() {
+        assert_eq!(loss_cause(DamageSource::Pirate), None);
+        assert_eq!(
+            loss_cause(DamageSource::Player),
+            Some(PirateLossCause::Player)
+        );
+        assert_eq!(
+            loss_cause(DamageSource::Security),
+            Some(PirateLossCause::SecurityFleet)
+        );
+        assert_eq!(
+            loss_cause(DamageSource::Station),
+            Some(PirateLossCause::StationDefense)
+        );
+    }
+
+    #[test]
+    fn security_ships_and_stations_fire_on_raiders_in_their_zone() {
+        let mut world = World::default();
+        world.spawn((
+            PirateShip::new(70.0),
+            ZoneId(3),
+            Transform::from_xyz(100.0, 0.0, 0.4),
+        ));
+        let mut disabled = PirateShip::new(70.0);
+        disabled.behavior = PirateShipBehavior::Disabled;
+        world.spawn((disabled, ZoneId(4), Transform::from_xyz(0.0, 0.0, 0.4)));
+        world.spawn((
+            Fleet {
+                role: FleetRole::Security,
+            },
+            security_armament(),
+            ZoneId(3),
+            Transform::from_xyz(0.0, 0.0, 0.4),
+        ));
+        world.spawn((
+            Station {
+                kind: StationKind::Refinery,
+                state: StationState::Operational,
+                fuel: 10.0,
+                fuel_capacity: 60.0,
+            },
+            station_armament(),
+            ZoneId(3),
+            Transform::from_xyz(150.0, 0.0, 0.5),
+        ));
+        // Next to a disabled raider only, nothing worth shooting
+        world.spawn((
+            Station {
+                kind: StationKind::FuelDepot,
+                state: StationState::Operational,
+                fuel: 10.0,
+                fuel_capacity: 60.0,
+            },
+            station_armament(),
+            ZoneId(4),
+            Transform::from_xyz(20.0, 0.0, 0.5),
+        ));
+
+        let mut system_state: SystemState<(
+            Commands,
+            Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+            Query<
+                (Entity, &Transform, &Fleet, &mut Armament, Option<&ZoneId>),
+                (Without<PlayerControl>, Without<PirateShip>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (commands, pirates, ships) = system_state.get_mut(&mut world);
+        security_fire(commands, pirates, ships);
+        system_state.apply(&mut world);
+
+        let mut system_state: SystemState<(
+            Commands,
+            Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+            Query<
+                (
+                    Entity,
+                    &Transform,
+                    &mut Station,
+                    &mut Armament,
+                    Option<&ZoneId>,
+                ),
+                (Without<StationBuild>, Without<PirateShip>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (commands, pirates, stations) = system_state.get_mut(&mut world);
+        station_defense_fire(commands, pirates, stations);
+        system_state.apply(&mut world);
+
+        let mut query = world.query::<(&Projectile, &ZoneId)>();
+        let mut sources: Vec<(DamageSource, u32)> = query
+            .iter(&world)
+            .map(|(projectile, zone)| (projectile.source, zone.0))
+            .collect();
+        sources.sort_by_key(|(source, _)| *source == DamageSource::Station);
+        assert_eq!(
+            sources,
+            vec![(DamageSource::Security, 3), (DamageSource::Station, 3)]
+        );
+
+        let mut query = world.query::<&Station>();
+        let spent: Vec<f32> = query.iter(&world).map(|station| station.fuel).collect();
+        assert!(spent.contains(&10.0) && spent.iter().any(|fuel| *fuel < 10.0));
+    }
+
+    #[test]
+    fn taking_down_a_raider_eases_harassment_at_nearby_stations() {
+        let mut world = World::default();
+        let station = world.spawn_empty().id();
+        let mut crisis = Crisis::new(
+            station,
+            StationKind::Refinery,
+            CrisisType::PirateHarassment,
+            0,
+        );
+        crisis.stage = CrisisStage::Failing;
+        crisis.grace_seconds = 0.0;
+        let crisis = world.spawn(crisis).id();
+        let mut station_crises = StationCrises::default();
+        station_crises.track(
+            CrisisType::PirateHarassment,
+            crate::stations::ActiveCrisis {
+                entity: crisis,
+                stage: CrisisStage::Failing,
+                elapsed_seconds: 0.0,
+                stage_seconds: 0.0,
+            },
+        );
+        world.entity_mut(station).insert((
+            Station {
+                kind: StationKind::Refinery,
+                state: StationState::Failing,
+                fuel: 20.0,
+                fuel_capacity: 60.0,
+            },
+            station_crises,
+            ZoneId(2),
+            Transform::from_xyz(50.0, 0.0, 0.5),
+        ));
+        let mut hull = pirate_hull();
+        hull.current = 1.0;
+        hull.last_hit = Some(DamageSource::Player);
+        world.spawn((
+            PirateShip::new(70.0),
+            hull,
+            ZoneId(2),
+            Transform::from_xyz(0.0, 0.0, 0.4),
+        ));
+
+        let mut system_state: SystemState<(
+            Query<(&Hull, &mut PirateShip, &Transform, Option<&ZoneId>), Changed<Hull>>,
+            Query<(&Transform, &StationCrises, Option<&ZoneId>), With<Station>>,
+            Query<&mut Crisis>,
+        )> = SystemState::new(&mut world);
+        let (pirates, stations, crises) = system_state.get_mut(&mut world);
+        pirate_damage_state(pirates, stations, crises);
+
+        let mut query = world.query::<&PirateShip>();
+        let pirate = query.single(&world).unwrap();
+        assert_eq!(pirate.behavior, PirateShipBehavior::Disabled);
+        let crisis = world.get::<Crisis>(crisis).unwrap();
+        assert_eq!(crisis.stage, CrisisStage::Strained);
+        assert_eq!(crisis.grace_seconds, crate::crises::CRISIS_GRACE_SECONDS);
+    }
+
+    #[test]
+    fn patrolling_security_ships_chase_raiders_near_their_post_then_return() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(std::time::Duration::from_secs(1));
+        time.advance_by(std::time::Duration::from_secs(1));
+        world.insert_resource(time);
+        let raider = world
+            .spawn((
+                PirateShip::new(70.0),
+                ZoneId(3),
+                Transform::from_xyz(200.0, 0.0, 0.4),
+            ))
+            .id();
+        let guard = world
+            .spawn((
+                Ship {
+                    kind: ShipKind::Security,
+                    state: ShipState::Idle,
+                    fuel: 30.0,
+                    fuel_capacity: 60.0,
+                },
+                Fleet {
+                    role: FleetRole::Security,
+                },
+                PatrolOrder { post: Vec2::ZERO },
+                ZoneId(3),
+                Transform::from_xyz(0.0, 0.0, 0.4),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Query<(&Transform, &PirateShip, Option<&ZoneId>)>,
+            Query<
+                (
+                    &mut Ship,
+                    &mut Transform,
+                    &Fleet,
+                    &PatrolOrder,
+                    Option<&ZoneId>,
+                ),
+                (Without<PlayerControl>, Without<PirateShip>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (time, pirates, ships) = system_state.get_mut(&mut world);
+        security_patrol(time, pirates, ships);
+
+        let transform = world.get::<Transform>(guard).unwrap();
+        assert_eq!(transform.translation.x, SECURITY_PATROL_SPEED);
+        assert_eq!(
+            world.get::<Ship>(guard).unwrap().state,
+            ShipState::InTransit
+        );
+
+        // With the raider gone the guard heads back to its post
+        world.despawn(raider);
+        let (time, pirates, ships) = system_state.get_mut(&mut world);
+        security_patrol(time, pirates, ships);
+        assert_eq!(world.get::<Transform>(guard).unwrap().translation.x, 0.0);
+    }
+
+    #[test]
+    fn ship_damage_state_disables_breached_ships() {
+        let mut world = World::default();
+        world.insert_resource(EventLog::default());
+        let mut hull = ship_hull(crate::ships::ShipKind::Scout);
+        hull.current = 1.0;
+        world.spawn((
+            hull,
+            Ship {
+                kind: crate::ships::ShipKind::Scout,
+                state: ShipState::Idle,
+                fuel: 10.0,
+                fuel_capacity: 30.0,
+            },
+        ));
+
+        let mut system_state: SystemState<(
+            ResMut<EventLog>,
+            Query<(&Hull, &mut Ship, Has<PlayerControl>), Changed<Hull>>,
+        )> = SystemState::new(&mut world);
+        let (log, ships) = system_state.get_mut(&mut world);
+        ship_damage_state(log, ships);
+        system_state.apply(&mut world);
+
+        let mut query = world.query::<&Ship>();
+        for ship in query.iter(&world) {
+            assert_eq!(ship.state, ShipState::Disabled);
+        }
+    }
+
+    #[test]
+    fn only_working_player_shipyards_can_rebuild() {
+        let mut shipyard = Station {
+            kind: StationKind::Shipyard,
+            state: StationState::Strained,
+            fuel: 5.0,
+            fuel_capacity: 50.0,
+        };
+        assert!(is_rebuild_site(&shipyard, Some(&Faction::Player)));
+        assert!(!is_rebuild_site(&shipyard, Some(&Faction::Independent)));
+
+        shipyard.state = StationState::Failed;
+        assert!(!is_rebuild_site(&shipyard, None));
+    }
+
+    #[test]
+    fn player_kills_book_recovered_loot_as_a_bounty() {
+        let mut world = World::default();
+        world.insert_resource(EventLog::default());
+        world.insert_resource(Ledger::default());
+        world.insert_resource(PirateMemory::default());
+        world.insert_resource(PirateControl::default());
+        world.insert_resource(SimTickCount { tick: 7 });
+        let player = world
+            .spawn((Cargo::default(), PlayerControl, Transform::default()))
+            .id();
+        let mut hull = pirate_hull();
+        hull.current = 0.0;
+        hull.last_hit = Some(DamageSource::Player);
+        world.spawn((
+            PirateShip::new(70.0),
+            PirateLoot {
+                ore: 10.0,
+                fuel: 6.0,
+            },
+            hull,
+            ZoneId(2),
+            Transform::from_xyz(40.0, 0.0, 0.4),
+        ));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(destroy_wrecked);
+        schedule.run(&mut world);
+
+        let recovered = intercept_recovery(&PirateLoot {
+            ore: 10.0,
+            fuel: 6.0,
+        });
+        let cargo = world.get::<Cargo>(player).unwrap();
+        let ledger = world.resource::<Ledger>();
+        let entry = ledger.entries.last().expect("recovery booked");
+        assert_eq!(entry.tick, 7);
+        assert_eq!(entry.category, LedgerCategory::Bounty);
+        assert_eq!(entry.ore, cargo.ore() as f32);
+        assert_eq!(entry.ore, recovered.ore.floor());
+        assert_eq!(entry.fuel, recovered.fuel);
+    }
+
+    #[test]
+    fn destroyed_player_ship_becomes_escape_pod_and_wreck() {
+        let mut world = World::default();
+        world.insert_resource(EventLog::default());
+        world.insert_resource(RunStatus::default());
+        let mut hull = ship_hull(ShipKind::PlayerShip);
+        hull.current = 0.0;
+        let player = world
+            .spawn((
+                hull,
+                Ship {
+                    kind: ShipKind::PlayerShip,
+                    state: ShipState::Disabled,
+                    fuel: 40.0,
+                    fuel_capacity: 60.0,
+                },
+                {
+                    let mut cargo = Cargo::default();
+                    cargo.add_ore(12);
+                    cargo
+                },
+                PlayerControl,
+                ZoneId(3),
+                Transform::from_xyz(5.0, 5.0, 0.4),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Commands,
+            ResMut<EventLog>,
+            ResMut<RunStatus>,
+            Query<
+                (
+                    Entity,
+                    &mut Hull,
+                    &mut Ship,
+                    &mut Cargo,
+                    &Transform,
+                    &ZoneId,
+                    Has<EscapePod>,
+                ),
+                (With<PlayerControl>, Changed<Hull>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (commands, log, status, players) = system_state.get_mut(&mut world);
+        eject_escape_pod(commands, log, status, players);
+        system_state.apply(&mut world);
+
+        assert!(world.entity(player).contains::<EscapePod>());
+        assert_eq!(
+            world.get::<Ship>(player).map(|ship| ship.fuel),
+            Some(ESCAPE_POD_FUEL)
+        );
+        assert_eq!(world.get::<Cargo>(player).map(Cargo::ore), Some(0));
+        assert_eq!(world.resource::<RunStatus>().ships_lost, 1);
+        assert!(!world.resource::<RunStatus>().is_over());
+
+        let mut wrecks = world.query::<&Wreck>();
+        let wreck = wrecks.single(&world).expect("player wreck spawned");
+        assert_eq!(wreck.ore, 12.0);
+        assert_eq!(wreck.fuel, 32.0);
+    }
+}
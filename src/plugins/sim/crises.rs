@@ -0,0 +1,469 @@
+//! Crisis lifecycle systems: opening, escalating and resolving station crises.
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::crises::{cascade_stage, Crisis, CrisisHistory, CrisisResolution, CASCADE_ROUTE_RISK};
+use crate::plugins::core::{EventLog, RunStatus};
+use crate::plugins::player::find_zone_for_position;
+use crate::stations::{
+    is_npc_station, ActiveCrisis, CascadePressure, CascadeSource, CrisisStage, CrisisType, Station,
+    StationCrises, StationKind, StationState,
+};
+use crate::world::{Sector, ZoneId};
+
+use super::SimTickCount;
+
+// =============================================================================
+// Systems
+// =============================================================================
+
+/// A station as seen by the cascade pass
+struct CascadeNode {
+    entity: Entity,
+    position: Vec2,
+    kind: StationKind,
+    state: StationState,
+    zone: Option<u32>,
+    worst: Option<(CrisisType, CrisisStage)>,
+    harassment: Option<CrisisStage>,
+}
+
+impl CascadeNode {
+    fn source(&self, crisis_type: Option<CrisisType>) -> CascadeSource {
+        CascadeSource {
+            station: self.entity,
+            station_kind: self.kind,
+            zone: self.zone,
+            crisis_type,
+        }
+    }
+
+    /// Pressure this station passes on to the stations that depend on it
+    fn passed_on(&self) -> Option<(CrisisStage, CascadeSource)> {
+        let failed = self.state == StationState::Failed;
+        let stage = cascade_stage(failed, self.worst.map(|(_, stage)| stage))?;
+        let crisis_type = if failed {
+            None
+        } else {
+            self.worst.map(|(crisis_type, _)| crisis_type)
+        };
+        Some((stage, self.source(crisis_type)))
+    }
+}
+
+/// Pass crisis pressure from stations in trouble to the stations that depend
+/// on them: every station draws fuel from the nearest FuelDepot in its zone,
+/// every MiningOutpost feeds the nearest Refinery in its zone, and a zone overrun by pirates
+/// spills raiders down its low-risk routes.
+pub fn propagate_crisis_cascades(
+    sector: Res<Sector>,
+    mut stations: Query<(
+        Entity,
+        &Transform,
+        &Station,
+        Option<&ZoneId>,
+        &mut StationCrises,
+    )>,
+) {
+    let nodes: Vec<CascadeNode> = stations
+        .iter()
+        .filter(|(_, _, station, _, _)| !is_npc_station(station.kind))
+        .map(|(entity, transform, station, zone, crises)| {
+            let position = Vec2::new(transform.translation.x, transform.translation.y);
+            CascadeNode {
+                entity,
+                position,
+                kind: station.kind,
+                state: station.state,
+                // Stations restored from an older save carry no ZoneId
+                zone: zone
+                    .map(|zone| zone.0)
+                    .or_else(|| find_zone_for_position(&sector.nodes, position)),
+                worst: crises.worst(),
+                harassment: crises
+                    .get(CrisisType::PirateHarassment)
+                    .map(|open| open.stage),
+            }
+        })
+        .collect();
+
+    // Supply links never reach past the zone a station sits in
+    let nearest = |kind: StationKind, from: &CascadeNode| {
+        let position = from.position;
+        nodes
+            .iter()
+            .filter(|node| node.kind == kind && node.zone.is_some() && node.zone == from.zone)
+            .min_by(|a, b| {
+                a.position
+                    .distance(position)
+                    .total_cmp(&b.position.distance(position))
+            })
+    };
+
+    let mut cascades: HashMap<Entity, BTreeMap<CrisisType, CascadePressure>> = HashMap::new();
+    let mut press = |target: Entity, crisis_type: CrisisType, stage: CrisisStage, source| {
+        let entry = cascades.entry(target).or_default();
+        let worse = entry
+            .get(&crisis_type)
+            .is_none_or(|existing| stage.severity() > existing.stage.severity());
+        if worse {
+            entry.insert(crisis_type, CascadePressure { stage, source });
+        }
+    };
+
+    for node in &nodes {
+        if node.kind != StationKind::FuelDepot {
+            let depot = nearest(StationKind::FuelDepot, node);
+            if let Some((stage, source)) = depot.and_then(CascadeNode::passed_on) {
+                press(node.entity, CrisisType::FuelShortage, stage, source);
+            }
+        }
+
+        if node.kind == StationKind::MiningOutpost {
+            let refinery = nearest(StationKind::Refinery, node);
+            if let (Some(refinery), Some((stage, source))) = (refinery, node.passed_on()) {
+                press(refinery.entity, CrisisType::SupplyShortage, stage, source);
+            }
+        }
+
+        let (Some(zone), Some(CrisisStage::Failing)) = (node.zone, node.harassment) else {
+            continue;
+        };
+        for route in sector
+            .routes
+            .iter()
+            .filter(|route| route.risk <= CASCADE_ROUTE_RISK)
+        {
+            let neighbour = if route.from == zone {
+                route.to
+            } else if route.to == zone {
+                route.from
+            } else {
+                continue;
+            };
+            for target in nodes.iter().filter(|other| other.zone == Some(neighbour)) {
+                press(
+                    target.entity,
+                    CrisisType::PirateHarassment,
+                    CrisisStage::Strained,
+                    node.source(Some(CrisisType::PirateHarassment)),
+                );
+            }
+        }
+    }
+
+    for (entity, _, _, _, mut crises) in stations.iter_mut() {
+        let cascade = cascades.remove(&entity).unwrap_or_default();
+        if crises.cascades() != &cascade {
+            crises.set_cascades(cascade);
+        }
+    }
+}
+
+/// Open a crisis entity for every type of pressure a station reports that has
+/// no crisis open yet
+pub fn open_crises(
+    mut commands: Commands,
+    ticks: Res<SimTickCount>,
+    stations: Query<(Entity, &Station, &StationCrises)>,
+    crises: Query<&Crisis>,
+) {
+    let open: BTreeSet<_> = crises
+        .iter()
+        .map(|crisis| (crisis.station, crisis.crisis_type))
+        .collect();
+
+    for (entity, station, station_crises) in stations.iter() {
+        if matches!(
+            station.state,
+            StationState::Deploying | StationState::Failed
+        ) {
+            continue;
+        }
+
+        for (crisis_type, _) in station_crises.pressures() {
+            if open.contains(&(entity, crisis_type)) {
+                continue;
+            }
+            commands.spawn((
+                Crisis::new(entity, station.kind, crisis_type, ticks.tick),
+                Name::new("Crisis"),
+            ));
+        }
+    }
+}
+
+/// Run each crisis against its station's pressure and close out the ones that
+/// have ended, one way or another. A failed station's crises stay open at
+/// their last stage until the player restarts, abandons or decommissions it.
+pub fn advance_crises(
+    time: Res<Time<Fixed>>,
+    ticks: Res<SimTickCount>,
+    mut commands: Commands,
+    mut log: ResMut<EventLog>,
+    mut history: ResMut<CrisisHistory>,
+    stations: Query<(&Station, &StationCrises)>,
+    mut crises: Query<(Entity, &mut Crisis)>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (entity, mut crisis) in crises.iter_mut() {
+        let resolution = match stations.get(crisis.station) {
+            Err(_) => Some(CrisisResolution::CollapsedIntoWreck),
+            Ok((station, _)) if station.state == StationState::Failed => None,
+            Ok((_, station_crises)) => {
+                let pressure = station_crises.pressure(crisis.crisis_type);
+                crisis.advance(delta_seconds, pressure);
+                crisis.is_resolved().then_some(CrisisResolution::Stabilized)
+            }
+        };
+
+        let Some(resolution) = resolution else {
+            continue;
+        };
+
+        log.push(format!(
+            "Station {:?} crisis {}: {:?}",
+            crisis.station_kind,
+            resolution.label(),
+            crisis.crisis_type
+        ));
+        history.push(crisis.record(ticks.tick, resolution));
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Mirror open crises back onto their stations, keeping each one's stage
+/// clock running and dropping the ones that have closed
+pub fn sync_station_crises(
+    time: Res<Time<Fixed>>,
+    crises: Query<(Entity, &Crisis)>,
+    mut stations: Query<(Entity, &mut StationCrises)>,
+) {
+    let open: BTreeSet<_> = crises
+        .iter()
+        .map(|(_, crisis)| (crisis.station, crisis.crisis_type))
+        .collect();
+
+    for (station, mut station_crises) in stations.iter_mut() {
+        if station_crises.is_empty() {
+            continue;
+        }
+        station_crises.tick(time.delta_secs());
+        let closed: Vec<CrisisType> = station_crises
+            .iter()
+            .map(|(crisis_type, _)| crisis_type)
+            .filter(|crisis_type| !open.contains(&(station, *crisis_type)))
+            .collect();
+        for crisis_type in closed {
+            station_crises.clear(crisis_type);
+        }
+    }
+
+    for (entity, crisis) in crises.iter() {
+        if let Ok((_, mut station_crises)) = stations.get_mut(crisis.station) {
+            station_crises.track(
+                crisis.crisis_type,
+                ActiveCrisis {
+                    entity,
+                    stage: crisis.stage,
+                    elapsed_seconds: crisis.elapsed_seconds,
+                    stage_seconds: 0.0,
+                },
+            );
+        }
+    }
+}
+
+/// Close out the run with a tally of how its crises ended
+pub fn summarize_crises_at_run_end(
+    status: Res<RunStatus>,
+    history: Res<CrisisHistory>,
+    mut log: ResMut<EventLog>,
+    mut reported: Local<bool>,
+) {
+    if *reported || !status.is_over() {
+        return;
+    }
+    *reported = true;
+    log.push(history.summary());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stations::{CrisisStage, CrisisType, StationKind};
+    use bevy::ecs::system::SystemState;
+    use std::time::Duration;
+
+    fn crisis_world() -> (World, Entity) {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(1.0));
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+        world.insert_resource(SimTickCount { tick: 12 });
+        world.insert_resource(EventLog::default());
+        world.insert_resource(CrisisHistory::default());
+
+        let mut crises = StationCrises::default();
+        crises.report(CrisisType::FuelShortage, CrisisStage::Failing);
+        let station = world
+            .spawn((
+                Station {
+                    kind: StationKind::MiningOutpost,
+                    state: StationState::Operational,
+                    fuel: 2.0,
+                    fuel_capacity: 30.0,
+                },
+                crises,
+            ))
+            .id();
+        (world, station)
+    }
+
+    fn run_lifecycle(world: &mut World) {
+        let mut open: SystemState<(
+            Commands,
+            Res<SimTickCount>,
+            Query<(Entity, &Station, &StationCrises)>,
+            Query<&Crisis>,
+        )> = SystemState::new(world);
+        let (commands, ticks, stations, crises) = open.get_mut(world);
+        open_crises(commands, ticks, stations, crises);
+        open.apply(world);
+
+        let mut advance: SystemState<(
+            Res<Time<Fixed>>,
+            Res<SimTickCount>,
+            Commands,
+            ResMut<EventLog>,
+            ResMut<CrisisHistory>,
+            Query<(&Station, &StationCrises)>,
+            Query<(Entity, &mut Crisis)>,
+        )> = SystemState::new(world);
+        let (time, ticks, commands, log, history, stations, crises) = advance.get_mut(world);
+        advance_crises(time, ticks, commands, log, history, stations, crises);
+        advance.apply(world);
+
+        let mut sync: SystemState<(
+            Res<Time<Fixed>>,
+            Query<(Entity, &Crisis)>,
+            Query<(Entity, &mut StationCrises)>,
+        )> = SystemState::new(world);
+        let (time, crises, stations) = sync.get_mut(world);
+        sync_station_crises(time, crises, stations);
+    }
+
+    #[test]
+    fn pressure_opens_a_strained_crisis_once() {
+        let (mut world, station) = crisis_world();
+
+        run_lifecycle(&mut world);
+        run_lifecycle(&mut world);
+
+        let mut query = world.query::<&Crisis>();
+        assert_eq!(query.iter(&world).count(), 1);
+        let crisis = query.iter(&world).next().unwrap();
+        assert_eq!(crisis.onset_tick, 12);
+
+        let index = world.get::<StationCrises>(station).unwrap();
+        assert_eq!(
+            index.get(CrisisType::FuelShortage).map(|open| open.stage),
+            Some(CrisisStage::Strained)
+        );
+    }
+
+    #[test]
+    fn failed_station_keeps_its_crises_open_for_the_player_to_settle() {
+        let (mut world, station) = crisis_world();
+        run_lifecycle(&mut world);
+
+        world.get_mut::<Station>(station).unwrap().state = StationState::Failed;
+        run_lifecycle(&mut world);
+        run_lifecycle(&mut world);
+
+        let mut query = world.query::<&Crisis>();
+        assert_eq!(query.iter(&world).count(), 1);
+        assert!(world.resource::<CrisisHistory>().records.is_empty());
+        assert!(world
+            .get::<StationCrises>(station)
+            .unwrap()
+            .contains(CrisisType::FuelShortage));
+    }
+
+    #[test]
+    fn destroyed_station_leaves_a_collapsed_record() {
+        let (mut world, station) = crisis_world();
+        run_lifecycle(&mut world);
+
+        world.despawn(station);
+        run_lifecycle(&mut world);
+
+        let mut query = world.query::<&Crisis>();
+        assert_eq!(query.iter(&world).count(), 0);
+        let history = world.resource::<CrisisHistory>();
+        assert_eq!(history.records.len(), 1);
+        assert_eq!(
+            history.records[0].resolution,
+            CrisisResolution::CollapsedIntoWreck
+        );
+    }
+
+    #[test]
+    fn fuel_cascades_stay_inside_the_depot_zone() {
+        let mut world = World::default();
+        world.insert_resource(Sector::default());
+
+        let station = |kind, state, fuel| Station {
+            kind,
+            state,
+            fuel,
+            fuel_capacity: 30.0,
+        };
+        world.spawn((
+            station(StationKind::FuelDepot, StationState::Failed, 0.0),
+            StationCrises::default(),
+            ZoneId(1),
+            Transform::default(),
+        ));
+        let neighbour = world
+            .spawn((
+                station(StationKind::Refinery, StationState::Operational, 20.0),
+                StationCrises::default(),
+                ZoneId(1),
+                Transform::from_xyz(2000.0, 0.0, 0.0),
+            ))
+            .id();
+        // Closer to the failed depot, but in another zone
+        let stranger = world
+            .spawn((
+                station(StationKind::Refinery, StationState::Operational, 20.0),
+                StationCrises::default(),
+                ZoneId(2),
+                Transform::from_xyz(100.0, 0.0, 0.0),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<Sector>,
+            Query<(
+                Entity,
+                &Transform,
+                &Station,
+                Option<&ZoneId>,
+                &mut StationCrises,
+            )>,
+        )> = SystemState::new(&mut world);
+        let (sector, stations) = system_state.get_mut(&mut world);
+        propagate_crisis_cascades(sector, stations);
+
+        let crises = world.get::<StationCrises>(neighbour).unwrap();
+        assert_eq!(
+            crises.pressure(CrisisType::FuelShortage),
+            Some(CrisisStage::Failing)
+        );
+        let crises = world.get::<StationCrises>(stranger).unwrap();
+        assert_eq!(crises.pressure(CrisisType::FuelShortage), None);
+    }
+}
@@ -1,22 +1,153 @@
 //! Intel decay and management systems.
 
 use bevy::prelude::*;
+use std::collections::HashMap;
 
+use crate::ore::OreNode;
+use crate::pirates::{PirateBase, PirateControl, PirateShip};
 use crate::plugins::core::FogConfig;
-use crate::world::{zone_modifier_effect, KnowledgeLayer, Sector, SystemIntel};
+use crate::plugins::player::find_zone_for_position;
+use crate::stations::{
+    sensor_neighbour_decay, sensor_strength, MarketIntel, SensorSweep, Station, StationKind,
+    StationState, SENSOR_IDENTIFY_RANGE, SENSOR_LAYER_SECONDS,
+};
+use crate::world::{
+    jump_risk, route_between, zone_modifier_effect, zone_modifier_of, Identified, JumpGate,
+    KnowledgeLayer, Sector, SystemIntel, SystemNode, ZoneId,
+};
 
 use super::SimTickCount;
 
+// =============================================================================
+// Resources
+// =============================================================================
+
+/// Zones whose intel decay is slowed by a sensor station next door
+#[derive(Resource, Debug, Default)]
+pub struct SensorCoverage {
+    decay_factor: HashMap<u32, f32>,
+}
+
+impl SensorCoverage {
+    /// Multiplier on intel decay in a zone; 1.0 when no sensor reaches it
+    pub fn decay_factor(&self, zone: u32) -> f32 {
+        self.decay_factor.get(&zone).copied().unwrap_or(1.0)
+    }
+
+    fn cover(&mut self, zone: u32, factor: f32) {
+        let entry = self.decay_factor.entry(zone).or_insert(1.0);
+        *entry = entry.min(factor);
+    }
+}
+
 // =============================================================================
 // Systems
 // =============================================================================
 
+/// Operational sensor stations keep their own zone fresh, deepen what is known
+/// about it over time, slow intel decay one route away and identify contacts
+/// within a wide radius. Zone signals scale all of it, and zones pirates jam
+/// stay dark to sensors just as they do to scouts.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn sensor_station_sweep(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    ticks: Res<SimTickCount>,
+    sector: Res<Sector>,
+    control: Res<PirateControl>,
+    mut coverage: ResMut<SensorCoverage>,
+    mut stations: Query<(&Transform, &Station, Option<&ZoneId>, &mut SensorSweep)>,
+    mut nodes: Query<(&SystemNode, &mut SystemIntel)>,
+    unidentified: Query<
+        (Entity, &Transform),
+        (
+            Without<Identified>,
+            Or<(
+                With<OreNode>,
+                With<Station>,
+                With<PirateShip>,
+                With<PirateBase>,
+                With<JumpGate>,
+            )>,
+        ),
+    >,
+) {
+    coverage.decay_factor.clear();
+    let delta_seconds = time.delta_secs();
+
+    for (transform, station, zone, mut sweep) in stations.iter_mut() {
+        // A station under strain has no power to spare for its sensors
+        if station.kind != StationKind::SensorStation || station.state != StationState::Operational
+        {
+            continue;
+        }
+
+        let position = Vec2::new(transform.translation.x, transform.translation.y);
+        let Some(zone) = zone
+            .map(|zone| zone.0)
+            .or_else(|| find_zone_for_position(&sector.nodes, position))
+        else {
+            continue;
+        };
+        if control.blocks_intel(zone) {
+            continue;
+        }
+        let strength = sensor_strength(zone_modifier_of(&sector, zone));
+
+        sweep.layer_seconds += delta_seconds * strength;
+        let deepen = sweep.layer_seconds >= SENSOR_LAYER_SECONDS;
+        if deepen {
+            sweep.layer_seconds -= SENSOR_LAYER_SECONDS;
+        }
+
+        for (node, mut intel) in nodes.iter_mut() {
+            if node.id == zone {
+                sweep_intel(&mut intel, ticks.tick, strength);
+                if deepen {
+                    advance_intel_layer(&mut intel);
+                }
+            }
+        }
+
+        for route in &sector.routes {
+            let neighbour = if route.from == zone {
+                route.to
+            } else if route.to == zone {
+                route.from
+            } else {
+                continue;
+            };
+            if !control.blocks_intel(neighbour) {
+                coverage.cover(neighbour, sensor_neighbour_decay(strength));
+            }
+        }
+
+        let range = SENSOR_IDENTIFY_RANGE * strength;
+        for (entity, contact_transform) in unidentified.iter() {
+            let contact_pos = Vec2::new(
+                contact_transform.translation.x,
+                contact_transform.translation.y,
+            );
+            if contact_pos.distance(position) > range {
+                continue;
+            }
+            let jammed = find_zone_for_position(&sector.nodes, contact_pos)
+                .is_some_and(|contact_zone| control.blocks_intel(contact_zone));
+            if !jammed {
+                commands.entity(entity).insert(Identified);
+            }
+        }
+    }
+}
+
 pub fn decay_intel(
     ticks: Res<SimTickCount>,
     config: Res<FogConfig>,
-    mut intel_query: Query<&mut SystemIntel>,
+    coverage: Res<SensorCoverage>,
+    mut intel_query: Query<(&SystemNode, &mut SystemIntel)>,
 ) {
-    for mut intel in intel_query.iter_mut() {
+    for (node, mut intel) in intel_query.iter_mut() {
         let age = ticks.tick.saturating_sub(intel.last_seen_tick);
         let base_decay = match intel.layer {
             KnowledgeLayer::Existence => config.decay_existence,
@@ -26,7 +157,7 @@ pub fn decay_intel(
             KnowledgeLayer::Stability => config.decay_stability,
         };
         let age_factor = (age as f32 / 1000.0).clamp(0.0, 1.0);
-        let decay = base_decay * (1.0 + age_factor);
+        let decay = base_decay * (1.0 + age_factor) * coverage.decay_factor(node.id);
 
         if intel.confidence > decay {
             intel.confidence -= decay;
@@ -36,6 +167,15 @@ pub fn decay_intel(
     }
 }
 
+/// Known Outpost prices go stale at the rate of the least durable intel layer
+pub fn decay_market_intel(
+    ticks: Res<SimTickCount>,
+    config: Res<FogConfig>,
+    mut market_intel: ResMut<MarketIntel>,
+) {
+    market_intel.decay(ticks.tick, config.decay_stability);
+}
+
 // =============================================================================
 // Utility Functions
 // =============================================================================
@@ -45,6 +185,45 @@ pub fn refresh_intel(intel: &mut SystemIntel, tick: u64) {
     intel.confidence = 1.0;
 }
 
+/// A sensor sweep keeps a zone fresh; one weakened by interference can only
+/// hold confidence up to its strength
+pub fn sweep_intel(intel: &mut SystemIntel, tick: u64, strength: f32) {
+    if strength >= 1.0 {
+        refresh_intel(intel, tick);
+        return;
+    }
+    intel.last_seen_tick = tick;
+    intel.confidence = intel.confidence.max(strength);
+}
+
+/// Reveal a zone from second-hand intel such as black-market deals or salvaged
+/// flight logs, bringing knowledge up to its threats.
+pub fn reveal_threat_intel(intel: &mut SystemIntel, tick: u64) {
+    intel.revealed = true;
+    intel.revealed_tick = tick;
+    intel.last_seen_tick = tick;
+    intel.confidence = intel.confidence.max(0.6);
+    if matches!(
+        intel.layer,
+        KnowledgeLayer::Existence | KnowledgeLayer::Geography | KnowledgeLayer::Resources
+    ) {
+        intel.layer = KnowledgeLayer::Threats;
+    }
+}
+
+/// Reveal the zone named by a salvaged flight log across every system in it.
+pub fn reveal_salvaged_intel(
+    intel: &mut Query<(&SystemNode, &mut SystemIntel)>,
+    zone: u32,
+    tick: u64,
+) {
+    for (node, mut intel) in intel.iter_mut() {
+        if node.id == zone {
+            reveal_threat_intel(&mut intel, tick);
+        }
+    }
+}
+
 pub fn advance_intel_layer(intel: &mut SystemIntel) {
     intel.layer = match intel.layer {
         KnowledgeLayer::Existence => KnowledgeLayer::Geography,
@@ -72,6 +251,19 @@ pub fn zone_modifier_risk(sector: &Sector) -> f32 {
     total / (sector.nodes.len() as f32)
 }
 
+/// Chance that a jump from `from` to `to` goes wrong. Zones without a charted
+/// route between them carry no jump risk.
+pub fn jump_route_risk(sector: &Sector, control: &PirateControl, from: u32, to: u32) -> f32 {
+    let Some(route) = route_between(sector, from, to) else {
+        return 0.0;
+    };
+
+    jump_risk(
+        control.effective_route_risk(route),
+        zone_modifier_of(sector, to),
+    )
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -79,6 +271,27 @@ pub fn zone_modifier_risk(sector: &Sector) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::ecs::system::SystemState;
+    use std::time::Duration;
+
+    #[test]
+    fn reveal_threat_intel_raises_layer_but_never_lowers_it() {
+        let mut intel = SystemIntel {
+            layer: KnowledgeLayer::Geography,
+            confidence: 0.2,
+            last_seen_tick: 0,
+            revealed: false,
+            revealed_tick: 0,
+        };
+        reveal_threat_intel(&mut intel, 12);
+        assert!(intel.revealed);
+        assert_eq!(intel.layer, KnowledgeLayer::Threats);
+        assert_eq!(intel.confidence, 0.6);
+
+        intel.layer = KnowledgeLayer::Stability;
+        reveal_threat_intel(&mut intel, 20);
+        assert_eq!(intel.layer, KnowledgeLayer::Stability);
+    }
 
     #[test]
     fn advance_intel_layer_stops_at_stability() {
@@ -111,10 +324,236 @@ mod tests {
         assert_eq!(intel.confidence, 1.0);
     }
 
+    #[test]
+    fn weakened_sweep_holds_partial_confidence() {
+        let mut intel = SystemIntel {
+            layer: KnowledgeLayer::Geography,
+            confidence: 0.2,
+            last_seen_tick: 5,
+            revealed: true,
+            revealed_tick: 0,
+        };
+
+        sweep_intel(&mut intel, 30, 0.5);
+        assert_eq!(intel.last_seen_tick, 30);
+        assert_eq!(intel.confidence, 0.5);
+
+        sweep_intel(&mut intel, 31, 1.5);
+        assert_eq!(intel.confidence, 1.0);
+    }
+
+    #[test]
+    fn sensor_coverage_keeps_the_strongest_relief() {
+        let mut coverage = SensorCoverage::default();
+        assert_eq!(coverage.decay_factor(3), 1.0);
+
+        coverage.cover(3, 0.75);
+        coverage.cover(3, 0.25);
+        coverage.cover(3, 0.5);
+        assert_eq!(coverage.decay_factor(3), 0.25);
+    }
+
+    fn run_sensor_sweep(world: &mut World) {
+        let mut system_state: SystemState<(
+            Commands,
+            Res<Time<Fixed>>,
+            Res<SimTickCount>,
+            Res<Sector>,
+            Res<PirateControl>,
+            ResMut<SensorCoverage>,
+            Query<(&Transform, &Station, Option<&ZoneId>, &mut SensorSweep)>,
+            Query<(&SystemNode, &mut SystemIntel)>,
+            Query<
+                (Entity, &Transform),
+                (
+                    Without<Identified>,
+                    Or<(
+                        With<OreNode>,
+                        With<Station>,
+                        With<PirateShip>,
+                        With<PirateBase>,
+                        With<JumpGate>,
+                    )>,
+                ),
+            >,
+        )> = SystemState::new(world);
+        let (commands, time, ticks, sector, control, coverage, stations, nodes, unidentified) =
+            system_state.get_mut(world);
+        sensor_station_sweep(
+            commands,
+            time,
+            ticks,
+            sector,
+            control,
+            coverage,
+            stations,
+            nodes,
+            unidentified,
+        );
+        system_state.apply(world);
+    }
+
+    #[test]
+    fn sensor_station_sweeps_only_while_operational() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(1.0));
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+        world.insert_resource(SimTickCount { tick: 40 });
+        world.insert_resource(SensorCoverage::default());
+        world.insert_resource(PirateControl::default());
+        let node = |id: u32, x: f32| SystemNode {
+            id,
+            position: Vec2::new(x, 0.0),
+            modifier: None,
+        };
+        world.insert_resource(Sector {
+            nodes: vec![node(1, 0.0), node(2, 1000.0)],
+            routes: vec![crate::world::RouteEdge {
+                from: 1,
+                to: 2,
+                distance: 1000.0,
+                risk: 0.2,
+            }],
+        });
+        let intel = world
+            .spawn((
+                node(1, 0.0),
+                SystemIntel {
+                    layer: KnowledgeLayer::Geography,
+                    confidence: 0.3,
+                    last_seen_tick: 0,
+                    revealed: true,
+                    revealed_tick: 0,
+                },
+            ))
+            .id();
+        let sensor = world
+            .spawn((
+                Station {
+                    kind: StationKind::SensorStation,
+                    state: StationState::Strained,
+                    fuel: 10.0,
+                    fuel_capacity: 40.0,
+                },
+                SensorSweep::default(),
+                ZoneId(1),
+                Transform::default(),
+            ))
+            .id();
+        let gate = world
+            .spawn((
+                JumpGate {
+                    source_zone: 1,
+                    destination_zone: 2,
+                },
+                Transform::from_xyz(300.0, 0.0, 0.0),
+            ))
+            .id();
+
+        run_sensor_sweep(&mut world);
+        assert_eq!(world.get::<SystemIntel>(intel).unwrap().confidence, 0.3);
+        assert!(world.get::<Identified>(gate).is_none());
+
+        world.get_mut::<Station>(sensor).unwrap().state = StationState::Operational;
+        run_sensor_sweep(&mut world);
+        let swept = world.get::<SystemIntel>(intel).unwrap();
+        assert_eq!(swept.confidence, 1.0);
+        assert_eq!(swept.last_seen_tick, 40);
+        assert!(world.get::<Identified>(gate).is_some());
+        assert!(world.resource::<SensorCoverage>().decay_factor(2) < 1.0);
+    }
+
+    #[test]
+    fn sensor_station_is_jammed_in_pirate_held_zones() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(1.0));
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+        world.insert_resource(SimTickCount { tick: 40 });
+        world.insert_resource(SensorCoverage::default());
+        let mut control = PirateControl::default();
+        control.adjust(1, 0.8);
+        world.insert_resource(control);
+        let node = SystemNode {
+            id: 1,
+            position: Vec2::ZERO,
+            modifier: None,
+        };
+        world.insert_resource(Sector {
+            nodes: vec![node.clone()],
+            routes: Vec::new(),
+        });
+        let intel = world
+            .spawn((
+                node,
+                SystemIntel {
+                    layer: KnowledgeLayer::Geography,
+                    confidence: 0.3,
+                    last_seen_tick: 0,
+                    revealed: true,
+                    revealed_tick: 0,
+                },
+            ))
+            .id();
+        world.spawn((
+            Station {
+                kind: StationKind::SensorStation,
+                state: StationState::Operational,
+                fuel: 10.0,
+                fuel_capacity: 40.0,
+            },
+            SensorSweep::default(),
+            ZoneId(1),
+            Transform::default(),
+        ));
+        let gate = world
+            .spawn((
+                JumpGate {
+                    source_zone: 1,
+                    destination_zone: 2,
+                },
+                Transform::from_xyz(300.0, 0.0, 0.0),
+            ))
+            .id();
+
+        run_sensor_sweep(&mut world);
+        let jammed = world.get::<SystemIntel>(intel).unwrap();
+        assert_eq!(jammed.confidence, 0.3);
+        assert_eq!(jammed.last_seen_tick, 0);
+        assert!(world.get::<Identified>(gate).is_none());
+    }
+
     #[test]
     fn zone_modifier_risk_empty_sector_is_zero() {
         let sector = Sector::default();
         let risk = zone_modifier_risk(&sector);
         assert_eq!(risk, 0.0);
     }
+
+    #[test]
+    fn jump_route_risk_without_route_is_zero() {
+        let sector = Sector::default();
+        let control = PirateControl::default();
+        assert_eq!(jump_route_risk(&sector, &control, 1, 2), 0.0);
+    }
+
+    #[test]
+    fn jump_route_risk_rises_with_pirate_control() {
+        let sector = Sector {
+            nodes: Vec::new(),
+            routes: vec![crate::world::RouteEdge {
+                from: 1,
+                to: 2,
+                distance: 100.0,
+                risk: 0.2,
+            }],
+        };
+        let mut control = PirateControl::default();
+        let calm = jump_route_risk(&sector, &control, 1, 2);
+
+        control.adjust(2, 0.5);
+        let contested = jump_route_risk(&sector, &control, 1, 2);
+        assert!(contested > calm);
+    }
 }
@@ -1,6 +1,8 @@
 //! Simulation plugin for game logic and AI systems.
 
 mod boundary;
+mod combat;
+mod crises;
 mod intel;
 mod ore;
 mod pirates;
@@ -10,13 +12,22 @@ mod stations;
 
 use bevy::prelude::*;
 
+use crate::combat::DamageEvent;
+use crate::crises::CrisisHistory;
+use crate::ledger::Ledger;
+use crate::pirates::{PirateControl, PirateMemory};
 use crate::plugins::core::SimConfig;
 use crate::plugins::player::process_jump_transition;
+use crate::ships::ShipIds;
+use crate::stations::MarketIntel;
 use crate::world::Sector;
 
 // Re-export public items
 pub use boundary::BoundaryWarningState;
-pub use intel::{advance_intel_layer, refresh_intel, zone_modifier_risk};
+pub use intel::{
+    advance_intel_layer, jump_route_risk, refresh_intel, reveal_salvaged_intel,
+    reveal_threat_intel, zone_modifier_risk,
+};
 pub use ore::RevealedNodesTracker;
 
 // =============================================================================
@@ -29,6 +40,14 @@ impl Plugin for SimPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SimTickCount>()
             .init_resource::<RevealedNodesTracker>()
+            .init_resource::<PirateMemory>()
+            .init_resource::<PirateControl>()
+            .init_resource::<CrisisHistory>()
+            .init_resource::<intel::SensorCoverage>()
+            .init_resource::<MarketIntel>()
+            .init_resource::<Ledger>()
+            .init_resource::<ShipIds>()
+            .add_message::<DamageEvent>()
             .add_systems(
                 FixedUpdate,
                 (
@@ -37,11 +56,16 @@ impl Plugin for SimPlugin {
                     stations::station_fuel_burn,
                     stations::station_ore_production,
                     stations::station_build_progress,
+                    stations::depot_refuel_ships,
+                    stations::refinery_supply_depots,
                     stations::station_crisis_stub,
-                    stations::station_lifecycle,
-                    stations::log_station_crisis_changes,
+                    stations::station_automation_drift,
+                    stations::outpost_political_flashpoint,
+                    stations::station_lifecycle.after(crises::sync_station_crises),
+                    stations::log_station_crisis_changes.after(crises::sync_station_crises),
                     stations::shipyard_job_progress,
-                    stations::refinery_job_progress,
+                    stations::refinery_processing,
+                    stations::station_recipe_progress,
                     stations::station_job_loss_on_fail.after(stations::station_lifecycle),
                     scouts::scout_behavior.after(process_jump_transition),
                 )
@@ -53,16 +77,95 @@ impl Plugin for SimPlugin {
                     ore::spawn_ore_at_revealed_nodes,
                     boundary::check_boundary_warnings,
                     pirates::pirate_launches,
+                    pirates::pirate_dock_decisions.before(pirates::pirate_move),
                     pirates::pirate_move,
+                    pirates::pirate_relocate.after(pirates::pirate_move),
                     pirates::pirate_outpost_docking.after(pirates::pirate_move),
                     pirates::pirate_dock_timer,
+                    pirates::pirate_outpost_repairs,
+                    pirates::update_outpost_trade.after(pirates::pirate_outpost_docking),
                     pirates::pirate_harassment.after(pirates::pirate_outpost_docking),
+                    pirates::pirate_raids.after(pirates::pirate_outpost_docking),
+                    pirates::pirate_deliver_loot.after(pirates::pirate_raids),
+                    pirates::update_pirate_control,
+                    pirates::pirate_control_outposts.after(pirates::update_pirate_control),
+                    ships::assign_ship_ids.before(process_jump_transition),
                     ships::ship_fuel_burn,
                     ships::ship_fuel_alerts,
                     ships::ship_state_stub,
+                    combat::security_patrol.after(pirates::pirate_move),
                 )
                     .run_if(sim_not_paused),
             )
+            .add_systems(
+                FixedUpdate,
+                (
+                    combat::equip_hulls,
+                    combat::equip_weapons,
+                    combat::regen_defenses,
+                    combat::weapon_cooldowns,
+                    combat::pirate_fire.after(combat::weapon_cooldowns),
+                    combat::security_fire.after(combat::weapon_cooldowns),
+                    combat::station_defense_fire.after(combat::weapon_cooldowns),
+                    combat::move_projectiles
+                        .after(combat::pirate_fire)
+                        .after(combat::security_fire)
+                        .after(combat::station_defense_fire),
+                    combat::impact_decay,
+                    combat::apply_damage_events
+                        .after(pirates::pirate_raids)
+                        .after(combat::move_projectiles),
+                    combat::ship_damage_state.after(combat::apply_damage_events),
+                    combat::pirate_damage_state.after(combat::apply_damage_events),
+                    combat::station_hull_crisis.after(combat::apply_damage_events),
+                    combat::destroy_wrecked.after(combat::apply_damage_events),
+                    combat::eject_escape_pod.after(combat::ship_damage_state),
+                    combat::evaluate_stranded_pod.after(combat::eject_escape_pod),
+                    combat::ai_salvage,
+                    combat::wreck_decay.after(combat::ai_salvage),
+                )
+                    .run_if(sim_not_paused),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    crises::propagate_crisis_cascades.before(crises::open_crises),
+                    crises::open_crises
+                        .after(stations::station_crisis_stub)
+                        .after(stations::station_automation_drift)
+                        .after(stations::outpost_political_flashpoint)
+                        .after(pirates::pirate_harassment)
+                        .after(combat::station_hull_crisis),
+                    crises::advance_crises.after(crises::open_crises),
+                    crises::sync_station_crises.after(crises::advance_crises),
+                    crises::summarize_crises_at_run_end,
+                )
+                    .run_if(sim_not_paused),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    stations::station_pull_zone_ore.after(stations::station_ore_production),
+                    stations::supply_construction_sites
+                        .after(stations::station_pull_zone_ore)
+                        .before(stations::station_build_progress),
+                    stations::haul_station_output
+                        .after(stations::supply_construction_sites)
+                        .after(combat::ai_salvage),
+                    stations::outpost_market_recovery,
+                    stations::route_low_fuel_ships
+                        .after(ships::ship_state_stub)
+                        .before(stations::depot_refuel_ships),
+                    intel::decay_market_intel,
+                )
+                    .run_if(sim_not_paused),
+            )
+            .add_systems(
+                FixedUpdate,
+                intel::sensor_station_sweep
+                    .before(intel::decay_intel)
+                    .run_if(sim_not_paused),
+            )
             .add_systems(Update, scouts::handle_scout_risk_input);
     }
 }
@@ -88,7 +191,11 @@ fn sim_not_paused(config: Res<SimConfig>) -> bool {
 // Systems
 // =============================================================================
 
-fn tick_simulation(mut counter: ResMut<SimTickCount>, sector: Res<Sector>) {
+fn tick_simulation(
+    mut counter: ResMut<SimTickCount>,
+    sector: Res<Sector>,
+    control: Res<PirateControl>,
+) {
     counter.tick = counter.tick.saturating_add(1);
 
     if counter.tick.is_multiple_of(10) {
@@ -107,7 +214,11 @@ fn tick_simulation(mut counter: ResMut<SimTickCount>, sector: Res<Sector>) {
         let average_risk = if sector.routes.is_empty() {
             0.0
         } else {
-            let total_risk = sector.routes.iter().map(|route| route.risk).sum::<f32>();
+            let total_risk = sector
+                .routes
+                .iter()
+                .map(|route| control.effective_route_risk(route))
+                .sum::<f32>();
             total_risk / (sector.routes.len() as f32)
         };
 
@@ -1,18 +1,80 @@
 //! Pirate AI systems.
 
+use bevy::ecs::message::MessageWriter;
 use bevy::prelude::*;
+use std::collections::BTreeMap;
 
+use crate::combat::{DamageEvent, DamageSource, Hull};
+use crate::commodities::Commodity;
 use crate::compat::SpatialBundle;
 use crate::factions::Faction;
-use crate::pirates::{schedule_next_launch, PirateBase, PirateShip, PirateShipBehavior};
-use crate::stations::{CrisisStage, CrisisType, Station, StationCrisis, StationKind};
-use crate::world::ZoneId;
+use crate::pirates::{
+    accelerate_launch, choose_relocation_zone, pirate_control_delta, schedule_next_launch,
+    PirateBase, PirateControl, PirateLoot, PirateMemory, PirateShip, PirateShipBehavior,
+    RaidTargetType, ZonePresence, RAID_CONTROL_GAIN,
+};
+use crate::plugins::core::EventLog;
+use crate::plugins::player::PlayerControl;
+use crate::ships::{Cargo, Fleet, FleetRole, Ship};
+use crate::stations::{
+    CrisisStage, CrisisType, MarketGood, OutpostMarket, OutpostTrade, RefineryStorage, Station,
+    StationCrises, StationKind, StationProduction,
+};
+use crate::world::{JumpGate, JumpTransition, ZoneId, JUMP_TRANSITION_SECONDS};
 
 use super::SimTickCount;
 
 /// Duration in ticks that pirates dock at Outposts (about 5 seconds at 60 ticks/sec)
 const PIRATE_DOCK_DURATION_TICKS: u64 = 300;
 
+/// Distance at which a pirate docks at an Outpost
+const PIRATE_OUTPOST_DOCK_RANGE: f32 = 15.0;
+
+/// Fuel a docked pirate buys from the Outpost's stock
+const PIRATE_DOCK_REFUEL: f32 = 10.0;
+
+/// Ticks after undocking before a pirate considers docking again (about 30 seconds)
+const PIRATE_DOCK_COOLDOWN_TICKS: u64 = 1800;
+
+/// Ticks between pirate decisions to dock at an Outpost
+const DOCK_DECISION_TICKS: u64 = 60;
+
+/// Ticks between Outpost trade term updates
+const OUTPOST_TRADE_UPDATE_TICKS: u64 = 60;
+
+/// Ticks between raid pulses while a pirate loiters near a target
+const RAID_PULSE_TICKS: u64 = 10;
+
+/// Distance at which a pirate can siphon cargo from a station or ship
+const RAID_RANGE: f32 = 18.0;
+
+/// Fuel siphoned from each target per raid pulse
+const RAID_FUEL_PER_PULSE: f32 = 2.0;
+
+/// Ore siphoned from each target per raid pulse
+const RAID_ORE_PER_PULSE: f32 = 2.0;
+
+/// Damage dealt to each target per raid pulse
+const RAID_DAMAGE_PER_PULSE: f32 = 4.0;
+
+/// Hull repaired per second while a pirate is docked at an Outpost
+const PIRATE_DOCK_REPAIR_PER_SECOND: f32 = 5.0;
+
+/// Distance at which a returning raider unloads at its base
+const LOOT_DROP_RANGE: f32 = 20.0;
+
+/// Ticks between checks of whether a zone has become too costly to stay in
+const RELOCATE_EVALUATION_TICKS: u64 = 60;
+
+/// Remembered zone danger at which pirates start looking for softer zones
+const RELOCATE_DANGER_THRESHOLD: f32 = 3.0;
+
+/// Distance at which a relocating pirate can enter its chosen gate
+const GATE_USE_RANGE: f32 = 30.0;
+
+/// Ticks between pirate control updates
+const CONTROL_UPDATE_TICKS: u64 = 60;
+
 // =============================================================================
 // Systems
 // =============================================================================
@@ -29,10 +91,8 @@ pub fn pirate_launches(
 
         base.next_launch_tick = schedule_next_launch(ticks.tick, base.launch_interval_ticks);
         commands.spawn((
-            PirateShip {
-                speed: 70.0,
-                behavior: PirateShipBehavior::default(),
-            },
+            PirateShip::new(70.0),
+            PirateLoot::default(),
             Faction::Pirate,
             ZoneId(zone_id.0),
             Name::new("Pirate-Ship"),
@@ -41,56 +101,437 @@ pub fn pirate_launches(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn pirate_move(
     time: Res<Time<Fixed>>,
-    stations: Query<&Transform, (With<Station>, Without<PirateShip>)>,
-    mut pirates: Query<(&mut Transform, &PirateShip)>,
+    memory: Res<PirateMemory>,
+    stations: Query<(&Transform, &Station, Option<&ZoneId>), Without<PirateShip>>,
+    bases: Query<&Transform, (With<PirateBase>, Without<PirateShip>)>,
+    mut pirates: Query<(&mut Transform, &PirateShip, Option<&ZoneId>), Without<JumpTransition>>,
 ) {
-    if stations.is_empty() {
+    // Outposts are neutral ground: never hunted, only visited after a dock decision
+    let mut station_targets: Vec<(Vec2, Option<u32>, RaidTargetType)> = Vec::new();
+    let mut outposts: Vec<(Vec2, Option<u32>)> = Vec::new();
+    for (transform, station, zone) in stations.iter() {
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let zone = zone.map(|zone| zone.0);
+        match RaidTargetType::from_station_kind(station.kind) {
+            Some(target) => station_targets.push((pos, zone, target)),
+            None => outposts.push((pos, zone)),
+        }
+    }
+    let base_positions: Vec<Vec2> = bases
+        .iter()
+        .map(|transform| Vec2::new(transform.translation.x, transform.translation.y))
+        .collect();
+
+    let delta_seconds = time.delta_secs();
+
+    for (mut transform, pirate, zone) in pirates.iter_mut() {
+        let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
+
+        let target = match pirate.behavior {
+            // Loaded raiders head home; without a base they keep hunting
+            PirateShipBehavior::ReturningToBase if !base_positions.is_empty() => {
+                nearest_position(pirate_pos, &base_positions)
+            }
+            PirateShipBehavior::Roaming | PirateShipBehavior::ReturningToBase => {
+                preferred_station(pirate_pos, &station_targets, &memory)
+            }
+            PirateShipBehavior::HeadingToOutpost => {
+                let pirate_zone = zone.map(|zone| zone.0);
+                let zone_outposts: Vec<Vec2> = outposts
+                    .iter()
+                    .filter(|(_, outpost_zone)| {
+                        pirate_zone.is_none() || *outpost_zone == pirate_zone
+                    })
+                    .map(|(pos, _)| *pos)
+                    .collect();
+                nearest_position(pirate_pos, &zone_outposts)
+            }
+            // Docked pirates hold still, relocating pirates are steered by pirate_relocate
+            PirateShipBehavior::DockedAtOutpost { .. }
+            | PirateShipBehavior::Relocating { .. }
+            | PirateShipBehavior::Disabled => None,
+        };
+
+        let target = match target {
+            Some(target) => target,
+            None => continue,
+        };
+
+        let direction = (target - pirate_pos).normalize_or_zero();
+        let step = direction * pirate.speed * delta_seconds;
+        transform.translation.x += step.x;
+        transform.translation.y += step.y;
+    }
+}
+
+/// Roaming pirates off their dock cooldown decide to visit an Outpost in their
+/// zone when they have ore to fence or a hull to patch.
+pub fn pirate_dock_decisions(
+    ticks: Res<SimTickCount>,
+    stations: Query<(&Station, &ZoneId), Without<PirateShip>>,
+    mut pirates: Query<(&mut PirateShip, &ZoneId, Option<&PirateLoot>, Option<&Hull>)>,
+) {
+    if !ticks.tick.is_multiple_of(DOCK_DECISION_TICKS) {
+        return;
+    }
+
+    for (mut pirate, zone, loot, hull) in pirates.iter_mut() {
+        let outpost_in_zone = stations.iter().any(|(station, station_zone)| {
+            station.kind == StationKind::Outpost && station_zone.0 == zone.0
+        });
+
+        match pirate.behavior {
+            PirateShipBehavior::Roaming => {
+                if ticks.tick < pirate.next_dock_tick || !outpost_in_zone {
+                    continue;
+                }
+
+                let has_ore = loot.is_some_and(|loot| loot.ore > 0.0);
+                let damaged = hull.is_some_and(|hull| hull.current < hull.max);
+                if has_ore || damaged {
+                    pirate.behavior = PirateShipBehavior::HeadingToOutpost;
+                }
+            }
+            // The Outpost is gone; go back to raiding
+            PirateShipBehavior::HeadingToOutpost if !outpost_in_zone => {
+                pirate.behavior = PirateShipBehavior::Roaming;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Recompute per-zone pirate control from bases, pirates and security patrols.
+pub fn update_pirate_control(
+    ticks: Res<SimTickCount>,
+    mut control: ResMut<PirateControl>,
+    bases: Query<&ZoneId, With<PirateBase>>,
+    pirates: Query<(&ZoneId, &PirateShip)>,
+    patrols: Query<(&ZoneId, &Fleet), With<Ship>>,
+) {
+    if !ticks.tick.is_multiple_of(CONTROL_UPDATE_TICKS) {
         return;
     }
 
-    let mut station_positions = Vec::new();
-    for transform in stations.iter() {
-        station_positions.push(Vec2::new(transform.translation.x, transform.translation.y));
+    let mut presence: BTreeMap<u32, ZonePresence> = BTreeMap::new();
+
+    // Zones already under control keep decaying even when empty
+    for zone in control.zones.keys() {
+        presence.entry(*zone).or_default();
+    }
+    for zone in bases.iter() {
+        presence.entry(zone.0).or_default().bases += 1;
+    }
+    for (zone, pirate) in pirates.iter() {
+        let entry = presence.entry(zone.0).or_default();
+        match pirate.behavior {
+            PirateShipBehavior::DockedAtOutpost { .. } => entry.docked += 1,
+            // Crippled raiders hold nothing
+            PirateShipBehavior::Disabled => {}
+            _ => entry.roaming += 1,
+        }
+    }
+    for (zone, fleet) in patrols.iter() {
+        if fleet.role == FleetRole::Security {
+            presence.entry(zone.0).or_default().security += 1;
+        }
     }
 
+    for (zone, zone_presence) in presence {
+        control.adjust(zone, pirate_control_delta(&zone_presence));
+    }
+}
+
+/// Neutral outposts in pirate-held zones turn hostile, and back once control slips.
+pub fn pirate_control_outposts(
+    control: Res<PirateControl>,
+    mut log: ResMut<EventLog>,
+    mut outposts: Query<(&Station, &ZoneId, &mut Faction)>,
+) {
+    for (station, zone, mut faction) in outposts.iter_mut() {
+        if station.kind != StationKind::Outpost {
+            continue;
+        }
+
+        let hostile = control.outposts_hostile(zone.0);
+        if hostile && *faction != Faction::Pirate {
+            *faction = Faction::Pirate;
+            log.push(format!("Outpost in zone {} has fallen to pirates", zone.0));
+        } else if !hostile && *faction == Faction::Pirate {
+            *faction = Faction::Independent;
+            log.push(format!("Outpost in zone {} is neutral again", zone.0));
+        }
+    }
+}
+
+/// Pirates in zones they remember as costly head for the gate to a softer neighbour.
+#[allow(clippy::type_complexity)]
+pub fn pirate_relocate(
+    time: Res<Time<Fixed>>,
+    ticks: Res<SimTickCount>,
+    memory: Res<PirateMemory>,
+    mut commands: Commands,
+    gates: Query<(&Transform, &JumpGate), Without<PirateShip>>,
+    mut pirates: Query<(Entity, &mut Transform, &ZoneId, &mut PirateShip), Without<JumpTransition>>,
+) {
+    let evaluate = ticks.tick.is_multiple_of(RELOCATE_EVALUATION_TICKS);
     let delta_seconds = time.delta_secs();
 
-    for (mut transform, pirate) in pirates.iter_mut() {
-        // Skip docked pirates - they don't move
-        if matches!(pirate.behavior, PirateShipBehavior::DockedAtOutpost { .. }) {
+    for (entity, mut transform, zone_id, mut pirate) in pirates.iter_mut() {
+        match pirate.behavior {
+            PirateShipBehavior::Roaming if evaluate => {
+                if memory.zone_danger(zone_id.0) < RELOCATE_DANGER_THRESHOLD {
+                    continue;
+                }
+
+                let destinations: Vec<u32> = gates
+                    .iter()
+                    .filter(|(_, gate)| gate.source_zone == zone_id.0)
+                    .map(|(_, gate)| gate.destination_zone)
+                    .collect();
+
+                if let Some(destination_zone) =
+                    choose_relocation_zone(&memory, zone_id.0, &destinations)
+                {
+                    pirate.behavior = PirateShipBehavior::Relocating { destination_zone };
+                    debug!(
+                        "Pirate leaving zone {} (danger {:.1}) for zone {}",
+                        zone_id.0,
+                        memory.zone_danger(zone_id.0),
+                        destination_zone
+                    );
+                }
+            }
+            PirateShipBehavior::Relocating { destination_zone } => {
+                let gate_pos = gates
+                    .iter()
+                    .find(|(_, gate)| {
+                        gate.source_zone == zone_id.0 && gate.destination_zone == destination_zone
+                    })
+                    .map(|(gate_transform, _)| {
+                        Vec2::new(gate_transform.translation.x, gate_transform.translation.y)
+                    });
+
+                let gate_pos = match gate_pos {
+                    Some(pos) => pos,
+                    None => {
+                        pirate.behavior = PirateShipBehavior::Roaming;
+                        continue;
+                    }
+                };
+
+                let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
+                if pirate_pos.distance(gate_pos) <= GATE_USE_RANGE {
+                    commands.entity(entity).insert(JumpTransition {
+                        destination_zone,
+                        remaining_seconds: JUMP_TRANSITION_SECONDS,
+                    });
+                    pirate.behavior = PirateShipBehavior::Roaming;
+                    continue;
+                }
+
+                let direction = (gate_pos - pirate_pos).normalize_or_zero();
+                let step = direction * pirate.speed * delta_seconds;
+                transform.translation.x += step.x;
+                transform.translation.y += step.y;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Roaming pirates shoot at nearby stations and ships, siphoning fuel and ore from
+/// stations and AI ships. The player is engaged with projectiles instead.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn pirate_raids(
+    ticks: Res<SimTickCount>,
+    mut memory: ResMut<PirateMemory>,
+    mut control: ResMut<PirateControl>,
+    mut damage: MessageWriter<DamageEvent>,
+    bases: Query<(), With<PirateBase>>,
+    mut stations: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Station,
+            Option<&mut StationProduction>,
+            Option<&mut RefineryStorage>,
+        ),
+        Without<PirateShip>,
+    >,
+    mut ships: Query<
+        (Entity, &Transform, &mut Cargo),
+        (With<Ship>, Without<PlayerControl>, Without<PirateShip>),
+    >,
+    mut pirates: Query<(
+        &Transform,
+        &mut PirateShip,
+        &mut PirateLoot,
+        Option<&ZoneId>,
+    )>,
+) {
+    if !ticks.tick.is_multiple_of(RAID_PULSE_TICKS) {
+        return;
+    }
+
+    let has_base = !bases.is_empty();
+
+    for (pirate_transform, mut pirate, mut loot, zone) in pirates.iter_mut() {
+        if pirate.behavior != PirateShipBehavior::Roaming {
+            pirate.engaged.clear();
             continue;
         }
 
-        let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
-        let mut target = station_positions[0];
-        let mut best_dist = pirate_pos.distance(target);
+        let pirate_pos = Vec2::new(
+            pirate_transform.translation.x,
+            pirate_transform.translation.y,
+        );
+
+        if loot.is_full() {
+            continue;
+        }
+
+        // Targets looted this pulse; only ones not looted last pulse count as new successes
+        let mut looted = Vec::new();
+
+        for (station_entity, station_transform, mut station, production, storage) in
+            stations.iter_mut()
+        {
+            // Outposts are neutral ground
+            let target = match RaidTargetType::from_station_kind(station.kind) {
+                Some(target) => target,
+                None => continue,
+            };
+
+            let station_pos = Vec2::new(
+                station_transform.translation.x,
+                station_transform.translation.y,
+            );
+            if pirate_pos.distance(station_pos) > RAID_RANGE {
+                continue;
+            }
+
+            damage.write(DamageEvent {
+                target: station_entity,
+                amount: RAID_DAMAGE_PER_PULSE,
+                source: DamageSource::Pirate,
+            });
+
+            let before = loot.total();
+
+            let fuel = loot.stow_fuel(RAID_FUEL_PER_PULSE.min(station.fuel.max(0.0)));
+            station.fuel -= fuel;
+
+            if let Some(mut storage) = storage {
+                let fuel = loot.stow_fuel(RAID_FUEL_PER_PULSE.min(storage.fuel));
+                storage.remove_fuel(fuel);
+            }
+
+            if let Some(mut production) = production {
+                let ore = loot.stow_ore(RAID_ORE_PER_PULSE.min(production.ore()));
+                production.withdraw(ore);
+            }
 
-        for pos in &station_positions[1..] {
-            let dist = pirate_pos.distance(*pos);
-            if dist < best_dist {
-                best_dist = dist;
-                target = *pos;
+            if loot.total() > before {
+                if !pirate.engaged.contains(&station_entity) {
+                    memory.record_success(target);
+                }
+                looted.push(station_entity);
+                if let Some(zone) = zone {
+                    control.adjust(zone.0, RAID_CONTROL_GAIN);
+                }
             }
         }
 
-        let direction = (target - pirate_pos).normalize_or_zero();
-        let step = direction * pirate.speed * delta_seconds;
-        transform.translation.x += step.x;
-        transform.translation.y += step.y;
+        for (ship_entity, ship_transform, mut cargo) in ships.iter_mut() {
+            let ship_pos = Vec2::new(ship_transform.translation.x, ship_transform.translation.y);
+            if pirate_pos.distance(ship_pos) > RAID_RANGE {
+                continue;
+            }
+
+            damage.write(DamageEvent {
+                target: ship_entity,
+                amount: RAID_DAMAGE_PER_PULSE,
+                source: DamageSource::Pirate,
+            });
+
+            let before = loot.total();
+
+            // Ship holds carry whole units; clamp before stowing so nothing is duplicated
+            let ore_wanted = RAID_ORE_PER_PULSE.min(cargo.ore() as f32).floor();
+            let ore = ore_wanted.min(loot.free_space().floor());
+            loot.stow_ore(ore);
+            cargo.remove_ore(ore as u32);
+
+            let fuel = loot.stow_fuel(RAID_FUEL_PER_PULSE.min(cargo.fuel));
+            cargo.remove_fuel(fuel);
+
+            if loot.total() > before {
+                if !pirate.engaged.contains(&ship_entity) {
+                    memory.record_success(RaidTargetType::Ship);
+                }
+                looted.push(ship_entity);
+                if let Some(zone) = zone {
+                    control.adjust(zone.0, RAID_CONTROL_GAIN);
+                }
+            }
+        }
+
+        pirate.engaged = looted;
+
+        // Without a base to return to, raiders keep what they have and roam on
+        if loot.should_return() && has_base {
+            pirate.behavior = PirateShipBehavior::ReturningToBase;
+        }
+    }
+}
+
+/// Returning raiders unload at their base, pulling the next launch forward.
+pub fn pirate_deliver_loot(
+    ticks: Res<SimTickCount>,
+    mut bases: Query<(&Transform, &mut PirateBase), Without<PirateShip>>,
+    mut pirates: Query<(&Transform, &mut PirateShip, &mut PirateLoot)>,
+) {
+    for (pirate_transform, mut pirate, mut loot) in pirates.iter_mut() {
+        if pirate.behavior != PirateShipBehavior::ReturningToBase {
+            continue;
+        }
+
+        let pirate_pos = Vec2::new(
+            pirate_transform.translation.x,
+            pirate_transform.translation.y,
+        );
+
+        for (base_transform, mut base) in bases.iter_mut() {
+            let base_pos = Vec2::new(base_transform.translation.x, base_transform.translation.y);
+            if pirate_pos.distance(base_pos) > LOOT_DROP_RANGE {
+                continue;
+            }
+
+            let delivered = loot.unload();
+            base.next_launch_tick =
+                accelerate_launch(base.next_launch_tick, ticks.tick, &delivered);
+            pirate.behavior = PirateShipBehavior::Roaming;
+            debug!(
+                "Pirate base received {:.0} ore, {:.0} fuel; next launch at tick {}",
+                delivered.ore, delivered.fuel, base.next_launch_tick
+            );
+            break;
+        }
     }
 }
 
 pub fn pirate_harassment(
-    mut commands: Commands,
-    stations: Query<(Entity, &Transform, &Station)>,
-    pirates: Query<&Transform, With<PirateShip>>,
-    crises: Query<Option<&StationCrisis>>,
+    mut stations: Query<(&Transform, &Station, &mut StationCrises)>,
+    pirates: Query<(&Transform, &PirateShip)>,
 ) {
     let range = 18.0;
 
-    for (station_entity, station_transform, station) in stations.iter() {
+    for (station_transform, station, mut crises) in stations.iter_mut() {
         // Outposts are neutral - pirates dock there, not harass
         if station.kind == StationKind::Outpost {
             continue;
@@ -102,7 +543,14 @@ pub fn pirate_harassment(
         );
         let mut count = 0u32;
 
-        for pirate_transform in pirates.iter() {
+        for (pirate_transform, pirate) in pirates.iter() {
+            // Crippled hulks and raiders docked to trade threaten nobody
+            if matches!(
+                pirate.behavior,
+                PirateShipBehavior::Disabled | PirateShipBehavior::DockedAtOutpost { .. }
+            ) {
+                continue;
+            }
             let pirate_pos = Vec2::new(
                 pirate_transform.translation.x,
                 pirate_transform.translation.y,
@@ -118,68 +566,444 @@ pub fn pirate_harassment(
             } else {
                 CrisisStage::Strained
             };
-
-            commands.entity(station_entity).insert(StationCrisis {
-                crisis_type: CrisisType::PirateHarassment,
-                stage,
-            });
-        } else if let Ok(Some(existing)) = crises.get(station_entity) {
-            if matches!(existing.crisis_type, CrisisType::PirateHarassment) {
-                commands.entity(station_entity).remove::<StationCrisis>();
-            }
+            crises.report(CrisisType::PirateHarassment, stage);
+        } else if crises
+            .direct_pressure(CrisisType::PirateHarassment)
+            .is_some()
+        {
+            crises.relieve(CrisisType::PirateHarassment);
         }
     }
 }
 
-/// System to handle pirates docking at Outposts
+/// System to handle pirates docking at Outposts, where they fence stolen ore
 pub fn pirate_outpost_docking(
-    outposts: Query<(&Transform, &Station), Without<PirateShip>>,
-    mut pirates: Query<(&Transform, &mut PirateShip)>,
+    mut outposts: Query<
+        (&Transform, &Station, &mut OutpostTrade, &mut OutpostMarket),
+        Without<PirateShip>,
+    >,
+    mut pirates: Query<(&Transform, &mut PirateShip, Option<&mut PirateLoot>)>,
 ) {
-    let dock_range = 15.0;
-
-    // Collect Outpost positions
-    let outpost_positions: Vec<Vec2> = outposts
-        .iter()
-        .filter(|(_, station)| station.kind == StationKind::Outpost)
-        .map(|(transform, _)| Vec2::new(transform.translation.x, transform.translation.y))
-        .collect();
-
-    if outpost_positions.is_empty() {
-        return;
-    }
-
-    for (transform, mut pirate) in pirates.iter_mut() {
-        // Skip already docked pirates
-        if matches!(pirate.behavior, PirateShipBehavior::DockedAtOutpost { .. }) {
+    for (transform, mut pirate, loot) in pirates.iter_mut() {
+        // Only pirates that decided to dock pull in; passing raiders fly on
+        if pirate.behavior != PirateShipBehavior::HeadingToOutpost {
             continue;
         }
 
         let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
 
         // Check if near any Outpost
-        for outpost_pos in &outpost_positions {
-            if pirate_pos.distance(*outpost_pos) <= dock_range {
-                // Dock at the Outpost
-                pirate.behavior = PirateShipBehavior::DockedAtOutpost {
-                    ticks_remaining: PIRATE_DOCK_DURATION_TICKS,
-                };
-                break;
+        for (outpost_transform, station, mut trade, mut market) in outposts.iter_mut() {
+            if station.kind != StationKind::Outpost {
+                continue;
             }
+
+            let outpost_pos = Vec2::new(
+                outpost_transform.translation.x,
+                outpost_transform.translation.y,
+            );
+            if pirate_pos.distance(outpost_pos) > PIRATE_OUTPOST_DOCK_RANGE {
+                continue;
+            }
+
+            // Dock at the Outpost
+            pirate.behavior = PirateShipBehavior::DockedAtOutpost {
+                ticks_remaining: PIRATE_DOCK_DURATION_TICKS,
+            };
+
+            // Sell the ore haul, then top up the tanks from the Outpost's fuel stock
+            if let Some(mut loot) = loot {
+                if loot.ore > 0.0 {
+                    trade.record_pirate_sale(loot.ore);
+                    market.buy(MarketGood::Goods(Commodity::RawOre), loot.ore);
+                    loot.ore = 0.0;
+                }
+                let fuel = PIRATE_DOCK_REFUEL
+                    .min(loot.free_space())
+                    .min(market.stock(MarketGood::Fuel));
+                if fuel > 0.0 && market.sell(MarketGood::Fuel, fuel) {
+                    loot.stow_fuel(fuel);
+                }
+            }
+            break;
+        }
+    }
+}
+
+/// Track docked pirates at each Outpost; terms drift back to normal once they leave.
+pub fn update_outpost_trade(
+    ticks: Res<SimTickCount>,
+    mut outposts: Query<(&Transform, &mut OutpostTrade, Option<&StationCrises>)>,
+    pirates: Query<(&Transform, &PirateShip)>,
+) {
+    if !ticks.tick.is_multiple_of(OUTPOST_TRADE_UPDATE_TICKS) {
+        return;
+    }
+
+    for (outpost_transform, mut trade, crises) in outposts.iter_mut() {
+        let outpost_pos = Vec2::new(
+            outpost_transform.translation.x,
+            outpost_transform.translation.y,
+        );
+
+        let docked = pirates
+            .iter()
+            .filter(|(_, pirate)| {
+                matches!(pirate.behavior, PirateShipBehavior::DockedAtOutpost { .. })
+            })
+            .filter(|(transform, _)| {
+                let pos = Vec2::new(transform.translation.x, transform.translation.y);
+                pos.distance(outpost_pos) <= PIRATE_OUTPOST_DOCK_RANGE
+            })
+            .count() as u32;
+
+        trade.docked_pirates = docked;
+        if docked == 0 {
+            trade.recover();
+        }
+
+        // A political flashpoint sours terms faster than they recover
+        let flashpoint = crises
+            .and_then(|crises| crises.get(CrisisType::PoliticalFlashpoint))
+            .map(|open| open.stage);
+        if let Some(stage) = flashpoint {
+            trade.record_protest(stage);
         }
     }
 }
 
 /// System to update pirate docking timers
-pub fn pirate_dock_timer(mut pirates: Query<&mut PirateShip>) {
+pub fn pirate_dock_timer(ticks: Res<SimTickCount>, mut pirates: Query<&mut PirateShip>) {
     for mut pirate in pirates.iter_mut() {
         if let PirateShipBehavior::DockedAtOutpost { ticks_remaining } = &mut pirate.behavior {
             if *ticks_remaining > 0 {
                 *ticks_remaining -= 1;
             } else {
-                // Undock and resume roaming
+                // Undock and resume roaming; the cooldown keeps it from docking right back
                 pirate.behavior = PirateShipBehavior::Roaming;
+                pirate.next_dock_tick = ticks.tick + PIRATE_DOCK_COOLDOWN_TICKS;
             }
         }
     }
 }
+
+/// Docked pirates patch their hulls at the Outpost
+pub fn pirate_outpost_repairs(
+    time: Res<Time<Fixed>>,
+    mut pirates: Query<(&PirateShip, &mut Hull)>,
+) {
+    let repair = PIRATE_DOCK_REPAIR_PER_SECOND * time.delta_secs();
+
+    for (pirate, mut hull) in pirates.iter_mut() {
+        if matches!(pirate.behavior, PirateShipBehavior::DockedAtOutpost { .. }) {
+            hull.repair(repair);
+        }
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn nearest_position(origin: Vec2, positions: &[Vec2]) -> Option<Vec2> {
+    let mut nearest = None;
+    let mut best_dist = f32::MAX;
+
+    for pos in positions {
+        let dist = origin.distance(*pos);
+        if dist < best_dist {
+            best_dist = dist;
+            nearest = Some(*pos);
+        }
+    }
+
+    nearest
+}
+
+/// Station the pirate memory rates cheapest to pursue from `origin`
+fn preferred_station(
+    origin: Vec2,
+    targets: &[(Vec2, Option<u32>, RaidTargetType)],
+    memory: &PirateMemory,
+) -> Option<Vec2> {
+    let mut preferred = None;
+    let mut best_score = f32::MAX;
+
+    for (pos, zone, target) in targets {
+        let distance = origin.distance(*pos);
+        let score = memory.target_score(distance, *zone, *target);
+        if score < best_score {
+            best_score = score;
+            preferred = Some(*pos);
+        }
+    }
+
+    preferred
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pirates::{PIRATE_LOOT_CAPACITY, PIRATE_RETURN_FRACTION};
+    use crate::stations::StationState;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::SystemState;
+
+    fn depot(world: &mut World, fuel: f32) -> Entity {
+        world
+            .spawn((
+                Station {
+                    kind: StationKind::FuelDepot,
+                    state: StationState::Operational,
+                    fuel,
+                    fuel_capacity: 100.0,
+                },
+                Transform::from_xyz(0.0, 0.0, 0.5),
+            ))
+            .id()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn run_raids(world: &mut World) {
+        let mut system_state: SystemState<(
+            Res<SimTickCount>,
+            ResMut<PirateMemory>,
+            ResMut<PirateControl>,
+            MessageWriter<DamageEvent>,
+            Query<(), With<PirateBase>>,
+            Query<
+                (
+                    Entity,
+                    &Transform,
+                    &mut Station,
+                    Option<&mut StationProduction>,
+                    Option<&mut RefineryStorage>,
+                ),
+                Without<PirateShip>,
+            >,
+            Query<
+                (Entity, &Transform, &mut Cargo),
+                (With<Ship>, Without<PlayerControl>, Without<PirateShip>),
+            >,
+            Query<(
+                &Transform,
+                &mut PirateShip,
+                &mut PirateLoot,
+                Option<&ZoneId>,
+            )>,
+        )> = SystemState::new(world);
+        let (ticks, memory, control, damage, bases, stations, ships, pirates) =
+            system_state.get_mut(world);
+        pirate_raids(
+            ticks, memory, control, damage, bases, stations, ships, pirates,
+        );
+        system_state.apply(world);
+    }
+
+    fn raid_world(loot: PirateLoot) -> (World, Entity) {
+        let mut world = World::default();
+        world.insert_resource(SimTickCount {
+            tick: RAID_PULSE_TICKS,
+        });
+        world.init_resource::<PirateMemory>();
+        world.init_resource::<PirateControl>();
+        world.init_resource::<Messages<DamageEvent>>();
+        world.spawn((
+            PirateBase {
+                launch_interval_ticks: 600,
+                next_launch_tick: 600,
+            },
+            Transform::from_xyz(500.0, 0.0, 0.0),
+        ));
+        let pirate = world
+            .spawn((
+                PirateShip::new(70.0),
+                loot,
+                Transform::from_xyz(5.0, 0.0, 0.4),
+            ))
+            .id();
+        (world, pirate)
+    }
+
+    #[test]
+    fn pirate_raids_siphon_fuel_and_keep_raiding_below_the_threshold() {
+        let (mut world, pirate) = raid_world(PirateLoot::default());
+        let station = depot(&mut world, 50.0);
+
+        run_raids(&mut world);
+
+        assert_eq!(
+            world.get::<Station>(station).unwrap().fuel,
+            50.0 - RAID_FUEL_PER_PULSE
+        );
+        assert_eq!(
+            world.get::<PirateLoot>(pirate).unwrap().fuel,
+            RAID_FUEL_PER_PULSE
+        );
+        let ship = world.get::<PirateShip>(pirate).unwrap();
+        assert_eq!(ship.behavior, PirateShipBehavior::Roaming);
+        assert_eq!(ship.engaged, vec![station]);
+        assert_eq!(world.resource::<Messages<DamageEvent>>().len(), 1);
+    }
+
+    #[test]
+    fn pirate_raids_send_raiders_home_once_the_haul_is_worth_it() {
+        let loot = PirateLoot {
+            ore: 0.0,
+            fuel: PIRATE_LOOT_CAPACITY * PIRATE_RETURN_FRACTION - RAID_FUEL_PER_PULSE,
+        };
+        let (mut world, pirate) = raid_world(loot);
+        depot(&mut world, 50.0);
+
+        run_raids(&mut world);
+
+        let loot = *world.get::<PirateLoot>(pirate).unwrap();
+        assert!(!loot.is_full());
+        assert_eq!(
+            world.get::<PirateShip>(pirate).unwrap().behavior,
+            PirateShipBehavior::ReturningToBase
+        );
+    }
+
+    #[test]
+    fn only_flying_raiders_harass_a_station() {
+        let mut world = World::default();
+        let station = depot(&mut world, 50.0);
+        world.entity_mut(station).insert(StationCrises::default());
+        let mut hulk = PirateShip::new(70.0);
+        hulk.behavior = PirateShipBehavior::Disabled;
+        let mut trader = PirateShip::new(70.0);
+        trader.behavior = PirateShipBehavior::DockedAtOutpost {
+            ticks_remaining: 10,
+        };
+        world.spawn((hulk, Transform::from_xyz(5.0, 0.0, 0.4)));
+        world.spawn((trader, Transform::from_xyz(0.0, 5.0, 0.4)));
+
+        let mut system_state: SystemState<(
+            Query<(&Transform, &Station, &mut StationCrises)>,
+            Query<(&Transform, &PirateShip)>,
+        )> = SystemState::new(&mut world);
+        let (stations, pirates) = system_state.get_mut(&mut world);
+        pirate_harassment(stations, pirates);
+        let crises = world.get::<StationCrises>(station).unwrap();
+        assert_eq!(crises.pressure(CrisisType::PirateHarassment), None);
+
+        world.spawn((PirateShip::new(70.0), Transform::from_xyz(-5.0, 0.0, 0.4)));
+        let (stations, pirates) = system_state.get_mut(&mut world);
+        pirate_harassment(stations, pirates);
+        let crises = world.get::<StationCrises>(station).unwrap();
+        assert_eq!(
+            crises.pressure(CrisisType::PirateHarassment),
+            Some(CrisisStage::Strained)
+        );
+    }
+
+    #[test]
+    fn docked_pirates_fence_ore_and_refuel_from_the_outpost() {
+        let mut world = World::default();
+        let outpost = world
+            .spawn((
+                Station {
+                    kind: StationKind::Outpost,
+                    state: StationState::Operational,
+                    fuel: 0.0,
+                    fuel_capacity: 0.0,
+                },
+                // Trade terms bring a market along with them
+                OutpostTrade::default(),
+                Transform::from_xyz(0.0, 0.0, 0.5),
+            ))
+            .id();
+        let mut visitor = PirateShip::new(70.0);
+        visitor.behavior = PirateShipBehavior::HeadingToOutpost;
+        let pirate = world
+            .spawn((
+                visitor,
+                PirateLoot {
+                    ore: 6.0,
+                    fuel: 0.0,
+                },
+                Transform::from_xyz(5.0, 0.0, 0.4),
+            ))
+            .id();
+        let fuel_stock = OutpostMarket::default().stock(MarketGood::Fuel);
+
+        let mut system_state: SystemState<(
+            Query<
+                (&Transform, &Station, &mut OutpostTrade, &mut OutpostMarket),
+                Without<PirateShip>,
+            >,
+            Query<(&Transform, &mut PirateShip, Option<&mut PirateLoot>)>,
+        )> = SystemState::new(&mut world);
+        let (outposts, pirates) = system_state.get_mut(&mut world);
+        pirate_outpost_docking(outposts, pirates);
+        system_state.apply(&mut world);
+
+        let loot = *world.get::<PirateLoot>(pirate).unwrap();
+        assert_eq!(loot.ore, 0.0);
+        assert_eq!(loot.fuel, PIRATE_DOCK_REFUEL);
+        assert!(matches!(
+            world.get::<PirateShip>(pirate).unwrap().behavior,
+            PirateShipBehavior::DockedAtOutpost { .. }
+        ));
+        let market = world.get::<OutpostMarket>(outpost).unwrap();
+        assert_eq!(
+            market.stock(MarketGood::Fuel),
+            fuel_stock - PIRATE_DOCK_REFUEL
+        );
+        assert!(world.get::<OutpostTrade>(outpost).unwrap().price_multiplier > 1.0);
+    }
+
+    #[test]
+    fn pirate_deliver_loot_unloads_at_base_and_speeds_up_launches() {
+        let mut world = World::default();
+        world.insert_resource(SimTickCount { tick: 100 });
+        let base = world
+            .spawn((
+                PirateBase {
+                    launch_interval_ticks: 600,
+                    next_launch_tick: 1000,
+                },
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ))
+            .id();
+        let carried = PirateLoot {
+            ore: 10.0,
+            fuel: 10.0,
+        };
+        let mut returning = PirateShip::new(70.0);
+        returning.behavior = PirateShipBehavior::ReturningToBase;
+        let home = world
+            .spawn((returning, carried, Transform::from_xyz(5.0, 0.0, 0.4)))
+            .id();
+        let mut far_away = PirateShip::new(70.0);
+        far_away.behavior = PirateShipBehavior::ReturningToBase;
+        let en_route = world
+            .spawn((far_away, carried, Transform::from_xyz(300.0, 0.0, 0.4)))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<SimTickCount>,
+            Query<(&Transform, &mut PirateBase), Without<PirateShip>>,
+            Query<(&Transform, &mut PirateShip, &mut PirateLoot)>,
+        )> = SystemState::new(&mut world);
+        let (ticks, bases, pirates) = system_state.get_mut(&mut world);
+        pirate_deliver_loot(ticks, bases, pirates);
+        system_state.apply(&mut world);
+
+        assert!(world.get::<PirateLoot>(home).unwrap().is_empty());
+        assert_eq!(
+            world.get::<PirateShip>(home).unwrap().behavior,
+            PirateShipBehavior::Roaming
+        );
+        assert_eq!(
+            world.get::<PirateBase>(base).unwrap().next_launch_tick,
+            accelerate_launch(1000, 100, &carried)
+        );
+        assert_eq!(*world.get::<PirateLoot>(en_route).unwrap(), carried);
+        assert_eq!(
+            world.get::<PirateShip>(en_route).unwrap().behavior,
+            PirateShipBehavior::ReturningToBase
+        );
+    }
+}
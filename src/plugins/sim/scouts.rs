@@ -2,18 +2,20 @@
 
 use bevy::prelude::*;
 
+use crate::combat::Wreck;
 use crate::fleets::{next_risk, RiskTolerance, ScoutBehavior, ScoutPhase};
 use crate::ore::OreNode;
-use crate::pirates::{PirateBase, PirateShip};
+use crate::pirates::{PirateBase, PirateControl, PirateShip};
 use crate::plugins::core::{EventLog, InputBindings};
 use crate::plugins::player::PlayerControl;
 use crate::ships::{Ship, ShipState};
 use crate::stations::Station;
 use crate::world::{
-    Identified, JumpGate, JumpTransition, KnowledgeLayer, SystemIntel, SystemNode, ZoneId,
+    Identified, JumpGate, JumpTransition, KnowledgeLayer, Sector, SystemIntel, SystemNode, ZoneId,
     JUMP_TRANSITION_SECONDS,
 };
 
+use super::intel::jump_route_risk;
 use super::SimTickCount;
 
 // =============================================================================
@@ -52,6 +54,11 @@ pub fn handle_scout_risk_input(
     for mut scout in scouts.iter_mut() {
         scout.risk = next_risk(scout.risk, delta);
         updated = Some(scout.risk);
+
+        // Scouts held back by risky routes reconsider under the new tolerance
+        if matches!(scout.phase, ScoutPhase::Complete) && scout.next_gate_to_explore().is_some() {
+            scout.phase = ScoutPhase::ZoneComplete;
+        }
     }
 
     if let Some(risk) = updated {
@@ -69,6 +76,8 @@ pub fn handle_scout_risk_input(
 pub fn scout_behavior(
     time: Res<Time<Fixed>>,
     ticks: Res<SimTickCount>,
+    sector: Res<Sector>,
+    control: Res<PirateControl>,
     mut commands: Commands,
     mut log: ResMut<EventLog>,
     mut scouts: Query<(
@@ -88,6 +97,7 @@ pub fn scout_behavior(
         (Entity, &Transform, &ZoneId),
         (With<Ship>, Without<ScoutBehavior>, Without<PlayerControl>),
     >,
+    wrecks: Query<(Entity, &Transform, &ZoneId), (With<Wreck>, Without<ScoutBehavior>)>,
     mut intel_query: Query<(&SystemNode, &mut SystemIntel)>,
 ) {
     use crate::fleets::{ContactType, IDENTIFY_RANGE};
@@ -114,9 +124,10 @@ pub fn scout_behavior(
                     // Mark zone as visited
                     behavior.mark_zone_visited(zone_id.0);
 
-                    // Update intel for current zone (already revealed on arrival)
+                    // Update intel for current zone (already revealed on arrival),
+                    // unless pirates hold the zone and jam the scan
                     for (node, mut intel) in intel_query.iter_mut() {
-                        if node.id == zone_id.0 {
+                        if node.id == zone_id.0 && !control.blocks_intel(node.id) {
                             intel.confidence = 0.8;
                             intel.last_seen_tick = ticks.tick;
                             if matches!(intel.layer, KnowledgeLayer::Existence) {
@@ -193,6 +204,17 @@ pub fn scout_behavior(
                         }
                     }
 
+                    // Add wrecks as pending contacts to investigate
+                    for (wreck_entity, wreck_transform, wreck_zone) in wrecks.iter() {
+                        if wreck_zone.0 == zone_id.0 {
+                            let pos = Vec2::new(
+                                wreck_transform.translation.x,
+                                wreck_transform.translation.y,
+                            );
+                            behavior.add_contact(wreck_entity, pos);
+                        }
+                    }
+
                     // Add other ships as pending (will be skipped during investigation)
                     for (ship_entity, ship_transform, ship_zone) in other_ships.iter() {
                         if ship_zone.0 == zone_id.0 {
@@ -254,6 +276,8 @@ pub fn scout_behavior(
                             ContactType::Asteroid
                         } else if stations.get(contact_entity).is_ok() {
                             ContactType::Station
+                        } else if wrecks.get(contact_entity).is_ok() {
+                            ContactType::Wreck
                         } else {
                             ContactType::Unknown
                         };
@@ -277,7 +301,15 @@ pub fn scout_behavior(
                 }
             }
             ScoutPhase::ZoneComplete => {
-                scout_zone_complete(&mut behavior, &mut ship, &zone_id, &gates);
+                scout_zone_complete(
+                    &mut behavior,
+                    &mut ship,
+                    &zone_id,
+                    &gates,
+                    &sector,
+                    &control,
+                    &mut log,
+                );
             }
             ScoutPhase::TravelingToGate => {
                 scout_travel_to_gate(
@@ -378,9 +410,15 @@ fn scout_zone_complete(
     ship: &mut Ship,
     zone_id: &ZoneId,
     gates: &Query<(Entity, &Transform, &JumpGate, &ZoneId), Without<ScoutBehavior>>,
+    sector: &Sector,
+    control: &PirateControl,
+    log: &mut EventLog,
 ) {
-    // Find next gate to explore
-    if let Some((gate_entity, _dest_zone)) = behavior.next_gate_to_explore() {
+    // Find the safest next gate the scout is willing to risk
+    let next_gate = behavior.next_gate_within_tolerance(|destination| {
+        jump_route_risk(sector, control, zone_id.0, destination)
+    });
+    if let Some((gate_entity, _dest_zone)) = next_gate {
         // Find the gate's position
         for (entity, gate_transform, gate, gate_zone) in gates.iter() {
             if entity == gate_entity && gate_zone.0 == zone_id.0 {
@@ -400,6 +438,13 @@ fn scout_zone_complete(
         }
     }
 
+    if behavior.next_gate_to_explore().is_some() {
+        log.push(format!(
+            "Scout holding in zone {}: remaining routes exceed risk tolerance",
+            zone_id.0
+        ));
+    }
+
     // No more gates to explore - exploration complete
     info!("Scout: Exploration complete - all reachable zones visited");
     behavior.phase = ScoutPhase::Complete;
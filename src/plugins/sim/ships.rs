@@ -3,7 +3,7 @@
 use bevy::prelude::*;
 
 use crate::plugins::core::EventLog;
-use crate::ships::{ship_fuel_burn_per_minute, Ship, ShipFuelAlert, ShipState};
+use crate::ships::{ship_fuel_burn_per_minute, Ship, ShipFuelAlert, ShipId, ShipIds, ShipState};
 
 // =============================================================================
 // Systems
@@ -27,6 +27,21 @@ pub fn ship_fuel_burn(time: Res<Time<Fixed>>, mut ships: Query<&mut Ship>) {
     }
 }
 
+/// Give every new ship a run-stable id, in entity order so a replayed run
+/// numbers its ships the same way.
+pub fn assign_ship_ids(
+    mut commands: Commands,
+    mut ids: ResMut<ShipIds>,
+    ships: Query<Entity, (With<Ship>, Without<ShipId>)>,
+) {
+    let mut new_ships: Vec<Entity> = ships.iter().collect();
+    new_ships.sort();
+
+    for entity in new_ships {
+        commands.entity(entity).insert(ids.issue());
+    }
+}
+
 pub fn ship_state_stub(mut ships: Query<&mut Ship>) {
     for mut ship in ships.iter_mut() {
         if ship.fuel <= 0.0 {
@@ -83,6 +98,35 @@ mod tests {
     use crate::ships::ShipKind;
     use bevy::ecs::system::SystemState;
 
+    #[test]
+    fn assign_ship_ids_numbers_new_ships_once() {
+        let mut world = World::default();
+        world.insert_resource(ShipIds { next: 4 });
+        let ship = || Ship {
+            kind: ShipKind::Scout,
+            state: ShipState::Idle,
+            fuel: 10.0,
+            fuel_capacity: 30.0,
+        };
+        let first = world.spawn(ship()).id();
+        let second = world.spawn((ship(), ShipId(9))).id();
+
+        for _ in 0..2 {
+            let mut system_state: SystemState<(
+                Commands,
+                ResMut<ShipIds>,
+                Query<Entity, (With<Ship>, Without<ShipId>)>,
+            )> = SystemState::new(&mut world);
+            let (commands, ids, ships) = system_state.get_mut(&mut world);
+            assign_ship_ids(commands, ids, ships);
+            system_state.apply(&mut world);
+        }
+
+        assert_eq!(world.get::<ShipId>(first), Some(&ShipId(4)));
+        assert_eq!(world.get::<ShipId>(second), Some(&ShipId(9)));
+        assert_eq!(world.resource::<ShipIds>().next, 5);
+    }
+
     #[test]
     fn ship_state_stub_disables_empty_fuel() {
         let mut world = World::default();
@@ -1,20 +1,34 @@
 //! Station simulation systems.
 
 use bevy::prelude::*;
+use std::collections::BTreeMap;
 
+use crate::combat::{Wreck, WreckKind};
 use crate::compat::SpatialBundle;
-use crate::fleets::{RiskTolerance, ScoutBehavior};
+use crate::crises::{
+    drift_fuel_leak, drift_pressure, flashpoint_pressure, Crisis, DRIFT_MISROUTED_ORE_PER_MINUTE,
+    DRIFT_SPILL_OFFSET, DRIFT_SPILL_ORE, DRIFT_STRAIN_SECONDS, DRIFT_VISIT_RANGE, FLASHPOINT_RANGE,
+};
+use crate::factions::Faction;
+use crate::fleets::{PatrolOrder, RiskTolerance, ScoutBehavior};
+use crate::ledger::{Ledger, LedgerCategory, LedgerEntry};
 use crate::plugins::core::EventLog;
+use crate::plugins::player::{find_zone_for_position, PlayerControl};
 use crate::ships::{
-    cargo_capacity, ship_default_role, ship_fuel_capacity, Cargo, Fleet, Ship, ShipFuelAlert,
-    ShipKind, ShipState,
+    cargo_capacity, ship_default_role, ship_fuel_capacity, Cargo, Credits, Fleet, FleetRole,
+    MinerTask, Ship, ShipFuelAlert, ShipKind, ShipState,
 };
 use crate::stations::{
-    station_fuel_burn_per_minute, station_ore_production_per_minute, CrisisStage, CrisisType,
-    RefineryJob, RefineryStorage, ShipyardJob, Station, StationBuild, StationCrisis,
-    StationCrisisLog, StationProduction, StationState,
+    handles_ore, is_npc_station, is_ore_consumer, is_ore_producer, refinery_ore_per_minute,
+    shipyard_launch_fuel, station_fuel_burn_per_minute, station_ore_production_per_minute,
+    ConstructionSite, CrisisStage, CrisisType, FuelDepotPolicy, OutpostMarket, OutpostTrade,
+    RefineryQueue, RefineryStorage, ShipyardQueue, ShipyardStorage, Station, StationBuild,
+    StationCrises, StationCrisisLog, StationKind, StationMaintenance, StationProduction,
+    StationRecipe, StationState, CONSTRUCTION_SUPPLY_PER_MINUTE, DEPOT_DISPENSE_PER_SECOND,
+    DEPOT_DISPENSE_RANGE, DEPOT_RETURN_SPEED, HAULER_SEEK_RANGE, HAULER_SPEED,
+    HAULER_TRANSFER_RANGE, REFINERY_DEPOT_DELIVERY_PER_MINUTE, ZONE_ORE_PULL_PER_MINUTE,
 };
-use crate::world::ZoneId;
+use crate::world::{Sector, ZoneId};
 
 use super::SimTickCount;
 
@@ -22,16 +36,25 @@ use super::SimTickCount;
 // Systems
 // =============================================================================
 
-pub fn station_fuel_burn(time: Res<Time<Fixed>>, mut stations: Query<&mut Station>) {
+pub fn station_fuel_burn(
+    time: Res<Time<Fixed>>,
+    mut stations: Query<(&mut Station, Option<&StationCrises>)>,
+) {
     let delta_seconds = time.delta_secs();
     let minutes = delta_seconds / 60.0;
 
-    for mut station in stations.iter_mut() {
+    for (mut station, crises) in stations.iter_mut() {
         if matches!(station.state, StationState::Failed) {
             continue;
         }
 
-        let burn = station_fuel_burn_per_minute(station.kind) * minutes;
+        // Drifting automation leaks fuel on top of the normal burn
+        let leak = drift_fuel_leak(
+            crises
+                .and_then(|crises| crises.get(CrisisType::AutomationDrift))
+                .map(|open| open.stage),
+        );
+        let burn = station_fuel_burn_per_minute(station.kind) * leak * minutes;
         if station.fuel > burn {
             station.fuel -= burn;
         } else {
@@ -54,40 +77,498 @@ pub fn station_ore_production(
 
         let rate = station_ore_production_per_minute(station.kind);
         let produced = rate * minutes;
-        let free_capacity = (production.ore_capacity - production.ore).max(0.0);
-        let added = produced.min(free_capacity);
-
-        if added > 0.0 {
-            production.ore += added;
-        }
+        production.deposit(produced);
     }
 }
 
+/// Count down deploying stations. Ordered construction sites only advance as
+/// far as their delivered ore and fuel allow, and stall without them.
 pub fn station_build_progress(
     time: Res<Time<Fixed>>,
     mut commands: Commands,
-    mut stations: Query<(Entity, &mut Station, &mut StationBuild)>,
+    mut log: ResMut<EventLog>,
+    mut stations: Query<(
+        Entity,
+        &mut Station,
+        &mut StationBuild,
+        Option<&mut ConstructionSite>,
+    )>,
 ) {
     let delta_seconds = time.delta_secs();
 
-    for (entity, mut station, mut build) in stations.iter_mut() {
-        if build.remaining_seconds > delta_seconds {
-            build.remaining_seconds -= delta_seconds;
+    for (entity, mut station, mut build, site) in stations.iter_mut() {
+        let mut step = delta_seconds;
+        if let Some(mut site) = site {
+            let (built, fuel) = site.build(delta_seconds);
+            station.fuel = (station.fuel + fuel).min(station.fuel_capacity);
+            step = built;
+
+            let stalled = built < delta_seconds && build.remaining_seconds > built;
+            if stalled != site.stalled {
+                site.stalled = stalled;
+                if let (true, Some(material)) = (stalled, site.waiting_on()) {
+                    log.push(format!(
+                        "{} construction stalled: needs {}",
+                        station.kind.label(),
+                        material
+                    ));
+                }
+            }
+        }
+
+        if build.remaining_seconds > step {
+            build.remaining_seconds -= step;
         } else {
             build.remaining_seconds = 0.0;
             station.state = StationState::Operational;
-            commands.entity(entity).remove::<StationBuild>();
+            commands
+                .entity(entity)
+                .remove::<(StationBuild, ConstructionSite)>();
+        }
+    }
+}
+
+/// Depots pump fuel into AI ships alongside them, keeping back their reserve
+#[allow(clippy::type_complexity)]
+pub fn depot_refuel_ships(
+    time: Res<Time<Fixed>>,
+    mut depots: Query<(&Transform, &mut Station, &FuelDepotPolicy)>,
+    mut ships: Query<(&Transform, &mut Ship), (With<Fleet>, Without<PlayerControl>)>,
+) {
+    let pumped = DEPOT_DISPENSE_PER_SECOND * time.delta_secs();
+
+    for (depot_transform, mut station, policy) in depots.iter_mut() {
+        let depot_pos = Vec2::new(depot_transform.translation.x, depot_transform.translation.y);
+
+        for (transform, mut ship) in ships.iter_mut() {
+            let pos = Vec2::new(transform.translation.x, transform.translation.y);
+            if pos.distance(depot_pos) > DEPOT_DISPENSE_RANGE {
+                continue;
+            }
+
+            let wanted = (ship.fuel_capacity - ship.fuel).max(0.0).min(pumped);
+            if wanted > 0.0 {
+                ship.fuel += policy.dispense(&mut station, wanted);
+            }
+        }
+    }
+}
+
+/// Refineries ship refined fuel from storage to the nearest working depot in
+/// their zone
+#[allow(clippy::type_complexity)]
+pub fn refinery_supply_depots(
+    time: Res<Time<Fixed>>,
+    sector: Res<Sector>,
+    mut refineries: Query<(&Transform, &Station, Option<&ZoneId>, &mut RefineryStorage)>,
+    mut depots: Query<
+        (Entity, &Transform, &mut Station, Option<&ZoneId>),
+        (With<FuelDepotPolicy>, Without<RefineryStorage>),
+    >,
+) {
+    let shipped = REFINERY_DEPOT_DELIVERY_PER_MINUTE * time.delta_secs() / 60.0;
+
+    for (transform, station, zone, mut storage) in refineries.iter_mut() {
+        if station.state == StationState::Failed || storage.fuel <= 0.0 {
+            continue;
+        }
+
+        let position = Vec2::new(transform.translation.x, transform.translation.y);
+        let Some(zone) = station_zone(&sector, zone, position) else {
+            continue;
+        };
+        let nearest = nearest_depot(depots.iter(), &sector, zone, position);
+
+        let Some((_, _, mut depot, _)) = nearest.and_then(|entity| depots.get_mut(entity).ok())
+        else {
+            continue;
+        };
+        let free = (depot.fuel_capacity - depot.fuel).max(0.0);
+        depot.fuel += storage.remove_fuel(shipped.min(free));
+    }
+}
+
+/// AI ships that turned back on low fuel head for the nearest working depot
+/// in their zone, and go back to work once it has filled them up
+#[allow(clippy::type_complexity)]
+pub fn route_low_fuel_ships(
+    time: Res<Time<Fixed>>,
+    sector: Res<Sector>,
+    depots: Query<(Entity, &Transform, &Station, Option<&ZoneId>), With<FuelDepotPolicy>>,
+    mut ships: Query<
+        (&mut Ship, &mut Transform, Option<&ZoneId>),
+        (With<Fleet>, Without<PlayerControl>, Without<Station>),
+    >,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (mut ship, mut transform, zone) in ships.iter_mut() {
+        if ship.state != ShipState::Returning {
+            continue;
+        }
+        if ship.fuel >= ship.fuel_capacity {
+            ship.state = ShipState::Idle;
+            continue;
+        }
+
+        let ship_pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let Some(zone) = station_zone(&sector, zone, ship_pos) else {
+            continue;
+        };
+        let Some((_, depot_transform, _, _)) =
+            nearest_depot(depots.iter(), &sector, zone, ship_pos)
+                .and_then(|entity| depots.get(entity).ok())
+        else {
+            continue;
+        };
+
+        let depot_pos = Vec2::new(depot_transform.translation.x, depot_transform.translation.y);
+        if ship_pos.distance(depot_pos) > DEPOT_DISPENSE_RANGE * 0.5 {
+            let step =
+                (depot_pos - ship_pos).normalize_or_zero() * DEPOT_RETURN_SPEED * delta_seconds;
+            transform.translation.x += step.x;
+            transform.translation.y += step.y;
+        }
+    }
+}
+
+/// Closest working depot in `zone`
+fn nearest_depot<'a>(
+    depots: impl Iterator<Item = (Entity, &'a Transform, &'a Station, Option<&'a ZoneId>)>,
+    sector: &Sector,
+    zone: u32,
+    from: Vec2,
+) -> Option<Entity> {
+    depots
+        .filter(|(_, _, depot, _)| {
+            !matches!(depot.state, StationState::Deploying | StationState::Failed)
+        })
+        .filter_map(|(entity, transform, _, depot_zone)| {
+            let position = Vec2::new(transform.translation.x, transform.translation.y);
+            (station_zone(sector, depot_zone, position) == Some(zone))
+                .then(|| (entity, position.distance(from)))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// A station's ore hold as seen by the logistics passes
+struct OreSite {
+    entity: Entity,
+    position: Vec2,
+    kind: StationKind,
+    zone: Option<u32>,
+    ore: f32,
+    free: f32,
+    /// A construction site waiting on ore rather than a working station
+    construction: bool,
+}
+
+/// Ore holds of every running station that produces or consumes ore
+fn ore_sites(
+    sector: &Sector,
+    stations: &Query<(
+        Entity,
+        &Transform,
+        &Station,
+        Option<&ZoneId>,
+        &mut StationProduction,
+    )>,
+) -> Vec<OreSite> {
+    stations
+        .iter()
+        .filter(|(_, _, station, _, _)| {
+            handles_ore(station.state)
+                && (is_ore_producer(station.kind) || is_ore_consumer(station.kind))
+        })
+        .map(|(entity, transform, station, zone, production)| {
+            let position = Vec2::new(transform.translation.x, transform.translation.y);
+            OreSite {
+                entity,
+                position,
+                kind: station.kind,
+                zone: station_zone(sector, zone, position),
+                ore: production.ore(),
+                free: production.free_space(),
+                construction: false,
+            }
+        })
+        .collect()
+}
+
+fn station_zone(sector: &Sector, zone: Option<&ZoneId>, position: Vec2) -> Option<u32> {
+    // Stations restored from a save carry no ZoneId
+    zone.map(|zone| zone.0)
+        .or_else(|| find_zone_for_position(&sector.nodes, position))
+}
+
+/// Closest site in a zone within hauling range that passes `wanted`
+fn nearest_site(
+    sites: &[OreSite],
+    zone: u32,
+    from: Vec2,
+    wanted: impl Fn(&OreSite) -> bool,
+) -> Option<(usize, f32)> {
+    sites
+        .iter()
+        .enumerate()
+        .filter(|(_, site)| site.zone == Some(zone) && wanted(site))
+        .map(|(index, site)| (index, site.position.distance(from)))
+        .filter(|(_, dist)| *dist <= HAULER_SEEK_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Refineries and Shipyards draw ore straight from the mining outposts in
+/// their own zone, a little each minute
+#[allow(clippy::type_complexity)]
+pub fn station_pull_zone_ore(
+    time: Res<Time<Fixed>>,
+    sector: Res<Sector>,
+    mut stations: Query<(
+        Entity,
+        &Transform,
+        &Station,
+        Option<&ZoneId>,
+        &mut StationProduction,
+    )>,
+) {
+    let pull = ZONE_ORE_PULL_PER_MINUTE * time.delta_secs() / 60.0;
+    let sites = ore_sites(&sector, &stations);
+
+    for consumer in sites.iter().filter(|site| is_ore_consumer(site.kind)) {
+        let Some(zone) = consumer.zone else {
+            continue;
+        };
+
+        let mut wanted = pull.min(consumer.free);
+        let mut pulled = 0.0;
+        for producer in sites
+            .iter()
+            .filter(|site| is_ore_producer(site.kind) && site.zone == Some(zone))
+        {
+            if wanted <= 0.0 {
+                break;
+            }
+            if let Ok((_, _, _, _, mut production)) = stations.get_mut(producer.entity) {
+                let taken = production.withdraw(wanted);
+                wanted -= taken;
+                pulled += taken;
+            }
+        }
+
+        if pulled > 0.0 {
+            if let Ok((_, _, _, _, mut production)) = stations.get_mut(consumer.entity) {
+                production.deposit(pulled);
+            }
+        }
+    }
+}
+
+/// Working stations feed construction sites in their own zone: depots send
+/// fuel above their reserve and mining outposts send ore from their hold
+#[allow(clippy::type_complexity)]
+pub fn supply_construction_sites(
+    time: Res<Time<Fixed>>,
+    sector: Res<Sector>,
+    mut sites: Query<(&Transform, Option<&ZoneId>, &mut ConstructionSite)>,
+    mut depots: Query<
+        (&Transform, &mut Station, &FuelDepotPolicy, Option<&ZoneId>),
+        Without<ConstructionSite>,
+    >,
+    mut outposts: Query<
+        (
+            &Transform,
+            &Station,
+            &mut StationProduction,
+            Option<&ZoneId>,
+        ),
+        (Without<ConstructionSite>, Without<FuelDepotPolicy>),
+    >,
+) {
+    let supply = CONSTRUCTION_SUPPLY_PER_MINUTE * time.delta_secs() / 60.0;
+    let position =
+        |transform: &Transform| Vec2::new(transform.translation.x, transform.translation.y);
+
+    for (transform, zone, mut site) in sites.iter_mut() {
+        let Some(zone) = station_zone(&sector, zone, position(transform)) else {
+            continue;
+        };
+
+        let mut fuel = site.fuel.wanted().min(supply);
+        for (depot_transform, mut depot, policy, depot_zone) in depots.iter_mut() {
+            if fuel <= 0.0 {
+                break;
+            }
+            if !matches!(depot.state, StationState::Deploying | StationState::Failed)
+                && station_zone(&sector, depot_zone, position(depot_transform)) == Some(zone)
+            {
+                fuel -= site.fuel.deliver(policy.dispense(&mut depot, fuel));
+            }
+        }
+
+        let mut ore = site.ore.wanted().min(supply);
+        for (outpost_transform, outpost, mut production, outpost_zone) in outposts.iter_mut() {
+            if ore <= 0.0 {
+                break;
+            }
+            if is_ore_producer(outpost.kind)
+                && handles_ore(outpost.state)
+                && station_zone(&sector, outpost_zone, position(outpost_transform)) == Some(zone)
+            {
+                ore -= site.ore.deliver(production.withdraw(ore));
+            }
+        }
+    }
+}
+
+/// Mining fleet ships double as haulers: they collect the output of mining
+/// outposts in their zone and carry it to a construction site short of ore,
+/// or failing that a Refinery or Shipyard there
+#[allow(clippy::type_complexity)]
+pub fn haul_station_output(
+    time: Res<Time<Fixed>>,
+    sector: Res<Sector>,
+    mut stations: Query<(
+        Entity,
+        &Transform,
+        &Station,
+        Option<&ZoneId>,
+        &mut StationProduction,
+    )>,
+    mut builds: Query<(
+        Entity,
+        &Transform,
+        &Station,
+        Option<&ZoneId>,
+        &mut ConstructionSite,
+    )>,
+    mut haulers: Query<
+        (
+            &mut Ship,
+            &mut Cargo,
+            &mut Transform,
+            &Fleet,
+            &mut MinerTask,
+            &ZoneId,
+        ),
+        (Without<PlayerControl>, Without<Station>),
+    >,
+) {
+    let delta_seconds = time.delta_secs();
+    let mut sites = ore_sites(&sector, &stations);
+    sites.extend(
+        builds
+            .iter()
+            .map(|(entity, transform, station, zone, build)| {
+                let position = Vec2::new(transform.translation.x, transform.translation.y);
+                OreSite {
+                    entity,
+                    position,
+                    kind: station.kind,
+                    zone: station_zone(&sector, zone, position),
+                    ore: 0.0,
+                    free: build.ore.wanted(),
+                    construction: true,
+                }
+            }),
+    );
+
+    for (mut ship, mut cargo, mut transform, fleet, mut task, zone) in haulers.iter_mut() {
+        // Ships picking over a wreck belong to `ai_salvage`
+        if fleet.role != FleetRole::Mining || *task == MinerTask::Salvaging {
+            continue;
+        }
+        if matches!(ship.state, ShipState::Disabled | ShipState::Returning) {
+            *task = MinerTask::Idle;
+            continue;
+        }
+
+        let ship_pos = Vec2::new(transform.translation.x, transform.translation.y);
+
+        // Fill up at the outposts first, then deliver what was picked up
+        let pickup = if cargo.ore_free_space() > 0 {
+            nearest_site(&sites, zone.0, ship_pos, |site| {
+                !site.construction && is_ore_producer(site.kind) && site.ore >= 1.0
+            })
+        } else {
+            None
+        };
+        let target = match pickup {
+            Some(found) => Some((found, true)),
+            None if cargo.ore() > 0 => nearest_site(&sites, zone.0, ship_pos, |site| {
+                site.construction && site.free >= 1.0
+            })
+            .or_else(|| {
+                nearest_site(&sites, zone.0, ship_pos, |site| {
+                    !site.construction && is_ore_consumer(site.kind) && site.free >= 1.0
+                })
+            })
+            .map(|found| (found, false)),
+            None => None,
+        };
+
+        let Some(((index, dist), loading)) = target else {
+            *task = MinerTask::Idle;
+            continue;
+        };
+        *task = MinerTask::Hauling;
+        let site = &mut sites[index];
+
+        if dist > HAULER_TRANSFER_RANGE {
+            let step =
+                (site.position - ship_pos).normalize_or_zero() * HAULER_SPEED * delta_seconds;
+            transform.translation.x += step.x;
+            transform.translation.y += step.y;
+            ship.state = ShipState::InTransit;
+            continue;
+        }
+
+        if site.construction {
+            let Ok((_, _, _, _, mut build)) = builds.get_mut(site.entity) else {
+                continue;
+            };
+            let units = (build.ore.wanted().floor() as u32).min(cargo.ore());
+            let stored = build.ore.deliver(units as f32);
+            cargo.remove_ore(stored as u32);
+            site.free = build.ore.wanted();
+            ship.state = ShipState::Executing;
+            if cargo.ore() == 0 {
+                // Run done: salvage gets first call on the ship next tick
+                *task = MinerTask::Idle;
+            }
+            continue;
         }
+
+        let Ok((_, _, _, _, mut production)) = stations.get_mut(site.entity) else {
+            continue;
+        };
+
+        // Cargo holds take whole units only
+        if loading {
+            let units = (production.ore().floor() as u32).min(cargo.ore_free_space());
+            let taken = production.withdraw(units as f32);
+            cargo.add_ore(taken as u32);
+        } else {
+            let units = (production.free_space().floor() as u32).min(cargo.ore());
+            let stored = production.deposit(units as f32);
+            cargo.remove_ore(stored as u32);
+            if cargo.ore() == 0 {
+                *task = MinerTask::Idle;
+            }
+        }
+        site.ore = production.ore();
+        site.free = production.free_space();
+        ship.state = ShipState::Executing;
     }
 }
 
 pub fn station_lifecycle(
     ticks: Res<SimTickCount>,
-    mut stations: Query<(&mut Station, Option<&StationBuild>, Option<&StationCrisis>)>,
+    mut stations: Query<(&mut Station, Option<&StationBuild>, Option<&StationCrises>)>,
 ) {
     let mut counts = std::collections::BTreeMap::new();
 
-    for (mut station, build, crisis) in stations.iter_mut() {
+    for (mut station, build, crises) in stations.iter_mut() {
         if matches!(station.state, StationState::Failed) {
             let entry = counts.entry("Failed").or_insert(0u32);
             *entry += 1;
@@ -96,14 +577,15 @@ pub fn station_lifecycle(
 
         if build.is_some() {
             station.state = StationState::Deploying;
-        } else if station.fuel <= 0.0 {
+        } else if station.fuel_capacity > 0.0
+            && station.kind != StationKind::Outpost
+            && station.fuel <= 0.0
+        {
+            // Self-sufficient stations (Outposts) carry no fuel to run out of
             station.state = StationState::Failed;
-        } else if let Some(crisis) = crisis {
-            station.state = match crisis.stage {
-                CrisisStage::Failing => StationState::Failing,
-                CrisisStage::Strained => StationState::Strained,
-                CrisisStage::Stable | CrisisStage::Resolved => StationState::Operational,
-            };
+        } else if let Some(crises) = crises {
+            // The worst of all concurrent crises sets the pace
+            station.state = crises.derived_state();
         } else {
             station.state = StationState::Operational;
         }
@@ -129,11 +611,8 @@ pub fn station_lifecycle(
     }
 }
 
-pub fn station_crisis_stub(
-    mut commands: Commands,
-    stations: Query<(Entity, &Station, Option<&StationCrisis>)>,
-) {
-    for (entity, station, crisis) in stations.iter() {
+pub fn station_crisis_stub(mut stations: Query<(&Station, &mut StationCrises)>) {
+    for (station, mut crises) in stations.iter_mut() {
         if station.fuel_capacity <= 0.0 {
             continue;
         }
@@ -145,26 +624,155 @@ pub fn station_crisis_stub(
             } else {
                 CrisisStage::Strained
             };
+            crises.report(CrisisType::FuelShortage, stage);
+        } else if crises.direct_pressure(CrisisType::FuelShortage).is_some() {
+            crises.relieve(CrisisType::FuelShortage);
+        }
+    }
+}
+
+/// Stations nobody calls on drift: output stalls, fuel leaks and stored ore is
+/// mis-routed out of the hold, to be jettisoned as salvage, until the player
+/// or a fleet ship visits.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn station_automation_drift(
+    mut commands: Commands,
+    time: Res<Time<Fixed>>,
+    sector: Res<Sector>,
+    mut log: ResMut<EventLog>,
+    mut stations: Query<(
+        &Transform,
+        &Station,
+        Option<&ZoneId>,
+        &mut StationMaintenance,
+        &mut StationCrises,
+        Option<&mut StationProduction>,
+    )>,
+    visitors: Query<&Transform, (Or<(With<PlayerControl>, With<Fleet>)>, Without<Station>)>,
+    mut crises: Query<&mut Crisis>,
+) {
+    let delta_seconds = time.delta_secs();
+
+    for (transform, station, zone, mut maintenance, mut station_crises, production) in
+        stations.iter_mut()
+    {
+        if is_npc_station(station.kind)
+            || matches!(
+                station.state,
+                StationState::Deploying | StationState::Failed
+            )
+        {
+            continue;
+        }
+
+        let station_pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let visited = visitors.iter().any(|visitor| {
+            let pos = Vec2::new(visitor.translation.x, visitor.translation.y);
+            pos.distance(station_pos) <= DRIFT_VISIT_RANGE
+        });
 
-            match crisis {
-                Some(existing) => {
-                    if existing.stage != stage || existing.crisis_type != CrisisType::FuelShortage {
-                        commands.entity(entity).insert(StationCrisis {
-                            crisis_type: CrisisType::FuelShortage,
-                            stage,
-                        });
-                    }
+        if visited {
+            if maintenance.unvisited_seconds >= DRIFT_STRAIN_SECONDS {
+                let drifting = station_crises
+                    .get(CrisisType::AutomationDrift)
+                    .and_then(|open| crises.get_mut(open.entity).ok());
+                if let Some(mut crisis) = drifting {
+                    crisis.intervene();
                 }
-                None => {
-                    commands.entity(entity).insert(StationCrisis {
-                        crisis_type: CrisisType::FuelShortage,
-                        stage,
-                    });
+                log.push(format!("Station {:?} automation reset", station.kind));
+            }
+            maintenance.unvisited_seconds = 0.0;
+        } else {
+            maintenance.unvisited_seconds += delta_seconds;
+        }
+
+        match drift_pressure(maintenance.unvisited_seconds) {
+            Some(stage) => {
+                station_crises.report(CrisisType::AutomationDrift, stage);
+            }
+            None => {
+                if station_crises
+                    .direct_pressure(CrisisType::AutomationDrift)
+                    .is_some()
+                {
+                    station_crises.relieve(CrisisType::AutomationDrift);
                 }
             }
-        } else if let Some(existing) = crisis {
-            if matches!(existing.crisis_type, CrisisType::FuelShortage) {
-                commands.entity(entity).remove::<StationCrisis>();
+        }
+
+        let drift_stage = station_crises
+            .get(CrisisType::AutomationDrift)
+            .map(|open| open.stage);
+        if let (Some(stage), Some(mut production)) = (drift_stage, production) {
+            let misrouted =
+                DRIFT_MISROUTED_ORE_PER_MINUTE * stage.severity() as f32 * delta_seconds / 60.0;
+            maintenance.misrouted_ore += production.withdraw(misrouted);
+        }
+
+        if maintenance.misrouted_ore >= DRIFT_SPILL_ORE {
+            let ore = maintenance.misrouted_ore.floor();
+            maintenance.misrouted_ore -= ore;
+            let mut wreck = commands.spawn((
+                Wreck::new(WreckKind::Station(station.kind), ore, 0.0, 0.0),
+                Name::new("Wreck"),
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    station_pos.x + DRIFT_SPILL_OFFSET,
+                    station_pos.y,
+                    transform.translation.z,
+                )),
+            ));
+            if let Some(zone) = station_zone(&sector, zone, station_pos) {
+                wreck.insert(ZoneId(zone));
+            }
+            log.push(format!(
+                "Station {:?} jettisoned {:.0} mis-routed ore",
+                station.kind, ore
+            ));
+        }
+    }
+}
+
+/// Independent Outposts protest player-built stations close to them in their zone
+#[allow(clippy::type_complexity)]
+pub fn outpost_political_flashpoint(
+    sector: Res<Sector>,
+    stations: Query<(&Transform, &Station, &Faction, Option<&ZoneId>)>,
+    mut outposts: Query<(&Transform, Option<&ZoneId>, &mut StationCrises), With<OutpostTrade>>,
+) {
+    for (outpost_transform, outpost_zone, mut crises) in outposts.iter_mut() {
+        let outpost_pos = Vec2::new(
+            outpost_transform.translation.x,
+            outpost_transform.translation.y,
+        );
+        let outpost_zone = station_zone(&sector, outpost_zone, outpost_pos);
+
+        let nearby = stations
+            .iter()
+            .filter(|(_, station, faction, _)| {
+                **faction == Faction::Player
+                    && !is_npc_station(station.kind)
+                    && station.state != StationState::Failed
+            })
+            .filter(|(transform, _, _, zone)| {
+                let pos = Vec2::new(transform.translation.x, transform.translation.y);
+                outpost_zone.is_some()
+                    && station_zone(&sector, *zone, pos) == outpost_zone
+                    && pos.distance(outpost_pos) <= FLASHPOINT_RANGE
+            })
+            .count() as u32;
+
+        match flashpoint_pressure(nearby) {
+            Some(stage) => {
+                crises.report(CrisisType::PoliticalFlashpoint, stage);
+            }
+            None => {
+                if crises
+                    .direct_pressure(CrisisType::PoliticalFlashpoint)
+                    .is_some()
+                {
+                    crises.relieve(CrisisType::PoliticalFlashpoint);
+                }
             }
         }
     }
@@ -172,154 +780,312 @@ pub fn station_crisis_stub(
 
 pub fn log_station_crisis_changes(
     mut log: ResMut<EventLog>,
-    mut stations: Query<(&Station, Option<&StationCrisis>, &mut StationCrisisLog)>,
+    mut stations: Query<(&Station, Option<&StationCrises>, &mut StationCrisisLog)>,
 ) {
-    for (station, crisis, mut log_state) in stations.iter_mut() {
-        let current_type = crisis.map(|crisis| crisis.crisis_type);
-        let current_stage = crisis.map(|crisis| crisis.stage);
-
-        if crisis_changed(
-            log_state.last_type,
-            log_state.last_stage,
-            current_type,
-            current_stage,
-        ) {
-            match (current_type, current_stage) {
-                (Some(kind), Some(stage)) => {
-                    log.push(format!(
-                        "Station {:?} crisis: {:?} {:?}",
-                        station.kind, kind, stage
-                    ));
-                }
-                _ => {
-                    log.push(format!("Station {:?} crisis resolved", station.kind));
-                }
+    for (station, crises, mut log_state) in stations.iter_mut() {
+        let current: BTreeMap<CrisisType, CrisisStage> = crises
+            .map(|crises| {
+                crises
+                    .iter()
+                    .map(|(crisis_type, crisis)| (crisis_type, crisis.stage))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut types: Vec<CrisisType> = log_state.last.keys().copied().collect();
+        types.extend(
+            current
+                .keys()
+                .filter(|kind| !log_state.last.contains_key(kind)),
+        );
+
+        for kind in types {
+            let previous_stage = log_state.last.get(&kind).copied();
+            let current_stage = current.get(&kind).copied();
+            if !crisis_changed(
+                previous_stage.map(|_| kind),
+                previous_stage,
+                current_stage.map(|_| kind),
+                current_stage,
+            ) {
+                continue;
+            }
+
+            // Closed crises are logged with their outcome by the lifecycle
+            if let Some(stage) = current_stage {
+                log.push(format!(
+                    "Station {:?} crisis: {:?} {:?}",
+                    station.kind, kind, stage
+                ));
             }
-            log_state.last_type = current_type;
-            log_state.last_stage = current_stage;
         }
+
+        log_state.last = current;
     }
 }
 
-/// Process shipyard jobs - tick timers and spawn scouts when complete
+/// Work through shipyard build queues. The job at the front advances while
+/// the yard is Operational and its hull moves into the hangar when done;
+/// hangar ships launch one at a time once the yard can fuel them.
+#[allow(clippy::type_complexity)]
 pub fn shipyard_job_progress(
     time: Res<Time<Fixed>>,
     mut commands: Commands,
     mut log: ResMut<EventLog>,
-    mut stations: Query<(Entity, &Station, &Transform, &ZoneId, &mut ShipyardJob)>,
+    mut stations: Query<(
+        Entity,
+        &mut Station,
+        &Transform,
+        &ZoneId,
+        &mut ShipyardQueue,
+        Option<&mut ShipyardStorage>,
+    )>,
 ) {
     let delta_seconds = time.delta_secs();
 
-    for (entity, station, transform, zone_id, mut job) in stations.iter_mut() {
+    for (entity, mut station, transform, zone_id, mut queue, storage) in stations.iter_mut() {
+        let mut fresh = None;
+        let storage = match storage {
+            Some(storage) => storage.into_inner(),
+            None => fresh.insert(ShipyardStorage::new()),
+        };
+
         // Only progress when station is Operational
-        if !matches!(station.state, StationState::Operational) {
-            continue;
+        if matches!(station.state, StationState::Operational) {
+            let finished = queue
+                .jobs
+                .front_mut()
+                .is_some_and(|job| job.tick(delta_seconds));
+            // A finished hull waits on the slip until the hangar has room
+            if finished && !storage.is_full() {
+                if let Some(job) = queue.jobs.pop_front() {
+                    storage.add_ship(job.kind);
+                    log.push(format!(
+                        "Shipyard: {:?} construction complete, waiting in hangar",
+                        job.kind
+                    ));
+                }
+            }
         }
 
-        job.remaining_seconds -= delta_seconds;
-
-        if job.remaining_seconds <= 0.0 {
-            // Job complete - spawn scout
-            let scout_capacity = ship_fuel_capacity(ShipKind::Scout);
-            let spawn_pos = Vec3::new(
-                transform.translation.x + 30.0,
-                transform.translation.y + 15.0,
-                0.4,
-            );
+        if !matches!(
+            station.state,
+            StationState::Deploying | StationState::Failed
+        ) {
+            let launch = storage
+                .next_launch()
+                .and_then(|kind| Some((kind, shipyard_launch_fuel(&station, kind)?)));
+            if let Some((kind, fuel)) = launch {
+                storage.launch();
+                station.fuel -= fuel;
+                launch_ship(
+                    &mut commands,
+                    kind,
+                    fuel,
+                    transform,
+                    zone_id.0,
+                    queue.scout_risk,
+                );
+                log.push(format!("Shipyard: {:?} launched", kind));
+            }
+        }
 
-            commands.spawn((
-                Ship {
-                    kind: ShipKind::Scout,
-                    state: ShipState::Idle,
-                    fuel: scout_capacity * 0.8,
-                    fuel_capacity: scout_capacity,
-                },
-                Cargo {
-                    ore: 0,
-                    ore_capacity: cargo_capacity(ShipKind::Scout) as u32,
-                    fuel: 0.0,
-                    fuel_capacity: 20.0,
-                },
-                Fleet {
-                    role: ship_default_role(ShipKind::Scout),
-                },
-                ScoutBehavior::new(zone_id.0, RiskTolerance::Balanced),
-                ShipFuelAlert::default(),
-                ZoneId(zone_id.0),
-                Name::new("Pathfinder"),
-                SpatialBundle::from_transform(Transform::from_translation(spawn_pos)),
-            ));
+        if let Some(storage) = fresh {
+            commands.entity(entity).insert(storage);
+        }
+    }
+}
 
-            commands.entity(entity).remove::<ShipyardJob>();
-            log.push("Shipyard: Scout construction complete".to_string());
+/// Put a newly built ship into space beside its shipyard
+fn launch_ship(
+    commands: &mut Commands,
+    kind: ShipKind,
+    fuel: f32,
+    yard: &Transform,
+    zone: u32,
+    scout_risk: RiskTolerance,
+) {
+    let spawn_pos = Vec3::new(yard.translation.x + 30.0, yard.translation.y + 15.0, 0.4);
+    let name = match kind {
+        ShipKind::Scout => "Pathfinder",
+        ShipKind::Miner => "Harvester",
+        ShipKind::Security | ShipKind::PlayerShip => "Sentinel",
+    };
+
+    let mut ship = commands.spawn((
+        Ship {
+            kind,
+            state: ShipState::Idle,
+            fuel,
+            fuel_capacity: ship_fuel_capacity(kind),
+        },
+        Cargo {
+            fuel_capacity: 20.0,
+            ..Cargo::with_capacity(cargo_capacity(kind) as u32)
+        },
+        Fleet {
+            role: ship_default_role(kind),
+        },
+        ShipFuelAlert::default(),
+        ZoneId(zone),
+        Name::new(name),
+        SpatialBundle::from_transform(Transform::from_translation(spawn_pos)),
+    ));
+    // Miners pick up their task from Fleet; the others need standing orders
+    match kind {
+        ShipKind::Scout => {
+            ship.insert(ScoutBehavior::new(zone, scout_risk));
         }
+        ShipKind::Security => {
+            ship.insert(PatrolOrder {
+                post: yard.translation.truncate(),
+            });
+        }
+        ShipKind::Miner | ShipKind::PlayerShip => {}
     }
 }
 
-/// Process refinery jobs - tick timers and add fuel to storage when complete
-pub fn refinery_job_progress(
+/// Refineries work ore into fuel at a rate set by their state: queued player
+/// batches first, then their input hold up to the target stock
+#[allow(clippy::type_complexity)]
+pub fn refinery_processing(
     time: Res<Time<Fixed>>,
     mut commands: Commands,
     mut log: ResMut<EventLog>,
     mut stations: Query<(
         Entity,
         &Station,
-        &mut RefineryJob,
+        Option<&mut RefineryQueue>,
+        Option<&mut StationProduction>,
         Option<&mut RefineryStorage>,
     )>,
 ) {
-    let delta_seconds = time.delta_secs();
+    let minutes = time.delta_secs() / 60.0;
 
-    for (entity, station, mut job, storage) in stations.iter_mut() {
-        // Only progress when station is Operational
-        if !matches!(station.state, StationState::Operational) {
+    for (entity, station, queue, production, storage) in stations.iter_mut() {
+        if station.kind != StationKind::Refinery {
+            continue;
+        }
+        let ore = refinery_ore_per_minute(station.state) * minutes;
+        if ore <= 0.0 {
             continue;
         }
 
-        job.remaining_seconds -= delta_seconds;
-
-        if job.remaining_seconds <= 0.0 {
-            // Job complete - add fuel to storage
-            let fuel_produced = job.fuel_out;
-
-            if let Some(mut storage) = storage {
-                storage.add_fuel(fuel_produced);
-            } else {
-                // Station doesn't have storage, create one
-                commands.entity(entity).insert(RefineryStorage {
-                    fuel: fuel_produced,
-                    fuel_capacity: 50.0,
-                });
-            }
+        let mut fresh_queue = None;
+        let queue = match queue {
+            Some(queue) => queue.into_inner(),
+            None => fresh_queue.insert(RefineryQueue::default()),
+        };
+        let mut fresh_storage = None;
+        let storage = match storage {
+            Some(storage) => storage.into_inner(),
+            None => fresh_storage.insert(RefineryStorage::new()),
+        };
 
-            commands.entity(entity).remove::<RefineryJob>();
+        let (finished, _) = queue.refine(ore, production.map(Mut::into_inner), storage);
+        for job in finished {
             log.push(format!(
-                "Refinery: Converted ore to {:.0} fuel",
-                fuel_produced
+                "Refinery: Converted {} ore to {:.0} fuel",
+                job.ore_in, job.fuel_out
             ));
         }
+
+        if let Some(queue) = fresh_queue {
+            commands.entity(entity).insert(queue);
+        }
+        if let Some(storage) = fresh_storage {
+            commands.entity(entity).insert(storage);
+        }
     }
 }
 
-/// Remove jobs from stations that have failed
-pub fn station_job_loss_on_fail(
-    mut commands: Commands,
+/// Run each station's chosen recipe on the goods in its hold. Only
+/// Operational stations keep the line going.
+pub fn station_recipe_progress(
+    time: Res<Time<Fixed>>,
     mut log: ResMut<EventLog>,
-    stations: Query<(Entity, &Station, Option<&ShipyardJob>, Option<&RefineryJob>)>,
+    mut stations: Query<(&Station, &mut StationRecipe, &mut StationProduction)>,
 ) {
-    for (entity, station, shipyard_job, refinery_job) in stations.iter() {
-        if !matches!(station.state, StationState::Failed) {
-            continue;
+    let delta_seconds = time.delta_secs();
+
+    for (station, mut recipe, mut production) in stations.iter_mut() {
+        if !matches!(station.state, StationState::Operational) {
+            continue;
+        }
+        let Some(active) = recipe.active else {
+            continue;
+        };
+        let cycles = recipe.work(delta_seconds, &mut production.hold);
+        if cycles > 0 {
+            let (output, amount) = active.spec().output;
+            log.push(format!(
+                "{:?}: {} produced {:.0} {}",
+                station.kind,
+                active.spec().name,
+                amount * cycles as f32,
+                output.name().to_lowercase()
+            ));
         }
+    }
+}
+
+/// Outposts trade with passing locals, drawing stock back toward demand so
+/// prices recover after the player or pirates move the market
+pub fn outpost_market_recovery(time: Res<Time<Fixed>>, mut markets: Query<&mut OutpostMarket>) {
+    let delta_minutes = time.delta_secs() / 60.0;
+    for mut market in markets.iter_mut() {
+        market.recover(delta_minutes);
+    }
+}
 
-        if shipyard_job.is_some() {
-            commands.entity(entity).remove::<ShipyardJob>();
-            log.push("Station failed: Scout construction lost!".to_string());
+/// Remove jobs from stations that have failed
+#[allow(clippy::type_complexity)]
+pub fn station_job_loss_on_fail(
+    mut commands: Commands,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut player: Query<(&mut Cargo, &mut Credits), With<PlayerControl>>,
+    mut stations: Query<(
+        Entity,
+        &Station,
+        Option<&Name>,
+        Option<&ShipyardQueue>,
+        Option<&mut RefineryQueue>,
+    )>,
+) {
+    for (entity, station, name, shipyard_queue, refinery_queue) in stations.iter_mut() {
+        if !matches!(station.state, StationState::Failed) {
+            continue;
         }
 
-        if refinery_job.is_some() {
-            commands.entity(entity).remove::<RefineryJob>();
-            log.push("Station failed: Ore conversion lost!".to_string());
+        if let Some(queue) = shipyard_queue.filter(|queue| !queue.jobs.is_empty()) {
+            // The player paid up front for every hull on the slip
+            if let Ok((mut cargo, mut credits)) = player.single_mut() {
+                let ore_in = queue.jobs.iter().map(|job| job.ore_in).sum();
+                let credits_in = queue.jobs.iter().map(|job| job.credits_in).sum();
+                let ore = cargo.add_ore(ore_in);
+                credits.add(credits_in);
+                let counterparty = name.map_or("Station", Name::as_str);
+                ledger.record(
+                    LedgerEntry::new(ticks.tick, LedgerCategory::Refund, counterparty)
+                        .with_credits(credits_in as i64)
+                        .with_ore(ore as f32),
+                );
+            }
+            commands.entity(entity).remove::<ShipyardQueue>();
+            log.push(format!(
+                "Station failed: {} queued ship construction(s) lost, payment refunded",
+                queue.jobs.len()
+            ));
+        }
+
+        if let Some(mut queue) = refinery_queue.filter(|queue| !queue.jobs.is_empty()) {
+            log.push(format!(
+                "Station failed: {} queued ore batch(es) lost!",
+                queue.jobs.len()
+            ));
+            queue.jobs.clear();
         }
     }
 }
@@ -344,10 +1110,68 @@ pub fn crisis_changed(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::stations::StationKind;
+    use crate::stations::{ActiveCrisis, RefineryJob, ShipyardJob, StationKind};
     use bevy::ecs::system::SystemState;
     use std::time::Duration;
 
+    #[test]
+    fn outposts_stay_up_and_open_flashpoints_on_schedule() {
+        use crate::plugins::sim::crises::open_crises;
+        use crate::world::SystemNode;
+
+        let mut world = World::default();
+        world.insert_resource(SimTickCount { tick: 10 });
+        world.insert_resource(Sector {
+            nodes: vec![SystemNode {
+                id: 1,
+                position: Vec2::ZERO,
+                modifier: None,
+            }],
+            routes: Vec::new(),
+        });
+        // Worldgen's Outposts carry no fuel at all
+        let outpost = world
+            .spawn((
+                Station {
+                    kind: StationKind::Outpost,
+                    state: StationState::Operational,
+                    fuel: 0.0,
+                    fuel_capacity: 0.0,
+                },
+                OutpostTrade::default(),
+                StationCrises::default(),
+                Faction::Independent,
+                ZoneId(1),
+                Transform::from_xyz(0.0, 0.0, 0.5),
+            ))
+            .id();
+        world.spawn((
+            Station {
+                kind: StationKind::MiningOutpost,
+                state: StationState::Operational,
+                fuel: 20.0,
+                fuel_capacity: 30.0,
+            },
+            StationCrises::default(),
+            Faction::Player,
+            ZoneId(1),
+            Transform::from_xyz(100.0, 0.0, 0.5),
+        ));
+
+        let mut schedule = Schedule::default();
+        schedule
+            .add_systems((station_lifecycle, outpost_political_flashpoint, open_crises).chain());
+        schedule.run(&mut world);
+
+        assert_eq!(
+            world.get::<Station>(outpost).unwrap().state,
+            StationState::Operational
+        );
+        let mut crises = world.query::<&Crisis>();
+        let flashpoint = crises.single(&world).expect("flashpoint opened");
+        assert_eq!(flashpoint.station, outpost);
+        assert_eq!(flashpoint.crisis_type, CrisisType::PoliticalFlashpoint);
+    }
     #[test]
     fn station_fuel_burn_skips_failed_state() {
         let mut world = World::default();
@@ -361,8 +1185,10 @@ mod tests {
             fuel_capacity: 30.0,
         });
 
-        let mut system_state: SystemState<(Res<Time<Fixed>>, Query<&mut Station>)> =
-            SystemState::new(&mut world);
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Query<(&mut Station, Option<&StationCrises>)>,
+        )> = SystemState::new(&mut world);
         let (time, stations) = system_state.get_mut(&mut world);
         station_fuel_burn(time, stations);
         system_state.apply(&mut world);
@@ -386,7 +1212,7 @@ mod tests {
 
         let mut system_state: SystemState<(
             Res<SimTickCount>,
-            Query<(&mut Station, Option<&StationBuild>, Option<&StationCrisis>)>,
+            Query<(&mut Station, Option<&StationBuild>, Option<&StationCrises>)>,
         )> = SystemState::new(&mut world);
         let (ticks, stations) = system_state.get_mut(&mut world);
         station_lifecycle(ticks, stations);
@@ -398,6 +1224,219 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unattended_station_drifts_until_visited() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(10.0));
+        time.advance_by(Duration::from_secs_f32(10.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+        world.insert_resource(EventLog::default());
+
+        let station = world
+            .spawn((
+                Station {
+                    kind: StationKind::MiningOutpost,
+                    state: StationState::Operational,
+                    fuel: 20.0,
+                    fuel_capacity: 30.0,
+                },
+                StationMaintenance {
+                    unvisited_seconds: DRIFT_STRAIN_SECONDS,
+                    misrouted_ore: 0.0,
+                },
+                StationCrises::default(),
+                Transform::default(),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Commands,
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            ResMut<EventLog>,
+            Query<(
+                &Transform,
+                &Station,
+                Option<&ZoneId>,
+                &mut StationMaintenance,
+                &mut StationCrises,
+                Option<&mut StationProduction>,
+            )>,
+            Query<&Transform, (Or<(With<PlayerControl>, With<Fleet>)>, Without<Station>)>,
+            Query<&mut Crisis>,
+        )> = SystemState::new(&mut world);
+        let (commands, time, sector, log, stations, visitors, crises) =
+            system_state.get_mut(&mut world);
+        station_automation_drift(commands, time, sector, log, stations, visitors, crises);
+
+        let pressure = world
+            .get::<StationCrises>(station)
+            .unwrap()
+            .pressure(CrisisType::AutomationDrift);
+        assert_eq!(pressure, Some(CrisisStage::Strained));
+
+        world.spawn((PlayerControl, Transform::from_xyz(10.0, 0.0, 0.0)));
+        let (commands, time, sector, log, stations, visitors, crises) =
+            system_state.get_mut(&mut world);
+        station_automation_drift(commands, time, sector, log, stations, visitors, crises);
+
+        assert_eq!(
+            world
+                .get::<StationMaintenance>(station)
+                .unwrap()
+                .unvisited_seconds,
+            0.0
+        );
+        assert!(world
+            .get::<StationCrises>(station)
+            .unwrap()
+            .pressure(CrisisType::AutomationDrift)
+            .is_none());
+        assert!(world.resource::<EventLog>().entries()[0].contains("automation reset"));
+    }
+
+    #[test]
+    fn drifting_station_jettisons_misrouted_ore_as_salvage() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(60.0));
+        time.advance_by(Duration::from_secs_f32(60.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+        world.insert_resource(EventLog::default());
+
+        let mut crises = StationCrises::default();
+        crises.track(CrisisType::AutomationDrift, tracked(CrisisStage::Failing));
+        let station = world
+            .spawn((
+                Station {
+                    kind: StationKind::Refinery,
+                    state: StationState::Failing,
+                    fuel: 20.0,
+                    fuel_capacity: 60.0,
+                },
+                StationMaintenance {
+                    unvisited_seconds: DRIFT_STRAIN_SECONDS,
+                    misrouted_ore: 0.0,
+                },
+                crises,
+                StationProduction::new(80.0).with_ore(30.0),
+                ZoneId(2),
+                Transform::default(),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Commands,
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            ResMut<EventLog>,
+            Query<(
+                &Transform,
+                &Station,
+                Option<&ZoneId>,
+                &mut StationMaintenance,
+                &mut StationCrises,
+                Option<&mut StationProduction>,
+            )>,
+            Query<&Transform, (Or<(With<PlayerControl>, With<Fleet>)>, Without<Station>)>,
+            Query<&mut Crisis>,
+        )> = SystemState::new(&mut world);
+        for _ in 0..2 {
+            let (commands, time, sector, log, stations, visitors, crises) =
+                system_state.get_mut(&mut world);
+            station_automation_drift(commands, time, sector, log, stations, visitors, crises);
+            system_state.apply(&mut world);
+        }
+
+        // Two minutes failing mis-routes 8 ore; none of it is lost
+        let mut wrecks = world.query::<(&Wreck, &ZoneId)>();
+        let (wreck, zone) = wrecks.single(&world).unwrap();
+        assert_eq!(wreck.ore, 8.0);
+        assert_eq!(zone.0, 2);
+        assert_eq!(world.get::<StationProduction>(station).unwrap().ore(), 22.0);
+        assert_eq!(
+            world
+                .get::<StationMaintenance>(station)
+                .unwrap()
+                .misrouted_ore,
+            0.0
+        );
+    }
+
+    #[test]
+    fn outposts_protest_nearby_expansion() {
+        let mut world = World::default();
+        world.insert_resource(Sector::default());
+        let outpost = world
+            .spawn((
+                Station {
+                    kind: StationKind::Outpost,
+                    state: StationState::Operational,
+                    fuel: 0.0,
+                    fuel_capacity: 0.0,
+                },
+                OutpostTrade::default(),
+                StationCrises::default(),
+                ZoneId(1),
+                Transform::default(),
+            ))
+            .id();
+        // Only the first two are the player's, in range and in the same zone
+        let sites = [
+            (100.0, 1, Faction::Player),
+            (200.0, 1, Faction::Player),
+            (900.0, 1, Faction::Player),
+            (150.0, 2, Faction::Player),
+            (120.0, 1, Faction::Independent),
+        ];
+        for (x, zone, faction) in sites {
+            world.spawn((
+                Station {
+                    kind: StationKind::MiningOutpost,
+                    state: StationState::Operational,
+                    fuel: 20.0,
+                    fuel_capacity: 30.0,
+                },
+                faction,
+                ZoneId(zone),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ));
+        }
+
+        let mut system_state: SystemState<(
+            Res<Sector>,
+            Query<(&Transform, &Station, &Faction, Option<&ZoneId>)>,
+            Query<(&Transform, Option<&ZoneId>, &mut StationCrises), With<OutpostTrade>>,
+        )> = SystemState::new(&mut world);
+        let (sector, stations, outposts) = system_state.get_mut(&mut world);
+        outpost_political_flashpoint(sector, stations, outposts);
+
+        let crises = world.get::<StationCrises>(outpost).unwrap();
+        assert_eq!(
+            crises.pressure(CrisisType::PoliticalFlashpoint),
+            Some(CrisisStage::Failing)
+        );
+
+        // With one of the two gone the protest eases to a strain
+        let mut query = world.query::<(Entity, &Transform, &Faction)>();
+        let near = query
+            .iter(&world)
+            .find(|(_, transform, faction)| {
+                transform.translation.x == 200.0 && **faction == Faction::Player
+            })
+            .map(|(entity, _, _)| entity)
+            .unwrap();
+        world.despawn(near);
+        let (sector, stations, outposts) = system_state.get_mut(&mut world);
+        outpost_political_flashpoint(sector, stations, outposts);
+        let crises = world.get::<StationCrises>(outpost).unwrap();
+        assert_eq!(
+            crises.pressure(CrisisType::PoliticalFlashpoint),
+            Some(CrisisStage::Strained)
+        );
+    }
+
     #[test]
     fn crisis_changed_detects_resolve() {
         let changed = crisis_changed(
@@ -423,10 +1462,7 @@ mod tests {
                 fuel: 20.0,
                 fuel_capacity: 30.0,
             },
-            StationProduction {
-                ore: 0.0,
-                ore_capacity: 60.0,
-            },
+            StationProduction::new(60.0),
         ));
 
         world.spawn((
@@ -436,10 +1472,7 @@ mod tests {
                 fuel: 20.0,
                 fuel_capacity: 30.0,
             },
-            StationProduction {
-                ore: 0.0,
-                ore_capacity: 60.0,
-            },
+            StationProduction::new(60.0),
         ));
 
         let mut system_state: SystemState<(
@@ -456,12 +1489,13 @@ mod tests {
             count += 1;
             if matches!(station.state, StationState::Operational) {
                 assert!(
-                    production.ore > 0.0,
+                    production.ore() > 0.0,
                     "Operational station should produce ore"
                 );
             } else {
                 assert_eq!(
-                    production.ore, 0.0,
+                    production.ore(),
+                    0.0,
                     "Non-operational station should not produce ore"
                 );
             }
@@ -483,10 +1517,7 @@ mod tests {
                 fuel: 20.0,
                 fuel_capacity: 30.0,
             },
-            StationProduction {
-                ore: 59.0,
-                ore_capacity: 60.0,
-            },
+            StationProduction::new(60.0).with_ore(59.0),
         ));
 
         let mut system_state: SystemState<(
@@ -499,10 +1530,31 @@ mod tests {
 
         let mut query = world.query::<&StationProduction>();
         for production in query.iter(&world) {
-            assert!(production.ore <= production.ore_capacity);
+            assert!(production.ore() <= production.ore_capacity());
         }
     }
 
+    fn pressured(crisis_type: CrisisType) -> StationCrises {
+        let mut crises = StationCrises::default();
+        crises.report(crisis_type, CrisisStage::Strained);
+        crises
+    }
+
+    fn tracked(stage: CrisisStage) -> ActiveCrisis {
+        ActiveCrisis {
+            entity: Entity::PLACEHOLDER,
+            stage,
+            elapsed_seconds: 0.0,
+            stage_seconds: 0.0,
+        }
+    }
+
+    fn strained_crises(crisis_type: CrisisType) -> StationCrises {
+        let mut crises = StationCrises::default();
+        crises.track(crisis_type, tracked(CrisisStage::Strained));
+        crises
+    }
+
     #[test]
     fn station_crisis_recovers_when_refueled() {
         let mut world = World::default();
@@ -515,38 +1567,68 @@ mod tests {
                     fuel: 5.0,
                     fuel_capacity: 30.0,
                 },
-                StationCrisis {
-                    crisis_type: CrisisType::FuelShortage,
-                    stage: CrisisStage::Strained,
-                },
+                pressured(CrisisType::FuelShortage),
             ))
             .id();
 
-        let mut system_state: SystemState<(
-            Commands,
-            Query<(Entity, &Station, Option<&StationCrisis>)>,
-        )> = SystemState::new(&mut world);
-        let (commands, stations) = system_state.get_mut(&mut world);
-        station_crisis_stub(commands, stations);
+        let mut system_state: SystemState<Query<(&Station, &mut StationCrises)>> =
+            SystemState::new(&mut world);
+        let stations = system_state.get_mut(&mut world);
+        station_crisis_stub(stations);
         system_state.apply(&mut world);
 
-        let has_crisis = world.get::<StationCrisis>(entity).is_some();
+        let has_crisis = world
+            .get::<StationCrises>(entity)
+            .unwrap()
+            .pressure(CrisisType::FuelShortage)
+            .is_some();
         assert!(has_crisis, "Station should still have crisis with low fuel");
 
         world.get_mut::<Station>(entity).unwrap().fuel = 20.0;
 
-        let mut system_state: SystemState<(
-            Commands,
-            Query<(Entity, &Station, Option<&StationCrisis>)>,
-        )> = SystemState::new(&mut world);
-        let (commands, stations) = system_state.get_mut(&mut world);
-        station_crisis_stub(commands, stations);
+        let stations = system_state.get_mut(&mut world);
+        station_crisis_stub(stations);
         system_state.apply(&mut world);
 
-        let has_crisis = world.get::<StationCrisis>(entity).is_some();
+        let has_crisis = world
+            .get::<StationCrises>(entity)
+            .unwrap()
+            .pressure(CrisisType::FuelShortage)
+            .is_some();
         assert!(!has_crisis, "Station should recover after refueling");
     }
 
+    #[test]
+    fn fuel_shortage_leaves_other_crises_alone() {
+        let mut world = World::default();
+
+        let entity = world
+            .spawn((
+                Station {
+                    kind: StationKind::MiningOutpost,
+                    state: StationState::Strained,
+                    fuel: 2.0,
+                    fuel_capacity: 30.0,
+                },
+                pressured(CrisisType::PirateHarassment),
+            ))
+            .id();
+
+        let mut system_state: SystemState<Query<(&Station, &mut StationCrises)>> =
+            SystemState::new(&mut world);
+        let stations = system_state.get_mut(&mut world);
+        station_crisis_stub(stations);
+        system_state.apply(&mut world);
+
+        let crises = world.get::<StationCrises>(entity).unwrap();
+        assert_eq!(crises.pressures().count(), 2);
+        assert_eq!(
+            crises.pressure(CrisisType::FuelShortage),
+            Some(CrisisStage::Failing)
+        );
+        assert!(crises.pressure(CrisisType::PirateHarassment).is_some());
+    }
+
     #[test]
     fn log_station_crisis_changes_logs_new_crisis() {
         let mut world = World::default();
@@ -559,16 +1641,13 @@ mod tests {
                 fuel: 5.0,
                 fuel_capacity: 30.0,
             },
-            StationCrisis {
-                crisis_type: CrisisType::FuelShortage,
-                stage: CrisisStage::Strained,
-            },
+            strained_crises(CrisisType::FuelShortage),
             StationCrisisLog::default(),
         ));
 
         let mut system_state: SystemState<(
             ResMut<EventLog>,
-            Query<(&Station, Option<&StationCrisis>, &mut StationCrisisLog)>,
+            Query<(&Station, Option<&StationCrises>, &mut StationCrisisLog)>,
         )> = SystemState::new(&mut world);
         let (log, stations) = system_state.get_mut(&mut world);
         log_station_crisis_changes(log, stations);
@@ -579,6 +1658,50 @@ mod tests {
         assert!(log.entries()[0].contains("crisis"));
     }
 
+    #[test]
+    fn concurrent_crises_do_not_repeat_in_the_log() {
+        let mut world = World::default();
+        world.insert_resource(EventLog::default());
+
+        let mut crises = strained_crises(CrisisType::FuelShortage);
+        crises.track(CrisisType::PirateHarassment, tracked(CrisisStage::Failing));
+        let entity = world
+            .spawn((
+                Station {
+                    kind: StationKind::MiningOutpost,
+                    state: StationState::Failing,
+                    fuel: 5.0,
+                    fuel_capacity: 30.0,
+                },
+                crises,
+                StationCrisisLog::default(),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            ResMut<EventLog>,
+            Query<(&Station, Option<&StationCrises>, &mut StationCrisisLog)>,
+        )> = SystemState::new(&mut world);
+        for _ in 0..3 {
+            let (log, stations) = system_state.get_mut(&mut world);
+            log_station_crisis_changes(log, stations);
+        }
+        assert_eq!(world.resource::<EventLog>().entries().len(), 2);
+
+        {
+            let mut crises = world.get_mut::<StationCrises>(entity).unwrap();
+            crises.clear_tracked();
+            crises.track(CrisisType::FuelShortage, tracked(CrisisStage::Stable));
+        }
+        let (log, stations) = system_state.get_mut(&mut world);
+        log_station_crisis_changes(log, stations);
+
+        // The closed crisis is left to the lifecycle; only the restage is new
+        let log = world.resource::<EventLog>();
+        assert_eq!(log.entries().len(), 3);
+        assert!(log.entries()[2].contains("Stable"));
+    }
+
     #[test]
     fn shipyard_job_pauses_when_strained() {
         let mut world = World::default();
@@ -594,11 +1717,7 @@ mod tests {
                 fuel: 5.0,
                 fuel_capacity: 50.0,
             },
-            super::ShipyardJob {
-                ore_in: 15,
-                fuel_in: 0.0,
-                remaining_seconds: 100.0,
-            },
+            queue_of(crate::ships::ShipKind::Scout),
             Transform::from_xyz(0.0, 0.0, 0.0),
             ZoneId(1),
         ));
@@ -607,50 +1726,158 @@ mod tests {
             Res<Time<Fixed>>,
             Commands,
             ResMut<EventLog>,
-            Query<(
-                Entity,
-                &Station,
-                &Transform,
-                &ZoneId,
-                &mut super::ShipyardJob,
-            )>,
+            ShipyardProgressQuery,
         )> = SystemState::new(&mut world);
         let (time, commands, log, stations) = system_state.get_mut(&mut world);
         super::shipyard_job_progress(time, commands, log, stations);
         system_state.apply(&mut world);
 
-        // Job should NOT have progressed (still 100 seconds)
-        let mut query = world.query::<&super::ShipyardJob>();
-        for job in query.iter(&world) {
+        // Job should NOT have progressed (still 120 seconds)
+        let mut query = world.query::<&super::ShipyardQueue>();
+        for queue in query.iter(&world) {
             assert!(
-                (job.remaining_seconds - 100.0).abs() < f32::EPSILON,
+                !queue.jobs[0].is_started(),
                 "Job should not progress when station is Strained"
             );
         }
     }
 
+    type ShipyardProgressQuery<'w, 's> = Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut Station,
+            &'static Transform,
+            &'static ZoneId,
+            &'static mut super::ShipyardQueue,
+            Option<&'static mut super::ShipyardStorage>,
+        ),
+    >;
+
+    fn queue_of(kind: crate::ships::ShipKind) -> super::ShipyardQueue {
+        let mut queue = super::ShipyardQueue::default();
+        queue.push(ShipyardJob::new(kind));
+        queue
+    }
+
     #[test]
-    fn refinery_job_pauses_when_failing() {
+    fn finished_ship_waits_in_hangar_then_launches_when_fuelled() {
+        use crate::ships::ShipKind;
         let mut world = World::default();
-        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(10.0));
-        time.advance_by(Duration::from_secs_f32(10.0));
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(200.0));
+        time.advance_by(Duration::from_secs_f32(200.0));
         world.insert_resource(time);
         world.insert_resource(EventLog::default());
 
+        let mut queue = queue_of(ShipKind::Miner);
+        queue.push(ShipyardJob::new(ShipKind::Scout));
+        let yard = world
+            .spawn((
+                Station {
+                    kind: StationKind::Shipyard,
+                    state: StationState::Operational,
+                    fuel: 5.0,
+                    fuel_capacity: 50.0,
+                },
+                queue,
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                ZoneId(1),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Commands,
+            ResMut<EventLog>,
+            ShipyardProgressQuery,
+        )> = SystemState::new(&mut world);
+        let (time, commands, log, stations) = system_state.get_mut(&mut world);
+        super::shipyard_job_progress(time, commands, log, stations);
+        system_state.apply(&mut world);
+
+        // Too little fuel to launch: the miner waits and the scout starts
+        let storage = world.get::<super::ShipyardStorage>(yard).unwrap();
+        assert_eq!(storage.hangar, vec![ShipKind::Miner]);
+        let queue = world.get::<super::ShipyardQueue>(yard).unwrap();
+        assert_eq!(
+            queue.jobs.front().map(|job| job.kind),
+            Some(ShipKind::Scout)
+        );
+        assert_eq!(world.query::<&Ship>().iter(&world).count(), 0);
+
+        world.get_mut::<Station>(yard).unwrap().fuel = 50.0;
+        let (time, commands, log, stations) = system_state.get_mut(&mut world);
+        super::shipyard_job_progress(time, commands, log, stations);
+        system_state.apply(&mut world);
+
+        let ships: Vec<ShipKind> = world
+            .query::<&Ship>()
+            .iter(&world)
+            .map(|s| s.kind)
+            .collect();
+        assert_eq!(ships, vec![ShipKind::Miner]);
+        assert!(world.get::<Station>(yard).unwrap().fuel < 50.0);
+    }
+
+    #[test]
+    fn security_ships_launch_with_a_post_at_their_yard() {
+        use crate::ships::ShipKind;
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(200.0));
+        time.advance_by(Duration::from_secs_f32(200.0));
+        world.insert_resource(time);
+        world.insert_resource(EventLog::default());
         world.spawn((
             Station {
-                kind: StationKind::Refinery,
-                state: StationState::Failing,
-                fuel: 2.0,
-                fuel_capacity: 60.0,
-            },
-            super::RefineryJob {
-                ore_in: 5,
-                fuel_out: 10.0,
-                remaining_seconds: 50.0,
+                kind: StationKind::Shipyard,
+                state: StationState::Operational,
+                fuel: 50.0,
+                fuel_capacity: 50.0,
             },
+            queue_of(ShipKind::Security),
+            Transform::from_xyz(40.0, -20.0, 0.0),
+            ZoneId(1),
         ));
 
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Commands,
+            ResMut<EventLog>,
+            ShipyardProgressQuery,
+        )> = SystemState::new(&mut world);
+        let (time, commands, log, stations) = system_state.get_mut(&mut world);
+        super::shipyard_job_progress(time, commands, log, stations);
+        system_state.apply(&mut world);
+
+        let mut launched = world.query::<(&Ship, &PatrolOrder)>();
+        let (ship, order) = launched.single(&world).expect("security ship launched");
+        assert_eq!(ship.kind, ShipKind::Security);
+        assert_eq!(order.post, Vec2::new(40.0, -20.0));
+    }
+
+    #[test]
+    fn refinery_slows_when_failing() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(60.0));
+        time.advance_by(Duration::from_secs_f32(60.0));
+        world.insert_resource(time);
+        world.insert_resource(EventLog::default());
+
+        let mut queue = super::RefineryQueue::default();
+        queue.push(RefineryJob::new(5));
+        let refinery = world
+            .spawn((
+                Station {
+                    kind: StationKind::Refinery,
+                    state: StationState::Failing,
+                    fuel: 2.0,
+                    fuel_capacity: 60.0,
+                },
+                queue,
+            ))
+            .id();
+
         let mut system_state: SystemState<(
             Res<Time<Fixed>>,
             Commands,
@@ -658,28 +1885,35 @@ mod tests {
             Query<(
                 Entity,
                 &Station,
-                &mut super::RefineryJob,
+                Option<&mut super::RefineryQueue>,
+                Option<&mut StationProduction>,
                 Option<&mut super::RefineryStorage>,
             )>,
         )> = SystemState::new(&mut world);
         let (time, commands, log, stations) = system_state.get_mut(&mut world);
-        super::refinery_job_progress(time, commands, log, stations);
+        super::refinery_processing(time, commands, log, stations);
         system_state.apply(&mut world);
 
-        // Job should NOT have progressed
-        let mut query = world.query::<&super::RefineryJob>();
-        for job in query.iter(&world) {
-            assert!(
-                (job.remaining_seconds - 50.0).abs() < f32::EPSILON,
-                "Job should not progress when station is Failing"
-            );
-        }
+        // A failing refinery runs at a quarter of its 6 ore a minute
+        let queue = world.get::<super::RefineryQueue>(refinery).unwrap();
+        assert!((queue.jobs[0].ore_remaining - 3.5).abs() < 1e-4);
+        let storage = world.get::<super::RefineryStorage>(refinery).unwrap();
+        assert!((storage.fuel - 3.0).abs() < 1e-4);
     }
 
     #[test]
-    fn job_removed_when_station_fails() {
+    fn job_removed_and_refunded_when_station_fails() {
         let mut world = World::default();
         world.insert_resource(EventLog::default());
+        world.insert_resource(SimTickCount { tick: 40 });
+        world.insert_resource(Ledger::default());
+        let player = world
+            .spawn((
+                Cargo::with_capacity(200),
+                Credits { amount: 10 },
+                PlayerControl,
+            ))
+            .id();
 
         let entity = world
             .spawn((
@@ -689,34 +1923,460 @@ mod tests {
                     fuel: 0.0,
                     fuel_capacity: 50.0,
                 },
-                super::ShipyardJob {
-                    ore_in: 15,
-                    fuel_in: 0.0,
-                    remaining_seconds: 50.0,
-                },
+                queue_of(crate::ships::ShipKind::Security),
             ))
             .id();
 
         let mut system_state: SystemState<(
             Commands,
+            Res<SimTickCount>,
             ResMut<EventLog>,
+            ResMut<Ledger>,
+            Query<(&mut Cargo, &mut Credits), With<PlayerControl>>,
             Query<(
                 Entity,
                 &Station,
-                Option<&super::ShipyardJob>,
-                Option<&super::RefineryJob>,
+                Option<&Name>,
+                Option<&super::ShipyardQueue>,
+                Option<&mut super::RefineryQueue>,
             )>,
         )> = SystemState::new(&mut world);
-        let (commands, log, stations) = system_state.get_mut(&mut world);
-        super::station_job_loss_on_fail(commands, log, stations);
+        let (commands, ticks, log, ledger, player_query, stations) =
+            system_state.get_mut(&mut world);
+        super::station_job_loss_on_fail(commands, ticks, log, ledger, player_query, stations);
         system_state.apply(&mut world);
 
         // Job should be removed
-        let has_job = world.get::<super::ShipyardJob>(entity).is_some();
+        let has_job = world.get::<super::ShipyardQueue>(entity).is_some();
         assert!(!has_job, "Job should be removed when station fails");
 
+        // The hull was paid for up front, so the player gets it all back
+        let cost = crate::stations::ship_build_cost(crate::ships::ShipKind::Security);
+        assert_eq!(world.get::<Cargo>(player).unwrap().ore(), cost.ore);
+        assert_eq!(
+            world.get::<Credits>(player).unwrap().amount,
+            10 + cost.credits
+        );
+        let entry = world.resource::<Ledger>().entries.last().cloned().unwrap();
+        assert_eq!(entry.category, LedgerCategory::Refund);
+        assert_eq!(entry.credits, cost.credits as i64);
+        assert_eq!(entry.ore, cost.ore as f32);
+
         // Should have logged the loss
         let log = world.resource::<EventLog>();
         assert!(log.entries().iter().any(|e| e.contains("lost")));
     }
+
+    #[test]
+    fn depot_refuels_nearby_fleet_ships_above_its_reserve() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(10.0));
+        time.advance_by(Duration::from_secs_f32(10.0));
+        world.insert_resource(time);
+        let depot = world
+            .spawn((
+                Station {
+                    kind: StationKind::FuelDepot,
+                    state: StationState::Operational,
+                    fuel: 40.0,
+                    fuel_capacity: 120.0,
+                },
+                FuelDepotPolicy::default(),
+                Transform::default(),
+            ))
+            .id();
+        let near = world
+            .spawn((
+                Ship {
+                    kind: ShipKind::Miner,
+                    state: ShipState::Idle,
+                    fuel: 0.0,
+                    fuel_capacity: 40.0,
+                },
+                Fleet {
+                    role: crate::ships::FleetRole::Mining,
+                },
+                Transform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+        let far = world
+            .spawn((
+                Ship {
+                    kind: ShipKind::Scout,
+                    state: ShipState::Idle,
+                    fuel: 0.0,
+                    fuel_capacity: 40.0,
+                },
+                Fleet {
+                    role: crate::ships::FleetRole::Scout,
+                },
+                Transform::from_xyz(200.0, 0.0, 0.0),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Query<(&Transform, &mut Station, &FuelDepotPolicy)>,
+            Query<(&Transform, &mut Ship), (With<Fleet>, Without<PlayerControl>)>,
+        )> = SystemState::new(&mut world);
+        let (time, depots, ships) = system_state.get_mut(&mut world);
+        depot_refuel_ships(time, depots, ships);
+
+        // 20 fuel pumped over 10s, but only 10 sits above the 25% reserve
+        assert_eq!(world.get::<Ship>(near).unwrap().fuel, 10.0);
+        assert_eq!(world.get::<Ship>(far).unwrap().fuel, 0.0);
+        assert_eq!(world.get::<Station>(depot).unwrap().fuel, 30.0);
+    }
+
+    #[test]
+    fn refinery_ships_stored_fuel_to_nearest_depot_in_its_zone() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(60.0));
+        time.advance_by(Duration::from_secs_f32(60.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+        let refinery = world
+            .spawn((
+                Station {
+                    kind: StationKind::Refinery,
+                    state: StationState::Operational,
+                    fuel: 30.0,
+                    fuel_capacity: 60.0,
+                },
+                RefineryStorage {
+                    fuel: 20.0,
+                    fuel_capacity: 50.0,
+                },
+                ZoneId(1),
+                Transform::default(),
+            ))
+            .id();
+        let mut depot_at = |zone: u32, x: f32| {
+            world
+                .spawn((
+                    Station {
+                        kind: StationKind::FuelDepot,
+                        state: StationState::Operational,
+                        fuel: 50.0,
+                        fuel_capacity: 120.0,
+                    },
+                    FuelDepotPolicy::default(),
+                    ZoneId(zone),
+                    Transform::from_xyz(x, 0.0, 0.0),
+                ))
+                .id()
+        };
+        let other_zone = depot_at(2, 50.0);
+        let near = depot_at(1, 100.0);
+        let far = depot_at(1, 300.0);
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            Query<(&Transform, &Station, Option<&ZoneId>, &mut RefineryStorage)>,
+            Query<
+                (Entity, &Transform, &mut Station, Option<&ZoneId>),
+                (With<FuelDepotPolicy>, Without<RefineryStorage>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (time, sector, refineries, depots) = system_state.get_mut(&mut world);
+        refinery_supply_depots(time, sector, refineries, depots);
+
+        let shipped = REFINERY_DEPOT_DELIVERY_PER_MINUTE;
+        assert_eq!(
+            world.get::<RefineryStorage>(refinery).unwrap().fuel,
+            20.0 - shipped
+        );
+        assert_eq!(world.get::<Station>(other_zone).unwrap().fuel, 50.0);
+        assert_eq!(world.get::<Station>(near).unwrap().fuel, 50.0 + shipped);
+        assert_eq!(world.get::<Station>(far).unwrap().fuel, 50.0);
+    }
+
+    #[test]
+    fn low_fuel_ships_head_for_a_depot_in_their_zone() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(1.0));
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+
+        let mut depot_at = |zone: u32, x: f32| {
+            world.spawn((
+                Station {
+                    kind: StationKind::FuelDepot,
+                    state: StationState::Operational,
+                    fuel: 50.0,
+                    fuel_capacity: 120.0,
+                },
+                FuelDepotPolicy::default(),
+                ZoneId(zone),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ));
+        };
+        depot_at(2, -50.0);
+        depot_at(1, 200.0);
+
+        let mut ship_with = |fuel: f32| {
+            world
+                .spawn((
+                    Ship {
+                        kind: ShipKind::Miner,
+                        state: ShipState::Returning,
+                        fuel,
+                        fuel_capacity: 40.0,
+                    },
+                    Fleet {
+                        role: FleetRole::Mining,
+                    },
+                    ZoneId(1),
+                    Transform::default(),
+                ))
+                .id()
+        };
+        let returning = ship_with(3.0);
+        let refuelled = ship_with(40.0);
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            Query<(Entity, &Transform, &Station, Option<&ZoneId>), With<FuelDepotPolicy>>,
+            Query<
+                (&mut Ship, &mut Transform, Option<&ZoneId>),
+                (With<Fleet>, Without<PlayerControl>, Without<Station>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (time, sector, depots, ships) = system_state.get_mut(&mut world);
+        route_low_fuel_ships(time, sector, depots, ships);
+
+        // Past the closer depot in the next zone, towards the one in its own
+        let transform = world.get::<Transform>(returning).unwrap();
+        assert_eq!(transform.translation.x, DEPOT_RETURN_SPEED);
+        assert_eq!(
+            world.get::<Ship>(returning).unwrap().state,
+            ShipState::Returning
+        );
+        assert_eq!(world.get::<Ship>(refuelled).unwrap().state, ShipState::Idle);
+    }
+
+    fn ore_station(world: &mut World, kind: StationKind, zone: u32, x: f32, ore: f32) -> Entity {
+        world
+            .spawn((
+                Station {
+                    kind,
+                    state: StationState::Operational,
+                    fuel: 20.0,
+                    fuel_capacity: 60.0,
+                },
+                StationProduction::new(80.0).with_ore(ore),
+                ZoneId(zone),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn refinery_pulls_ore_from_mining_outposts_in_its_zone() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(60.0));
+        time.advance_by(Duration::from_secs_f32(60.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+        let refinery = ore_station(&mut world, StationKind::Refinery, 1, 0.0, 0.0);
+        let local = ore_station(&mut world, StationKind::MiningOutpost, 1, 200.0, 10.0);
+        let remote = ore_station(&mut world, StationKind::MiningOutpost, 2, 50.0, 10.0);
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            Query<(
+                Entity,
+                &Transform,
+                &Station,
+                Option<&ZoneId>,
+                &mut StationProduction,
+            )>,
+        )> = SystemState::new(&mut world);
+        let (time, sector, stations) = system_state.get_mut(&mut world);
+        station_pull_zone_ore(time, sector, stations);
+
+        let ore = |entity: Entity| world.get::<StationProduction>(entity).unwrap().ore();
+        assert_eq!(ore(refinery), ZONE_ORE_PULL_PER_MINUTE);
+        assert_eq!(ore(local), 10.0 - ZONE_ORE_PULL_PER_MINUTE);
+        assert_eq!(ore(remote), 10.0);
+    }
+
+    #[test]
+    fn zone_stations_supply_construction_sites() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(60.0));
+        time.advance_by(Duration::from_secs_f32(60.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+        let outpost = ore_station(&mut world, StationKind::MiningOutpost, 1, 200.0, 10.0);
+        let depot = world
+            .spawn((
+                Station {
+                    kind: StationKind::FuelDepot,
+                    state: StationState::Operational,
+                    fuel: 32.0,
+                    fuel_capacity: 120.0,
+                },
+                FuelDepotPolicy::default(),
+                ZoneId(1),
+                Transform::from_xyz(-100.0, 0.0, 0.0),
+            ))
+            .id();
+        let site = world
+            .spawn((
+                ConstructionSite::new(StationKind::Refinery),
+                ZoneId(1),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            Query<(&Transform, Option<&ZoneId>, &mut ConstructionSite)>,
+            Query<
+                (&Transform, &mut Station, &FuelDepotPolicy, Option<&ZoneId>),
+                Without<ConstructionSite>,
+            >,
+            Query<
+                (
+                    &Transform,
+                    &Station,
+                    &mut StationProduction,
+                    Option<&ZoneId>,
+                ),
+                (Without<ConstructionSite>, Without<FuelDepotPolicy>),
+            >,
+        )> = SystemState::new(&mut world);
+        let (time, sector, sites, depots, outposts) = system_state.get_mut(&mut world);
+        supply_construction_sites(time, sector, sites, depots, outposts);
+
+        // A minute's supply of ore, but the depot only parts with fuel above its reserve
+        let build = world.get::<ConstructionSite>(site).unwrap();
+        assert_eq!(build.ore.stock, 7.0 + 6.0);
+        assert_eq!(build.fuel.stock, 7.5 + 2.0);
+        assert_eq!(world.get::<Station>(depot).unwrap().fuel, 30.0);
+        assert_eq!(world.get::<StationProduction>(outpost).unwrap().ore(), 4.0);
+    }
+
+    #[test]
+    fn mining_ships_haul_outpost_output_to_construction_and_the_refinery() {
+        let mut world = World::default();
+        let mut time = Time::<Fixed>::from_duration(Duration::from_secs_f32(1.0));
+        time.advance_by(Duration::from_secs_f32(1.0));
+        world.insert_resource(time);
+        world.insert_resource(Sector::default());
+        let outpost = ore_station(&mut world, StationKind::MiningOutpost, 1, 0.0, 12.5);
+        let refinery = ore_station(&mut world, StationKind::Refinery, 1, 20.0, 75.0);
+        let hauler = world
+            .spawn((
+                Ship {
+                    kind: ShipKind::Miner,
+                    state: ShipState::Idle,
+                    fuel: 20.0,
+                    fuel_capacity: 40.0,
+                },
+                Cargo::default(),
+                Fleet {
+                    role: FleetRole::Mining,
+                },
+                ZoneId(1),
+                Transform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+
+        let mut system_state: SystemState<(
+            Res<Time<Fixed>>,
+            Res<Sector>,
+            Query<(
+                Entity,
+                &Transform,
+                &Station,
+                Option<&ZoneId>,
+                &mut StationProduction,
+            )>,
+            Query<(
+                Entity,
+                &Transform,
+                &Station,
+                Option<&ZoneId>,
+                &mut ConstructionSite,
+            )>,
+            Query<
+                (
+                    &mut Ship,
+                    &mut Cargo,
+                    &mut Transform,
+                    &Fleet,
+                    &mut MinerTask,
+                    &ZoneId,
+                ),
+                (Without<PlayerControl>, Without<Station>),
+            >,
+        )> = SystemState::new(&mut world);
+
+        // First pass loads the whole units the outpost holds
+        let (time, sector, stations, builds, haulers) = system_state.get_mut(&mut world);
+        haul_station_output(time, sector, stations, builds, haulers);
+        assert_eq!(world.get::<Cargo>(hauler).unwrap().ore(), 12);
+        assert_eq!(world.get::<StationProduction>(outpost).unwrap().ore(), 0.5);
+        assert_eq!(*world.get::<MinerTask>(hauler).unwrap(), MinerTask::Hauling);
+
+        // With the outpost emptied it unloads what fits at the refinery
+        let (time, sector, stations, builds, haulers) = system_state.get_mut(&mut world);
+        haul_station_output(time, sector, stations, builds, haulers);
+        assert_eq!(world.get::<Cargo>(hauler).unwrap().ore(), 7);
+        assert_eq!(
+            world.get::<StationProduction>(refinery).unwrap().ore(),
+            80.0
+        );
+
+        // A construction site short of ore comes before any refinery
+        world
+            .get_mut::<StationProduction>(refinery)
+            .unwrap()
+            .withdraw(80.0);
+        let mut build = ConstructionSite::new(StationKind::SensorStation);
+        build.ore.stock = 10.0;
+        let site = world
+            .spawn((
+                Station {
+                    kind: StationKind::SensorStation,
+                    state: StationState::Deploying,
+                    fuel: 0.0,
+                    fuel_capacity: 40.0,
+                },
+                build,
+                ZoneId(1),
+                Transform::from_xyz(25.0, 0.0, 0.0),
+            ))
+            .id();
+        let (time, sector, stations, builds, haulers) = system_state.get_mut(&mut world);
+        haul_station_output(time, sector, stations, builds, haulers);
+        assert_eq!(world.get::<Cargo>(hauler).unwrap().ore(), 2);
+        assert_eq!(world.get::<ConstructionSite>(site).unwrap().ore.stock, 15.0);
+        assert_eq!(world.get::<StationProduction>(refinery).unwrap().ore(), 0.0);
+
+        // Unloading the last of the hold frees the ship for other work
+        let (time, sector, stations, builds, haulers) = system_state.get_mut(&mut world);
+        haul_station_output(time, sector, stations, builds, haulers);
+        assert_eq!(world.get::<Cargo>(hauler).unwrap().ore(), 0);
+        assert_eq!(*world.get::<MinerTask>(hauler).unwrap(), MinerTask::Idle);
+
+        // A ship salvaging a wreck is not pulled away to haul
+        world
+            .get_mut::<StationProduction>(outpost)
+            .unwrap()
+            .deposit(10.0);
+        *world.get_mut::<MinerTask>(hauler).unwrap() = MinerTask::Salvaging;
+        let before = world.get::<Transform>(hauler).unwrap().translation;
+        let (time, sector, stations, builds, haulers) = system_state.get_mut(&mut world);
+        haul_station_output(time, sector, stations, builds, haulers);
+        assert_eq!(world.get::<Transform>(hauler).unwrap().translation, before);
+        assert_eq!(world.get::<Cargo>(hauler).unwrap().ore(), 0);
+    }
 }
@@ -0,0 +1,358 @@
+//! Boarding menu UI systems for disabled pirate ships.
+
+use bevy::prelude::*;
+use bevy::ui::Node as UiNode;
+use std::path::Path;
+
+use crate::combat::{captured_hull, ship_shields, Hull};
+use crate::compat::{NodeBundle, SpatialBundle, TextBundle, TextStyle};
+use crate::factions::Faction;
+use crate::fleets::PatrolOrder;
+use crate::ledger::{Ledger, LedgerCategory, LedgerEntry};
+use crate::pirates::{
+    scrap_yield, Interrogated, PirateBase, PirateControl, PirateLoot, PirateLossCause,
+    PirateMemory, PirateShip, KILL_CONTROL_LOSS,
+};
+use crate::plugins::core::EventLog;
+use crate::plugins::player::{DockingState, PlayerControl};
+use crate::plugins::sim::{reveal_threat_intel, SimTickCount};
+use crate::ships::{
+    cargo_capacity, ship_default_role, ship_fuel_capacity, Captured, Cargo, Fleet, Ship,
+    ShipFuelAlert, ShipKind, ShipState,
+};
+use crate::stations::Station;
+use crate::world::{Identified, SystemIntel, SystemNode, ZoneId};
+
+use super::components::{
+    BoardingCaptureButton, BoardingInterrogateButton, BoardingLeaveButton, BoardingMenuRoot,
+    BoardingMenuStatus, BoardingScrapButton,
+};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Share of a full tank a captured raider is left with, on top of stolen fuel
+const CAPTURED_FUEL_FRACTION: f32 = 0.25;
+
+// =============================================================================
+// Setup Systems
+// =============================================================================
+
+pub fn setup_boarding_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font_path = "fonts/SpaceMono-Regular.ttf";
+    let font_on_disk = Path::new("assets").join(font_path);
+
+    if !font_on_disk.exists() {
+        return;
+    }
+
+    let font = asset_server.load(font_path);
+    let button_style = |color: Color| TextStyle {
+        font: font.clone(),
+        font_size: 13.0,
+        color,
+    };
+    let button_margin = UiNode {
+        margin: UiRect::vertical(Val::Px(2.0)),
+        ..default()
+    };
+
+    // Boarding menu panel (centered, initially hidden)
+    commands
+        .spawn((
+            BoardingMenuRoot,
+            NodeBundle {
+                node: UiNode {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(50.0),
+                    top: Val::Percent(50.0),
+                    margin: UiRect {
+                        left: Val::Px(-160.0),
+                        top: Val::Px(-120.0),
+                        ..default()
+                    },
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    min_width: Val::Px(320.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.08, 0.03, 0.03, 0.95).into(),
+                border_color: Color::srgb(0.9, 0.4, 0.3).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "BOARDED RAIDER",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    color: Color::srgb(1.0, 0.5, 0.4),
+                },
+            ));
+
+            parent.spawn((
+                BoardingMenuStatus,
+                TextBundle::from_section(
+                    "Hull: 0%",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color: Color::srgb(0.6, 0.7, 0.8),
+                    },
+                ),
+            ));
+
+            // Divider
+            parent.spawn(NodeBundle {
+                node: UiNode {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(1.0),
+                    margin: UiRect::vertical(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: Color::srgb(0.4, 0.15, 0.1).into(),
+                ..default()
+            });
+
+            parent.spawn((
+                BoardingCaptureButton,
+                Interaction::None,
+                TextBundle::from_section(
+                    "Capture (joins fleet as Security)",
+                    button_style(Color::srgb(0.4, 0.9, 0.4)),
+                )
+                .with_node(button_margin.clone()),
+            ));
+
+            parent.spawn((
+                BoardingScrapButton,
+                Interaction::None,
+                TextBundle::from_section("Scrap for ore", button_style(Color::srgb(0.9, 0.7, 0.3)))
+                    .with_node(button_margin.clone()),
+            ));
+
+            parent.spawn((
+                BoardingInterrogateButton,
+                Interaction::None,
+                TextBundle::from_section(
+                    "Interrogate crew",
+                    button_style(Color::srgb(0.5, 0.8, 1.0)),
+                )
+                .with_node(button_margin.clone()),
+            ));
+
+            parent.spawn((
+                BoardingLeaveButton,
+                Interaction::None,
+                TextBundle::from_section("[LEAVE]", button_style(Color::srgb(0.8, 0.6, 0.4)))
+                    .with_node(button_margin.clone()),
+            ));
+        });
+}
+
+// =============================================================================
+// Update Systems
+// =============================================================================
+
+/// Show the boarding menu while docked with a raider and describe what is aboard
+#[allow(clippy::type_complexity)]
+pub fn update_boarding_menu(
+    mut docking: ResMut<DockingState>,
+    pirates: Query<(&Hull, Option<&PirateLoot>, Has<Interrogated>), With<PirateShip>>,
+    docking_targets: Query<(), Or<(With<Station>, With<SystemNode>)>>,
+    mut menu: Query<&mut Visibility, With<BoardingMenuRoot>>,
+    mut status_text: Query<&mut Text, With<BoardingMenuStatus>>,
+    mut interrogate_btn: Query<
+        &mut Visibility,
+        (With<BoardingInterrogateButton>, Without<BoardingMenuRoot>),
+    >,
+) {
+    let Ok(mut visibility) = menu.single_mut() else {
+        return;
+    };
+
+    let boarded = docking
+        .docked_at
+        .and_then(|entity| pirates.get(entity).ok());
+
+    let Some((hull, loot, interrogated)) = boarded else {
+        *visibility = Visibility::Hidden;
+        // The raider was destroyed while we were aboard
+        if docking
+            .docked_at
+            .is_some_and(|entity| docking_targets.get(entity).is_err())
+        {
+            docking.undock();
+        }
+        return;
+    };
+
+    *visibility = Visibility::Visible;
+
+    if let Ok(mut text) = status_text.single_mut() {
+        let loot = loot.copied().unwrap_or_default();
+        text.0 = format!(
+            "Hull: {:.0}%\nHold: {:.0} ore, {:.0} fuel\nScrap value: {} ore",
+            hull.ratio() * 100.0,
+            loot.ore,
+            loot.fuel,
+            scrap_yield(Some(&loot))
+        );
+    }
+
+    if let Ok(mut vis) = interrogate_btn.single_mut() {
+        *vis = if interrogated {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
+
+/// Capture, scrap or interrogate the boarded raider
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_boarding_clicks(
+    mut commands: Commands,
+    mut docking: ResMut<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut control: ResMut<PirateControl>,
+    mut memory: ResMut<PirateMemory>,
+    mut ledger: ResMut<Ledger>,
+    mut player_query: Query<&mut Cargo, With<PlayerControl>>,
+    pirates: Query<
+        (
+            &Transform,
+            Option<&ZoneId>,
+            Option<&PirateLoot>,
+            Has<Interrogated>,
+        ),
+        With<PirateShip>,
+    >,
+    bases: Query<(Entity, &Transform, &ZoneId), With<PirateBase>>,
+    mut nodes: Query<(&SystemNode, &mut SystemIntel)>,
+    capture_btn: Query<&Interaction, (With<BoardingCaptureButton>, Changed<Interaction>)>,
+    scrap_btn: Query<&Interaction, (With<BoardingScrapButton>, Changed<Interaction>)>,
+    interrogate_btn: Query<&Interaction, (With<BoardingInterrogateButton>, Changed<Interaction>)>,
+    leave_btn: Query<&Interaction, (With<BoardingLeaveButton>, Changed<Interaction>)>,
+) {
+    let Some(pirate_entity) = docking.docked_at else {
+        return;
+    };
+
+    let Ok((transform, zone, loot, interrogated)) = pirates.get(pirate_entity) else {
+        return;
+    };
+
+    let pressed = |interaction: &Interaction| matches!(interaction, Interaction::Pressed);
+
+    if leave_btn.iter().any(pressed) {
+        docking.undock();
+        return;
+    }
+
+    if interrogate_btn.iter().any(pressed) && !interrogated {
+        commands.entity(pirate_entity).insert(Interrogated);
+
+        let pirate_pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let mut closest: Option<(Entity, u32, f32)> = None;
+        for (entity, base_transform, base_zone) in bases.iter() {
+            let pos = Vec2::new(base_transform.translation.x, base_transform.translation.y);
+            let dist = pos.distance(pirate_pos);
+            if closest.is_none_or(|(_, _, best)| dist < best) {
+                closest = Some((entity, base_zone.0, dist));
+            }
+        }
+
+        let Some((base_entity, base_zone, _)) = closest else {
+            log.push("Interrogation: the crew knows of no base".to_string());
+            return;
+        };
+
+        commands.entity(base_entity).insert(Identified);
+        for (node, mut intel) in nodes.iter_mut() {
+            if node.id == base_zone {
+                reveal_threat_intel(&mut intel, ticks.tick);
+            }
+        }
+        log.push(format!(
+            "Interrogation: the crew gave up a pirate base in zone {}",
+            base_zone
+        ));
+        return;
+    }
+
+    let capture = capture_btn.iter().any(pressed);
+    let scrap = scrap_btn.iter().any(pressed);
+    if !capture && !scrap {
+        return;
+    }
+
+    // Either way the raider is lost to the pirates
+    if let Some(zone) = zone {
+        memory.record_loss(zone.0, PirateLossCause::Player);
+        control.adjust(zone.0, -KILL_CONTROL_LOSS);
+    }
+    commands.entity(pirate_entity).despawn();
+    docking.undock();
+
+    if capture {
+        let fuel_capacity = ship_fuel_capacity(ShipKind::Security);
+        let base_fuel = fuel_capacity * CAPTURED_FUEL_FRACTION;
+        let fuel = (base_fuel + loot.map_or(0.0, |loot| loot.fuel)).min(fuel_capacity);
+        let mut captured = commands.spawn((
+            Ship {
+                kind: ShipKind::Security,
+                state: ShipState::Idle,
+                fuel,
+                fuel_capacity,
+            },
+            Cargo::with_capacity(cargo_capacity(ShipKind::Security) as u32),
+            Fleet {
+                role: ship_default_role(ShipKind::Security),
+            },
+            Faction::Player,
+            Captured,
+            // It guards the spot where it was taken until told otherwise
+            PatrolOrder {
+                post: Vec2::new(transform.translation.x, transform.translation.y),
+            },
+            captured_hull(),
+            ship_shields(ShipKind::Security),
+            ShipFuelAlert::default(),
+            Name::new("Captured Raider"),
+            SpatialBundle::from_transform(*transform),
+        ));
+        if let Some(zone) = zone {
+            captured.insert(ZoneId(zone.0));
+        }
+        // Stolen ore goes to the player's hold, stolen fuel into the prize's tank
+        let ore = loot.map_or(0, |loot| loot.ore.floor() as u32);
+        let added = player_query
+            .single_mut()
+            .map_or(0, |mut cargo| cargo.add_ore(ore));
+        ledger.record(
+            LedgerEntry::new(ticks.tick, LedgerCategory::Bounty, "Raider")
+                .with_ore(added as f32)
+                .with_fuel(fuel - base_fuel),
+        );
+        log.push("Raider captured and pressed into service".to_string());
+        if added > 0 {
+            log.push(format!("Recovered {} ore from the raider's hold", added));
+        }
+    } else {
+        let ore = scrap_yield(loot);
+        let added = player_query
+            .single_mut()
+            .map_or(0, |mut cargo| cargo.add_ore(ore));
+        ledger.record(
+            LedgerEntry::new(ticks.tick, LedgerCategory::Bounty, "Raider").with_ore(added as f32),
+        );
+        log.push(format!("Raider scrapped for {} ore", added));
+    }
+}
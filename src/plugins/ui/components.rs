@@ -2,6 +2,8 @@
 
 use bevy::prelude::*;
 
+use crate::ships::ShipKind;
+use crate::stations::StationKind;
 use crate::world::{KnowledgeLayer, ZoneModifier};
 
 // =============================================================================
@@ -150,9 +152,19 @@ pub struct DockingMenuJobText;
 #[derive(Component)]
 pub struct DockingMenuCancelButton;
 
-/// Build Scout button (Shipyard)
+/// Container for the build buttons and launch orders (Shipyard)
 #[derive(Component)]
-pub struct DockingMenuBuildScoutButton;
+pub struct DockingMenuShipyardSection;
+
+/// Queue a hull for construction (Shipyard)
+#[derive(Component)]
+pub struct DockingMenuBuildShipButton {
+    pub kind: ShipKind,
+}
+
+/// Cycle the risk tolerance launched scouts start with (Shipyard)
+#[derive(Component)]
+pub struct DockingMenuScoutOrdersButton;
 
 /// Convert ore options (Refinery)
 #[derive(Component)]
@@ -160,6 +172,10 @@ pub struct DockingMenuConvertButton {
     pub ore_amount: u32,
 }
 
+/// Cycle the stock level a refinery refines its input hold up to
+#[derive(Component)]
+pub struct DockingMenuRefineryTargetButton;
+
 /// Container for collect section (Refinery)
 #[derive(Component)]
 pub struct DockingMenuCollectSection;
@@ -184,24 +200,164 @@ pub struct DockingMenuOutpostSection;
 #[derive(Component)]
 pub struct DockingMenuBuyFuelButton {
     pub fuel_amount: u32,
-    pub credit_cost: u32,
 }
 
 /// Sell ore button (Outpost)
 #[derive(Component)]
 pub struct DockingMenuSellOreButton {
     pub ore_amount: u32,
-    pub credit_reward: u32,
 }
 
 /// Sell all ore button (Outpost)
 #[derive(Component)]
 pub struct DockingMenuSellAllOreButton;
 
+/// Sell every commodity other than raw ore (Outpost)
+#[derive(Component)]
+pub struct DockingMenuSellGoodsButton;
+
+/// Outpost stock and the prices it pays
+#[derive(Component)]
+pub struct DockingMenuOutpostMarketText;
+
 /// Text showing player credits
 #[derive(Component)]
 pub struct DockingMenuCreditsText;
 
+/// Text showing Outpost standing and price level
+#[derive(Component)]
+pub struct DockingMenuOutpostStandingText;
+
+/// Container for black-market section (Outpost with pirates docked)
+#[derive(Component)]
+pub struct DockingMenuBlackMarketSection;
+
+/// Buy pirate base intel button (black market)
+#[derive(Component)]
+pub struct DockingMenuBuyIntelButton;
+
+/// Buy a replacement hull button (Shipyard, only while in the escape pod)
+#[derive(Component)]
+pub struct DockingMenuReplaceHullButton;
+
+/// Container for FuelDepot section
+#[derive(Component)]
+pub struct DockingMenuDepotSection;
+
+/// Text showing depot stock and reserve
+#[derive(Component)]
+pub struct DockingMenuDepotText;
+
+/// Draw fuel from the depot button
+#[derive(Component)]
+pub struct DockingMenuDrawFuelButton;
+
+/// Deliver fuel to the depot button
+#[derive(Component)]
+pub struct DockingMenuDeliverFuelButton;
+
+/// Cycle the depot reserve policy button
+#[derive(Component)]
+pub struct DockingMenuDepotReserveButton;
+
+/// Container for a station's inventory (stations with an ore hold)
+#[derive(Component)]
+pub struct DockingMenuInventorySection;
+
+/// Text listing the station's goods hold and fuel
+#[derive(Component)]
+pub struct DockingMenuInventoryText;
+
+/// Withdraw goods from the station's hold button
+#[derive(Component)]
+pub struct DockingMenuWithdrawOreButton;
+
+/// Cycle the recipe a refinery or shipyard runs on its hold
+#[derive(Component)]
+pub struct DockingMenuRecipeButton;
+
+/// Container for station orders (repair, restart, abandon, decommission)
+#[derive(Component)]
+pub struct DockingMenuOrdersSection;
+
+/// Patch the station's hull with ore from the player's hold
+#[derive(Component)]
+pub struct DockingMenuRepairButton;
+
+/// Restart button (failed stations only)
+#[derive(Component)]
+pub struct DockingMenuRestartButton;
+
+/// Abandon station button
+#[derive(Component)]
+pub struct DockingMenuAbandonButton;
+
+/// Decommission station button
+#[derive(Component)]
+pub struct DockingMenuDecommissionButton;
+
+// =============================================================================
+// Construction Menu Components
+// =============================================================================
+
+/// Root marker for the construction menu panel
+#[derive(Component)]
+pub struct ConstructionMenuRoot;
+
+/// Title naming the zone being built in
+#[derive(Component)]
+pub struct ConstructionMenuTitle;
+
+/// Text describing the zone's conditions and the player's resources
+#[derive(Component)]
+pub struct ConstructionMenuStatus;
+
+/// Build a station of the given kind
+#[derive(Component)]
+pub struct ConstructionBuildButton {
+    pub kind: StationKind,
+}
+
+/// Cancel a station still under construction in this zone
+#[derive(Component)]
+pub struct ConstructionCancelButton;
+
+/// Close the construction menu
+#[derive(Component)]
+pub struct ConstructionLeaveButton;
+
+/// Cycle the construction site round the node
+#[derive(Component)]
+pub struct ConstructionSiteButton;
+
+// =============================================================================
+// Boarding Menu Components
+// =============================================================================
+
+/// Root marker for the boarding menu panel
+#[derive(Component)]
+pub struct BoardingMenuRoot;
+
+/// Text describing the boarded raider
+#[derive(Component)]
+pub struct BoardingMenuStatus;
+
+/// Capture the raider into the fleet
+#[derive(Component)]
+pub struct BoardingCaptureButton;
+
+/// Scrap the raider for ore
+#[derive(Component)]
+pub struct BoardingScrapButton;
+
+/// Interrogate the crew for their base's location
+#[derive(Component)]
+pub struct BoardingInterrogateButton;
+
+/// Leave the raider without acting
+#[derive(Component)]
+pub struct BoardingLeaveButton;
+
 // =============================================================================
 // Debug Panel Components
 // =============================================================================
@@ -209,6 +365,18 @@ pub struct DockingMenuCreditsText;
 #[derive(Component)]
 pub struct DebugPanelText;
 
+// =============================================================================
+// Ledger Screen Components
+// =============================================================================
+
+/// Ledger screen (L)
+#[derive(Component)]
+pub struct LedgerScreenRoot;
+
+/// Ledger screen body: totals per window and category, recent entries
+#[derive(Component)]
+pub struct LedgerScreenText;
+
 // =============================================================================
 // Resources
 // =============================================================================
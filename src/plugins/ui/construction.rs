@@ -0,0 +1,559 @@
+//! Construction menu UI systems for building the player's stations at a system node.
+
+use bevy::prelude::*;
+use bevy::ui::Node as UiNode;
+use std::path::Path;
+
+use crate::combat::{Wreck, WreckKind};
+use crate::compat::{NodeBundle, SpatialBundle, TextBundle, TextStyle};
+use crate::ledger::{Ledger, LedgerCategory, LedgerEntry};
+use crate::pirates::{PirateBase, PirateControl};
+use crate::plugins::core::EventLog;
+use crate::plugins::player::{
+    spawn_player_station, ConstructionMenu, ConstructionPreview, PlayerControl,
+};
+use crate::plugins::sim::SimTickCount;
+use crate::ships::{Cargo, Credits, Ship};
+use crate::stations::{
+    construction_site_offset, construction_site_problem, station_build_cost,
+    station_build_time_seconds, zone_claim_problem, ConstructionSite, Station, StationBuild,
+    StationKind, BUILDABLE_STATIONS, CONSTRUCTION_SITE_SLOTS,
+};
+use crate::world::{SystemNode, ZoneId};
+
+use super::components::{
+    modifier_to_long, ConstructionBuildButton, ConstructionCancelButton, ConstructionLeaveButton,
+    ConstructionMenuRoot, ConstructionMenuStatus, ConstructionMenuTitle, ConstructionSiteButton,
+};
+
+// =============================================================================
+// Setup Systems
+// =============================================================================
+
+pub fn setup_construction_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font_path = "fonts/SpaceMono-Regular.ttf";
+    let font_on_disk = Path::new("assets").join(font_path);
+
+    if !font_on_disk.exists() {
+        return;
+    }
+
+    let font = asset_server.load(font_path);
+    let button_style = |color: Color| TextStyle {
+        font: font.clone(),
+        font_size: 13.0,
+        color,
+    };
+    let button_margin = UiNode {
+        margin: UiRect::vertical(Val::Px(2.0)),
+        ..default()
+    };
+
+    // Construction menu panel (centered, initially hidden)
+    commands
+        .spawn((
+            ConstructionMenuRoot,
+            NodeBundle {
+                node: UiNode {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(50.0),
+                    top: Val::Percent(50.0),
+                    margin: UiRect {
+                        left: Val::Px(-200.0),
+                        top: Val::Px(-180.0),
+                        ..default()
+                    },
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    min_width: Val::Px(400.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.03, 0.06, 0.04, 0.95).into(),
+                border_color: Color::srgb(0.4, 0.8, 0.5).into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ConstructionMenuTitle,
+                TextBundle::from_section(
+                    "CONSTRUCTION",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        color: Color::srgb(0.5, 1.0, 0.6),
+                    },
+                ),
+            ));
+
+            parent.spawn((
+                ConstructionMenuStatus,
+                TextBundle::from_section(
+                    "Pirate control: 0%",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color: Color::srgb(0.6, 0.7, 0.8),
+                    },
+                ),
+            ));
+
+            // Divider
+            parent.spawn(NodeBundle {
+                node: UiNode {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(1.0),
+                    margin: UiRect::vertical(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: Color::srgb(0.1, 0.4, 0.2).into(),
+                ..default()
+            });
+
+            parent.spawn((
+                ConstructionSiteButton,
+                Interaction::None,
+                TextBundle::from_section("Site 1", button_style(Color::srgb(0.5, 0.8, 0.9)))
+                    .with_node(button_margin.clone()),
+            ));
+
+            for kind in BUILDABLE_STATIONS {
+                parent.spawn((
+                    ConstructionBuildButton { kind },
+                    Interaction::None,
+                    TextBundle::from_section(
+                        kind.label(),
+                        button_style(Color::srgb(0.4, 0.9, 0.4)),
+                    )
+                    .with_node(button_margin.clone()),
+                ));
+            }
+
+            parent.spawn((
+                ConstructionCancelButton,
+                Interaction::None,
+                TextBundle::from_section(
+                    "Cancel construction",
+                    button_style(Color::srgb(0.9, 0.5, 0.4)),
+                )
+                .with_node(button_margin.clone()),
+            ));
+
+            parent.spawn((
+                ConstructionLeaveButton,
+                Interaction::None,
+                TextBundle::from_section("[LEAVE]", button_style(Color::srgb(0.8, 0.6, 0.4)))
+                    .with_node(button_margin.clone()),
+            ));
+        });
+}
+
+// =============================================================================
+// Update Systems
+// =============================================================================
+
+/// Show the construction menu while at a system node: the picked site, what
+/// each station costs, whether it can go up there, and a preview of the site
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn update_construction_menu(
+    menu: Res<ConstructionMenu>,
+    control: Res<PirateControl>,
+    mut preview: ResMut<ConstructionPreview>,
+    player_query: Query<(&Cargo, &Credits, &Ship), With<PlayerControl>>,
+    nodes: Query<&SystemNode>,
+    stations: Query<&Transform, With<Station>>,
+    bases: Query<&ZoneId, With<PirateBase>>,
+    sites: Query<(Entity, &Station, &StationBuild, &ConstructionSite, &ZoneId)>,
+    mut root: Query<&mut Visibility, With<ConstructionMenuRoot>>,
+    mut title_text: Query<&mut Text, With<ConstructionMenuTitle>>,
+    mut status_text: Query<
+        &mut Text,
+        (With<ConstructionMenuStatus>, Without<ConstructionMenuTitle>),
+    >,
+    mut site_btn: Query<
+        (&Interaction, &mut Text),
+        (
+            With<ConstructionSiteButton>,
+            Without<ConstructionMenuTitle>,
+            Without<ConstructionMenuStatus>,
+        ),
+    >,
+    mut build_btns: Query<
+        (&Interaction, &ConstructionBuildButton, &mut Text),
+        (
+            Without<ConstructionMenuTitle>,
+            Without<ConstructionMenuStatus>,
+            Without<ConstructionSiteButton>,
+        ),
+    >,
+    mut cancel_btn: Query<
+        (&mut Text, &mut Visibility),
+        (
+            With<ConstructionCancelButton>,
+            Without<ConstructionBuildButton>,
+            Without<ConstructionMenuTitle>,
+            Without<ConstructionMenuStatus>,
+            Without<ConstructionMenuRoot>,
+            Without<ConstructionSiteButton>,
+        ),
+    >,
+) {
+    let Ok(mut visibility) = root.single_mut() else {
+        return;
+    };
+
+    let Some(node) = menu.node.and_then(|entity| nodes.get(entity).ok()) else {
+        *visibility = Visibility::Hidden;
+        if preview.site.is_some() {
+            preview.site = None;
+        }
+        return;
+    };
+
+    *visibility = Visibility::Visible;
+
+    if let Ok(mut text) = title_text.single_mut() {
+        text.0 = format!("CONSTRUCTION - Zone {}", node.id);
+    }
+
+    let (ore, fuel, credits) = player_query
+        .single()
+        .map_or((0, 0.0, 0), |(cargo, credits, ship)| {
+            (cargo.ore(), ship.fuel, credits.amount)
+        });
+
+    if let Ok(mut text) = status_text.single_mut() {
+        let conditions = match modifier_to_long(node.modifier) {
+            "" => "Normal",
+            long => long,
+        };
+        text.0 = format!(
+            "Conditions: {} | Pirate control: {:.0}%\nYour ore: {} | Fuel: {:.0} | Credits: {}",
+            conditions,
+            control.level(node.id) * 100.0,
+            ore,
+            fuel,
+            credits
+        );
+    }
+
+    // The picked site is always outlined; hovering a station checks it for that kind
+    let site = construction_site(node, menu.slot);
+    let problem = |kind| site_problem(kind, node, menu.slot, &control, &stations, &bases);
+    let mut shown = (
+        site,
+        BUILDABLE_STATIONS
+            .iter()
+            .any(|kind| problem(*kind).is_none()),
+    );
+
+    if let Ok((_, mut text)) = site_btn.single_mut() {
+        text.0 = site_line(menu.slot, site - node.position);
+    }
+
+    for (interaction, button, mut text) in build_btns.iter_mut() {
+        let blocked = problem(button.kind);
+        let shortfall = ConstructionSite::order_cost(button.kind).shortfall(ore, fuel, credits);
+        text.0 = build_option_line(button.kind, blocked.as_deref(), shortfall.as_deref());
+
+        if matches!(interaction, Interaction::Hovered | Interaction::Pressed) {
+            shown = (site, blocked.is_none());
+        }
+    }
+    if preview.site != Some(shown) {
+        preview.site = Some(shown);
+    }
+
+    if let Ok((mut text, mut vis)) = cancel_btn.single_mut() {
+        let pending = pending_site(node.id, &sites).and_then(|entity| sites.get(entity).ok());
+        match pending {
+            Some((_, station, build, site, _)) => {
+                let refund = site.refund(build.remaining_seconds, station.fuel);
+                text.0 = format!(
+                    "Cancel {} ({}, refund {} ore, {:.0} fuel, {} cr)",
+                    station.kind.label(),
+                    site.progress_line(),
+                    refund.ore,
+                    refund.fuel,
+                    refund.credits
+                );
+                *vis = Visibility::Inherited;
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
+/// Order a station built, cancel one still under construction, or leave
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_construction_clicks(
+    mut commands: Commands,
+    mut menu: ResMut<ConstructionMenu>,
+    control: Res<PirateControl>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut player_query: Query<(&mut Cargo, &mut Credits, &mut Ship), With<PlayerControl>>,
+    nodes: Query<&SystemNode>,
+    stations: Query<&Transform, With<Station>>,
+    bases: Query<&ZoneId, With<PirateBase>>,
+    sites: Query<(Entity, &Station, &StationBuild, &ConstructionSite, &ZoneId)>,
+    site_btn: Query<&Interaction, (With<ConstructionSiteButton>, Changed<Interaction>)>,
+    build_btns: Query<(&Interaction, &ConstructionBuildButton), Changed<Interaction>>,
+    cancel_btn: Query<&Interaction, (With<ConstructionCancelButton>, Changed<Interaction>)>,
+    leave_btn: Query<&Interaction, (With<ConstructionLeaveButton>, Changed<Interaction>)>,
+) {
+    let Some(node) = menu.node.and_then(|entity| nodes.get(entity).ok()) else {
+        return;
+    };
+
+    let pressed = |interaction: &Interaction| matches!(interaction, Interaction::Pressed);
+
+    if leave_btn.iter().any(pressed) {
+        menu.close();
+        return;
+    }
+    if site_btn.iter().any(pressed) {
+        menu.next_site();
+        return;
+    }
+
+    let Ok((mut cargo, mut credits, mut ship)) = player_query.single_mut() else {
+        return;
+    };
+
+    if cancel_btn.iter().any(pressed) {
+        let pending = pending_site(node.id, &sites).and_then(|entity| sites.get(entity).ok());
+        let Some((entity, station, build, site, _)) = pending else {
+            return;
+        };
+
+        let refund = site.refund(build.remaining_seconds, station.fuel);
+        let ore = cargo.add_ore(refund.ore);
+        let fuel = refund.fuel.min((ship.fuel_capacity - ship.fuel).max(0.0));
+        ship.fuel += fuel;
+        credits.add(refund.credits);
+
+        // Whatever doesn't fit aboard stays on the site as salvage
+        let left_ore = refund.ore - ore;
+        let left_fuel = refund.fuel - fuel;
+        let left_behind = left_ore > 0 || left_fuel > 0.0;
+        if left_behind {
+            if let Ok(transform) = stations.get(entity) {
+                commands.spawn((
+                    Wreck::new(
+                        WreckKind::Station(station.kind),
+                        left_ore as f32,
+                        left_fuel,
+                        0.0,
+                    ),
+                    Name::new("Wreck"),
+                    ZoneId(node.id),
+                    SpatialBundle::from_transform(*transform),
+                ));
+            }
+        }
+
+        ledger.record(
+            LedgerEntry::new(
+                ticks.tick,
+                LedgerCategory::Refund,
+                format!("{} site z{}", station.kind.label(), node.id),
+            )
+            .with_credits(refund.credits as i64)
+            .with_ore(ore as f32)
+            .with_fuel(fuel),
+        );
+        commands.entity(entity).despawn();
+        log.push(format!(
+            "{} construction cancelled: refunded {} ore, {:.0} fuel, {} credits",
+            station.kind.label(),
+            ore,
+            fuel,
+            refund.credits
+        ));
+        if left_behind {
+            log.push(format!(
+                "No room aboard for {} ore, {:.0} fuel: left in the site's wreckage",
+                left_ore, left_fuel
+            ));
+        }
+        return;
+    }
+
+    let Some(kind) = build_btns
+        .iter()
+        .find(|(interaction, _)| pressed(interaction))
+        .map(|(_, button)| button.kind)
+    else {
+        return;
+    };
+
+    if let Some(problem) = site_problem(kind, node, menu.slot, &control, &stations, &bases) {
+        log.push(format!("Construction: {}", problem));
+        return;
+    }
+
+    let cost = ConstructionSite::order_cost(kind);
+    if let Some(reason) = cost.shortfall(cargo.ore(), ship.fuel, credits.amount) {
+        log.push(format!("Construction: {}", reason));
+        return;
+    }
+
+    // The order lays the foundation; the rest of the ore and fuel is delivered
+    // while the station deploys, and its tank fills as the fuel is built in
+    cargo.remove_ore(cost.ore);
+    ship.fuel -= cost.fuel;
+    credits.try_spend(cost.credits);
+    ledger.record(
+        LedgerEntry::new(
+            ticks.tick,
+            LedgerCategory::Construction,
+            format!("{} site z{}", kind.label(), node.id),
+        )
+        .with_credits(-(cost.credits as i64))
+        .with_ore(-(cost.ore as f32))
+        .with_fuel(-cost.fuel),
+    );
+    spawn_player_station(
+        &mut commands,
+        kind,
+        node,
+        construction_site(node, menu.slot),
+        0.0,
+    )
+    .insert(ConstructionSite::new(kind));
+
+    let total = station_build_cost(kind);
+    log.push(format!(
+        "{} construction started at zone {} ({}s): deliver {} more ore and {:.0} more fuel",
+        kind.label(),
+        node.id,
+        station_build_time_seconds(kind) as u32,
+        total.ore - cost.ore,
+        total.fuel - cost.fuel
+    ));
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+fn construction_site(node: &SystemNode, slot: usize) -> Vec2 {
+    node.position + construction_site_offset(slot)
+}
+
+/// Why `kind` can't be built at the picked site by `node`, if it can't
+fn site_problem(
+    kind: StationKind,
+    node: &SystemNode,
+    slot: usize,
+    control: &PirateControl,
+    stations: &Query<&Transform, With<Station>>,
+    bases: &Query<&ZoneId, With<PirateBase>>,
+) -> Option<String> {
+    let pirate_base = bases.iter().any(|zone| zone.0 == node.id);
+    construction_site_problem(
+        kind,
+        construction_site(node, slot),
+        node.modifier,
+        zone_claim_problem(pirate_base, control.blocks_construction(node.id)),
+        stations
+            .iter()
+            .map(|transform| Vec2::new(transform.translation.x, transform.translation.y)),
+    )
+}
+
+/// The least advanced of the player's construction orders in a zone
+fn pending_site(
+    zone: u32,
+    sites: &Query<(Entity, &Station, &StationBuild, &ConstructionSite, &ZoneId)>,
+) -> Option<Entity> {
+    sites
+        .iter()
+        .filter(|(_, _, _, _, site_zone)| site_zone.0 == zone)
+        .max_by(|a, b| a.2.remaining_seconds.total_cmp(&b.2.remaining_seconds))
+        .map(|(entity, ..)| entity)
+}
+
+/// Button text for the picked site, with the compass direction it lies in
+fn site_line(slot: usize, offset: Vec2) -> String {
+    const DIRECTIONS: [&str; 8] = ["E", "NE", "N", "NW", "W", "SW", "S", "SE"];
+    let octant = (offset.to_angle().rem_euclid(std::f32::consts::TAU) / std::f32::consts::FRAC_PI_4)
+        .round() as usize
+        % DIRECTIONS.len();
+    format!(
+        "Site {}/{} ({} of the node) - next site",
+        slot + 1,
+        CONSTRUCTION_SITE_SLOTS,
+        DIRECTIONS[octant]
+    )
+}
+
+fn build_option_line(kind: StationKind, problem: Option<&str>, shortfall: Option<&str>) -> String {
+    let cost = station_build_cost(kind);
+    let order = ConstructionSite::order_cost(kind);
+    let mut line = format!(
+        "{}: {} ore, {:.0} fuel, {} cr, {}s ({} ore, {:.0} fuel up front)",
+        kind.label(),
+        cost.ore,
+        cost.fuel,
+        cost.credits,
+        station_build_time_seconds(kind) as u32,
+        order.ore,
+        order.fuel
+    );
+    if let Some(problem) = problem {
+        line.push_str(&format!("\n  Blocked: {}", problem));
+    } else if let Some(shortfall) = shortfall {
+        line.push_str(&format!("\n  Can't afford: {}", shortfall));
+    }
+    line
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn site_line_names_the_site_and_its_direction() {
+        assert_eq!(
+            site_line(0, construction_site_offset(0)),
+            format!(
+                "Site 1/{} (E of the node) - next site",
+                CONSTRUCTION_SITE_SLOTS
+            )
+        );
+        assert!(site_line(2, Vec2::new(-35.0, 60.0)).starts_with("Site 3/"));
+        assert!(site_line(2, Vec2::new(-35.0, 60.0)).contains("(NW of"));
+    }
+
+    #[test]
+    fn build_option_line_lists_cost_and_time() {
+        assert_eq!(
+            build_option_line(StationKind::MiningOutpost, None, None),
+            "Mining Outpost: 18 ore, 15 fuel, 0 cr, 180s (4 ore, 4 fuel up front)"
+        );
+    }
+
+    #[test]
+    fn build_option_line_puts_site_problems_before_cost() {
+        let line = build_option_line(
+            StationKind::SensorStation,
+            Some("site is occupied"),
+            Some("need 5 more ore"),
+        );
+        assert!(line.ends_with("Blocked: site is occupied"));
+
+        let line = build_option_line(StationKind::SensorStation, None, Some("need 5 more ore"));
+        assert!(line.ends_with("Can't afford: need 5 more ore"));
+    }
+}
@@ -2,17 +2,19 @@
 
 use bevy::prelude::*;
 use bevy::ui::Node as UiNode;
+use std::collections::HashMap;
 use std::path::Path;
 
 use crate::compat::NodeBundle;
 use crate::fleets::ScoutBehavior;
+use crate::pirates::PirateMemory;
 use crate::plugins::core::{DebugWindow, GameState, SimConfig};
 use crate::plugins::render2d::{FocusMarker, IntelRefreshCooldown, MapZoomOverride, RenderToggles};
 use crate::plugins::sim::SimTickCount;
 use crate::plugins::worldgen::WorldSeed;
 use crate::ships::Ship;
-use crate::stations::Station;
-use crate::world::{SystemIntel, SystemNode};
+use crate::stations::{Station, StationCrises, StationKind};
+use crate::world::{SystemIntel, SystemNode, ZoneId};
 
 use super::components::DebugPanelText;
 use super::panel::{PanelConfig, PanelPosition};
@@ -82,7 +84,8 @@ pub fn update_debug_panel(
     cooldown: Res<IntelRefreshCooldown>,
     marker: Res<FocusMarker>,
     state: Res<State<GameState>>,
-    stations: Query<&Station>,
+    pirate_memory: Res<PirateMemory>,
+    stations: Query<(Entity, &Station, Option<&ZoneId>, Option<&StationCrises>)>,
     ships: Query<&Ship>,
     scouts: Query<&ScoutBehavior>,
     nodes: Query<(&SystemNode, &SystemIntel)>,
@@ -150,6 +153,16 @@ pub fn update_debug_panel(
                         nodes.iter().count()
                     ));
 
+                    body.push_str(&pirate_memory_summary(&pirate_memory));
+
+                    let crisis_stations: HashMap<_, _> = stations
+                        .iter()
+                        .filter_map(|(entity, station, zone, crises)| {
+                            Some((entity, (station.kind, zone.map(|zone| zone.0), crises?)))
+                        })
+                        .collect();
+                    body.push_str(&crisis_chain_summary(&crisis_stations));
+
                     body.push_str("\nKeybinds (hold Shift):\n");
                     body.push_str("  -/=  seed     V  reveal adj   U  reveal all\n");
                     body.push_str("  Z    clear    I  intel        O  adv intel\n");
@@ -170,3 +183,195 @@ pub fn update_debug_panel(
         }
     }
 }
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Number of zones listed in the pirate memory section
+const PIRATE_MEMORY_ZONE_LINES: usize = 3;
+
+fn pirate_memory_summary(memory: &PirateMemory) -> String {
+    let mut body = String::from("\nPirate Memory:\n");
+
+    let zones = memory.most_dangerous_zones(PIRATE_MEMORY_ZONE_LINES);
+    if zones.is_empty() {
+        body.push_str("  Losses: --\n");
+    }
+    for (zone, danger) in zones {
+        if let Some(losses) = memory.losses.get(&zone) {
+            body.push_str(&format!(
+                "  Zone {}: danger {:.1} (ply {} sec {} stn {})\n",
+                zone, danger, losses.player, losses.security, losses.station
+            ));
+        }
+    }
+
+    if memory.successes.is_empty() {
+        body.push_str("  Raids: --\n");
+    } else {
+        let raids = memory
+            .successes
+            .iter()
+            .map(|(target, count)| format!("{:?}:{}", target, count))
+            .collect::<Vec<_>>()
+            .join(" ");
+        body.push_str(&format!("  Raids: {}\n", raids));
+    }
+
+    body
+}
+
+/// Number of causal chains listed in the crisis section
+const CRISIS_CHAIN_LINES: usize = 5;
+
+/// Links followed back along a chain before giving up
+const CRISIS_CHAIN_DEPTH: usize = 6;
+
+type CrisisStationInfo<'a> = (StationKind, Option<u32>, &'a StationCrises);
+
+fn zone_label(zone: Option<u32>) -> String {
+    zone.map_or_else(|| "z?".to_string(), |zone| format!("z{}", zone))
+}
+
+/// Explain each cascaded crisis by walking back through the stations that
+/// passed it on, e.g. `Refinery z3 Supply shortage <- MiningOutpost z3 failed`
+fn crisis_chain_summary(stations: &HashMap<Entity, CrisisStationInfo>) -> String {
+    let mut chains = Vec::new();
+
+    for (kind, zone, crises) in stations.values() {
+        for (crisis_type, _) in crises.iter() {
+            let Some(mut source) = crises.cascade_source(crisis_type).copied() else {
+                continue;
+            };
+
+            let mut chain = format!("{:?} {} {}", kind, zone_label(*zone), crisis_type.label());
+            for _ in 0..CRISIS_CHAIN_DEPTH {
+                let cause = source
+                    .crisis_type
+                    .map_or("failed", |crisis_type| crisis_type.label());
+                chain.push_str(&format!(
+                    " <- {:?} {} {}",
+                    source.station_kind,
+                    zone_label(source.zone),
+                    cause
+                ));
+
+                let next = source.crisis_type.and_then(|crisis_type| {
+                    let (_, _, crises) = stations.get(&source.station)?;
+                    crises.cascade_source(crisis_type).copied()
+                });
+                match next {
+                    Some(next) => source = next,
+                    None => break,
+                }
+            }
+            chains.push(chain);
+        }
+    }
+
+    chains.sort();
+    let mut body = String::from("\nCrisis Chains:\n");
+    if chains.is_empty() {
+        body.push_str("  --\n");
+    }
+    for chain in chains.iter().take(CRISIS_CHAIN_LINES) {
+        body.push_str(&format!("  {}\n", chain));
+    }
+    body
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pirates::{PirateLossCause, RaidTargetType};
+
+    #[test]
+    fn pirate_memory_summary_handles_empty_memory() {
+        let summary = pirate_memory_summary(&PirateMemory::default());
+        assert!(summary.contains("Losses: --"));
+        assert!(summary.contains("Raids: --"));
+    }
+
+    #[test]
+    fn pirate_memory_summary_lists_zones_and_raids() {
+        let mut memory = PirateMemory::default();
+        memory.record_loss(12, PirateLossCause::Player);
+        memory.record_success(RaidTargetType::Ship);
+
+        let summary = pirate_memory_summary(&memory);
+        assert!(summary.contains("Zone 12: danger 1.0 (ply 1 sec 0 stn 0)"));
+        assert!(summary.contains("Raids: Ship:1"));
+    }
+
+    #[test]
+    fn crisis_chain_summary_walks_back_to_the_root_cause() {
+        use crate::stations::{
+            ActiveCrisis, CascadePressure, CascadeSource, CrisisStage, CrisisType,
+        };
+        use std::collections::BTreeMap;
+
+        let mut world = World::default();
+        let depot = world.spawn_empty().id();
+        let outpost = world.spawn_empty().id();
+        let refinery = world.spawn_empty().id();
+
+        let open = ActiveCrisis {
+            entity: Entity::PLACEHOLDER,
+            stage: CrisisStage::Strained,
+            elapsed_seconds: 0.0,
+            stage_seconds: 0.0,
+        };
+        let cascade = |crisis_type, station, station_kind, source_type| {
+            let mut crises = StationCrises::default();
+            crises.track(crisis_type, open);
+            crises.set_cascades(BTreeMap::from([(
+                crisis_type,
+                CascadePressure {
+                    stage: CrisisStage::Strained,
+                    source: CascadeSource {
+                        station,
+                        station_kind,
+                        zone: Some(1),
+                        crisis_type: source_type,
+                    },
+                },
+            )]));
+            crises
+        };
+
+        let depot_crises = StationCrises::default();
+        let outpost_crises = cascade(
+            CrisisType::FuelShortage,
+            depot,
+            StationKind::FuelDepot,
+            None,
+        );
+        let refinery_crises = cascade(
+            CrisisType::SupplyShortage,
+            outpost,
+            StationKind::MiningOutpost,
+            Some(CrisisType::FuelShortage),
+        );
+
+        let stations = HashMap::from([
+            (depot, (StationKind::FuelDepot, Some(1), &depot_crises)),
+            (
+                outpost,
+                (StationKind::MiningOutpost, Some(1), &outpost_crises),
+            ),
+            (refinery, (StationKind::Refinery, Some(2), &refinery_crises)),
+        ]);
+        let summary = crisis_chain_summary(&stations);
+
+        assert!(summary.contains(
+            "Refinery z2 Supply shortage <- MiningOutpost z1 Fuel shortage <- FuelDepot z1 failed"
+        ));
+        assert!(summary.contains("MiningOutpost z1 Fuel shortage <- FuelDepot z1 failed"));
+        assert!(crisis_chain_summary(&HashMap::new()).contains("  --"));
+    }
+}
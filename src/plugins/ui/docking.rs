@@ -4,34 +4,55 @@ use bevy::prelude::*;
 use bevy::ui::Node as UiNode;
 use std::path::Path;
 
+use crate::combat::{ship_hull, ship_shields, Hull, HULL_PATCH_AMOUNT, HULL_PATCH_ORE};
+use crate::commodities::{Commodity, COMMODITIES};
 use crate::compat::{NodeBundle, TextBundle, TextStyle};
-use crate::plugins::player::{DockingState, PlayerControl};
-use crate::ships::{Cargo, Credits, Ship};
+use crate::crises::{Crisis, CrisisHistory, CrisisResolution};
+use crate::fleets::{next_risk, RiskTolerance};
+use crate::ledger::{Ledger, LedgerCategory, LedgerEntry};
+use crate::pirates::{PirateBase, PirateControl};
+use crate::plugins::core::EventLog;
+use crate::plugins::player::{DockingState, EscapePod, PlayerControl};
+use crate::plugins::sim::{reveal_threat_intel, SimTickCount};
+use crate::ships::{Cargo, Credits, Ship, ShipKind};
 use crate::stations::{
-    outpost_ore_to_credits, RefineryJob, RefineryStorage, ShipyardJob, Station, StationKind,
-    StationState, OUTPOST_BUY_FUEL_OPTIONS, OUTPOST_SELL_ORE_OPTIONS,
+    is_npc_station, refinery_ore_per_minute, ship_build_cost, station_recipes, trade_total,
+    ConstructionSite, CrisisType, Decommissioned, FuelDepotPolicy, MarketConditions, MarketGood,
+    MarketIntel, OutpostMarket, OutpostTrade, RecipeKind, RefineryJob, RefineryQueue,
+    RefineryStorage, ShipyardJob, ShipyardQueue, ShipyardStorage, Station, StationBuild,
+    StationCrises, StationKind, StationProduction, StationRecipe, StationState, StationStock,
+    ABANDON_SALVAGE_FRACTION, BLACK_MARKET_INTEL_COST, OUTPOST_FUEL_LOTS, OUTPOST_ORE_LOTS,
+    REFINERY_QUEUE_LIMIT, REPLACEMENT_HULL_CREDIT_COST, REPLACEMENT_HULL_ORE_COST, SHIPYARD_HULLS,
+    STATION_RESTART_FUEL_COST, STATION_RESTART_ORE_COST, STATION_RESTART_SECONDS,
 };
+use crate::world::{zone_modifier_of, Identified, Sector, SystemIntel, SystemNode, ZoneId};
 
 use super::components::{
-    DockingMenuBuildScoutButton, DockingMenuBuyFuelButton, DockingMenuCancelButton,
+    DockingMenuAbandonButton, DockingMenuBlackMarketSection, DockingMenuBuildShipButton,
+    DockingMenuBuyFuelButton, DockingMenuBuyIntelButton, DockingMenuCancelButton,
     DockingMenuCargoText, DockingMenuCollectButton, DockingMenuCollectSection,
-    DockingMenuConvertButton, DockingMenuCreditsText, DockingMenuJobSection, DockingMenuJobText,
-    DockingMenuOutpostSection, DockingMenuRoot, DockingMenuSellAllOreButton,
-    DockingMenuSellOreButton, DockingMenuStatus, DockingMenuTitle, DockingMenuUndockButton,
+    DockingMenuConvertButton, DockingMenuCreditsText, DockingMenuDecommissionButton,
+    DockingMenuDeliverFuelButton, DockingMenuDepotReserveButton, DockingMenuDepotSection,
+    DockingMenuDepotText, DockingMenuDrawFuelButton, DockingMenuInventorySection,
+    DockingMenuInventoryText, DockingMenuJobSection, DockingMenuJobText, DockingMenuOrdersSection,
+    DockingMenuOutpostMarketText, DockingMenuOutpostSection, DockingMenuOutpostStandingText,
+    DockingMenuRecipeButton, DockingMenuRefineryTargetButton, DockingMenuRepairButton,
+    DockingMenuReplaceHullButton, DockingMenuRestartButton, DockingMenuRoot,
+    DockingMenuScoutOrdersButton, DockingMenuSellAllOreButton, DockingMenuSellGoodsButton,
+    DockingMenuSellOreButton, DockingMenuShipyardSection, DockingMenuStatus, DockingMenuTitle,
+    DockingMenuUndockButton, DockingMenuWithdrawOreButton,
 };
+use super::intel::format_station_crises;
 
 // =============================================================================
 // Constants
 // =============================================================================
 
-/// Cost in ore to build a scout
-pub const SCOUT_BUILD_COST: u32 = 15;
+/// Fuel the player hands over per depot delivery
+pub const DEPOT_DELIVERY_AMOUNT: f32 = 10.0;
 
-/// Time in seconds to build a scout
-pub const SCOUT_BUILD_TIME: f32 = 120.0;
-
-/// Ore conversion options: (ore_in, fuel_out, time_seconds)
-pub const REFINERY_OPTIONS: [(u32, f32, f32); 2] = [(5, 10.0, 60.0), (10, 20.0, 90.0)];
+/// Ore batches the player can queue at a refinery
+pub const REFINERY_OPTIONS: [u32; 2] = [5, 10];
 
 // =============================================================================
 // Setup Systems
@@ -179,36 +200,94 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                 },
             ));
 
-            // Build Scout button (Shipyard)
-            parent.spawn((
-                DockingMenuBuildScoutButton,
-                Interaction::None,
-                TextBundle::from_section(
-                    format!(
-                        "Build Scout ({} ore, {}s)",
-                        SCOUT_BUILD_COST, SCOUT_BUILD_TIME as u32
-                    ),
-                    TextStyle {
-                        font: font.clone(),
-                        font_size: 13.0,
-                        color: Color::srgb(0.4, 0.8, 0.4),
+            // Shipyard section: a build button per hull and launch orders
+            parent
+                .spawn((
+                    DockingMenuShipyardSection,
+                    NodeBundle {
+                        node: UiNode {
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        },
+                        visibility: Visibility::Hidden,
+                        ..default()
                     },
-                )
-                .with_node(UiNode {
-                    margin: UiRect::vertical(Val::Px(2.0)),
-                    ..default()
-                }),
-            ));
+                ))
+                .with_children(|shipyard| {
+                    for kind in SHIPYARD_HULLS {
+                        let cost = ship_build_cost(kind);
+                        shipyard.spawn((
+                            DockingMenuBuildShipButton { kind },
+                            Interaction::None,
+                            TextBundle::from_section(
+                                format!(
+                                    "Build {:?} ({} ore, {} cr, {}s)",
+                                    kind, cost.ore, cost.credits, cost.seconds as u32
+                                ),
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 13.0,
+                                    color: Color::srgb(0.4, 0.8, 0.4),
+                                },
+                            )
+                            .with_node(UiNode {
+                                margin: UiRect::vertical(Val::Px(2.0)),
+                                ..default()
+                            }),
+                        ));
+                    }
+
+                    shipyard.spawn((
+                        DockingMenuScoutOrdersButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            "> Scout orders: Balanced",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.6, 0.7, 0.9),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+                });
+
+            // Replacement hull button (Shipyard, escape pod only)
+            parent
+                .spawn((
+                    DockingMenuReplaceHullButton,
+                    Interaction::None,
+                    TextBundle::from_section(
+                        format!(
+                            "Replacement hull ({} ore, {} cr)",
+                            REPLACEMENT_HULL_ORE_COST, REPLACEMENT_HULL_CREDIT_COST
+                        ),
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            color: Color::srgb(0.9, 0.7, 0.3),
+                        },
+                    )
+                    .with_node(UiNode {
+                        margin: UiRect::vertical(Val::Px(2.0)),
+                        ..default()
+                    }),
+                ))
+                .insert(Visibility::Hidden);
 
             // Convert ore buttons (Refinery)
-            for (ore_in, fuel_out, time) in REFINERY_OPTIONS {
+            for ore_in in REFINERY_OPTIONS {
                 parent.spawn((
                     DockingMenuConvertButton { ore_amount: ore_in },
                     Interaction::None,
                     TextBundle::from_section(
                         format!(
-                            "Convert {} ore -> {} fuel ({}s)",
-                            ore_in, fuel_out as u32, time as u32
+                            "Queue {} ore -> {} fuel",
+                            ore_in,
+                            RefineryJob::new(ore_in).fuel_out as u32
                         ),
                         TextStyle {
                             font: font.clone(),
@@ -223,6 +302,26 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                 ));
             }
 
+            // Target stock button (Refinery)
+            parent
+                .spawn((
+                    DockingMenuRefineryTargetButton,
+                    Interaction::None,
+                    TextBundle::from_section(
+                        "> Target stock: 50%",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            color: Color::srgb(0.6, 0.7, 0.9),
+                        },
+                    )
+                    .with_node(UiNode {
+                        margin: UiRect::vertical(Val::Px(2.0)),
+                        ..default()
+                    }),
+                ))
+                .insert(Visibility::Hidden);
+
             // Collect section (Refinery)
             parent
                 .spawn((
@@ -264,6 +363,89 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                     ));
                 });
 
+            // FuelDepot section (hidden by default)
+            parent
+                .spawn((
+                    DockingMenuDepotSection,
+                    NodeBundle {
+                        node: UiNode {
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::top(Val::Px(8.0)),
+                            ..default()
+                        },
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                ))
+                .with_children(|depot| {
+                    depot.spawn((
+                        DockingMenuDepotText,
+                        TextBundle::from_section(
+                            "Available: 0 | Reserve: 25%",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                color: Color::srgb(0.6, 0.7, 0.8),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::bottom(Val::Px(6.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    depot.spawn((
+                        DockingMenuDrawFuelButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            "> Fill tank (above reserve)",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.4, 0.8, 0.4),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    depot.spawn((
+                        DockingMenuDeliverFuelButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            format!("> Deliver {} fuel", DEPOT_DELIVERY_AMOUNT as u32),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.4, 0.6, 0.8),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    depot.spawn((
+                        DockingMenuDepotReserveButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            "> Reserve policy: 25%",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.8, 0.8, 0.4),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+                });
+
             // Outpost trading section (hidden by default)
             parent
                 .spawn((
@@ -279,6 +461,40 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                     },
                 ))
                 .with_children(|outpost| {
+                    // Standing and price level
+                    outpost.spawn((
+                        DockingMenuOutpostStandingText,
+                        TextBundle::from_section(
+                            "Standing: Neutral | Prices x1.00",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                color: Color::srgb(0.6, 0.7, 0.8),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::bottom(Val::Px(4.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    // Stock and the prices the Outpost pays
+                    outpost.spawn((
+                        DockingMenuOutpostMarketText,
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                color: Color::srgb(0.6, 0.7, 0.8),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::bottom(Val::Px(6.0)),
+                            ..default()
+                        }),
+                    ));
+
                     // BUY FUEL section
                     outpost.spawn(TextBundle::from_section(
                         "BUY FUEL",
@@ -289,17 +505,14 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                         },
                     ));
 
-                    for option in OUTPOST_BUY_FUEL_OPTIONS {
+                    for fuel_amount in OUTPOST_FUEL_LOTS {
                         outpost.spawn((
-                            DockingMenuBuyFuelButton {
-                                fuel_amount: option.fuel_amount,
-                                credit_cost: option.credit_cost,
-                            },
+                            DockingMenuBuyFuelButton { fuel_amount },
                             Interaction::None,
                             TextBundle::from_section(
-                                format!(
-                                    "> {} fuel for {} cr",
-                                    option.fuel_amount, option.credit_cost
+                                fuel_lot_label(
+                                    fuel_amount,
+                                    trade_total(MarketGood::Fuel.base_price(), fuel_amount),
                                 ),
                                 TextStyle {
                                     font: font.clone(),
@@ -330,17 +543,17 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                         }),
                     );
 
-                    for option in OUTPOST_SELL_ORE_OPTIONS {
+                    for ore_amount in OUTPOST_ORE_LOTS {
                         outpost.spawn((
-                            DockingMenuSellOreButton {
-                                ore_amount: option.ore_amount,
-                                credit_reward: option.credit_reward,
-                            },
+                            DockingMenuSellOreButton { ore_amount },
                             Interaction::None,
                             TextBundle::from_section(
-                                format!(
-                                    "> {} ore -> {} cr",
-                                    option.ore_amount, option.credit_reward
+                                ore_lot_label(
+                                    ore_amount,
+                                    trade_total(
+                                        MarketGood::Goods(Commodity::RawOre).base_price(),
+                                        ore_amount,
+                                    ),
                                 ),
                                 TextStyle {
                                     font: font.clone(),
@@ -372,48 +585,292 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
                             ..default()
                         }),
                     ));
-                });
 
-            // Divider
-            parent.spawn(NodeBundle {
-                node: UiNode {
-                    width: Val::Percent(100.0),
-                    height: Val::Px(1.0),
-                    margin: UiRect::vertical(Val::Px(6.0)),
-                    ..default()
-                },
-                background_color: Color::srgb(0.0, 0.4, 0.4).into(),
-                ..default()
-            });
+                    // Sell everything else in the hold
+                    outpost.spawn((
+                        DockingMenuSellGoodsButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            "> Sell other goods",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.8, 0.6, 0.3),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
 
-            // Cargo and credits display
-            parent.spawn((
-                DockingMenuCargoText,
-                TextBundle::from_section(
-                    "YOUR CARGO\nOre: 0/50  Fuel: 0/100",
-                    TextStyle {
-                        font: font.clone(),
-                        font_size: 12.0,
-                        color: Color::srgb(0.6, 0.7, 0.8),
-                    },
-                ),
-            ));
+                    // Black market (only while pirates are docked)
+                    outpost
+                        .spawn((
+                            DockingMenuBlackMarketSection,
+                            NodeBundle {
+                                node: UiNode {
+                                    flex_direction: FlexDirection::Column,
+                                    margin: UiRect::top(Val::Px(8.0)),
+                                    ..default()
+                                },
+                                visibility: Visibility::Hidden,
+                                ..default()
+                            },
+                        ))
+                        .with_children(|market| {
+                            market.spawn(TextBundle::from_section(
+                                "BLACK MARKET",
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 13.0,
+                                    color: Color::srgb(0.8, 0.3, 0.3),
+                                },
+                            ));
+
+                            market.spawn((
+                                DockingMenuBuyIntelButton,
+                                Interaction::None,
+                                TextBundle::from_section(
+                                    format!(
+                                        "> Pirate base intel ({} cr, standing risk)",
+                                        BLACK_MARKET_INTEL_COST
+                                    ),
+                                    TextStyle {
+                                        font: font.clone(),
+                                        font_size: 13.0,
+                                        color: Color::srgb(0.9, 0.4, 0.4),
+                                    },
+                                )
+                                .with_node(UiNode {
+                                    margin: UiRect::vertical(Val::Px(2.0)),
+                                    ..default()
+                                }),
+                            ));
+                        });
+                });
 
-            parent.spawn((
-                DockingMenuCreditsText,
-                TextBundle::from_section(
-                    "Credits: 50",
-                    TextStyle {
-                        font: font.clone(),
-                        font_size: 12.0,
-                        color: Color::srgb(0.8, 0.7, 0.4),
+            // Station inventory (stations with an ore hold)
+            parent
+                .spawn((
+                    DockingMenuInventorySection,
+                    NodeBundle {
+                        node: UiNode {
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::top(Val::Px(8.0)),
+                            ..default()
+                        },
+                        visibility: Visibility::Hidden,
+                        ..default()
                     },
-                )
-                .with_node(UiNode {
-                    margin: UiRect::top(Val::Px(4.0)),
-                    ..default()
-                }),
-            ));
+                ))
+                .with_children(|inventory| {
+                    inventory.spawn(TextBundle::from_section(
+                        "STATION INVENTORY",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            color: Color::srgb(0.7, 0.7, 0.7),
+                        },
+                    ));
+
+                    inventory.spawn((
+                        DockingMenuInventoryText,
+                        TextBundle::from_section(
+                            "Ore: 0/0 | Fuel: 0/0",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 12.0,
+                                color: Color::srgb(0.6, 0.7, 0.8),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(4.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    inventory.spawn((
+                        DockingMenuWithdrawOreButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            "> Withdraw goods",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.4, 0.8, 0.4),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    inventory
+                        .spawn((
+                            DockingMenuRecipeButton,
+                            Interaction::None,
+                            TextBundle::from_section(
+                                "> Recipe: idle",
+                                TextStyle {
+                                    font: font.clone(),
+                                    font_size: 13.0,
+                                    color: Color::srgb(0.6, 0.7, 0.9),
+                                },
+                            )
+                            .with_node(UiNode {
+                                margin: UiRect::vertical(Val::Px(2.0)),
+                                ..default()
+                            }),
+                        ))
+                        .insert(Visibility::Hidden);
+                });
+
+            // Station orders (player stations only)
+            parent
+                .spawn((
+                    DockingMenuOrdersSection,
+                    NodeBundle {
+                        node: UiNode {
+                            flex_direction: FlexDirection::Column,
+                            margin: UiRect::top(Val::Px(8.0)),
+                            ..default()
+                        },
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                ))
+                .with_children(|orders| {
+                    orders.spawn(TextBundle::from_section(
+                        "STATION ORDERS",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 13.0,
+                            color: Color::srgb(0.7, 0.7, 0.7),
+                        },
+                    ));
+
+                    orders.spawn((
+                        DockingMenuRestartButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            format!(
+                                "Restart ({} ore, {} fuel, {}s)",
+                                STATION_RESTART_ORE_COST,
+                                STATION_RESTART_FUEL_COST as u32,
+                                STATION_RESTART_SECONDS as u32
+                            ),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.4, 0.8, 0.4),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    orders.spawn((
+                        DockingMenuRepairButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            format!(
+                                "Repair hull ({} ore per {:.0} hull)",
+                                HULL_PATCH_ORE, HULL_PATCH_AMOUNT
+                            ),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.4, 0.8, 0.4),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    orders.spawn((
+                        DockingMenuDecommissionButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            "Decommission (stock goes to the next station you dock at)",
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.4, 0.6, 0.8),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+
+                    orders.spawn((
+                        DockingMenuAbandonButton,
+                        Interaction::None,
+                        TextBundle::from_section(
+                            format!(
+                                "Abandon (salvage {}% of stock)",
+                                (ABANDON_SALVAGE_FRACTION * 100.0) as u32
+                            ),
+                            TextStyle {
+                                font: font.clone(),
+                                font_size: 13.0,
+                                color: Color::srgb(0.8, 0.4, 0.4),
+                            },
+                        )
+                        .with_node(UiNode {
+                            margin: UiRect::vertical(Val::Px(2.0)),
+                            ..default()
+                        }),
+                    ));
+                });
+
+            // Divider
+            parent.spawn(NodeBundle {
+                node: UiNode {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(1.0),
+                    margin: UiRect::vertical(Val::Px(6.0)),
+                    ..default()
+                },
+                background_color: Color::srgb(0.0, 0.4, 0.4).into(),
+                ..default()
+            });
+
+            // Cargo and credits display
+            parent.spawn((
+                DockingMenuCargoText,
+                TextBundle::from_section(
+                    "YOUR CARGO\nOre: 0/50  Fuel: 0/100",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color: Color::srgb(0.6, 0.7, 0.8),
+                    },
+                ),
+            ));
+
+            parent.spawn((
+                DockingMenuCreditsText,
+                TextBundle::from_section(
+                    "Credits: 50",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color: Color::srgb(0.8, 0.7, 0.4),
+                    },
+                )
+                .with_node(UiNode {
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                }),
+            ));
 
             // Divider
             parent.spawn(NodeBundle {
@@ -451,17 +908,21 @@ pub fn setup_docking_menu(mut commands: Commands, asset_server: Res<AssetServer>
 // Update Systems
 // =============================================================================
 
-/// Show/hide the docking menu based on docking state
+/// Show/hide the docking menu based on docking state. Boarded raiders get their own menu.
 #[allow(clippy::type_complexity)]
 pub fn update_docking_menu_visibility(
     docking: Res<DockingState>,
+    stations: Query<(), With<Station>>,
     mut menu: Query<&mut Visibility, With<DockingMenuRoot>>,
 ) {
     let Ok(mut visibility) = menu.single_mut() else {
         return;
     };
 
-    if docking.is_docked() {
+    if docking
+        .docked_at
+        .is_some_and(|entity| stations.get(entity).is_ok())
+    {
         *visibility = Visibility::Visible;
     } else {
         *visibility = Visibility::Hidden;
@@ -477,9 +938,13 @@ pub fn update_docking_menu_content(
     stations: Query<(
         &Station,
         &Name,
-        Option<&ShipyardJob>,
-        Option<&RefineryJob>,
+        Option<&ShipyardQueue>,
+        Option<&ShipyardStorage>,
+        Option<&RefineryQueue>,
+        Option<&StationProduction>,
         Option<&RefineryStorage>,
+        Option<&StationCrises>,
+        Option<&ConstructionSite>,
     )>,
     mut title_text: Query<&mut Text, With<DockingMenuTitle>>,
     mut status_text: Query<&mut Text, (With<DockingMenuStatus>, Without<DockingMenuTitle>)>,
@@ -518,21 +983,12 @@ pub fn update_docking_menu_content(
             Without<DockingMenuJobSection>,
         ),
     >,
-    mut build_btn: Query<
-        &mut Visibility,
-        (
-            With<DockingMenuBuildScoutButton>,
-            Without<DockingMenuJobSection>,
-            Without<DockingMenuCancelButton>,
-        ),
-    >,
     mut convert_btns: Query<
         &mut Visibility,
         (
             With<DockingMenuConvertButton>,
             Without<DockingMenuJobSection>,
             Without<DockingMenuCancelButton>,
-            Without<DockingMenuBuildScoutButton>,
         ),
     >,
     mut collect_section: Query<
@@ -541,7 +997,6 @@ pub fn update_docking_menu_content(
             With<DockingMenuCollectSection>,
             Without<DockingMenuJobSection>,
             Without<DockingMenuCancelButton>,
-            Without<DockingMenuBuildScoutButton>,
             Without<DockingMenuConvertButton>,
         ),
     >,
@@ -551,7 +1006,6 @@ pub fn update_docking_menu_content(
             With<DockingMenuOutpostSection>,
             Without<DockingMenuJobSection>,
             Without<DockingMenuCancelButton>,
-            Without<DockingMenuBuildScoutButton>,
             Without<DockingMenuConvertButton>,
             Without<DockingMenuCollectSection>,
         ),
@@ -561,8 +1015,17 @@ pub fn update_docking_menu_content(
         return;
     };
 
-    let Ok((station, name, shipyard_job, refinery_job, refinery_storage)) =
-        stations.get(station_entity)
+    let Ok((
+        station,
+        name,
+        shipyard_queue,
+        shipyard_storage,
+        refinery_queue,
+        production,
+        refinery_storage,
+        crises,
+        site,
+    )) = stations.get(station_entity)
     else {
         return;
     };
@@ -574,6 +1037,8 @@ pub fn update_docking_menu_content(
         let kind_str = match station.kind {
             StationKind::Shipyard => "SHIPYARD",
             StationKind::Refinery => "REFINERY",
+            StationKind::FuelDepot => "FUEL DEPOT",
+            StationKind::MiningOutpost => "MINING OUTPOST",
             StationKind::Outpost => "FRONTIER OUTPOST",
             _ => "STATION",
         };
@@ -596,16 +1061,28 @@ pub fn update_docking_menu_content(
                 "Status: {} | Fuel: {:.0}/{:.0}",
                 status_str, station.fuel, station.fuel_capacity
             );
+            if let Some(site) = site {
+                text.0
+                    .push_str(&format!("\nConstruction: {}", site.progress_line()));
+            }
+            if let Some(crises) = crises.filter(|crises| !crises.is_empty()) {
+                text.0.push('\n');
+                text.0.push_str(&format_station_crises(crises).join("\n"));
+            }
         }
     }
 
     let is_outpost = matches!(station.kind, StationKind::Outpost);
 
     // Update job progress (not for Outpost)
-    let has_job = shipyard_job.is_some() || refinery_job.is_some();
+    let shipyard_jobs = shipyard_queue.map_or(0, |queue| queue.jobs.len());
+    let hangar = shipyard_storage.map_or(0, |storage| storage.hangar.len());
+    let refinery_jobs = refinery_queue.map_or(0, |queue| queue.jobs.len());
+    let is_refinery = station.kind == StationKind::Refinery;
+    let has_job = shipyard_jobs > 0 || refinery_jobs > 0;
 
     if let Ok(mut vis) = job_section.single_mut() {
-        *vis = if has_job && !is_outpost {
+        *vis = if (has_job || hangar > 0 || is_refinery) && !is_outpost {
             Visibility::Inherited
         } else {
             Visibility::Hidden
@@ -621,38 +1098,19 @@ pub fn update_docking_menu_content(
     }
 
     if let Ok(mut text) = job_text.single_mut() {
-        if let Some(job) = shipyard_job {
-            let progress = 1.0 - (job.remaining_seconds / SCOUT_BUILD_TIME);
-            let bar = progress_bar(progress, 20);
-            text.0 = format!(
-                "BUILDING: Scout ({:.0}s remaining)\n[{}]",
-                job.remaining_seconds, bar
-            );
-        } else if let Some(job) = refinery_job {
-            let total_time = 60.0; // Approximate
-            let progress = 1.0 - (job.remaining_seconds / total_time).min(1.0);
-            let bar = progress_bar(progress, 20);
-            text.0 = format!(
-                "CONVERTING: {} ore -> {} fuel ({:.0}s)\n[{}]",
-                job.ore_in, job.fuel_out as u32, job.remaining_seconds, bar
-            );
+        if shipyard_jobs > 0 || hangar > 0 {
+            text.0 = shipyard_queue_lines(shipyard_queue, shipyard_storage).join("\n");
+        } else if is_refinery {
+            text.0 = refinery_queue_lines(station, refinery_queue, production, refinery_storage)
+                .join("\n");
         } else {
             text.0 = String::new();
         }
     }
 
-    // Show/hide build scout button (Shipyard only, no active job)
-    if let Ok(mut vis) = build_btn.single_mut() {
-        *vis = if matches!(station.kind, StationKind::Shipyard) && shipyard_job.is_none() {
-            Visibility::Inherited
-        } else {
-            Visibility::Hidden
-        };
-    }
-
-    // Show/hide convert buttons (Refinery only, no active job)
+    // Show/hide convert buttons (Refinery only, room in the queue)
     for mut vis in convert_btns.iter_mut() {
-        *vis = if matches!(station.kind, StationKind::Refinery) && refinery_job.is_none() {
+        *vis = if is_refinery && refinery_jobs < REFINERY_QUEUE_LIMIT {
             Visibility::Inherited
         } else {
             Visibility::Hidden
@@ -682,9 +1140,19 @@ pub fn update_docking_menu_content(
     if let Some((cargo, credits, ship)) = player_data {
         if let Ok(mut text) = cargo_text.single_mut() {
             text.0 = format!(
-                "YOUR CARGO\nOre: {}/{}  Fuel: {:.0}/{:.0}",
-                cargo.ore, cargo.ore_capacity, ship.fuel, ship.fuel_capacity
+                "YOUR CARGO\nOre: {}/{}  Fuel: {:.0}/{:.0}  Parts: {}",
+                cargo.ore(),
+                cargo.ore_capacity(),
+                ship.fuel,
+                ship.fuel_capacity,
+                cargo.components()
             );
+            if let Some(goods) = cargo
+                .hold
+                .summary(&[Commodity::RawOre, Commodity::Components])
+            {
+                text.0.push_str(&format!("\nGoods: {}", goods));
+            }
         }
         if let Ok(mut text) = credits_text.single_mut() {
             text.0 = format!("Credits: {}", credits.amount);
@@ -698,25 +1166,21 @@ pub fn update_docking_menu_content(
 pub fn handle_docking_menu_clicks(
     mut commands: Commands,
     mut docking: ResMut<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut ledger: ResMut<Ledger>,
+    names: Query<&Name>,
     mut player_query: Query<(&mut Cargo, &mut Credits, &mut Ship), With<PlayerControl>>,
     mut stations: Query<(
         Entity,
         &mut Station,
-        Option<&ShipyardJob>,
-        Option<&RefineryJob>,
+        Option<&mut ShipyardQueue>,
+        Option<&mut RefineryQueue>,
         Option<&mut RefineryStorage>,
     )>,
     undock_btn: Query<&Interaction, (With<DockingMenuUndockButton>, Changed<Interaction>)>,
-    build_btn: Query<&Interaction, (With<DockingMenuBuildScoutButton>, Changed<Interaction>)>,
     convert_btns: Query<(&Interaction, &DockingMenuConvertButton), Changed<Interaction>>,
     cancel_btn: Query<&Interaction, (With<DockingMenuCancelButton>, Changed<Interaction>)>,
     collect_btn: Query<&Interaction, (With<DockingMenuCollectButton>, Changed<Interaction>)>,
-    buy_fuel_btns: Query<(&Interaction, &DockingMenuBuyFuelButton), Changed<Interaction>>,
-    sell_ore_btns: Query<(&Interaction, &DockingMenuSellOreButton), Changed<Interaction>>,
-    sell_all_ore_btn: Query<
-        &Interaction,
-        (With<DockingMenuSellAllOreButton>, Changed<Interaction>),
-    >,
 ) {
     let Some(station_entity) = docking.docked_at else {
         return;
@@ -733,69 +1197,71 @@ pub fn handle_docking_menu_clicks(
     // Handle cancel job
     for interaction in cancel_btn.iter() {
         if matches!(interaction, Interaction::Pressed) {
-            if let Ok((entity, _, shipyard_job, refinery_job, _)) = stations.get(station_entity) {
+            if let Ok((_, _, shipyard_queue, refinery_queue, _)) = stations.get_mut(station_entity)
+            {
                 let mut refund = 0u32;
-
-                if let Some(job) = shipyard_job {
-                    refund = job.ore_in / 2; // 50% refund
-                    commands.entity(entity).remove::<ShipyardJob>();
-                } else if let Some(job) = refinery_job {
-                    refund = job.ore_in / 2; // 50% refund
-                    commands.entity(entity).remove::<RefineryJob>();
+                let mut credit_refund = 0u32;
+
+                // Queues drop the most recently added job first. Hulls still
+                // waiting their turn are refunded in full; a batch hands back
+                // whatever ore it has not refined yet.
+                if let Some(job) = shipyard_queue.and_then(|mut queue| queue.jobs.pop_back()) {
+                    let share = if job.is_started() { 2 } else { 1 };
+                    refund = job.ore_in / share;
+                    credit_refund = job.credits_in / share;
+                } else if let Some(job) = refinery_queue.and_then(|mut queue| queue.jobs.pop_back())
+                {
+                    refund = job.unrefined_ore();
                 }
 
-                // Refund ore to player
-                if refund > 0 {
-                    if let Ok((mut cargo, _, _)) = player_query.single_mut() {
-                        cargo.add_ore(refund);
-                    }
+                // Refund ore and credits to player
+                if let Ok((mut cargo, mut credits, _)) = player_query.single_mut() {
+                    let ore = cargo.add_ore(refund);
+                    credits.add(credit_refund);
+                    ledger.record(
+                        LedgerEntry::new(
+                            ticks.tick,
+                            LedgerCategory::Refund,
+                            counterparty(&names, station_entity),
+                        )
+                        .with_credits(credit_refund as i64)
+                        .with_ore(ore as f32),
+                    );
                 }
             }
             return;
         }
     }
 
-    let Ok((mut cargo, mut credits, mut ship)) = player_query.single_mut() else {
+    let Ok((mut cargo, _, mut ship)) = player_query.single_mut() else {
         return;
     };
 
-    // Handle build scout
-    for interaction in build_btn.iter() {
-        if matches!(interaction, Interaction::Pressed) {
-            if cargo.ore >= SCOUT_BUILD_COST {
-                if let Ok((entity, station, existing_job, _, _)) = stations.get(station_entity) {
-                    if matches!(station.kind, StationKind::Shipyard) && existing_job.is_none() {
-                        cargo.remove_ore(SCOUT_BUILD_COST);
-                        commands.entity(entity).insert(ShipyardJob {
-                            ore_in: SCOUT_BUILD_COST,
-                            fuel_in: 0.0,
-                            remaining_seconds: SCOUT_BUILD_TIME,
-                        });
-                    }
-                }
-            }
-            return;
-        }
-    }
-
     // Handle convert ore
     for (interaction, convert_btn) in convert_btns.iter() {
         if matches!(interaction, Interaction::Pressed) {
             let ore_amount = convert_btn.ore_amount;
-            if cargo.ore >= ore_amount {
-                if let Ok((entity, station, _, existing_job, _)) = stations.get(station_entity) {
-                    if matches!(station.kind, StationKind::Refinery) && existing_job.is_none() {
-                        // Find the matching conversion option
-                        for (ore_in, fuel_out, time) in REFINERY_OPTIONS {
-                            if ore_in == ore_amount {
-                                cargo.remove_ore(ore_amount);
-                                commands.entity(entity).insert(RefineryJob {
-                                    ore_in: ore_amount,
-                                    fuel_out,
-                                    remaining_seconds: time,
-                                });
-                                break;
-                            }
+            if cargo.ore() >= ore_amount && REFINERY_OPTIONS.contains(&ore_amount) {
+                if let Ok((entity, station, _, queue, _)) = stations.get_mut(station_entity) {
+                    if matches!(station.kind, StationKind::Refinery) {
+                        let mut fresh = None;
+                        let queue = match queue {
+                            Some(queue) => queue.into_inner(),
+                            None => fresh.insert(RefineryQueue::default()),
+                        };
+                        if queue.push(RefineryJob::new(ore_amount)) {
+                            cargo.remove_ore(ore_amount);
+                            ledger.record(
+                                LedgerEntry::new(
+                                    ticks.tick,
+                                    LedgerCategory::Construction,
+                                    counterparty(&names, entity),
+                                )
+                                .with_ore(-(ore_amount as f32)),
+                            );
+                        }
+                        if let Some(queue) = fresh {
+                            commands.entity(entity).insert(queue);
                         }
                     }
                 }
@@ -813,67 +1279,1451 @@ pub fn handle_docking_menu_clicks(
                 if to_take > 0.0 {
                     storage.fuel -= to_take;
                     ship.fuel += to_take;
+                    ledger.record(
+                        LedgerEntry::new(
+                            ticks.tick,
+                            LedgerCategory::Construction,
+                            counterparty(&names, station_entity),
+                        )
+                        .with_fuel(to_take),
+                    );
                 }
             }
             return;
         }
     }
+}
 
-    // Handle buy fuel (Outpost)
-    for (interaction, buy_btn) in buy_fuel_btns.iter() {
-        if matches!(interaction, Interaction::Pressed) {
-            if let Ok((_, station, _, _, _)) = stations.get(station_entity) {
-                if matches!(station.kind, StationKind::Outpost) {
-                    // Check if player can afford and has cargo space
-                    let free_fuel_space = ship.fuel_capacity - ship.fuel;
-                    if credits.can_afford(buy_btn.credit_cost)
-                        && free_fuel_space >= buy_btn.fuel_amount as f32
-                    {
-                        credits.try_spend(buy_btn.credit_cost);
-                        ship.fuel += buy_btn.fuel_amount as f32;
-                    }
-                }
-            }
-            return;
-        }
+/// Trade fuel and goods with an Outpost at its current market prices
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_outpost_trade_clicks(
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    sector: Res<Sector>,
+    pirate_control: Res<PirateControl>,
+    mut ledger: ResMut<Ledger>,
+    names: Query<&Name>,
+    mut player_query: Query<(&mut Cargo, &mut Credits, &mut Ship), With<PlayerControl>>,
+    mut outposts: Query<(
+        &Station,
+        &OutpostTrade,
+        &mut OutpostMarket,
+        Option<&ZoneId>,
+        Option<&StationCrises>,
+    )>,
+    buy_fuel_btns: Query<(&Interaction, &DockingMenuBuyFuelButton), Changed<Interaction>>,
+    sell_ore_btns: Query<(&Interaction, &DockingMenuSellOreButton), Changed<Interaction>>,
+    sell_all_ore_btn: Query<
+        &Interaction,
+        (With<DockingMenuSellAllOreButton>, Changed<Interaction>),
+    >,
+    sell_goods_btn: Query<&Interaction, (With<DockingMenuSellGoodsButton>, Changed<Interaction>)>,
+    mut crises: Query<&mut Crisis>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    let pressed = |interaction: &Interaction| matches!(interaction, Interaction::Pressed);
+    let fuel_lot = buy_fuel_btns
+        .iter()
+        .find(|&(interaction, _)| pressed(interaction))
+        .map(|(_, button)| button.fuel_amount);
+    let ore_lot = sell_ore_btns
+        .iter()
+        .find(|&(interaction, _)| pressed(interaction))
+        .map(|(_, button)| button.ore_amount);
+    let sell_all_ore = sell_all_ore_btn.iter().any(pressed);
+    let sell_goods = sell_goods_btn.iter().any(pressed);
+    if fuel_lot.is_none() && ore_lot.is_none() && !sell_all_ore && !sell_goods {
+        return;
     }
 
-    // Handle sell ore (Outpost)
-    for (interaction, sell_btn) in sell_ore_btns.iter() {
-        if matches!(interaction, Interaction::Pressed) {
-            if let Ok((_, station, _, _, _)) = stations.get(station_entity) {
-                if matches!(station.kind, StationKind::Outpost) && cargo.ore >= sell_btn.ore_amount
-                {
-                    cargo.remove_ore(sell_btn.ore_amount);
-                    credits.add(sell_btn.credit_reward);
-                }
-            }
-            return;
-        }
+    let Ok((station, trade, mut market, zone, station_crises)) = outposts.get_mut(station_entity)
+    else {
+        return;
+    };
+    if !matches!(station.kind, StationKind::Outpost) || trade.refuses_trade() {
+        return;
     }
 
-    // Handle sell all ore (Outpost)
-    for interaction in sell_all_ore_btn.iter() {
-        if matches!(interaction, Interaction::Pressed) {
-            if let Ok((_, station, _, _, _)) = stations.get(station_entity) {
-                if matches!(station.kind, StationKind::Outpost) && cargo.ore > 0 {
-                    let credit_reward = outpost_ore_to_credits(cargo.ore);
-                    cargo.ore = 0;
-                    credits.add(credit_reward);
-                }
-            }
-            return;
+    let Ok((mut cargo, mut credits, mut ship)) = player_query.single_mut() else {
+        return;
+    };
+
+    let conditions = outpost_conditions(&sector, &pirate_control, zone, trade);
+    let entry = LedgerEntry::new(
+        ticks.tick,
+        LedgerCategory::Trade,
+        counterparty(&names, station_entity),
+    );
+
+    let traded = if let Some(fuel_amount) = fuel_lot {
+        let cost = market.ask_total(MarketGood::Fuel, fuel_amount, &conditions);
+        let free_fuel_space = ship.fuel_capacity - ship.fuel;
+        if credits.can_afford(cost)
+            && free_fuel_space >= fuel_amount as f32
+            && market.sell(MarketGood::Fuel, fuel_amount as f32)
+        {
+            credits.try_spend(cost);
+            ship.fuel += fuel_amount as f32;
+            ledger.record(
+                entry
+                    .with_credits(-(cost as i64))
+                    .with_fuel(fuel_amount as f32),
+            );
+            true
+        } else {
+            false
+        }
+    } else if let Some(ore_amount) = ore_lot.or(sell_all_ore.then(|| cargo.ore())) {
+        let ore = MarketGood::Goods(Commodity::RawOre);
+        if ore_amount > 0 && cargo.ore() >= ore_amount {
+            let reward = market.bid_total(ore, ore_amount, &conditions);
+            cargo.remove_ore(ore_amount);
+            market.buy(ore, ore_amount as f32);
+            credits.add(reward);
+            ledger.record(
+                entry
+                    .with_credits(reward as i64)
+                    .with_ore(-(ore_amount as f32)),
+            );
+            true
+        } else {
+            false
+        }
+    } else {
+        let goods: Vec<(Commodity, u32)> = cargo
+            .hold
+            .iter()
+            .filter(|(commodity, _)| *commodity != Commodity::RawOre)
+            .map(|(commodity, _)| (commodity, cargo.hold.whole(commodity)))
+            .filter(|(_, amount)| *amount > 0)
+            .collect();
+        let mut reward = 0;
+        let mut entry = entry;
+        for (commodity, amount) in &goods {
+            let good = MarketGood::Goods(*commodity);
+            reward += market.bid_total(good, *amount, &conditions);
+            cargo.remove_whole(*commodity, *amount);
+            market.buy(good, *amount as f32);
+            entry = entry.with_goods(*commodity, -(*amount as f32));
         }
+        credits.add(reward);
+        ledger.record(entry.with_credits(reward as i64));
+        !goods.is_empty()
+    };
+
+    if traded {
+        ease_flashpoint(station_crises, &mut crises);
     }
 }
 
-// =============================================================================
-// Utility Functions
-// =============================================================================
-
-fn progress_bar(progress: f32, width: usize) -> String {
-    let filled = ((progress * width as f32).round() as usize).min(width);
-    let empty = width - filled;
+/// Update Outpost standing, live prices and black-market visibility, and
+/// note the prices as market intel
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn update_outpost_trade_content(
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    sector: Res<Sector>,
+    pirate_control: Res<PirateControl>,
+    mut market_intel: ResMut<MarketIntel>,
+    outposts: Query<(&Station, &OutpostTrade, &OutpostMarket, Option<&ZoneId>)>,
+    mut standing_text: Query<
+        &mut Text,
+        (
+            With<DockingMenuOutpostStandingText>,
+            Without<DockingMenuOutpostMarketText>,
+            Without<DockingMenuBuyFuelButton>,
+            Without<DockingMenuSellOreButton>,
+        ),
+    >,
+    mut market_text: Query<
+        &mut Text,
+        (
+            With<DockingMenuOutpostMarketText>,
+            Without<DockingMenuBuyFuelButton>,
+            Without<DockingMenuSellOreButton>,
+        ),
+    >,
+    mut buy_fuel_btns: Query<
+        (&DockingMenuBuyFuelButton, &mut Text),
+        Without<DockingMenuSellOreButton>,
+    >,
+    mut sell_ore_btns: Query<(&DockingMenuSellOreButton, &mut Text)>,
+    mut black_market: Query<&mut Visibility, With<DockingMenuBlackMarketSection>>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    let outpost = outposts
+        .get(station_entity)
+        .ok()
+        .filter(|(station, ..)| matches!(station.kind, StationKind::Outpost));
+    let Some((_, trade, market, zone)) = outpost else {
+        if let Ok(mut vis) = black_market.single_mut() {
+            *vis = Visibility::Hidden;
+        }
+        return;
+    };
+
+    let conditions = outpost_conditions(&sector, &pirate_control, zone, trade);
+
+    if let Ok(mut text) = standing_text.single_mut() {
+        text.0 = outpost_standing_line(trade);
+    }
+
+    if let Ok(mut text) = market_text.single_mut() {
+        text.0 = outpost_market_line(market, &conditions);
+    }
+
+    for (button, mut text) in buy_fuel_btns.iter_mut() {
+        text.0 = fuel_lot_label(
+            button.fuel_amount,
+            market.ask_total(MarketGood::Fuel, button.fuel_amount, &conditions),
+        );
+    }
+
+    for (button, mut text) in sell_ore_btns.iter_mut() {
+        text.0 = ore_lot_label(
+            button.ore_amount,
+            market.bid_total(
+                MarketGood::Goods(Commodity::RawOre),
+                button.ore_amount,
+                &conditions,
+            ),
+        );
+    }
+
+    if let Some(zone) = zone {
+        market_intel.record(zone.0, ticks.tick, market.quotes(&conditions));
+    }
+
+    if let Ok(mut vis) = black_market.single_mut() {
+        *vis = if trade.black_market_open() && !trade.refuses_trade() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Buy pirate base intel from pirates docked at an Outpost
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_black_market_clicks(
+    mut commands: Commands,
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut player_query: Query<&mut Credits, With<PlayerControl>>,
+    mut outposts: Query<(&Transform, &mut OutpostTrade)>,
+    intel_btn: Query<&Interaction, (With<DockingMenuBuyIntelButton>, Changed<Interaction>)>,
+    bases: Query<(Entity, &Transform, &ZoneId), (With<PirateBase>, Without<Identified>)>,
+    mut nodes: Query<(&SystemNode, &mut SystemIntel)>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    if !intel_btn
+        .iter()
+        .any(|interaction| matches!(interaction, Interaction::Pressed))
+    {
+        return;
+    }
+
+    let Ok((outpost_transform, mut trade)) = outposts.get_mut(station_entity) else {
+        return;
+    };
+
+    if !trade.black_market_open() || trade.refuses_trade() {
+        return;
+    }
+
+    let Ok(mut credits) = player_query.single_mut() else {
+        return;
+    };
+
+    if !credits.can_afford(BLACK_MARKET_INTEL_COST) {
+        log.push("Black market: not enough credits".to_string());
+        return;
+    }
+
+    // Sell the closest base the player doesn't know about yet
+    let outpost_pos = Vec2::new(
+        outpost_transform.translation.x,
+        outpost_transform.translation.y,
+    );
+    let mut closest: Option<(Entity, u32, f32)> = None;
+    for (entity, transform, zone) in bases.iter() {
+        let pos = Vec2::new(transform.translation.x, transform.translation.y);
+        let dist = pos.distance(outpost_pos);
+        if closest.is_none_or(|(_, _, best)| dist < best) {
+            closest = Some((entity, zone.0, dist));
+        }
+    }
+
+    let Some((base_entity, base_zone, _)) = closest else {
+        log.push("Black market: no pirate base intel left to sell".to_string());
+        return;
+    };
+
+    credits.try_spend(BLACK_MARKET_INTEL_COST);
+    ledger.record(
+        LedgerEntry::new(ticks.tick, LedgerCategory::Trade, "Black market")
+            .with_credits(-(BLACK_MARKET_INTEL_COST as i64)),
+    );
+    trade.record_black_market_deal();
+    commands.entity(base_entity).insert(Identified);
+
+    for (node, mut intel) in nodes.iter_mut() {
+        if node.id == base_zone {
+            reveal_threat_intel(&mut intel, ticks.tick);
+        }
+    }
+
+    log.push(format!(
+        "Black market: pirate base located in zone {} (standing {})",
+        base_zone,
+        trade.standing_label()
+    ));
+}
+
+/// Offer a replacement hull at shipyards while the player is in the escape pod:
+/// order one, watch it build, then fit it once it reaches the hangar
+pub fn update_replacement_hull_button(
+    docking: Res<DockingState>,
+    stations: Query<(&Station, Option<&ShipyardQueue>, Option<&ShipyardStorage>)>,
+    player_query: Query<Has<EscapePod>, With<PlayerControl>>,
+    mut button: Query<(&mut Text, &mut Visibility), With<DockingMenuReplaceHullButton>>,
+) {
+    let Ok((mut text, mut vis)) = button.single_mut() else {
+        return;
+    };
+
+    let shipyard = docking
+        .docked_at
+        .and_then(|entity| stations.get(entity).ok())
+        .filter(|(station, _, _)| {
+            station.kind == StationKind::Shipyard && station.state != StationState::Failed
+        });
+    let in_pod = player_query.single().unwrap_or(false);
+
+    let Some((_, queue, storage)) = shipyard.filter(|_| in_pod) else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+
+    *vis = Visibility::Inherited;
+    text.0 = replacement_hull_label(queue, storage);
+}
+
+/// Order a replacement hull for the escape pod, paid up front and built on the
+/// docked shipyard's queue, or fit the finished hull waiting in its hangar
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_replacement_hull_clicks(
+    mut commands: Commands,
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut stations: Query<(
+        &Station,
+        &Name,
+        Option<&mut ShipyardQueue>,
+        Option<&mut ShipyardStorage>,
+    )>,
+    mut player_query: Query<
+        (Entity, &mut Cargo, &mut Credits, &mut Hull),
+        (With<PlayerControl>, With<EscapePod>),
+    >,
+    replace_btn: Query<&Interaction, (With<DockingMenuReplaceHullButton>, Changed<Interaction>)>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    if !replace_btn
+        .iter()
+        .any(|interaction| matches!(interaction, Interaction::Pressed))
+    {
+        return;
+    }
+
+    let Ok((station, name, queue, storage)) = stations.get_mut(station_entity) else {
+        return;
+    };
+    if station.kind != StationKind::Shipyard || station.state == StationState::Failed {
+        return;
+    }
+
+    let Ok((player, mut cargo, mut credits, mut hull)) = player_query.single_mut() else {
+        return;
+    };
+
+    if let Some(mut storage) = storage {
+        if storage.take_replacement_hull() {
+            *hull = ship_hull(ShipKind::PlayerShip);
+            commands
+                .entity(player)
+                .insert(ship_shields(ShipKind::PlayerShip))
+                .remove::<EscapePod>();
+            log.push(format!("Replacement hull fitted at {}", name.as_str()));
+            return;
+        }
+    }
+
+    let mut fresh = None;
+    let queue = match queue {
+        Some(queue) => queue.into_inner(),
+        None => fresh.insert(ShipyardQueue::default()),
+    };
+    if queue.has_replacement_hull() {
+        log.push("Shipyard: replacement hull is already being built".to_string());
+        return;
+    }
+    if queue.is_full() {
+        log.push("Shipyard: build queue is full".to_string());
+        return;
+    }
+    if let Some(reason) = replacement_hull_shortfall(cargo.ore(), credits.amount) {
+        log.push(format!("Shipyard: {}", reason));
+        return;
+    }
+
+    cargo.remove_ore(REPLACEMENT_HULL_ORE_COST);
+    credits.try_spend(REPLACEMENT_HULL_CREDIT_COST);
+    ledger.record(
+        LedgerEntry::new(ticks.tick, LedgerCategory::Construction, name.as_str())
+            .with_credits(-(REPLACEMENT_HULL_CREDIT_COST as i64))
+            .with_ore(-(REPLACEMENT_HULL_ORE_COST as f32)),
+    );
+    queue.push(ShipyardJob::new(ShipKind::PlayerShip));
+    log.push(format!("Replacement hull ordered at {}", name.as_str()));
+
+    if let Some(queue) = fresh {
+        commands.entity(station_entity).insert(queue);
+    }
+}
+
+/// Show the build buttons and scout launch orders at shipyards
+pub fn update_shipyard_content(
+    docking: Res<DockingState>,
+    stations: Query<(&Station, Option<&ShipyardQueue>)>,
+    mut section: Query<&mut Visibility, With<DockingMenuShipyardSection>>,
+    mut orders_btn: Query<&mut Text, With<DockingMenuScoutOrdersButton>>,
+) {
+    let shipyard = docking
+        .docked_at
+        .and_then(|entity| stations.get(entity).ok())
+        .filter(|(station, _)| station.kind == StationKind::Shipyard);
+
+    if let Ok(mut vis) = section.single_mut() {
+        *vis = if shipyard.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let Some((_, queue)) = shipyard else {
+        return;
+    };
+
+    if let Ok(mut text) = orders_btn.single_mut() {
+        let risk = queue.map(|queue| queue.scout_risk).unwrap_or_default();
+        text.0 = format!("> Scout orders: {:?}", risk);
+    }
+}
+
+/// Queue a hull at the docked shipyard, paid up front, or change the orders
+/// its scouts launch with
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_shipyard_clicks(
+    mut commands: Commands,
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    names: Query<&Name>,
+    mut stations: Query<(&Station, Option<&mut ShipyardQueue>)>,
+    mut player_query: Query<(&mut Cargo, &mut Credits), With<PlayerControl>>,
+    build_btns: Query<(&Interaction, &DockingMenuBuildShipButton), Changed<Interaction>>,
+    orders_btn: Query<&Interaction, (With<DockingMenuScoutOrdersButton>, Changed<Interaction>)>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    let build = build_btns
+        .iter()
+        .find(|(interaction, _)| matches!(interaction, Interaction::Pressed))
+        .map(|(_, button)| button.kind);
+    let orders = orders_btn
+        .iter()
+        .any(|interaction| matches!(interaction, Interaction::Pressed));
+    if build.is_none() && !orders {
+        return;
+    }
+
+    let Ok((station, queue)) = stations.get_mut(station_entity) else {
+        return;
+    };
+    if station.kind != StationKind::Shipyard {
+        return;
+    }
+
+    let mut fresh = None;
+    let queue = match queue {
+        Some(queue) => queue.into_inner(),
+        None => fresh.insert(ShipyardQueue::default()),
+    };
+
+    if orders {
+        // Cycle Cautious -> Balanced -> Bold and back round
+        let next = next_risk(queue.scout_risk, 1);
+        queue.scout_risk = if next == queue.scout_risk {
+            RiskTolerance::Cautious
+        } else {
+            next
+        };
+    }
+
+    if let Some(kind) = build {
+        if station.state == StationState::Failed {
+            log.push("Shipyard: failed stations cannot build".to_string());
+        } else if queue.is_full() {
+            log.push("Shipyard: build queue is full".to_string());
+        } else if let Ok((mut cargo, mut credits)) = player_query.single_mut() {
+            let cost = ship_build_cost(kind);
+            if let Some(reason) = ship_build_shortfall(kind, cargo.ore(), credits.amount) {
+                log.push(format!("Shipyard: {}", reason));
+            } else {
+                cargo.remove_ore(cost.ore);
+                credits.try_spend(cost.credits);
+                ledger.record(
+                    LedgerEntry::new(
+                        ticks.tick,
+                        LedgerCategory::Construction,
+                        counterparty(&names, station_entity),
+                    )
+                    .with_credits(-(cost.credits as i64))
+                    .with_ore(-(cost.ore as f32)),
+                );
+                queue.push(ShipyardJob::new(kind));
+                log.push(format!("Shipyard: {:?} queued", kind));
+            }
+        }
+    }
+
+    if let Some(queue) = fresh {
+        commands.entity(station_entity).insert(queue);
+    }
+}
+
+/// Show the target stock button at refineries
+pub fn update_refinery_target_button(
+    docking: Res<DockingState>,
+    stations: Query<(&Station, Option<&RefineryQueue>)>,
+    mut button: Query<(&mut Text, &mut Visibility), With<DockingMenuRefineryTargetButton>>,
+) {
+    let Ok((mut text, mut vis)) = button.single_mut() else {
+        return;
+    };
+
+    let refinery = docking
+        .docked_at
+        .and_then(|entity| stations.get(entity).ok())
+        .filter(|(station, _)| station.kind == StationKind::Refinery);
+
+    let Some((_, queue)) = refinery else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+
+    *vis = Visibility::Inherited;
+    let target_fraction = queue.map_or(RefineryQueue::default().target_fraction, |queue| {
+        queue.target_fraction
+    });
+    text.0 = format!("> Target stock: {:.0}%", target_fraction * 100.0);
+}
+
+/// Step the docked refinery to its next target stock level
+pub fn handle_refinery_target_clicks(
+    mut commands: Commands,
+    docking: Res<DockingState>,
+    mut stations: Query<(&Station, Option<&mut RefineryQueue>)>,
+    target_btn: Query<&Interaction, (With<DockingMenuRefineryTargetButton>, Changed<Interaction>)>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    if !target_btn
+        .iter()
+        .any(|interaction| matches!(interaction, Interaction::Pressed))
+    {
+        return;
+    }
+
+    let Ok((station, queue)) = stations.get_mut(station_entity) else {
+        return;
+    };
+    if station.kind != StationKind::Refinery {
+        return;
+    }
+
+    if let Some(mut queue) = queue {
+        queue.cycle_target();
+    } else {
+        let mut queue = RefineryQueue::default();
+        queue.cycle_target();
+        commands.entity(station_entity).insert(queue);
+    }
+}
+
+/// Update FuelDepot stock, reserve policy and visibility
+#[allow(clippy::type_complexity)]
+pub fn update_depot_content(
+    docking: Res<DockingState>,
+    depots: Query<(&Station, &FuelDepotPolicy)>,
+    mut section: Query<&mut Visibility, With<DockingMenuDepotSection>>,
+    mut depot_text: Query<&mut Text, With<DockingMenuDepotText>>,
+    mut reserve_btn: Query<
+        &mut Text,
+        (
+            With<DockingMenuDepotReserveButton>,
+            Without<DockingMenuDepotText>,
+        ),
+    >,
+) {
+    let depot = docking.docked_at.and_then(|entity| depots.get(entity).ok());
+
+    if let Ok(mut vis) = section.single_mut() {
+        *vis = if depot.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let Some((station, policy)) = depot else {
+        return;
+    };
+
+    if let Ok(mut text) = depot_text.single_mut() {
+        text.0 = depot_stock_line(station, policy);
+    }
+
+    if let Ok(mut text) = reserve_btn.single_mut() {
+        text.0 = format!("> Reserve policy: {:.0}%", policy.reserve_fraction * 100.0);
+    }
+}
+
+/// Draw fuel from a depot, deliver fuel to it, or change its reserve policy
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_depot_clicks(
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    names: Query<&Name>,
+    mut player_query: Query<&mut Ship, With<PlayerControl>>,
+    mut depots: Query<(&mut Station, &mut FuelDepotPolicy, Option<&StationCrises>)>,
+    mut crises: Query<&mut Crisis>,
+    draw_btn: Query<&Interaction, (With<DockingMenuDrawFuelButton>, Changed<Interaction>)>,
+    deliver_btn: Query<&Interaction, (With<DockingMenuDeliverFuelButton>, Changed<Interaction>)>,
+    reserve_btn: Query<&Interaction, (With<DockingMenuDepotReserveButton>, Changed<Interaction>)>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    let pressed = |interaction: &Interaction| matches!(interaction, Interaction::Pressed);
+    let draw = draw_btn.iter().any(pressed);
+    let deliver = deliver_btn.iter().any(pressed);
+    let reserve = reserve_btn.iter().any(pressed);
+    if !(draw || deliver || reserve) {
+        return;
+    }
+
+    let Ok((mut station, mut policy, station_crises)) = depots.get_mut(station_entity) else {
+        return;
+    };
+
+    if reserve {
+        policy.cycle_reserve();
+        return;
+    }
+
+    let Ok(mut ship) = player_query.single_mut() else {
+        return;
+    };
+    let entry = LedgerEntry::new(
+        ticks.tick,
+        LedgerCategory::Transfer,
+        counterparty(&names, station_entity),
+    );
+
+    if draw {
+        let free_space = (ship.fuel_capacity - ship.fuel).max(0.0);
+        let drawn = policy.dispense(&mut station, free_space);
+        if drawn > 0.0 {
+            ship.fuel += drawn;
+            ledger.record(entry.with_fuel(drawn));
+            log.push(format!("Drew {:.0} fuel from depot", drawn));
+        } else if free_space > 0.0 {
+            log.push("Depot: no fuel above the reserve".to_string());
+        }
+        return;
+    }
+
+    let tank_space = (station.fuel_capacity - station.fuel).max(0.0);
+    let delivered = DEPOT_DELIVERY_AMOUNT.min(ship.fuel).min(tank_space);
+    if delivered <= 0.0 {
+        return;
+    }
+    ship.fuel -= delivered;
+    station.fuel += delivered;
+    ledger.record(entry.with_fuel(-delivered));
+    log.push(format!("Delivered {:.0} fuel to depot", delivered));
+
+    // A delivery eases a depot's own shortage just as it does anywhere else
+    let shortage = station_crises
+        .and_then(|station_crises| station_crises.get(CrisisType::FuelShortage))
+        .and_then(|open| crises.get_mut(open.entity).ok());
+    if let Some(mut crisis) = shortage {
+        if crisis.intervene() {
+            log.push("Delivery eased the station's shortage".to_string());
+        }
+    }
+}
+
+/// Show the ore hold and fuel of the player's station the ship is docked at
+pub fn update_station_inventory(
+    docking: Res<DockingState>,
+    stations: Query<(&Station, &StationProduction, Option<&RefineryStorage>)>,
+    mut section: Query<&mut Visibility, With<DockingMenuInventorySection>>,
+    mut inventory_text: Query<&mut Text, With<DockingMenuInventoryText>>,
+) {
+    let inventory = docking
+        .docked_at
+        .and_then(|entity| stations.get(entity).ok())
+        .filter(|(station, _, _)| !is_npc_station(station.kind));
+
+    if let Ok(mut vis) = section.single_mut() {
+        *vis = if inventory.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    let Some((station, production, storage)) = inventory else {
+        return;
+    };
+
+    if let Ok(mut text) = inventory_text.single_mut() {
+        text.0 = inventory_line(station, production, storage);
+    }
+}
+
+/// Show the docked station's recipe, if it can run any
+pub fn update_recipe_button(
+    docking: Res<DockingState>,
+    stations: Query<(&Station, Option<&StationRecipe>)>,
+    mut button: Query<(&mut Text, &mut Visibility), With<DockingMenuRecipeButton>>,
+) {
+    let Ok((mut text, mut vis)) = button.single_mut() else {
+        return;
+    };
+
+    let station = docking
+        .docked_at
+        .and_then(|entity| stations.get(entity).ok())
+        .filter(|(station, _)| station_recipes(station.kind).next().is_some());
+
+    let Some((_, recipe)) = station else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+
+    *vis = Visibility::Inherited;
+    text.0 = recipe_line(recipe.and_then(|recipe| recipe.active));
+}
+
+/// Step the docked station to its next recipe, or back to idle
+pub fn handle_recipe_clicks(
+    mut commands: Commands,
+    docking: Res<DockingState>,
+    mut log: ResMut<EventLog>,
+    mut stations: Query<(&Station, Option<&mut StationRecipe>)>,
+    recipe_btn: Query<&Interaction, (With<DockingMenuRecipeButton>, Changed<Interaction>)>,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    if !recipe_btn
+        .iter()
+        .any(|interaction| matches!(interaction, Interaction::Pressed))
+    {
+        return;
+    }
+
+    let Ok((station, recipe)) = stations.get_mut(station_entity) else {
+        return;
+    };
+    if station_recipes(station.kind).next().is_none() {
+        return;
+    }
+
+    let mut fresh = None;
+    let recipe = match recipe {
+        Some(recipe) => recipe.into_inner(),
+        None => fresh.insert(StationRecipe::default()),
+    };
+    recipe.cycle(station.kind);
+    log.push(format!(
+        "{:?} recipe: {}",
+        station.kind,
+        recipe.active.map_or("idle", |active| active.spec().name)
+    ));
+    if let Some(recipe) = fresh {
+        commands.entity(station_entity).insert(recipe);
+    }
+}
+
+fn recipe_line(active: Option<RecipeKind>) -> String {
+    let Some(active) = active else {
+        return "> Recipe: idle".to_string();
+    };
+    let spec = active.spec();
+    let (input, input_amount) = spec.input;
+    let (output, output_amount) = spec.output;
+    format!(
+        "> Recipe: {} ({:.0} {} -> {:.0} {}, {:.0}s)",
+        spec.name,
+        input_amount,
+        input.name().to_lowercase(),
+        output_amount,
+        output.name().to_lowercase(),
+        spec.seconds
+    )
+}
+
+/// Move as much of a station's hold into the cargo hold as fits, ore first
+pub fn handle_withdraw_clicks(
+    docking: Res<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut ledger: ResMut<Ledger>,
+    mut player_query: Query<&mut Cargo, With<PlayerControl>>,
+    mut stations: Query<(&Station, &Name, &mut StationProduction)>,
+    withdraw_btn: Query<&Interaction, (With<DockingMenuWithdrawOreButton>, Changed<Interaction>)>,
+) {
+    let pressed = |interaction: &Interaction| matches!(interaction, Interaction::Pressed);
+    if !withdraw_btn.iter().any(pressed) {
+        return;
+    }
+
+    let Some((station, name, mut production)) = docking
+        .docked_at
+        .and_then(|entity| stations.get_mut(entity).ok())
+    else {
+        return;
+    };
+    if is_npc_station(station.kind) {
+        return;
+    }
+
+    let Ok(mut cargo) = player_query.single_mut() else {
+        return;
+    };
+
+    // The cargo hold only takes whole units
+    let held: Vec<(Commodity, f32)> = production.hold.iter().collect();
+    let mut moved = Vec::new();
+    let mut entry = LedgerEntry::new(ticks.tick, LedgerCategory::Transfer, name.as_str());
+    for (commodity, amount) in held {
+        let units = cargo.add_whole(commodity, amount.floor() as u32);
+        if units > 0 {
+            production.hold.remove(commodity, units as f32);
+            entry = entry.with_goods(commodity, units as f32);
+            moved.push(format!("{} {}", units, commodity.name().to_lowercase()));
+        }
+    }
+
+    if moved.is_empty() {
+        if production.hold.summary(&[]).is_none() {
+            log.push("Withdraw: nothing in the station's hold".to_string());
+        } else {
+            log.push("Withdraw: cargo hold is full".to_string());
+        }
+        return;
+    }
+    ledger.record(entry);
+    log.push(format!(
+        "Withdrew {} from {:?}",
+        moved.join(", "),
+        station.kind
+    ));
+}
+
+/// Offer station orders at the player's own stations; restarts only once failed
+#[allow(clippy::type_complexity)]
+pub fn update_station_orders(
+    docking: Res<DockingState>,
+    stations: Query<&Station>,
+    mut section: Query<&mut Visibility, With<DockingMenuOrdersSection>>,
+    mut restart_btn: Query<
+        &mut Visibility,
+        (
+            With<DockingMenuRestartButton>,
+            Without<DockingMenuOrdersSection>,
+        ),
+    >,
+) {
+    let station = docking
+        .docked_at
+        .and_then(|entity| stations.get(entity).ok());
+
+    if let Ok(mut vis) = section.single_mut() {
+        *vis = if station.is_some_and(|station| !is_npc_station(station.kind)) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    if let Ok(mut vis) = restart_btn.single_mut() {
+        *vis = if station.is_some_and(|station| station.state == StationState::Failed) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Carry out a station order: patch the station's hull, restart a failed
+/// station for ore, fuel and time, walk away from it with part of its stock,
+/// or decommission it so its stock moves to the next station the player docks at
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn handle_station_order_clicks(
+    mut commands: Commands,
+    mut docking: ResMut<DockingState>,
+    ticks: Res<SimTickCount>,
+    mut log: ResMut<EventLog>,
+    mut history: ResMut<CrisisHistory>,
+    mut ledger: ResMut<Ledger>,
+    mut player_query: Query<(&mut Cargo, &mut Ship), With<PlayerControl>>,
+    mut stations: Query<(
+        &mut Station,
+        &Name,
+        &mut StationCrises,
+        Option<&mut Hull>,
+        Option<&StationProduction>,
+        Option<&RefineryStorage>,
+    )>,
+    mut crises: Query<(Entity, &mut Crisis)>,
+    repair_btn: Query<&Interaction, (With<DockingMenuRepairButton>, Changed<Interaction>)>,
+    restart_btn: Query<&Interaction, (With<DockingMenuRestartButton>, Changed<Interaction>)>,
+    abandon_btn: Query<&Interaction, (With<DockingMenuAbandonButton>, Changed<Interaction>)>,
+    decommission_btn: Query<
+        &Interaction,
+        (With<DockingMenuDecommissionButton>, Changed<Interaction>),
+    >,
+) {
+    let Some(station_entity) = docking.docked_at else {
+        return;
+    };
+
+    let pressed = |interaction: &Interaction| matches!(interaction, Interaction::Pressed);
+    let repair = repair_btn.iter().any(pressed);
+    let restart = restart_btn.iter().any(pressed);
+    let abandon = abandon_btn.iter().any(pressed);
+    let decommission = decommission_btn.iter().any(pressed);
+    if !(repair || restart || abandon || decommission) {
+        return;
+    }
+
+    let Ok((mut cargo, mut ship)) = player_query.single_mut() else {
+        return;
+    };
+    let Ok((mut station, name, mut station_crises, hull, production, storage)) =
+        stations.get_mut(station_entity)
+    else {
+        return;
+    };
+    if is_npc_station(station.kind) {
+        return;
+    }
+    let name = name.as_str().to_string();
+
+    if repair {
+        let Some(mut hull) = hull else {
+            return;
+        };
+        let ore = hull.patch(cargo.ore());
+        if ore == 0 {
+            if hull.current >= hull.max {
+                log.push("Repair: hull is already whole".to_string());
+            } else {
+                log.push(format!("Repair: needs {} ore", HULL_PATCH_ORE));
+            }
+            return;
+        }
+        cargo.remove_ore(ore);
+        ledger.record(
+            LedgerEntry::new(ticks.tick, LedgerCategory::Upkeep, name.as_str())
+                .with_ore(-(ore as f32)),
+        );
+        let breach = station_crises
+            .get(CrisisType::HullBreach)
+            .and_then(|open| crises.get_mut(open.entity).ok());
+        if let Some((_, mut crisis)) = breach {
+            crisis.intervene();
+        }
+        log.push(format!(
+            "Patched {} ({:.0}/{:.0} hull)",
+            name, hull.current, hull.max
+        ));
+        return;
+    }
+
+    if restart {
+        if station.state != StationState::Failed {
+            return;
+        }
+        if let Some(reason) = restart_shortfall(cargo.ore(), ship.fuel) {
+            log.push(format!("Restart: {}", reason));
+            return;
+        }
+
+        cargo.remove_ore(STATION_RESTART_ORE_COST);
+        ship.fuel -= STATION_RESTART_FUEL_COST;
+        ledger.record(
+            LedgerEntry::new(ticks.tick, LedgerCategory::Upkeep, name.as_str())
+                .with_ore(-(STATION_RESTART_ORE_COST as f32))
+                .with_fuel(-STATION_RESTART_FUEL_COST),
+        );
+        station.fuel = (station.fuel + STATION_RESTART_FUEL_COST).min(station.fuel_capacity);
+        station.state = StationState::Deploying;
+        // The station comes back clean; whatever it failed with is settled
+        *station_crises = StationCrises::default();
+        settle_station_crises(
+            &mut commands,
+            &mut log,
+            &mut history,
+            ticks.tick,
+            &crises,
+            station_entity,
+            CrisisResolution::Restarted,
+        );
+        commands
+            .entity(station_entity)
+            .insert(StationBuild {
+                remaining_seconds: STATION_RESTART_SECONDS,
+            })
+            .remove::<Decommissioned>();
+        log.push(format!(
+            "{} restarting ({}s)",
+            name, STATION_RESTART_SECONDS as u32
+        ));
+        return;
+    }
+
+    if abandon {
+        let salvage = StationStock::of(&station, production, storage).salvage();
+        let ore = cargo.add_ore(salvage.ore as u32);
+        let fuel = salvage.fuel.min((ship.fuel_capacity - ship.fuel).max(0.0));
+        ship.fuel += fuel;
+        ledger.record(
+            LedgerEntry::new(ticks.tick, LedgerCategory::Refund, name.as_str())
+                .with_ore(ore as f32)
+                .with_fuel(fuel),
+        );
+        log.push(format!(
+            "{} abandoned: salvaged {} ore, {:.0} fuel",
+            name, ore, fuel
+        ));
+        commands.entity(station_entity).despawn();
+    } else {
+        // The station shuts down where it is; its stock waits for the player
+        // to pick the station that takes it by docking there
+        station.state = StationState::Failed;
+        *station_crises = StationCrises::default();
+        commands.entity(station_entity).insert(Decommissioned);
+        log.push(format!(
+            "{} decommissioned: dock at one of your working stations to move its stock",
+            name
+        ));
+    }
+
+    // Walking away settles whatever crises the station still had open
+    settle_station_crises(
+        &mut commands,
+        &mut log,
+        &mut history,
+        ticks.tick,
+        &crises,
+        station_entity,
+        CrisisResolution::Abandoned,
+    );
+    docking.undock();
+}
+
+/// Move the stock of decommissioned stations into the player's station they
+/// just docked at, then take the empty stations down
+#[allow(clippy::type_complexity)]
+pub fn deliver_decommissioned_stock(
+    mut commands: Commands,
+    docking: Res<DockingState>,
+    mut log: ResMut<EventLog>,
+    decommissioned: Query<
+        (
+            Entity,
+            &Station,
+            &Name,
+            Option<&StationProduction>,
+            Option<&RefineryStorage>,
+        ),
+        With<Decommissioned>,
+    >,
+    mut stations: Query<
+        (
+            &mut Station,
+            &Name,
+            Option<&mut StationProduction>,
+            Option<&mut RefineryStorage>,
+        ),
+        Without<Decommissioned>,
+    >,
+) {
+    if !docking.is_changed() {
+        return;
+    }
+    let Some(target) = docking.docked_at else {
+        return;
+    };
+    let Ok((mut station, target_name, mut production, mut storage)) = stations.get_mut(target)
+    else {
+        return;
+    };
+    if is_npc_station(station.kind) || station.state == StationState::Failed {
+        return;
+    }
+
+    for (entity, old, name, old_production, old_storage) in decommissioned.iter() {
+        let stock = StationStock::of(old, old_production, old_storage);
+        let moved = stock.deliver(
+            &mut station,
+            production.as_deref_mut(),
+            storage.as_deref_mut(),
+        );
+        log.push(format!(
+            "{} decommissioned: moved {:.0} ore, {:.0} fuel to {}",
+            name.as_str(),
+            moved.ore,
+            moved.fuel,
+            target_name.as_str()
+        ));
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Close every crisis still open at a station with the given resolution
+fn settle_station_crises(
+    commands: &mut Commands,
+    log: &mut EventLog,
+    history: &mut CrisisHistory,
+    tick: u64,
+    crises: &Query<(Entity, &mut Crisis)>,
+    station: Entity,
+    resolution: CrisisResolution,
+) {
+    for (crisis_entity, crisis) in crises
+        .iter()
+        .filter(|(_, crisis)| crisis.station == station)
+    {
+        log.push(format!(
+            "Station {:?} crisis {}: {:?}",
+            crisis.station_kind,
+            resolution.label(),
+            crisis.crisis_type
+        ));
+        history.push(crisis.record(tick, resolution));
+        commands.entity(crisis_entity).despawn();
+    }
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+fn replacement_hull_shortfall(ore: u32, credits: u32) -> Option<String> {
+    if ore < REPLACEMENT_HULL_ORE_COST {
+        return Some(format!(
+            "replacement hull needs {} ore (have {})",
+            REPLACEMENT_HULL_ORE_COST, ore
+        ));
+    }
+    if credits < REPLACEMENT_HULL_CREDIT_COST {
+        return Some(format!(
+            "replacement hull needs {} cr (have {})",
+            REPLACEMENT_HULL_CREDIT_COST, credits
+        ));
+    }
+    None
+}
+
+/// Button text for the replacement hull, following the order from the
+/// queue through to the hangar
+fn replacement_hull_label(
+    queue: Option<&ShipyardQueue>,
+    storage: Option<&ShipyardStorage>,
+) -> String {
+    let ready = storage.is_some_and(|storage| storage.hangar.contains(&ShipKind::PlayerShip));
+    let building = queue.and_then(|queue| {
+        queue
+            .jobs
+            .iter()
+            .find(|job| job.kind == ShipKind::PlayerShip)
+    });
+    if ready {
+        "Fit replacement hull".to_string()
+    } else if let Some(job) = building {
+        format!("Replacement hull building ({:.0}%)", job.progress() * 100.0)
+    } else {
+        format!(
+            "Replacement hull ({} ore, {} cr)",
+            REPLACEMENT_HULL_ORE_COST, REPLACEMENT_HULL_CREDIT_COST
+        )
+    }
+}
+
+fn ship_build_shortfall(kind: ShipKind, ore: u32, credits: u32) -> Option<String> {
+    let cost = ship_build_cost(kind);
+    if ore < cost.ore {
+        Some(format!("{:?} needs {} ore (have {})", kind, cost.ore, ore))
+    } else if credits < cost.credits {
+        Some(format!(
+            "{:?} needs {} cr (have {})",
+            kind, cost.credits, credits
+        ))
+    } else {
+        None
+    }
+}
+
+/// The shipyard's build queue, front first with a progress bar, and the
+/// ships waiting in its hangar
+fn shipyard_queue_lines(
+    queue: Option<&ShipyardQueue>,
+    storage: Option<&ShipyardStorage>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (index, job) in queue.iter().flat_map(|queue| queue.jobs.iter()).enumerate() {
+        if index == 0 {
+            lines.push(format!(
+                "BUILDING: {:?} ({:.0}s remaining)",
+                job.kind, job.remaining_seconds
+            ));
+        } else {
+            lines.push(format!("QUEUED {}: {:?}", index, job.kind));
+        }
+        lines.push(format!("[{}]", progress_bar(job.progress(), 20)));
+    }
+    if let Some(storage) = storage.filter(|storage| !storage.hangar.is_empty()) {
+        let waiting: Vec<String> = storage
+            .hangar
+            .iter()
+            .map(|kind| format!("{:?}", kind))
+            .collect();
+        lines.push(format!(
+            "HANGAR ({}/{}): {} - awaiting launch fuel",
+            storage.hangar.len(),
+            storage.capacity,
+            waiting.join(", ")
+        ));
+    }
+    lines
+}
+
+/// The refinery's batch queue, front first with a progress bar, then its
+/// input hold, fuel stock against target and current rate
+fn refinery_queue_lines(
+    station: &Station,
+    queue: Option<&RefineryQueue>,
+    production: Option<&StationProduction>,
+    storage: Option<&RefineryStorage>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (index, job) in queue.iter().flat_map(|queue| queue.jobs.iter()).enumerate() {
+        if index == 0 {
+            lines.push(format!(
+                "REFINING: {} ore -> {} fuel ({:.1} ore left)",
+                job.ore_in, job.fuel_out as u32, job.ore_remaining
+            ));
+        } else {
+            lines.push(format!("QUEUED {}: {} ore", index, job.ore_in));
+        }
+        lines.push(format!("[{}]", progress_bar(job.progress(), 20)));
+    }
+
+    if let Some(production) = production {
+        lines.push(format!(
+            "Input hold: {:.0}/{:.0} ore",
+            production.ore(),
+            production.ore_capacity()
+        ));
+    }
+    let target_fraction = queue.map_or(RefineryQueue::default().target_fraction, |queue| {
+        queue.target_fraction
+    });
+    let (fuel, capacity) = storage.map_or((0.0, RefineryStorage::new().fuel_capacity), |s| {
+        (s.fuel, s.fuel_capacity)
+    });
+    lines.push(format!(
+        "Stock: {:.0}/{:.0} fuel (target {:.0}%) | {:.1} ore/min",
+        fuel,
+        capacity,
+        target_fraction * 100.0,
+        refinery_ore_per_minute(station.state)
+    ));
+    lines
+}
+
+fn restart_shortfall(ore: u32, fuel: f32) -> Option<String> {
+    if ore < STATION_RESTART_ORE_COST {
+        return Some(format!(
+            "needs {} ore (have {})",
+            STATION_RESTART_ORE_COST, ore
+        ));
+    }
+    if fuel < STATION_RESTART_FUEL_COST {
+        return Some(format!(
+            "needs {:.0} fuel (have {:.0})",
+            STATION_RESTART_FUEL_COST, fuel
+        ));
+    }
+    None
+}
+
+/// Doing business with a protesting Outpost cools its political flashpoint
+fn ease_flashpoint(station_crises: Option<&StationCrises>, crises: &mut Query<&mut Crisis>) {
+    let flashpoint = station_crises
+        .and_then(|station_crises| station_crises.get(CrisisType::PoliticalFlashpoint))
+        .and_then(|open| crises.get_mut(open.entity).ok());
+    if let Some(mut crisis) = flashpoint {
+        crisis.intervene();
+    }
+}
+
+fn depot_stock_line(station: &Station, policy: &FuelDepotPolicy) -> String {
+    format!(
+        "Available: {:.0} | Reserve: {:.0}%",
+        policy.available(station),
+        policy.reserve_fraction * 100.0
+    )
+}
+
+fn inventory_line(
+    station: &Station,
+    production: &StationProduction,
+    storage: Option<&RefineryStorage>,
+) -> String {
+    let mut line = format!(
+        "Ore: {:.0}/{:.0} | Fuel: {:.0}/{:.0}",
+        production.ore().floor(),
+        production.ore_capacity(),
+        station.fuel,
+        station.fuel_capacity
+    );
+    if let Some(goods) = production.hold.summary(&[Commodity::RawOre]) {
+        line.push_str(&format!("\nGoods: {}", goods));
+    }
+    if let Some(storage) = storage {
+        line.push_str(&format!(
+            "\nStored fuel: {:.0}/{:.0}",
+            storage.fuel, storage.fuel_capacity
+        ));
+    }
+    line
+}
+
+/// Who the player dealt with, as the ledger names them
+fn counterparty(names: &Query<&Name>, entity: Entity) -> String {
+    names
+        .get(entity)
+        .map_or_else(|_| "Station".to_string(), |name| name.as_str().to_string())
+}
+
+/// Price terms at an Outpost: its zone's conditions, pirate grip and trade terms
+fn outpost_conditions(
+    sector: &Sector,
+    pirate_control: &PirateControl,
+    zone: Option<&ZoneId>,
+    trade: &OutpostTrade,
+) -> MarketConditions {
+    let zone = zone.map(|zone| zone.0);
+    MarketConditions::new(
+        zone.and_then(|zone| zone_modifier_of(sector, zone)),
+        zone.map_or(0.0, |zone| pirate_control.level(zone)),
+        trade,
+    )
+}
+
+fn fuel_lot_label(fuel_amount: u32, credits: u32) -> String {
+    format!("> {} fuel for {} cr", fuel_amount, credits)
+}
+
+fn ore_lot_label(ore_amount: u32, credits: u32) -> String {
+    format!("> {} ore -> {} cr", ore_amount, credits)
+}
+
+fn outpost_market_line(market: &OutpostMarket, conditions: &MarketConditions) -> String {
+    let bids: Vec<String> = COMMODITIES
+        .iter()
+        .map(|commodity| {
+            format!(
+                "{} {:.1}",
+                commodity.name(),
+                market.bid(MarketGood::Goods(*commodity), conditions)
+            )
+        })
+        .collect();
+    format!(
+        "Fuel stock: {:.0}\nPays per unit: {}",
+        market.stock(MarketGood::Fuel),
+        bids.join(", ")
+    )
+}
+
+fn outpost_standing_line(trade: &OutpostTrade) -> String {
+    if trade.refuses_trade() {
+        return "Standing: Hostile | Refuses to trade".to_string();
+    }
+
+    format!(
+        "Standing: {} | Prices x{:.2}",
+        trade.standing_label(),
+        trade.price_multiplier
+    )
+}
+
+fn progress_bar(progress: f32, width: usize) -> String {
+    let filled = ((progress * width as f32).round() as usize).min(width);
+    let empty = width - filled;
     format!("{}{}", "=".repeat(filled), "-".repeat(empty))
 }
 
@@ -884,6 +2734,53 @@ fn progress_bar(progress: f32, width: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::ecs::system::SystemState;
+
+    #[test]
+    fn replacement_hull_needs_ore_and_credits() {
+        assert!(replacement_hull_shortfall(5, 100).is_some_and(|reason| reason.contains("ore")));
+        assert!(replacement_hull_shortfall(30, 10).is_some_and(|reason| reason.contains("cr")));
+        assert_eq!(
+            replacement_hull_shortfall(REPLACEMENT_HULL_ORE_COST, REPLACEMENT_HULL_CREDIT_COST),
+            None
+        );
+    }
+
+    #[test]
+    fn replacement_hull_label_follows_the_order() {
+        assert_eq!(
+            replacement_hull_label(None, None),
+            format!(
+                "Replacement hull ({} ore, {} cr)",
+                REPLACEMENT_HULL_ORE_COST, REPLACEMENT_HULL_CREDIT_COST
+            )
+        );
+
+        let mut queue = ShipyardQueue::default();
+        queue.push(ShipyardJob::new(ShipKind::PlayerShip));
+        queue.jobs[0].tick(45.0);
+        assert_eq!(
+            replacement_hull_label(Some(&queue), None),
+            "Replacement hull building (50%)"
+        );
+
+        let mut storage = ShipyardStorage::new();
+        storage.add_ship(ShipKind::PlayerShip);
+        assert_eq!(
+            replacement_hull_label(None, Some(&storage)),
+            "Fit replacement hull"
+        );
+    }
+
+    #[test]
+    fn restart_needs_ore_and_fuel() {
+        assert!(restart_shortfall(5, 50.0).is_some_and(|reason| reason.contains("ore")));
+        assert!(restart_shortfall(20, 5.0).is_some_and(|reason| reason.contains("fuel")));
+        assert_eq!(
+            restart_shortfall(STATION_RESTART_ORE_COST, STATION_RESTART_FUEL_COST),
+            None
+        );
+    }
 
     #[test]
     fn progress_bar_empty() {
@@ -902,12 +2799,37 @@ mod tests {
 
     #[test]
     fn scout_build_cost_is_15_ore() {
-        assert_eq!(SCOUT_BUILD_COST, 15);
+        assert_eq!(ship_build_cost(ShipKind::Scout).ore, 15);
     }
 
     #[test]
     fn scout_build_time_is_120_seconds() {
-        assert!((SCOUT_BUILD_TIME - 120.0).abs() < f32::EPSILON);
+        assert!((ship_build_cost(ShipKind::Scout).seconds - 120.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn ship_build_needs_ore_and_credits() {
+        assert!(ship_build_shortfall(ShipKind::Security, 10, 100)
+            .is_some_and(|reason| reason.contains("ore")));
+        assert!(ship_build_shortfall(ShipKind::Security, 40, 10)
+            .is_some_and(|reason| reason.contains("cr")));
+        assert_eq!(ship_build_shortfall(ShipKind::Scout, 15, 0), None);
+    }
+
+    #[test]
+    fn shipyard_queue_lines_show_progress_and_hangar() {
+        let mut queue = ShipyardQueue::default();
+        queue.push(ShipyardJob::new(ShipKind::Miner));
+        queue.push(ShipyardJob::new(ShipKind::Scout));
+        queue.jobs[0].tick(75.0);
+        let mut storage = ShipyardStorage::new();
+        storage.add_ship(ShipKind::Security);
+
+        let lines = shipyard_queue_lines(Some(&queue), Some(&storage));
+        assert_eq!(lines[0], "BUILDING: Miner (75s remaining)");
+        assert_eq!(lines[1], format!("[{}]", progress_bar(0.5, 20)));
+        assert_eq!(lines[2], "QUEUED 1: Scout");
+        assert!(lines[4].contains("HANGAR (1/3): Security"));
     }
 
     #[test]
@@ -917,13 +2839,226 @@ mod tests {
 
     #[test]
     fn refinery_first_option_is_5_ore() {
-        assert_eq!(REFINERY_OPTIONS[0].0, 5);
-        assert!((REFINERY_OPTIONS[0].1 - 10.0).abs() < f32::EPSILON);
+        assert_eq!(REFINERY_OPTIONS[0], 5);
+        assert!((RefineryJob::new(REFINERY_OPTIONS[0]).fuel_out - 10.0).abs() < f32::EPSILON);
     }
 
     #[test]
     fn refinery_second_option_is_10_ore() {
-        assert_eq!(REFINERY_OPTIONS[1].0, 10);
-        assert!((REFINERY_OPTIONS[1].1 - 20.0).abs() < f32::EPSILON);
+        assert_eq!(REFINERY_OPTIONS[1], 10);
+        assert!((RefineryJob::new(REFINERY_OPTIONS[1]).fuel_out - 20.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn refinery_queue_lines_show_batches_hold_and_target() {
+        let station = Station {
+            kind: StationKind::Refinery,
+            state: StationState::Strained,
+            fuel: 30.0,
+            fuel_capacity: 60.0,
+        };
+        let mut queue = RefineryQueue::default();
+        queue.push(RefineryJob::new(10));
+        queue.push(RefineryJob::new(5));
+        queue.jobs[0].process(5.0);
+        let production = StationProduction::new(80.0).with_ore(12.0);
+
+        let lines = refinery_queue_lines(&station, Some(&queue), Some(&production), None);
+        assert_eq!(lines[0], "REFINING: 10 ore -> 20 fuel (5.0 ore left)");
+        assert_eq!(lines[1], format!("[{}]", progress_bar(0.5, 20)));
+        assert_eq!(lines[2], "QUEUED 1: 5 ore");
+        assert_eq!(lines[4], "Input hold: 12/80 ore");
+        assert_eq!(lines[5], "Stock: 0/50 fuel (target 50%) | 3.0 ore/min");
+    }
+
+    #[test]
+    fn depot_stock_line_shows_fuel_above_reserve() {
+        let station = Station {
+            kind: StationKind::FuelDepot,
+            state: StationState::Operational,
+            fuel: 90.0,
+            fuel_capacity: 120.0,
+        };
+        let policy = FuelDepotPolicy {
+            reserve_fraction: 0.5,
+        };
+        assert_eq!(
+            depot_stock_line(&station, &policy),
+            "Available: 30 | Reserve: 50%"
+        );
+    }
+
+    #[test]
+    fn inventory_line_shows_whole_ore_and_stored_fuel() {
+        let station = Station {
+            kind: StationKind::Refinery,
+            state: StationState::Operational,
+            fuel: 30.0,
+            fuel_capacity: 60.0,
+        };
+        let production = StationProduction::new(80.0).with_ore(12.8);
+        assert_eq!(
+            inventory_line(&station, &production, None),
+            "Ore: 12/80 | Fuel: 30/60"
+        );
+
+        let storage = RefineryStorage {
+            fuel: 5.0,
+            fuel_capacity: 50.0,
+        };
+        assert_eq!(
+            inventory_line(&station, &production, Some(&storage)),
+            "Ore: 12/80 | Fuel: 30/60\nStored fuel: 5/50"
+        );
+    }
+
+    #[test]
+    fn inventory_line_lists_goods_beside_ore() {
+        let station = Station {
+            kind: StationKind::Shipyard,
+            state: StationState::Operational,
+            fuel: 30.0,
+            fuel_capacity: 60.0,
+        };
+        let mut production = StationProduction::new(100.0).with_ore(12.0);
+        production.hold.add(Commodity::Alloys, 3.0);
+        production.hold.add(Commodity::Components, 4.5);
+        assert_eq!(
+            inventory_line(&station, &production, None),
+            "Ore: 12/100 | Fuel: 30/60\nGoods: Alloys 3, Components 4"
+        );
+    }
+
+    #[test]
+    fn recipe_line_shows_inputs_outputs_and_cycle_time() {
+        assert_eq!(recipe_line(None), "> Recipe: idle");
+        assert_eq!(
+            recipe_line(Some(RecipeKind::AlloySmelting)),
+            "> Recipe: Alloy smelting (4 raw ore -> 1 alloys, 60s)"
+        );
+    }
+
+    #[test]
+    fn outpost_standing_line_shows_price_level() {
+        let trade = OutpostTrade {
+            price_multiplier: 1.25,
+            ..Default::default()
+        };
+        assert_eq!(
+            outpost_standing_line(&trade),
+            "Standing: Neutral | Prices x1.25"
+        );
+    }
+
+    #[test]
+    fn outpost_standing_line_reports_refusal() {
+        let trade = OutpostTrade {
+            player_standing: -1.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            outpost_standing_line(&trade),
+            "Standing: Hostile | Refuses to trade"
+        );
+    }
+
+    #[test]
+    fn outpost_market_line_lists_fuel_stock_and_bids() {
+        let market = OutpostMarket::default();
+        assert_eq!(
+            outpost_market_line(&market, &MarketConditions::default()),
+            "Fuel stock: 200\nPays per unit: Raw ore 2.0, Fuel ore 3.0, Alloys 12.0, \
+             Components 20.0, Rare isotopes 60.0"
+        );
+        assert_eq!(fuel_lot_label(25, 13), "> 25 fuel for 13 cr");
+        assert_eq!(ore_lot_label(5, 10), "> 5 ore -> 10 cr");
+    }
+
+    #[test]
+    fn outpost_conditions_read_zone_modifier_and_pirate_control() {
+        let sector = Sector {
+            nodes: vec![SystemNode {
+                id: 4,
+                position: Vec2::ZERO,
+                modifier: Some(crate::world::ZoneModifier::RichOreVeins),
+            }],
+            routes: Vec::new(),
+        };
+        let mut control = PirateControl::default();
+        control.adjust(4, 0.6);
+        let trade = OutpostTrade::default();
+
+        let conditions = outpost_conditions(&sector, &control, Some(&ZoneId(4)), &trade);
+        assert_eq!(
+            conditions.modifier,
+            Some(crate::world::ZoneModifier::RichOreVeins)
+        );
+        assert!((conditions.pirate_control - 0.6).abs() < 1e-6);
+
+        let unknown = outpost_conditions(&sector, &control, None, &trade);
+        assert_eq!(unknown, MarketConditions::default());
+    }
+
+    #[test]
+    fn decommissioned_stock_moves_to_the_station_the_player_docks_at() {
+        let mut world = World::default();
+        world.insert_resource(EventLog::default());
+
+        let station = |kind, state, fuel| Station {
+            kind,
+            state,
+            fuel,
+            fuel_capacity: 60.0,
+        };
+        let old = world
+            .spawn((
+                station(StationKind::MiningOutpost, StationState::Failed, 25.0),
+                Name::new("Old"),
+                StationProduction::new(80.0).with_ore(30.0),
+                Decommissioned,
+            ))
+            .id();
+        let target = world
+            .spawn((
+                station(StationKind::Refinery, StationState::Operational, 20.0),
+                Name::new("Target"),
+                StationProduction::new(80.0),
+            ))
+            .id();
+        world.insert_resource(DockingState {
+            docked_at: Some(target),
+        });
+
+        let mut system_state: SystemState<(
+            Commands,
+            Res<DockingState>,
+            ResMut<EventLog>,
+            Query<
+                (
+                    Entity,
+                    &Station,
+                    &Name,
+                    Option<&StationProduction>,
+                    Option<&RefineryStorage>,
+                ),
+                With<Decommissioned>,
+            >,
+            Query<
+                (
+                    &mut Station,
+                    &Name,
+                    Option<&mut StationProduction>,
+                    Option<&mut RefineryStorage>,
+                ),
+                Without<Decommissioned>,
+            >,
+        )> = SystemState::new(&mut world);
+        let (commands, docking, log, decommissioned, stations) = system_state.get_mut(&mut world);
+        deliver_decommissioned_stock(commands, docking, log, decommissioned, stations);
+        system_state.apply(&mut world);
+
+        assert!(world.get_entity(old).is_err());
+        assert_eq!(world.get::<Station>(target).unwrap().fuel, 45.0);
+        assert_eq!(world.get::<StationProduction>(target).unwrap().ore(), 30.0);
     }
 }
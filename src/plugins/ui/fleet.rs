@@ -3,8 +3,11 @@
 use bevy::prelude::*;
 use std::path::Path;
 
+use crate::combat::Hull;
 use crate::compat::{TextBundle, TextStyle};
 use crate::fleets::{RiskTolerance, ScoutBehavior, ScoutPhase};
+use crate::ships::{Captured, Ship};
+use crate::world::ZoneId;
 
 use super::components::{
     contact_item_color, FleetDetailDivider, FleetDetailText, FleetEmptyText, FleetItem,
@@ -15,10 +18,13 @@ use super::components::{
 // Systems
 // =============================================================================
 
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 pub fn update_fleet_panel(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     scouts: Query<&ScoutBehavior>,
+    captured: Query<(&Hull, Option<&ZoneId>), (With<Ship>, With<Captured>)>,
     selected: Res<SelectedFleetUnit>,
     container_query: Query<Entity, With<FleetListContainer>>,
     existing_items: Query<Entity, With<FleetItem>>,
@@ -44,12 +50,13 @@ pub fn update_fleet_panel(
         commands.entity(empty_entity).despawn();
     }
 
-    // Collect scout data
+    // Collect scout data, then captured ships listed after the scouts
     let scout_data: Vec<_> = scouts.iter().collect();
+    let captured_data: Vec<_> = captured.iter().collect();
 
     // Spawn new items
     commands.entity(container_entity).with_children(|parent| {
-        if scout_data.is_empty() {
+        if scout_data.is_empty() && captured_data.is_empty() {
             parent.spawn((
                 FleetEmptyText,
                 TextBundle::from_section(
@@ -95,6 +102,33 @@ pub fn update_fleet_panel(
                     ),
                 ));
             }
+
+            for (offset, (hull, zone)) in captured_data.iter().enumerate() {
+                let index = scout_data.len() + offset;
+                let zone_label = zone.map_or("?".to_string(), |zone| zone.0.to_string());
+                let text_content = format!(
+                    "Captured-{}  Z{}  {:.0}%",
+                    offset + 1,
+                    zone_label,
+                    hull.ratio() * 100.0
+                );
+
+                let is_selected = selected.index == Some(index);
+                let color = contact_item_color(is_selected, false);
+
+                parent.spawn((
+                    FleetItem { index },
+                    Interaction::None,
+                    TextBundle::from_section(
+                        text_content,
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 12.0,
+                            color,
+                        },
+                    ),
+                ));
+            }
         }
     });
 }
@@ -141,8 +175,10 @@ pub fn update_fleet_item_styles(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn update_fleet_detail(
     scouts: Query<&ScoutBehavior>,
+    captured: Query<(&Ship, &Hull), With<Captured>>,
     selected: Res<SelectedFleetUnit>,
     mut detail_text: Query<&mut Text, With<FleetDetailText>>,
     mut divider: Query<&mut Visibility, With<FleetDetailDivider>>,
@@ -163,11 +199,26 @@ pub fn update_fleet_detail(
         return;
     };
 
-    // Find the scout at the selected index
+    // Find the scout at the selected index; captured ships follow the scouts
     let scout_data: Vec<_> = scouts.iter().collect();
     let Some(scout) = scout_data.get(selected_index) else {
-        text.0 = String::new();
-        *divider_vis = Visibility::Hidden;
+        let captured_data: Vec<_> = captured.iter().collect();
+        let Some((ship, hull)) = selected_index
+            .checked_sub(scout_data.len())
+            .and_then(|offset| captured_data.get(offset))
+        else {
+            text.0 = String::new();
+            *divider_vis = Visibility::Hidden;
+            return;
+        };
+
+        *divider_vis = Visibility::Inherited;
+        text.0 = [
+            format!("Role: {:?} (captured)", ship.kind),
+            format!("Hull: {:.0}/{:.0}", hull.current, hull.max),
+            format!("Fuel: {:.0}/{:.0}", ship.fuel, ship.fuel_capacity),
+        ]
+        .join("\n");
         return;
     };
 
@@ -4,8 +4,10 @@ use bevy::prelude::*;
 use bevy::ui::Node as UiNode;
 use std::path::Path;
 
+use crate::combat::{Armament, Hull, Shields};
 use crate::compat::{NodeBundle, TextBundle, TextStyle};
-use crate::plugins::player::PlayerControl;
+use crate::plugins::core::RunStatus;
+use crate::plugins::player::{EscapePod, PlayerControl};
 use crate::ships::{Cargo, Credits, Ship};
 use crate::world::ZoneId;
 
@@ -434,8 +436,22 @@ fn spawn_compass_markers(commands: &mut Commands, font: &Handle<Font>) {
 // Update Systems
 // =============================================================================
 
+#[allow(clippy::type_complexity)]
 pub fn update_player_panel(
-    player: Query<(&Ship, &Cargo, &Credits, &ZoneId), With<PlayerControl>>,
+    player: Query<
+        (
+            &Ship,
+            &Cargo,
+            &Credits,
+            &ZoneId,
+            Option<&Hull>,
+            Option<&Shields>,
+            Option<&Armament>,
+            Has<EscapePod>,
+        ),
+        With<PlayerControl>,
+    >,
+    status: Res<RunStatus>,
     mut panel: Query<&mut Text, With<PlayerPanelText>>,
 ) {
     let Some(mut text) = panel.iter_mut().next() else {
@@ -443,7 +459,7 @@ pub fn update_player_panel(
     };
 
     match player.single() {
-        Ok((ship, cargo, credits, zone_id)) => {
+        Ok((ship, cargo, credits, zone_id, hull, shields, armament, in_pod)) => {
             let fuel_pct = if ship.fuel_capacity > 0.0 {
                 (ship.fuel / ship.fuel_capacity) * 100.0
             } else {
@@ -454,19 +470,47 @@ pub fn update_player_panel(
             let fuel_filled = ((fuel_pct / 10.0).round() as usize).min(10);
             let fuel_bar: String = "=".repeat(fuel_filled) + &"-".repeat(10 - fuel_filled);
 
-            // Build ore bar (10 chars) using ASCII
-            let ore_pct = if cargo.ore_capacity > 0 {
-                (cargo.ore as f32 / cargo.ore_capacity as f32) * 100.0
+            // Build hold bar (10 chars) using ASCII, filled by mass
+            let hold_pct = if cargo.hold.mass_capacity > 0.0 {
+                (cargo.hold.mass() / cargo.hold.mass_capacity) * 100.0
             } else {
                 0.0
             };
-            let ore_filled = ((ore_pct / 10.0).round() as usize).min(10);
-            let ore_bar: String = "=".repeat(ore_filled) + &"-".repeat(10 - ore_filled);
+            let hold_filled = ((hold_pct / 10.0).round() as usize).min(10);
+            let hold_bar: String = "=".repeat(hold_filled) + &"-".repeat(10 - hold_filled);
 
             text.0 = format!(
-                "Zone {} | FUEL [{}] {:>3.0}% | ORE [{}] {:>3.0}% | {} cr",
-                zone_id.0, fuel_bar, fuel_pct, ore_bar, ore_pct, credits.amount
+                "Zone {} | FUEL [{}] {:>3.0}% | HOLD [{}] {:>3.0}% | {} cr",
+                zone_id.0, fuel_bar, fuel_pct, hold_bar, hold_pct, credits.amount
             );
+
+            if let Some(hull) = hull {
+                let shield_pct = shields.map_or(0.0, |shields| {
+                    if shields.max > 0.0 {
+                        (shields.current / shields.max) * 100.0
+                    } else {
+                        0.0
+                    }
+                });
+                text.0.push_str(&format!(
+                    " | HULL {:>3.0}% SH {:>3.0}%",
+                    hull.ratio() * 100.0,
+                    shield_pct
+                ));
+            }
+
+            if let Some(weapon) = armament.and_then(|armament| armament.selected()) {
+                text.0.push_str(&format!(" | WPN {}", weapon.status()));
+            }
+
+            if in_pod {
+                text.0.push_str(" | ESCAPE POD");
+            }
+
+            if let Some(reason) = status.ended {
+                text.0
+                    .push_str(&format!(" | RUN OVER: {}", reason.describe()));
+            }
         }
         Err(_) => {
             text.0 = String::new();
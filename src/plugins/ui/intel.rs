@@ -2,9 +2,11 @@
 
 use bevy::prelude::*;
 
+use crate::crises::crisis_responses;
 use crate::plugins::player::{NearbyTargets, PlayerControl};
 use crate::plugins::render2d::IntelRefreshCooldown;
 use crate::plugins::sim::SimTickCount;
+use crate::stations::StationCrises;
 
 use super::components::{IntelContentText, IntelInfo};
 
@@ -17,6 +19,7 @@ pub fn update_intel_panel(
     ticks: Res<SimTickCount>,
     cooldown: Res<IntelRefreshCooldown>,
     player_query: Query<&Transform, With<PlayerControl>>,
+    stations: Query<&StationCrises>,
     mut intel_text: Query<&mut Text, With<IntelContentText>>,
 ) {
     let mut text = match intel_text.single_mut() {
@@ -34,7 +37,7 @@ pub fn update_intel_panel(
     };
 
     // Get selected entity info
-    let Some((_, pos, label)) = targets.entities.get(targets.selected_index) else {
+    let Some((entity, pos, label)) = targets.entities.get(targets.selected_index) else {
         text.0 = format_cooldown_only(&ticks, &cooldown);
         return;
     };
@@ -53,6 +56,9 @@ pub fn update_intel_panel(
     };
 
     let mut content = format_intel_panel(Some(&info));
+    if let Ok(crises) = stations.get(*entity) {
+        content.push_str(&format!("\n{}", format_station_crises(crises).join("\n")));
+    }
     content.push_str(&format!("\n\n{}", format_cooldown(&ticks, &cooldown)));
     text.0 = content;
 }
@@ -91,6 +97,34 @@ pub fn format_intel_panel(info: Option<&IntelInfo>) -> String {
     lines.join("\n")
 }
 
+/// One line per open station crisis, worst first, with what the player can do
+/// about it.
+pub fn format_station_crises(crises: &StationCrises) -> Vec<String> {
+    if crises.is_empty() {
+        return vec!["Crises: none".to_string()];
+    }
+
+    let mut active: Vec<_> = crises.iter().collect();
+    active.sort_by_key(|(_, crisis)| std::cmp::Reverse(crisis.stage.severity()));
+
+    let mut lines = vec![format!("Crises: {}", active.len())];
+    for (crisis_type, crisis) in active {
+        let responses = crisis_responses(crisis_type)
+            .iter()
+            .map(|response| response.label())
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!(
+            "  {} - {:?} ({:.0}s) -> {}",
+            crisis_type.label(),
+            crisis.stage,
+            crisis.elapsed_seconds,
+            responses
+        ));
+    }
+    lines
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -99,6 +133,29 @@ pub fn format_intel_panel(info: Option<&IntelInfo>) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn format_station_crises_lists_worst_first() {
+        use crate::stations::{ActiveCrisis, CrisisStage, CrisisType};
+
+        let mut crises = StationCrises::default();
+        assert_eq!(format_station_crises(&crises), vec!["Crises: none"]);
+
+        let open = |stage| ActiveCrisis {
+            entity: Entity::PLACEHOLDER,
+            stage,
+            elapsed_seconds: 12.0,
+            stage_seconds: 0.0,
+        };
+        crises.track(CrisisType::FuelShortage, open(CrisisStage::Strained));
+        crises.track(CrisisType::HullBreach, open(CrisisStage::Failing));
+        let lines = format_station_crises(&crises);
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("Hull breach"));
+        assert!(lines[1].ends_with("repair the hull"));
+        assert!(lines[2].contains("Fuel shortage"));
+    }
+
     #[test]
     fn format_intel_empty_when_no_selection() {
         let result = format_intel_panel(None);
@@ -0,0 +1,229 @@
+//! Ledger screen: where the player's credits, ore and fuel came from and went.
+
+use bevy::prelude::*;
+use bevy::ui::Node as UiNode;
+use std::path::Path;
+
+use crate::compat::{NodeBundle, TextBundle, TextStyle};
+use crate::ledger::{Flow, Ledger, LEDGER_CATEGORIES};
+use crate::plugins::core::{LedgerScreen, SimConfig};
+use crate::plugins::sim::SimTickCount;
+
+use super::components::{LedgerScreenRoot, LedgerScreenText};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Trailing windows the screen totals, in minutes of sim time
+const LEDGER_WINDOWS: [(&str, f32); 2] = [("Last min", 1.0), ("Last 10 min", 10.0)];
+
+/// Transactions listed under RECENT
+const LEDGER_RECENT_ENTRIES: usize = 8;
+
+// =============================================================================
+// Setup Systems
+// =============================================================================
+
+pub fn setup_ledger_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font_path = "fonts/SpaceMono-Regular.ttf";
+    let font_on_disk = Path::new("assets").join(font_path);
+
+    if !font_on_disk.exists() {
+        return;
+    }
+
+    let font = asset_server.load(font_path);
+
+    // Ledger panel (centered, initially hidden)
+    commands
+        .spawn((
+            LedgerScreenRoot,
+            NodeBundle {
+                node: UiNode {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(50.0),
+                    top: Val::Percent(50.0),
+                    margin: UiRect {
+                        left: Val::Px(-240.0),
+                        top: Val::Px(-200.0),
+                        ..default()
+                    },
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    min_width: Val::Px(480.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.05, 0.05, 0.03, 0.95).into(),
+                border_color: Color::srgb(0.8, 0.7, 0.3).into(),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex(90),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "LEDGER (L to close)",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    color: Color::srgb(1.0, 0.85, 0.4),
+                },
+            ));
+
+            parent.spawn((
+                LedgerScreenText,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        color: Color::srgb(0.8, 0.8, 0.75),
+                    },
+                )
+                .with_node(UiNode {
+                    margin: UiRect::top(Val::Px(8.0)),
+                    ..default()
+                }),
+            ));
+        });
+}
+
+// =============================================================================
+// Update Systems
+// =============================================================================
+
+/// Show or hide the ledger screen and refresh its totals while open
+pub fn update_ledger_screen(
+    ledger_screen: Res<LedgerScreen>,
+    ledger: Res<Ledger>,
+    ticks: Res<SimTickCount>,
+    config: Res<SimConfig>,
+    mut root: Query<&mut Visibility, With<LedgerScreenRoot>>,
+    mut body: Query<&mut Text, With<LedgerScreenText>>,
+) {
+    let Ok(mut visibility) = root.single_mut() else {
+        return;
+    };
+
+    if !ledger_screen.open {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    *visibility = Visibility::Visible;
+    if let Ok(mut text) = body.single_mut() {
+        text.0 = ledger_report(&ledger, ticks.tick, config.tick_hz);
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Income and expense per window, net per category over the run, and the
+/// latest transactions
+fn ledger_report(ledger: &Ledger, tick: u64, tick_hz: f32) -> String {
+    let row = |label: &str, credits: String, ore: String, fuel: String| {
+        format!("{:<13}{:>13}{:>11}{:>11}", label, credits, ore, fuel)
+    };
+    let mut lines = vec![row(
+        "",
+        "Credits".to_string(),
+        "Ore".to_string(),
+        "Fuel".to_string(),
+    )];
+
+    let windows = LEDGER_WINDOWS
+        .iter()
+        .map(|(label, minutes)| {
+            let window_ticks = (minutes * 60.0 * tick_hz) as u64;
+            (*label, tick.saturating_sub(window_ticks))
+        })
+        .chain(std::iter::once(("Run", 0)));
+    for (label, since_tick) in windows {
+        let totals = ledger.totals(since_tick);
+        lines.push(row(
+            label,
+            flow_cell(totals.credits),
+            flow_cell(totals.ore),
+            flow_cell(totals.fuel),
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("NET BY CATEGORY (run)".to_string());
+    let by_category = ledger.by_category(0);
+    for category in LEDGER_CATEGORIES {
+        let totals = by_category.get(&category).copied().unwrap_or_default();
+        lines.push(row(
+            category.label(),
+            format!("{:+.0}", totals.credits.net()),
+            format!("{:+.0}", totals.ore.net()),
+            format!("{:+.0}", totals.fuel.net()),
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push("RECENT".to_string());
+    if ledger.entries.is_empty() {
+        lines.push("No transactions yet".to_string());
+    }
+    for entry in ledger.recent(LEDGER_RECENT_ENTRIES) {
+        lines.push(format!(
+            "t{} {} {}: {}",
+            entry.tick,
+            entry.category.label(),
+            entry.counterparty,
+            entry.amounts()
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn flow_cell(flow: Flow) -> String {
+    format!("+{:.0}/-{:.0}", flow.income, flow.expense)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{LedgerCategory, LedgerEntry};
+
+    #[test]
+    fn empty_ledger_report_says_so() {
+        let report = ledger_report(&Ledger::default(), 0, 10.0);
+        assert!(report.ends_with("RECENT\nNo transactions yet"));
+        assert!(report.contains("Run                  +0/-0      +0/-0      +0/-0"));
+    }
+
+    #[test]
+    fn ledger_report_totals_windows_and_categories() {
+        let mut ledger = Ledger::default();
+        ledger.record(
+            LedgerEntry::new(100, LedgerCategory::Construction, "Station-Shipyard-2")
+                .with_credits(-40)
+                .with_ore(-20.0),
+        );
+        ledger.record(
+            LedgerEntry::new(6_000, LedgerCategory::Trade, "Outpost-1")
+                .with_credits(25)
+                .with_ore(-10.0),
+        );
+
+        // At 10 Hz the last minute starts at tick 5_400 and the last ten at 0
+        let report = ledger_report(&ledger, 6_000, 10.0);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[1], "Last min            +25/-0     +0/-10      +0/-0");
+        assert_eq!(lines[2], "Last 10 min        +25/-40     +0/-30      +0/-0");
+        assert_eq!(lines[6], "Trade                  +25        -10         +0");
+        assert_eq!(lines[7], "Construction           -40        -20         +0");
+        assert_eq!(lines[14], "t6000 Trade Outpost-1: +25 cr, -10 ore");
+    }
+}
@@ -3,9 +3,11 @@
 use bevy::prelude::*;
 use bevy::ui::Node as UiNode;
 
+use crate::commodities::Commodity;
 use crate::compat::NodeBundle;
 use crate::plugins::core::{DebugWindow, ViewMode};
 use crate::plugins::render2d::RenderToggles;
+use crate::stations::{MarketGood, MarketIntel, PriceReport};
 use crate::world::{
     zone_modifier_effect, KnowledgeLayer, Sector, SystemIntel, SystemNode, ZoneModifier,
 };
@@ -116,6 +118,7 @@ pub fn setup_hover_panel(mut commands: Commands, asset_server: Res<AssetServer>)
 // =============================================================================
 
 pub fn update_node_panel(
+    market_intel: Res<MarketIntel>,
     nodes: Query<(&SystemNode, &SystemIntel)>,
     mut panel: Query<&mut Text, With<NodeListText>>,
 ) {
@@ -147,6 +150,9 @@ pub fn update_node_panel(
                     confidence * 100.0,
                     modifier_suffix
                 ));
+                if let Some(report) = market_intel.reports.get(&id) {
+                    body.push_str(&format!("  {}\n", price_report_line(report)));
+                }
             }
             text.0 = body.trim_end().to_string();
         }
@@ -198,6 +204,17 @@ pub fn update_risk_panel(sector: Res<Sector>, mut panel: Query<&mut Text, With<R
     }
 }
 
+/// Last known Outpost prices in a zone and how far they can still be trusted
+fn price_report_line(report: &PriceReport) -> String {
+    let price = |good| report.prices.get(&good).copied().unwrap_or(0.0);
+    format!(
+        "$ fuel {:.2} | ore {:.1} ({:.0}%)",
+        price(MarketGood::Fuel),
+        price(MarketGood::Goods(Commodity::RawOre)),
+        report.confidence * 100.0
+    )
+}
+
 fn risk_breakdown(sector: &Sector) -> (f32, f32) {
     let route_risk = if sector.routes.is_empty() {
         0.0
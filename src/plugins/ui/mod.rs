@@ -5,16 +5,21 @@
 //! - Log panel (subspace transmissions)
 //! - Map panels (nodes, hover, risk, modifiers, grid)
 //! - Contacts and Intel panels (world view targeting)
-//! - Fleet panel (scout management)
+//! - Fleet panel (scouts and captured ships)
+//! - Docking and boarding menus
+//! - Ledger screen (L)
 //! - Debug panel (F3)
 
+mod boarding;
 mod components;
+mod construction;
 mod contacts;
 mod debug;
 mod docking;
 mod fleet;
 mod hud;
 mod intel;
+mod ledger;
 mod log;
 mod map_panels;
 pub mod panel;
@@ -47,6 +52,9 @@ impl Plugin for UIPlugin {
                 hud::setup_hud,
                 debug::setup_debug_panel,
                 docking::setup_docking_menu,
+                boarding::setup_boarding_menu,
+                construction::setup_construction_menu,
+                ledger::setup_ledger_screen,
             ),
         )
         .add_systems(
@@ -64,6 +72,34 @@ impl Plugin for UIPlugin {
                 docking::update_docking_menu_visibility,
                 docking::update_docking_menu_content,
                 docking::handle_docking_menu_clicks,
+                docking::update_outpost_trade_content,
+                docking::handle_black_market_clicks,
+                docking::update_replacement_hull_button,
+                docking::handle_replacement_hull_clicks,
+                docking::update_shipyard_content,
+                docking::handle_shipyard_clicks,
+                docking::update_depot_content,
+                docking::handle_depot_clicks,
+                docking::update_station_inventory,
+                docking::handle_withdraw_clicks,
+                docking::update_station_orders,
+                docking::handle_station_order_clicks,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                docking::update_refinery_target_button,
+                docking::handle_refinery_target_clicks,
+                docking::update_recipe_button,
+                docking::handle_recipe_clicks,
+                docking::handle_outpost_trade_clicks,
+                docking::deliver_decommissioned_stock,
+                boarding::update_boarding_menu,
+                boarding::handle_boarding_clicks,
+                construction::update_construction_menu,
+                construction::handle_construction_clicks,
+                ledger::update_ledger_screen,
             ),
         )
         .add_systems(
@@ -3,17 +3,18 @@ use bevy::prelude::*;
 use crate::compat::SpatialBundle;
 use crate::factions::Faction;
 use crate::fleets::{RiskTolerance, ScoutBehavior};
-use crate::pirates::{PirateBase, PirateShip};
+use crate::pirates::{PirateBase, PirateControl, PirateLoot, PirateMemory, PirateShip};
 use crate::plugins::core::{DebugWindow, EventLog, GameState, InputBindings};
-use crate::plugins::player::PlayerControl;
+use crate::plugins::player::{spawn_player_station, PlayerControl};
 use crate::plugins::sim::{BoundaryWarningState, SimTickCount};
 use crate::ships::{
     cargo_capacity, ship_default_role, ship_fuel_capacity, Cargo, Credits, Fleet, Ship,
     ShipFuelAlert, ShipKind, ShipState, Velocity,
 };
 use crate::stations::{
-    station_build_time_seconds, station_fuel_capacity, Station, StationBuild, StationCrisisLog,
-    StationKind, StationState,
+    station_fuel_capacity, station_ore_capacity, OutpostMarket, OutpostTrade, Station,
+    StationCrises, StationCrisisLog, StationKind, StationMaintenance, StationProduction,
+    StationState,
 };
 use crate::world::{
     JumpGate, KnowledgeLayer, RouteEdge, Sector, SystemIntel, SystemNode, ZoneId, ZoneModifier,
@@ -69,6 +70,9 @@ fn seed_world(mut commands: Commands, seed: Res<WorldSeed>, mut sector: ResMut<S
 fn apply_seed_world(commands: &mut Commands, sector: &mut Sector, seed: u64) {
     sector.nodes.clear();
     sector.routes.clear();
+    // A fresh sector means pirates start without any memory of it
+    commands.insert_resource(PirateMemory::default());
+    commands.insert_resource(PirateControl::default());
 
     let mut rng = seed;
     let node_count = 50;
@@ -270,6 +274,11 @@ fn handle_debug_spawns(
         spawn_outpost_debug(&mut commands, node);
     }
 
+    // Spawn SensorStation with Shift+4
+    if input.just_pressed(bindings.spawn_sensor) {
+        spawn_station_debug(&mut commands, node, StationKind::SensorStation);
+    }
+
     if input.just_pressed(bindings.spawn_ship) {
         spawn_ship_stub(&mut commands, node);
     }
@@ -391,10 +400,8 @@ fn spawn_ship_stub(commands: &mut Commands, node: &SystemNode) {
             fuel_capacity: scout_capacity,
         },
         Cargo {
-            ore: 0,
-            ore_capacity: cargo_capacity(ShipKind::Scout) as u32,
-            fuel: 0.0,
             fuel_capacity: 20.0,
+            ..Cargo::with_capacity(cargo_capacity(ShipKind::Scout) as u32)
         },
         Fleet {
             role: ship_default_role(ShipKind::Scout),
@@ -414,29 +421,13 @@ fn spawn_ship_stub(commands: &mut Commands, node: &SystemNode) {
 
 /// Spawn a station of the given kind at the given node (debug command)
 fn spawn_station_debug(commands: &mut Commands, node: &SystemNode, kind: StationKind) {
-    let capacity = station_fuel_capacity(kind);
-    let build_time = station_build_time_seconds(kind);
-
-    commands.spawn((
-        Station {
-            kind,
-            state: StationState::Deploying,
-            fuel: capacity * 0.5,
-            fuel_capacity: capacity,
-        },
-        Faction::Player,
-        StationBuild {
-            remaining_seconds: build_time,
-        },
-        StationCrisisLog::default(),
-        ZoneId(node.id),
-        Name::new(format!("Station-{:?}-{}", kind, node.id)),
-        SpatialBundle::from_transform(Transform::from_xyz(
-            node.position.x + 40.0,
-            node.position.y + 20.0,
-            0.5,
-        )),
-    ));
+    spawn_player_station(
+        commands,
+        kind,
+        node,
+        node.position + Vec2::new(40.0, 20.0),
+        station_fuel_capacity(kind) * 0.5,
+    );
 }
 
 /// Spawn an NPC Outpost at the given node (debug command)
@@ -449,6 +440,9 @@ fn spawn_outpost_debug(commands: &mut Commands, node: &SystemNode) {
             fuel_capacity: 0.0,
         },
         Faction::Independent,
+        OutpostTrade::default(),
+        OutpostMarket::default(),
+        StationCrises::default(),
         StationCrisisLog::default(),
         ZoneId(node.id),
         Name::new(format!("Outpost-Debug-{}", node.id)),
@@ -462,10 +456,8 @@ fn spawn_outpost_debug(commands: &mut Commands, node: &SystemNode) {
 
 fn spawn_pirate(commands: &mut Commands, node: &SystemNode) {
     commands.spawn((
-        PirateShip {
-            speed: 70.0,
-            behavior: crate::pirates::PirateShipBehavior::default(),
-        },
+        PirateShip::new(70.0),
+        PirateLoot::default(),
         Faction::Pirate,
         ZoneId(node.id),
         Name::new("Pirate-Ship"),
@@ -528,7 +520,10 @@ fn spawn_stations(commands: &mut Commands, sector: &Sector, rng: &mut u64) {
                 fuel_capacity: capacity,
             },
             Faction::Player,
+            StationCrises::default(),
+            StationMaintenance::default(),
             StationCrisisLog::default(),
+            StationProduction::new(station_ore_capacity(kind)),
             ZoneId(node.id),
             Name::new(format!("Refinery-{}-{}", node.id, i)),
             SpatialBundle::from_transform(Transform::from_xyz(
@@ -572,7 +567,10 @@ fn spawn_stations(commands: &mut Commands, sector: &Sector, rng: &mut u64) {
                 fuel_capacity: capacity,
             },
             Faction::Player,
+            StationCrises::default(),
+            StationMaintenance::default(),
             StationCrisisLog::default(),
+            StationProduction::new(station_ore_capacity(kind)),
             ZoneId(node.id),
             Name::new(format!("Shipyard-{}-{}", node.id, i)),
             SpatialBundle::from_transform(Transform::from_xyz(
@@ -656,6 +654,9 @@ fn spawn_outpost_at(commands: &mut Commands, node: &SystemNode, index: usize, rn
             fuel_capacity: 0.0,
         },
         Faction::Independent,
+        OutpostTrade::default(),
+        OutpostMarket::default(),
+        StationCrises::default(),
         StationCrisisLog::default(),
         ZoneId(node.id),
         Name::new(format!("Outpost-{}", index)),
@@ -739,10 +740,8 @@ fn spawn_pirates(commands: &mut Commands, sector: &Sector, rng: &mut u64) {
             let offset_y = angle.sin() * radius;
 
             commands.spawn((
-                PirateShip {
-                    speed: 70.0,
-                    behavior: crate::pirates::PirateShipBehavior::default(),
-                },
+                PirateShip::new(70.0),
+                PirateLoot::default(),
                 Faction::Pirate,
                 ZoneId(node.id),
                 Name::new(format!("Pirate-Ship-{}-{}", node.id, i)),
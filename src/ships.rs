@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::commodities::{Commodity, Inventory};
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum ShipKind {
     PlayerShip,
@@ -29,6 +31,41 @@ pub enum ShipState {
     Disabled,
 }
 
+/// Ship identity that survives save/load, unlike the ECS entity
+#[derive(
+    Component,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ShipId(pub u64);
+
+/// Hands out ship ids in spawn order; saved with the run so ids never repeat
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ShipIds {
+    pub next: u64,
+}
+
+impl ShipIds {
+    pub fn issue(&mut self) -> ShipId {
+        let id = ShipId(self.next);
+        self.next += 1;
+        id
+    }
+
+    /// Make sure ids restored from a save are never handed out again
+    pub fn reserve(&mut self, id: ShipId) {
+        self.next = self.next.max(id.0 + 1);
+    }
+}
+
 #[derive(Component, Debug)]
 pub struct Ship {
     pub kind: ShipKind,
@@ -37,10 +74,10 @@ pub struct Ship {
     pub fuel_capacity: f32,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone)]
 pub struct Cargo {
-    pub ore: u32,
-    pub ore_capacity: u32,
+    /// Ore, salvaged parts and other goods, bounded by mass
+    pub hold: Inventory,
     #[allow(dead_code)]
     pub fuel: f32,
     #[allow(dead_code)]
@@ -49,33 +86,62 @@ pub struct Cargo {
 
 impl Default for Cargo {
     fn default() -> Self {
+        Self::with_capacity(50)
+    }
+}
+
+impl Cargo {
+    /// An empty hold that carries `ore_capacity` units of raw ore
+    pub fn with_capacity(ore_capacity: u32) -> Self {
         Self {
-            ore: 0,
-            ore_capacity: 50,
+            hold: Inventory::new(ore_capacity as f32 * Commodity::RawOre.mass()),
             fuel: 0.0,
             fuel_capacity: 100.0,
         }
     }
-}
 
-impl Cargo {
+    /// Whole units of raw ore in the hold
+    pub fn ore(&self) -> u32 {
+        self.hold.whole(Commodity::RawOre)
+    }
+
+    /// Raw ore the hold carries when it holds nothing else
+    pub fn ore_capacity(&self) -> u32 {
+        self.hold.capacity_for(Commodity::RawOre).floor() as u32
+    }
+
+    /// Whole salvaged parts in the hold
+    pub fn components(&self) -> u32 {
+        self.hold.whole(Commodity::Components)
+    }
+
     /// Add ore up to capacity. Returns the amount actually added.
     pub fn add_ore(&mut self, amount: u32) -> u32 {
-        let free = self.ore_capacity.saturating_sub(self.ore);
-        let added = amount.min(free);
-        self.ore += added;
-        added
+        self.add_whole(Commodity::RawOre, amount)
     }
 
     /// Remove ore. Returns the amount actually removed.
     pub fn remove_ore(&mut self, amount: u32) -> u32 {
-        let removed = amount.min(self.ore);
-        self.ore -= removed;
+        self.remove_whole(Commodity::RawOre, amount)
+    }
+
+    /// Add whole units of a commodity up to the hold's mass limit. Returns
+    /// the amount actually added.
+    pub fn add_whole(&mut self, commodity: Commodity, amount: u32) -> u32 {
+        let room = self.hold.room_for(commodity).floor() as u32;
+        let added = amount.min(room);
+        self.hold.add(commodity, added as f32);
+        added
+    }
+
+    /// Remove whole units of a commodity. Returns the amount actually removed.
+    pub fn remove_whole(&mut self, commodity: Commodity, amount: u32) -> u32 {
+        let removed = amount.min(self.hold.whole(commodity));
+        self.hold.remove(commodity, removed as f32);
         removed
     }
 
     /// Add fuel up to capacity. Returns the amount actually added.
-    #[allow(dead_code)]
     pub fn add_fuel(&mut self, amount: f32) -> f32 {
         let free = (self.fuel_capacity - self.fuel).max(0.0);
         let added = amount.min(free);
@@ -84,7 +150,6 @@ impl Cargo {
     }
 
     /// Remove fuel. Returns the amount actually removed.
-    #[allow(dead_code)]
     pub fn remove_fuel(&mut self, amount: f32) -> f32 {
         let removed = amount.min(self.fuel);
         self.fuel -= removed;
@@ -93,7 +158,12 @@ impl Cargo {
 
     /// Get available ore space.
     pub fn ore_free_space(&self) -> u32 {
-        self.ore_capacity.saturating_sub(self.ore)
+        self.hold.room_for(Commodity::RawOre).floor() as u32
+    }
+
+    /// Get available space in whole components.
+    pub fn components_free_space(&self) -> u32 {
+        self.hold.room_for(Commodity::Components).floor() as u32
     }
 
     /// Get available fuel space.
@@ -131,10 +201,24 @@ pub fn cargo_capacity(kind: ShipKind) -> f32 {
 }
 
 #[derive(Component, Debug, Clone, Copy)]
+#[require(MinerTask)]
 pub struct Fleet {
     pub role: FleetRole,
 }
 
+/// The one job a mining ship is on, so salvage and hauling never steer it at once
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MinerTask {
+    #[default]
+    Idle,
+    Salvaging,
+    Hauling,
+}
+
+/// A raider the player boarded and pressed into service
+#[derive(Component, Debug, Default)]
+pub struct Captured;
+
 #[derive(Component, Debug, Default)]
 pub struct ShipFuelAlert {
     pub low: bool,
@@ -526,7 +610,7 @@ mod tests {
     #[test]
     fn cargo_default_has_zero_ore() {
         let cargo = Cargo::default();
-        assert_eq!(cargo.ore, 0);
+        assert_eq!(cargo.ore(), 0);
     }
 
     #[test]
@@ -538,7 +622,7 @@ mod tests {
     #[test]
     fn cargo_default_ore_capacity_is_50() {
         let cargo = Cargo::default();
-        assert_eq!(cargo.ore_capacity, 50);
+        assert_eq!(cargo.ore_capacity(), 50);
     }
 
     #[test]
@@ -552,7 +636,7 @@ mod tests {
         let mut cargo = Cargo::default();
         let added = cargo.add_ore(30);
         assert_eq!(added, 30);
-        assert_eq!(cargo.ore, 30);
+        assert_eq!(cargo.ore(), 30);
     }
 
     #[test]
@@ -561,7 +645,7 @@ mod tests {
         cargo.add_ore(40);
         let added = cargo.add_ore(20);
         assert_eq!(added, 10); // Only 10 fits
-        assert_eq!(cargo.ore, 50); // At capacity
+        assert_eq!(cargo.ore(), 50); // At capacity
     }
 
     #[test]
@@ -587,7 +671,7 @@ mod tests {
         cargo.add_ore(30);
         let removed = cargo.remove_ore(20);
         assert_eq!(removed, 20);
-        assert_eq!(cargo.ore, 10);
+        assert_eq!(cargo.ore(), 10);
     }
 
     #[test]
@@ -596,7 +680,7 @@ mod tests {
         cargo.add_ore(10);
         let removed = cargo.remove_ore(20);
         assert_eq!(removed, 10); // Only had 10
-        assert_eq!(cargo.ore, 0);
+        assert_eq!(cargo.ore(), 0);
     }
 
     #[test]
@@ -1,4 +1,10 @@
 use bevy::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::commodities::{Commodity, Inventory, COMMODITIES};
+use crate::fleets::RiskTolerance;
+use crate::ships::{ship_fuel_capacity, ShipKind};
+use crate::world::ZoneModifier;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum StationKind {
@@ -12,6 +18,19 @@ pub enum StationKind {
     Outpost, // Independent trader station
 }
 
+impl StationKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StationKind::MiningOutpost => "Mining Outpost",
+            StationKind::FuelDepot => "Fuel Depot",
+            StationKind::SensorStation => "Sensor Station",
+            StationKind::Shipyard => "Shipyard",
+            StationKind::Refinery => "Refinery",
+            StationKind::Outpost => "Outpost",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum StationState {
     Deploying,
@@ -29,15 +48,42 @@ pub struct Station {
     pub fuel_capacity: f32,
 }
 
+/// How long a station's automation has run without anyone calling in
+#[derive(Component, Debug, Default)]
+pub struct StationMaintenance {
+    pub unvisited_seconds: f32,
+    /// Ore mis-routed out of the hold that has not yet drifted off as a wreck
+    pub misrouted_ore: f32,
+}
+
 #[derive(Component, Debug)]
 pub struct StationBuild {
     pub remaining_seconds: f32,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub enum CrisisType {
     FuelShortage,
     PirateHarassment,
+    HullBreach,
+    AutomationDrift,
+    PoliticalFlashpoint,
+    SupplyShortage,
+}
+
+impl CrisisType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CrisisType::FuelShortage => "Fuel shortage",
+            CrisisType::PirateHarassment => "Pirate harassment",
+            CrisisType::HullBreach => "Hull breach",
+            CrisisType::AutomationDrift => "Automation drift",
+            CrisisType::PoliticalFlashpoint => "Political flashpoint",
+            CrisisType::SupplyShortage => "Supply shortage",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
@@ -48,70 +94,487 @@ pub enum CrisisStage {
     Resolved,
 }
 
-#[derive(Component, Debug)]
-pub struct StationCrisis {
-    pub crisis_type: CrisisType,
+impl CrisisStage {
+    /// How hard this stage pulls on the station; higher is worse
+    pub fn severity(&self) -> u8 {
+        match self {
+            CrisisStage::Stable | CrisisStage::Resolved => 0,
+            CrisisStage::Strained => 1,
+            CrisisStage::Failing => 2,
+        }
+    }
+}
+
+/// Index entry for an open crisis entity at a station
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActiveCrisis {
+    pub entity: Entity,
+    pub stage: CrisisStage,
+    /// Seconds since the crisis opened
+    pub elapsed_seconds: f32,
+    /// Seconds spent at the current stage
+    pub stage_seconds: f32,
+}
+
+/// The station whose trouble is being passed on through a cascade
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CascadeSource {
+    pub station: Entity,
+    pub station_kind: StationKind,
+    pub zone: Option<u32>,
+    /// The crisis being passed on, or None when the source has failed outright
+    pub crisis_type: Option<CrisisType>,
+}
+
+/// Pressure a station picks up from a neighbour it depends on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CascadePressure {
     pub stage: CrisisStage,
+    pub source: CascadeSource,
 }
 
+/// Crisis bookkeeping for a station. Sim systems report the pressure they see
+/// per crisis type; the open crisis entities are mirrored back here so the
+/// station state and UI can read them without a second query.
+#[derive(Component, Debug, Default)]
+pub struct StationCrises {
+    pressure: BTreeMap<CrisisType, CrisisStage>,
+    cascade: BTreeMap<CrisisType, CascadePressure>,
+    active: BTreeMap<CrisisType, ActiveCrisis>,
+}
+
+impl StationCrises {
+    /// Report pressure towards a crisis. Returns true if anything changed.
+    pub fn report(&mut self, crisis_type: CrisisType, stage: CrisisStage) -> bool {
+        self.pressure.insert(crisis_type, stage) != Some(stage)
+    }
+
+    /// Withdraw pressure for a crisis type. Returns true if any was reported.
+    pub fn relieve(&mut self, crisis_type: CrisisType) -> bool {
+        self.pressure.remove(&crisis_type).is_some()
+    }
+
+    /// Pressure on a crisis type, direct or cascaded, whichever is worse
+    pub fn pressure(&self, crisis_type: CrisisType) -> Option<CrisisStage> {
+        let direct = self.pressure.get(&crisis_type).copied();
+        let cascade = self.cascade.get(&crisis_type).map(|cascade| cascade.stage);
+        match (direct, cascade) {
+            (Some(direct), Some(cascade)) if cascade.severity() > direct.severity() => {
+                Some(cascade)
+            }
+            (direct, cascade) => direct.or(cascade),
+        }
+    }
+
+    /// Pressure reported by this station's own producers, ignoring cascades
+    pub fn direct_pressure(&self, crisis_type: CrisisType) -> Option<CrisisStage> {
+        self.pressure.get(&crisis_type).copied()
+    }
+
+    pub fn pressures(&self) -> impl Iterator<Item = (CrisisType, CrisisStage)> + '_ {
+        let types: BTreeSet<CrisisType> = self
+            .pressure
+            .keys()
+            .chain(self.cascade.keys())
+            .copied()
+            .collect();
+        types
+            .into_iter()
+            .filter_map(move |crisis_type| Some((crisis_type, self.pressure(crisis_type)?)))
+    }
+
+    /// Pressure passed on by neighbours, keyed by the crisis it feeds
+    pub fn cascades(&self) -> &BTreeMap<CrisisType, CascadePressure> {
+        &self.cascade
+    }
+
+    /// Where cascaded pressure on a crisis type is coming from
+    pub fn cascade_source(&self, crisis_type: CrisisType) -> Option<&CascadeSource> {
+        self.cascade
+            .get(&crisis_type)
+            .map(|cascade| &cascade.source)
+    }
+
+    /// Replace cascaded pressure wholesale; it is recomputed every tick
+    pub fn set_cascades(&mut self, cascade: BTreeMap<CrisisType, CascadePressure>) {
+        self.cascade = cascade;
+    }
+
+    /// Raise or restage a crisis. Returns true if anything changed.
+    #[allow(dead_code)]
+    pub fn set(&mut self, crisis_type: CrisisType, stage: CrisisStage) -> bool {
+        match self.active.get_mut(&crisis_type) {
+            Some(existing) if existing.stage == stage => false,
+            Some(existing) => {
+                existing.stage = stage;
+                existing.stage_seconds = 0.0;
+                true
+            }
+            None => {
+                self.active.insert(
+                    crisis_type,
+                    ActiveCrisis {
+                        entity: Entity::PLACEHOLDER,
+                        stage,
+                        elapsed_seconds: 0.0,
+                        stage_seconds: 0.0,
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    /// Resolve a crisis. Returns true if it was active.
+    pub fn clear(&mut self, crisis_type: CrisisType) -> bool {
+        self.active.remove(&crisis_type).is_some()
+    }
+
+    /// Advance every crisis clock
+    pub fn tick(&mut self, delta_seconds: f32) {
+        for crisis in self.active.values_mut() {
+            crisis.elapsed_seconds += delta_seconds;
+            crisis.stage_seconds += delta_seconds;
+        }
+    }
+
+    /// Mirror an open crisis entity into the index. A crisis that kept its
+    /// stage keeps its stage clock.
+    pub fn track(&mut self, crisis_type: CrisisType, mut crisis: ActiveCrisis) {
+        if let Some(existing) = self.active.get(&crisis_type) {
+            if existing.stage == crisis.stage {
+                crisis.stage_seconds = existing.stage_seconds;
+            }
+        }
+        self.active.insert(crisis_type, crisis);
+    }
+
+    /// Drop every mirrored crisis ahead of a rebuild
+    #[allow(dead_code)]
+    pub fn clear_tracked(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn get(&self, crisis_type: CrisisType) -> Option<&ActiveCrisis> {
+        self.active.get(&crisis_type)
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, crisis_type: CrisisType) -> bool {
+        self.active.contains_key(&crisis_type)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CrisisType, &ActiveCrisis)> {
+        self.active
+            .iter()
+            .map(|(crisis_type, crisis)| (*crisis_type, crisis))
+    }
+
+    /// The most severe open crisis
+    pub fn worst(&self) -> Option<(CrisisType, CrisisStage)> {
+        self.active
+            .iter()
+            .map(|(crisis_type, crisis)| (*crisis_type, crisis.stage))
+            .max_by_key(|(_, stage)| stage.severity())
+    }
+
+    /// The most severe stage across all open crises
+    pub fn worst_stage(&self) -> Option<CrisisStage> {
+        self.active
+            .values()
+            .map(|crisis| crisis.stage)
+            .max_by_key(|stage| stage.severity())
+    }
+
+    /// Station state implied by the worst open crisis
+    pub fn derived_state(&self) -> StationState {
+        match self.worst_stage() {
+            Some(CrisisStage::Failing) => StationState::Failing,
+            Some(CrisisStage::Strained) => StationState::Strained,
+            Some(CrisisStage::Stable | CrisisStage::Resolved) | None => StationState::Operational,
+        }
+    }
+}
+
+/// Crisis stages last reported to the event log, used to log only changes
 #[derive(Component, Debug, Default)]
 pub struct StationCrisisLog {
-    pub last_type: Option<CrisisType>,
-    pub last_stage: Option<CrisisStage>,
+    pub last: BTreeMap<CrisisType, CrisisStage>,
 }
 
+/// A station's goods hold. Raw ore mined, hauled or pulled from the zone
+/// lands here, and recipes draw their inputs from it.
 #[derive(Component, Debug)]
 pub struct StationProduction {
-    pub ore: f32,
-    pub ore_capacity: f32,
+    pub hold: Inventory,
 }
 
-/// A refinery job converting ore to fuel
-#[derive(Component, Debug, Clone)]
-#[allow(dead_code)]
+impl StationProduction {
+    /// An empty hold that stores `ore_capacity` units of raw ore
+    pub fn new(ore_capacity: f32) -> Self {
+        Self {
+            hold: Inventory::new(ore_capacity * Commodity::RawOre.mass()),
+        }
+    }
+
+    /// Start the hold with `ore` already in it
+    pub fn with_ore(mut self, ore: f32) -> Self {
+        self.deposit(ore);
+        self
+    }
+
+    pub fn ore(&self) -> f32 {
+        self.hold.amount(Commodity::RawOre)
+    }
+
+    pub fn ore_capacity(&self) -> f32 {
+        self.hold.capacity_for(Commodity::RawOre)
+    }
+
+    pub fn free_space(&self) -> f32 {
+        self.hold.room_for(Commodity::RawOre)
+    }
+
+    /// Take up to `amount` ore out of the hold. Returns the amount taken.
+    pub fn withdraw(&mut self, amount: f32) -> f32 {
+        self.hold.remove(Commodity::RawOre, amount)
+    }
+
+    /// Put up to `amount` ore into the hold. Returns the amount stored.
+    pub fn deposit(&mut self, amount: f32) -> f32 {
+        self.hold.add(Commodity::RawOre, amount)
+    }
+}
+
+/// Fuel a refinery makes from each unit of ore
+pub const REFINERY_FUEL_PER_ORE: f32 = 2.0;
+
+/// Ore per minute an Operational refinery works through
+pub const REFINERY_ORE_PER_MINUTE: f32 = 6.0;
+
+/// Batches a refinery's queue holds, counting the one being processed
+pub const REFINERY_QUEUE_LIMIT: usize = 4;
+
+/// Storage levels, as a share of capacity, a refinery can be told to refine
+/// its input hold up to
+pub const REFINERY_TARGET_OPTIONS: [f32; 4] = [0.25, 0.5, 0.75, 1.0];
+
+/// Ore per minute a refinery processes in the given state; crises slow the
+/// line down and a refinery that is not running stops it
+pub fn refinery_ore_per_minute(state: StationState) -> f32 {
+    let rate = match state {
+        StationState::Operational => 1.0,
+        StationState::Strained => 0.5,
+        StationState::Failing => 0.25,
+        StationState::Deploying | StationState::Failed => 0.0,
+    };
+    REFINERY_ORE_PER_MINUTE * rate
+}
+
+/// A batch of the player's ore queued at a refinery
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RefineryJob {
     pub ore_in: u32,
     pub fuel_out: f32,
-    pub remaining_seconds: f32,
+    pub ore_remaining: f32,
 }
 
-#[allow(dead_code)]
 impl RefineryJob {
     /// Create a new refinery job
     pub fn new(ore_in: u32) -> Self {
-        // 2 ore -> 1 fuel, takes 60 seconds
-        let fuel_out = ore_in as f32 / 2.0;
         Self {
             ore_in,
-            fuel_out,
-            remaining_seconds: 60.0,
+            fuel_out: ore_in as f32 * REFINERY_FUEL_PER_ORE,
+            ore_remaining: ore_in as f32,
         }
     }
 
-    /// Update job timer, returns true if complete
-    pub fn tick(&mut self, delta_seconds: f32) -> bool {
-        self.remaining_seconds -= delta_seconds;
-        self.remaining_seconds <= 0.0
+    /// Work up to `ore` of the batch. Returns the amount processed.
+    pub fn process(&mut self, ore: f32) -> f32 {
+        let processed = ore.clamp(0.0, self.ore_remaining);
+        self.ore_remaining -= processed;
+        processed
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.ore_remaining <= 0.0
+    }
+
+    /// Whole units of ore not yet refined, handed back if the batch is cancelled
+    pub fn unrefined_ore(&self) -> u32 {
+        self.ore_remaining.max(0.0).floor() as u32
+    }
+
+    /// Share of the batch done
+    pub fn progress(&self) -> f32 {
+        if self.ore_in > 0 {
+            (1.0 - self.ore_remaining / self.ore_in as f32).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
     }
 }
 
-/// A shipyard job building a scout
-#[derive(Component, Debug, Clone)]
-#[allow(dead_code)]
+/// A refinery's batch queue and stock policy. Queued batches are worked
+/// first, front to back; with none left the refinery draws on its input hold
+/// until its storage reaches the target.
+#[derive(Component, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RefineryQueue {
+    pub jobs: VecDeque<RefineryJob>,
+    pub target_fraction: f32,
+}
+
+impl Default for RefineryQueue {
+    fn default() -> Self {
+        Self {
+            jobs: VecDeque::new(),
+            target_fraction: 0.5,
+        }
+    }
+}
+
+impl RefineryQueue {
+    pub fn is_full(&self) -> bool {
+        self.jobs.len() >= REFINERY_QUEUE_LIMIT
+    }
+
+    /// Queue a batch behind the others, returns true if there was room
+    pub fn push(&mut self, job: RefineryJob) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        self.jobs.push_back(job);
+        true
+    }
+
+    /// Fuel in storage at which the refinery stops drawing on its hold
+    pub fn target_stock(&self, storage: &RefineryStorage) -> f32 {
+        storage.fuel_capacity * self.target_fraction
+    }
+
+    /// Step to the next target level, wrapping back to the lowest
+    pub fn cycle_target(&mut self) {
+        self.target_fraction = REFINERY_TARGET_OPTIONS
+            .iter()
+            .copied()
+            .find(|option| *option > self.target_fraction + f32::EPSILON)
+            .unwrap_or(REFINERY_TARGET_OPTIONS[0]);
+    }
+
+    /// Refine up to `ore` this tick: queued batches first, then the input
+    /// hold while storage is under target. Output that would not fit in
+    /// storage is never started. Returns the batches finished and the fuel
+    /// made.
+    pub fn refine(
+        &mut self,
+        ore: f32,
+        hold: Option<&mut StationProduction>,
+        storage: &mut RefineryStorage,
+    ) -> (Vec<RefineryJob>, f32) {
+        let mut budget = ore.min(storage.free_space() / REFINERY_FUEL_PER_ORE);
+        let mut finished = Vec::new();
+        let mut fuel = 0.0;
+
+        while budget > 0.0 {
+            let Some(job) = self.jobs.front_mut() else {
+                break;
+            };
+            let processed = job.process(budget);
+            budget -= processed;
+            fuel += processed * REFINERY_FUEL_PER_ORE;
+            if !job.is_complete() {
+                break;
+            }
+            finished.extend(self.jobs.pop_front());
+        }
+
+        if let Some(hold) = hold {
+            let target = self.target_stock(storage);
+            let wanted = (target - storage.fuel - fuel).max(0.0) / REFINERY_FUEL_PER_ORE;
+            fuel += hold.withdraw(budget.min(wanted)) * REFINERY_FUEL_PER_ORE;
+        }
+
+        storage.add_fuel(fuel);
+        (finished, fuel)
+    }
+}
+
+/// Hulls a shipyard can build
+pub const SHIPYARD_HULLS: [ShipKind; 3] = [ShipKind::Scout, ShipKind::Miner, ShipKind::Security];
+
+/// Jobs a shipyard's build queue holds, counting the one being built
+pub const SHIPYARD_QUEUE_LIMIT: usize = 4;
+
+/// Share of a finished ship's tank the shipyard fills before launching it
+pub const SHIPYARD_LAUNCH_FUEL_FRACTION: f32 = 0.5;
+
+/// Share of its own tank a shipyard keeps back when fuelling launches
+pub const SHIPYARD_LAUNCH_RESERVE: f32 = 0.25;
+
+/// Ore a shipyard charges to build a replacement hull for an escape pod
+pub const REPLACEMENT_HULL_ORE_COST: u32 = 20;
+
+/// Credits a shipyard charges to build a replacement hull for an escape pod
+pub const REPLACEMENT_HULL_CREDIT_COST: u32 = 40;
+
+/// Seconds a shipyard takes to build a replacement hull
+pub const REPLACEMENT_HULL_BUILD_SECONDS: f32 = 90.0;
+
+/// What a hull costs to build and how long it takes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShipBuildCost {
+    pub ore: u32,
+    pub credits: u32,
+    pub seconds: f32,
+}
+
+pub fn ship_build_cost(kind: ShipKind) -> ShipBuildCost {
+    let (ore, credits, seconds) = match kind {
+        ShipKind::Scout => (15, 0, 120.0),
+        ShipKind::Miner => (25, 20, 150.0),
+        ShipKind::Security => (35, 50, 180.0),
+        // Only ever built as a replacement for the player's escape pod
+        ShipKind::PlayerShip => (
+            REPLACEMENT_HULL_ORE_COST,
+            REPLACEMENT_HULL_CREDIT_COST,
+            REPLACEMENT_HULL_BUILD_SECONDS,
+        ),
+    };
+    ShipBuildCost {
+        ore,
+        credits,
+        seconds,
+    }
+}
+
+/// A hull on a shipyard's build queue
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ShipyardJob {
+    pub kind: ShipKind,
     pub ore_in: u32,
-    pub fuel_in: f32,
+    pub credits_in: u32,
     pub remaining_seconds: f32,
+    pub build_seconds: f32,
 }
 
-#[allow(dead_code)]
 impl ShipyardJob {
-    /// Create a new shipyard job for building a scout
-    pub fn new() -> Self {
+    /// Create a new shipyard job for building a hull, paid in full
+    pub fn new(kind: ShipKind) -> Self {
+        let cost = ship_build_cost(kind);
         Self {
-            ore_in: 30,
-            fuel_in: 15.0,
-            remaining_seconds: 120.0,
+            kind,
+            ore_in: cost.ore,
+            credits_in: cost.credits,
+            remaining_seconds: cost.seconds,
+            build_seconds: cost.seconds,
         }
     }
 
@@ -120,16 +583,58 @@ impl ShipyardJob {
         self.remaining_seconds -= delta_seconds;
         self.remaining_seconds <= 0.0
     }
+
+    /// Share of the build done
+    pub fn progress(&self) -> f32 {
+        if self.build_seconds > 0.0 {
+            (1.0 - self.remaining_seconds / self.build_seconds).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.remaining_seconds < self.build_seconds
+    }
 }
 
 impl Default for ShipyardJob {
     fn default() -> Self {
-        Self::new()
+        Self::new(ShipKind::Scout)
+    }
+}
+
+/// A shipyard's build queue, first in first out: only the job at the front
+/// is being built
+#[derive(Component, Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ShipyardQueue {
+    pub jobs: VecDeque<ShipyardJob>,
+    /// Initial orders for the scouts this yard launches
+    pub scout_risk: RiskTolerance,
+}
+
+impl ShipyardQueue {
+    pub fn is_full(&self) -> bool {
+        self.jobs.len() >= SHIPYARD_QUEUE_LIMIT
+    }
+
+    /// Queue a job behind the others, returns true if there was room
+    pub fn push(&mut self, job: ShipyardJob) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        self.jobs.push_back(job);
+        true
+    }
+
+    /// Whether a replacement hull for the player is on the slip
+    pub fn has_replacement_hull(&self) -> bool {
+        self.jobs.iter().any(|job| job.kind == ShipKind::PlayerShip)
     }
 }
 
 /// Storage for refined fuel at a refinery
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[allow(dead_code)]
 pub struct RefineryStorage {
     pub fuel: f32,
@@ -167,52 +672,193 @@ impl RefineryStorage {
     }
 }
 
-/// Storage for completed scouts at a shipyard
-#[derive(Component, Debug, Clone, Default)]
-#[allow(dead_code)]
+/// Hangar for finished ships waiting at a shipyard for fuel to launch
+#[derive(Component, Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct ShipyardStorage {
-    pub ready_scouts: u8,
+    pub hangar: Vec<ShipKind>,
     pub capacity: u8,
 }
 
-#[allow(dead_code)]
 impl ShipyardStorage {
     /// Create new shipyard storage with default capacity
     pub fn new() -> Self {
         Self {
-            ready_scouts: 0,
+            hangar: Vec::new(),
             capacity: 3,
         }
     }
 
-    /// Add a completed scout, returns true if successful
-    pub fn add_scout(&mut self) -> bool {
-        if self.ready_scouts < self.capacity {
-            self.ready_scouts += 1;
-            true
-        } else {
-            false
+    /// Add a finished ship, returns true if successful
+    pub fn add_ship(&mut self, kind: ShipKind) -> bool {
+        if self.is_full() {
+            return false;
         }
-    }
-
-    /// Remove a scout for deployment, returns true if successful
-    pub fn remove_scout(&mut self) -> bool {
-        if self.ready_scouts > 0 {
-            self.ready_scouts -= 1;
-            true
-        } else {
-            false
+        self.hangar.push(kind);
+        true
+    }
+
+    /// The ship next in line to launch. Replacement hulls wait for the player
+    /// to collect them instead.
+    pub fn next_launch(&self) -> Option<ShipKind> {
+        self.hangar
+            .iter()
+            .copied()
+            .find(|kind| *kind != ShipKind::PlayerShip)
+    }
+
+    /// Take the longest-waiting ship out to launch it
+    pub fn launch(&mut self) -> Option<ShipKind> {
+        let index = self
+            .hangar
+            .iter()
+            .position(|kind| *kind != ShipKind::PlayerShip)?;
+        Some(self.hangar.remove(index))
+    }
+
+    /// Hand a finished replacement hull over to the player, returns true if one was waiting
+    pub fn take_replacement_hull(&mut self) -> bool {
+        match self
+            .hangar
+            .iter()
+            .position(|kind| *kind == ShipKind::PlayerShip)
+        {
+            Some(index) => {
+                self.hangar.remove(index);
+                true
+            }
+            None => false,
         }
     }
 
     /// Check if storage is full
     pub fn is_full(&self) -> bool {
-        self.ready_scouts >= self.capacity
+        self.hangar.len() >= self.capacity as usize
     }
 
     /// Get number of available slots
+    #[allow(dead_code)]
     pub fn free_slots(&self) -> u8 {
-        self.capacity.saturating_sub(self.ready_scouts)
+        self.capacity.saturating_sub(self.hangar.len() as u8)
+    }
+}
+
+/// Fuel a shipyard puts into a `kind` it launches, if it can spare it above
+/// its reserve
+pub fn shipyard_launch_fuel(station: &Station, kind: ShipKind) -> Option<f32> {
+    let fuel = ship_fuel_capacity(kind) * SHIPYARD_LAUNCH_FUEL_FRACTION;
+    let reserve = station.fuel_capacity * SHIPYARD_LAUNCH_RESERVE;
+    (station.fuel - fuel >= reserve).then_some(fuel)
+}
+
+/// Goods a refinery or shipyard can make from what sits in its hold
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum RecipeKind {
+    AlloySmelting,
+    IsotopeExtraction,
+    ComponentFabrication,
+}
+
+/// Every recipe, in the order the docking menu cycles through them
+pub const RECIPES: [RecipeKind; 3] = [
+    RecipeKind::AlloySmelting,
+    RecipeKind::IsotopeExtraction,
+    RecipeKind::ComponentFabrication,
+];
+
+/// What one cycle of a recipe takes from the hold and puts back
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecipeSpec {
+    pub name: &'static str,
+    pub station: StationKind,
+    pub input: (Commodity, f32),
+    pub output: (Commodity, f32),
+    pub seconds: f32,
+}
+
+impl RecipeKind {
+    pub fn spec(self) -> RecipeSpec {
+        match self {
+            RecipeKind::AlloySmelting => RecipeSpec {
+                name: "Alloy smelting",
+                station: StationKind::Refinery,
+                input: (Commodity::RawOre, 4.0),
+                output: (Commodity::Alloys, 1.0),
+                seconds: 60.0,
+            },
+            RecipeKind::IsotopeExtraction => RecipeSpec {
+                name: "Isotope extraction",
+                station: StationKind::Refinery,
+                input: (Commodity::FuelOre, 5.0),
+                output: (Commodity::RareIsotopes, 1.0),
+                seconds: 90.0,
+            },
+            RecipeKind::ComponentFabrication => RecipeSpec {
+                name: "Component fabrication",
+                station: StationKind::Shipyard,
+                input: (Commodity::Alloys, 1.0),
+                output: (Commodity::Components, 2.0),
+                seconds: 45.0,
+            },
+        }
+    }
+}
+
+/// Recipes a station kind can run
+pub fn station_recipes(kind: StationKind) -> impl Iterator<Item = RecipeKind> {
+    RECIPES
+        .into_iter()
+        .filter(move |recipe| recipe.spec().station == kind)
+}
+
+/// The recipe a station runs alongside its other work, and how far along the
+/// current cycle is
+#[derive(Component, Debug, Clone, Default)]
+pub struct StationRecipe {
+    pub active: Option<RecipeKind>,
+    pub elapsed_seconds: f32,
+}
+
+impl StationRecipe {
+    /// Step to the next recipe the station can run, then back to idle
+    pub fn cycle(&mut self, kind: StationKind) {
+        let recipes: Vec<RecipeKind> = station_recipes(kind).collect();
+        let next = match self.active {
+            None => recipes.first().copied(),
+            Some(active) => recipes
+                .iter()
+                .position(|recipe| *recipe == active)
+                .and_then(|index| recipes.get(index + 1).copied()),
+        };
+        self.active = next;
+        self.elapsed_seconds = 0.0;
+    }
+
+    /// Work the active recipe for `seconds`, drawing inputs from `hold` and
+    /// storing outputs back into it. Outputs always weigh less than inputs,
+    /// so a finished cycle never overfills the hold. A cycle only runs while
+    /// its input is in the hold. Returns the cycles completed.
+    pub fn work(&mut self, seconds: f32, hold: &mut Inventory) -> u32 {
+        let Some(recipe) = self.active else {
+            return 0;
+        };
+        let spec = recipe.spec();
+        let (input, input_amount) = spec.input;
+        let (output, output_amount) = spec.output;
+
+        let mut completed = 0;
+        self.elapsed_seconds += seconds;
+        while hold.amount(input) >= input_amount {
+            if self.elapsed_seconds < spec.seconds {
+                return completed;
+            }
+            self.elapsed_seconds -= spec.seconds;
+            hold.remove(input, input_amount);
+            hold.add(output, output_amount);
+            completed += 1;
+        }
+        // Nothing to work on: the next cycle starts from scratch
+        self.elapsed_seconds = 0.0;
+        completed
     }
 }
 
@@ -271,74 +917,1050 @@ pub fn station_ore_production_per_minute(kind: StationKind) -> f32 {
     }
 }
 
-/// Check if station kind is an NPC-owned type (not player-buildable).
-#[allow(dead_code)]
-pub fn is_npc_station(kind: StationKind) -> bool {
-    matches!(kind, StationKind::Outpost)
-}
+/// Check if station kind is an NPC-owned type (not player-buildable).
+#[allow(dead_code)]
+pub fn is_npc_station(kind: StationKind) -> bool {
+    matches!(kind, StationKind::Outpost)
+}
+
+// =============================================================================
+// Fuel Depots
+// =============================================================================
+
+/// Reserve levels a depot can be told to keep back for its own burn
+pub const DEPOT_RESERVE_OPTIONS: [f32; 4] = [0.0, 0.25, 0.5, 0.75];
+
+/// Range at which a depot refuels AI ships alongside it
+pub const DEPOT_DISPENSE_RANGE: f32 = 30.0;
+
+/// Fuel per second a depot pumps into each ship alongside it
+pub const DEPOT_DISPENSE_PER_SECOND: f32 = 2.0;
+
+/// Fuel per minute a refinery ships from its storage to the nearest depot
+/// in its zone
+pub const REFINERY_DEPOT_DELIVERY_PER_MINUTE: f32 = 6.0;
+
+/// Speed of an AI ship limping to a depot on low fuel
+pub const DEPOT_RETURN_SPEED: f32 = 12.0;
+
+/// How much of a depot's fuel is kept back for the station itself
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct FuelDepotPolicy {
+    pub reserve_fraction: f32,
+}
+
+impl Default for FuelDepotPolicy {
+    fn default() -> Self {
+        Self {
+            reserve_fraction: 0.25,
+        }
+    }
+}
+
+impl FuelDepotPolicy {
+    /// Fuel the depot will hand out before touching its reserve
+    pub fn available(&self, station: &Station) -> f32 {
+        if matches!(
+            station.state,
+            StationState::Deploying | StationState::Failed
+        ) {
+            return 0.0;
+        }
+        (station.fuel - station.fuel_capacity * self.reserve_fraction).max(0.0)
+    }
+
+    /// Hand out up to `amount` without dipping into the reserve. Returns the
+    /// amount actually dispensed.
+    pub fn dispense(&self, station: &mut Station, amount: f32) -> f32 {
+        let dispensed = amount.max(0.0).min(self.available(station));
+        station.fuel -= dispensed;
+        dispensed
+    }
+
+    /// Step to the next reserve level, wrapping back to none
+    pub fn cycle_reserve(&mut self) {
+        self.reserve_fraction = DEPOT_RESERVE_OPTIONS
+            .iter()
+            .copied()
+            .find(|option| *option > self.reserve_fraction + f32::EPSILON)
+            .unwrap_or(DEPOT_RESERVE_OPTIONS[0]);
+    }
+}
+
+// =============================================================================
+// Sensor Stations
+// =============================================================================
+
+/// Radius inside which an operational sensor station identifies contacts
+pub const SENSOR_IDENTIFY_RANGE: f32 = 400.0;
+
+/// Seconds of sweeping it takes to deepen knowledge of a zone by one layer
+pub const SENSOR_LAYER_SECONDS: f32 = 90.0;
+
+/// How far a sensor station slows intel decay one route away
+pub const SENSOR_NEIGHBOUR_DECAY_RELIEF: f32 = 0.5;
+
+/// Progress of a sensor station's sweep toward the next knowledge layer
+#[derive(Component, Debug, Default)]
+pub struct SensorSweep {
+    pub layer_seconds: f32,
+}
+
+/// How well a sensor station sees through its zone: nebulae scatter the
+/// sweep, clear space sharpens it
+pub fn sensor_strength(modifier: Option<ZoneModifier>) -> f32 {
+    match modifier {
+        Some(ZoneModifier::NebulaInterference) => 0.5,
+        Some(ZoneModifier::ClearSignals) => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Decay multiplier a sweep of the given strength lends the zones next door
+pub fn sensor_neighbour_decay(strength: f32) -> f32 {
+    (1.0 - SENSOR_NEIGHBOUR_DECAY_RELIEF * strength).clamp(0.1, 1.0)
+}
+
+// =============================================================================
+// Construction
+// =============================================================================
+
+/// Station kinds the player can build from the construction menu
+pub const BUILDABLE_STATIONS: [StationKind; 5] = [
+    StationKind::MiningOutpost,
+    StationKind::Refinery,
+    StationKind::Shipyard,
+    StationKind::FuelDepot,
+    StationKind::SensorStation,
+];
+
+/// How close another station may sit to a construction site
+pub const STATION_SITE_SPACING: f32 = 18.0;
+
+/// Sites the player can pick from, spaced evenly around a zone's node
+pub const CONSTRUCTION_SITE_SLOTS: usize = 6;
+
+/// Distance from the node to each construction site
+pub const CONSTRUCTION_SITE_RADIUS: f32 = 70.0;
+
+/// Share of a station's ore and fuel the player lays down when ordering it;
+/// the rest has to be delivered while it deploys
+pub const CONSTRUCTION_FOUNDATION_SHARE: f32 = 0.25;
+
+/// Ore or fuel a working station sends each minute to construction sites in
+/// its own zone
+pub const CONSTRUCTION_SUPPLY_PER_MINUTE: f32 = 6.0;
+
+/// What a new station costs: ore from the hold, credits, and fuel from the
+/// ship's tank that primes the station
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StationCost {
+    pub ore: u32,
+    pub fuel: f32,
+    pub credits: u32,
+}
+
+impl StationCost {
+    /// A share of the cost, with ore and credits rounded down
+    pub fn scaled(&self, fraction: f32) -> Self {
+        let fraction = fraction.clamp(0.0, 1.0);
+        Self {
+            ore: (self.ore as f32 * fraction).floor() as u32,
+            fuel: self.fuel * fraction,
+            credits: (self.credits as f32 * fraction).floor() as u32,
+        }
+    }
+
+    /// What the player is missing to pay this cost, if anything
+    pub fn shortfall(&self, ore: u32, fuel: f32, credits: u32) -> Option<String> {
+        if ore < self.ore {
+            return Some(format!("need {} more ore", self.ore - ore));
+        }
+        if fuel < self.fuel {
+            return Some(format!("need {:.0} more fuel", (self.fuel - fuel).ceil()));
+        }
+        if credits < self.credits {
+            return Some(format!("need {} more credits", self.credits - credits));
+        }
+        None
+    }
+}
+
+pub fn station_build_cost(kind: StationKind) -> StationCost {
+    let (ore, credits) = match kind {
+        StationKind::MiningOutpost => (18, 0),
+        StationKind::FuelDepot => (20, 30),
+        StationKind::SensorStation => (15, 25),
+        StationKind::Shipyard => (40, 60),
+        StationKind::Refinery => (30, 40),
+        StationKind::Outpost => (0, 0), // Not player-buildable
+    };
+    StationCost {
+        ore,
+        // Fuel delivered during construction fills the new station's tank halfway
+        fuel: station_fuel_capacity(kind) * 0.5,
+        credits,
+    }
+}
+
+/// Where the player's picked site sits relative to its zone's node, going
+/// round the node from due east
+pub fn construction_site_offset(slot: usize) -> Vec2 {
+    let angle = (slot % CONSTRUCTION_SITE_SLOTS) as f32 * std::f32::consts::TAU
+        / CONSTRUCTION_SITE_SLOTS as f32;
+    Vec2::from_angle(angle) * CONSTRUCTION_SITE_RADIUS
+}
+
+/// Why the player can't claim a zone for construction, if they can't: a
+/// pirate base operating there, or pirates holding the zone
+pub fn zone_claim_problem(pirate_base: bool, pirate_held: bool) -> Option<&'static str> {
+    if pirate_base {
+        Some("a pirate base operates in this zone")
+    } else if pirate_held {
+        Some("pirates hold this zone")
+    } else {
+        None
+    }
+}
+
+/// Zone conditions a kind of station can't be built in
+pub fn site_modifier_problem(
+    kind: StationKind,
+    modifier: Option<ZoneModifier>,
+) -> Option<&'static str> {
+    match (kind, modifier?) {
+        (StationKind::SensorStation, ZoneModifier::NebulaInterference) => {
+            Some("nebula interference would blind its sensors")
+        }
+        (StationKind::Shipyard, ZoneModifier::HighRadiation) => {
+            Some("radiation is too high for shipyard crews")
+        }
+        (StationKind::FuelDepot, ZoneModifier::HighRadiation) => {
+            Some("radiation makes bulk fuel storage unsafe")
+        }
+        _ => None,
+    }
+}
+
+/// Why a station can't go up at `site`, if it can't: the zone has to suit the
+/// kind, be one the player can claim and leave the site clear of other stations
+pub fn construction_site_problem(
+    kind: StationKind,
+    site: Vec2,
+    modifier: Option<ZoneModifier>,
+    claim_problem: Option<&str>,
+    mut stations: impl Iterator<Item = Vec2>,
+) -> Option<String> {
+    if let Some(problem) = site_modifier_problem(kind, modifier) {
+        return Some(problem.to_string());
+    }
+    if let Some(problem) = claim_problem {
+        return Some(problem.to_string());
+    }
+    if stations.any(|position| position.distance(site) <= STATION_SITE_SPACING) {
+        return Some("site is occupied".to_string());
+    }
+    None
+}
+
+/// One material a construction site works through: deliveries wait in
+/// `stock` until the build uses them
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BuildMaterial {
+    pub required: f32,
+    pub stock: f32,
+    pub used: f32,
+}
+
+impl BuildMaterial {
+    fn new(required: f32, stock: f32) -> Self {
+        Self {
+            required,
+            stock: stock.min(required),
+            used: 0.0,
+        }
+    }
+
+    /// How much more the site will take delivery of
+    pub fn wanted(&self) -> f32 {
+        (self.required - self.used - self.stock).max(0.0)
+    }
+
+    /// Take up to `amount`; returns what was accepted
+    pub fn deliver(&mut self, amount: f32) -> f32 {
+        let accepted = amount.clamp(0.0, self.wanted());
+        self.stock += accepted;
+        accepted
+    }
+
+    /// Share of the requirement already built into the station
+    pub fn progress(&self) -> f32 {
+        if self.required > 0.0 {
+            (self.used / self.required).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Seconds of building the stock covers at `rate` per second
+    fn covers(&self, rate: f32) -> f32 {
+        // Once everything still needed is on site, rounding can't hold the build up
+        if rate <= 0.0 || self.stock >= self.required - self.used - 0.01 {
+            f32::INFINITY
+        } else {
+            self.stock / rate
+        }
+    }
+
+    fn is_dry(&self) -> bool {
+        self.covers(1.0) < 0.01
+    }
+
+    fn consume(&mut self, amount: f32) -> f32 {
+        let used = amount.min(self.stock);
+        self.stock -= used;
+        self.used += used;
+        used
+    }
+}
+
+/// A station the player ordered built. It needs ore and fuel delivered over
+/// its build time and stalls while either runs dry; the order can be cancelled
+/// while it is still deploying.
+#[derive(Component, Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConstructionSite {
+    pub cost: StationCost,
+    pub build_seconds: f32,
+    pub ore: BuildMaterial,
+    pub fuel: BuildMaterial,
+    /// Whether the last tick was held up for want of materials
+    #[serde(default)]
+    pub stalled: bool,
+}
+
+impl ConstructionSite {
+    /// A freshly ordered site, stocked with the foundation the order paid for
+    pub fn new(kind: StationKind) -> Self {
+        let cost = station_build_cost(kind);
+        let foundation = Self::order_cost(kind);
+        Self {
+            cost,
+            build_seconds: station_build_time_seconds(kind),
+            ore: BuildMaterial::new(cost.ore as f32, foundation.ore as f32),
+            fuel: BuildMaterial::new(cost.fuel, foundation.fuel),
+            stalled: false,
+        }
+    }
+
+    /// What ordering a station takes from the player: all the credits and the
+    /// foundation share of its ore and fuel
+    pub fn order_cost(kind: StationKind) -> StationCost {
+        let cost = station_build_cost(kind);
+        StationCost {
+            credits: cost.credits,
+            ..cost.scaled(CONSTRUCTION_FOUNDATION_SHARE)
+        }
+    }
+
+    /// Build for up to `seconds`, using materials at an even rate over the
+    /// build time. Returns the seconds actually built and the fuel used, which
+    /// goes into the station's tank.
+    pub fn build(&mut self, seconds: f32) -> (f32, f32) {
+        let build_seconds = self.build_seconds.max(1.0);
+        let ore_rate = self.ore.required / build_seconds;
+        let fuel_rate = self.fuel.required / build_seconds;
+
+        let built = seconds
+            .min(self.ore.covers(ore_rate))
+            .min(self.fuel.covers(fuel_rate))
+            .max(0.0);
+        self.ore.consume(ore_rate * built);
+        let fuel = self.fuel.consume(fuel_rate * built);
+        (built, fuel)
+    }
+
+    /// The material holding the build up, if one has run dry
+    pub fn waiting_on(&self) -> Option<&'static str> {
+        if self.ore.is_dry() {
+            Some("ore")
+        } else if self.fuel.is_dry() {
+            Some("fuel")
+        } else {
+            None
+        }
+    }
+
+    /// Per-material progress, e.g. "Ore 40% | Fuel 55%"
+    pub fn progress_line(&self) -> String {
+        let mut line = format!(
+            "Ore {:.0}% | Fuel {:.0}%",
+            self.ore.progress() * 100.0,
+            self.fuel.progress() * 100.0
+        );
+        if let Some(material) = self.waiting_on() {
+            line.push_str(&format!(" - stalled, needs {}", material));
+        }
+        line
+    }
+
+    /// Credits come back in proportion to the build time left; ore and fuel
+    /// are whatever is still on site, unused, plus the station's tank
+    pub fn refund(&self, remaining_seconds: f32, tank: f32) -> StationCost {
+        let left = if self.build_seconds > 0.0 {
+            remaining_seconds / self.build_seconds
+        } else {
+            0.0
+        };
+        StationCost {
+            ore: self.ore.stock.floor() as u32,
+            fuel: tank.max(0.0) + self.fuel.stock,
+            credits: self.cost.scaled(left).credits,
+        }
+    }
+}
+
+// =============================================================================
+// Station Output & Hauling
+// =============================================================================
+
+/// How close a hauler has to be to load or unload at a station
+pub const HAULER_TRANSFER_RANGE: f32 = 30.0;
+
+/// How far a hauler will fly within its zone to reach a station
+pub const HAULER_SEEK_RANGE: f32 = 400.0;
+
+/// Hauler cruise speed in units per second
+pub const HAULER_SPEED: f32 = 14.0;
+
+/// Ore a Refinery or Shipyard draws from mining outposts in its zone each minute
+pub const ZONE_ORE_PULL_PER_MINUTE: f32 = 4.0;
+
+/// Stations whose output haulers collect and that feed same-zone consumers
+pub fn is_ore_producer(kind: StationKind) -> bool {
+    kind == StationKind::MiningOutpost
+}
+
+/// Stations that take ore in from haulers and same-zone producers
+pub fn is_ore_consumer(kind: StationKind) -> bool {
+    matches!(kind, StationKind::Refinery | StationKind::Shipyard)
+}
+
+/// Stations only move ore while they are running
+pub fn handles_ore(state: StationState) -> bool {
+    !matches!(state, StationState::Deploying | StationState::Failed)
+}
+
+// =============================================================================
+// Restart, Abandonment & Decommissioning
+// =============================================================================
+
+/// Ore it takes to bring a failed station back online
+pub const STATION_RESTART_ORE_COST: u32 = 10;
+
+/// Fuel it takes to bring a failed station back online; it primes the tank
+pub const STATION_RESTART_FUEL_COST: f32 = 15.0;
+
+/// Seconds a restarted station spends redeploying before it operates again
+pub const STATION_RESTART_SECONDS: f32 = 60.0;
+
+/// Share of its stock the player strips out of a station they walk away from
+pub const ABANDON_SALVAGE_FRACTION: f32 = 0.5;
+
+/// Ore and fuel held at a station, across its tank and storage
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StationStock {
+    pub ore: f32,
+    pub fuel: f32,
+}
+
+impl StationStock {
+    pub fn of(
+        station: &Station,
+        production: Option<&StationProduction>,
+        storage: Option<&RefineryStorage>,
+    ) -> Self {
+        Self {
+            ore: production.map_or(0.0, |production| production.ore()),
+            fuel: station.fuel + storage.map_or(0.0, |storage| storage.fuel),
+        }
+    }
+
+    /// What the player recovers when abandoning the station; the rest is lost
+    pub fn salvage(&self) -> Self {
+        Self {
+            ore: (self.ore * ABANDON_SALVAGE_FRACTION).floor(),
+            fuel: self.fuel * ABANDON_SALVAGE_FRACTION,
+        }
+    }
+
+    /// Move the stock into another station: fuel tops up its tank and then
+    /// its refinery storage, ore goes to its production hold. Returns what fit.
+    pub fn deliver(
+        &self,
+        station: &mut Station,
+        production: Option<&mut StationProduction>,
+        storage: Option<&mut RefineryStorage>,
+    ) -> Self {
+        let tank_space = (station.fuel_capacity - station.fuel).max(0.0);
+        let mut fuel = self.fuel.min(tank_space);
+        station.fuel += fuel;
+        if let Some(storage) = storage {
+            fuel += storage.add_fuel(self.fuel - fuel);
+        }
+
+        let ore = production.map_or(0.0, |production| production.deposit(self.ore));
+
+        Self { ore, fuel }
+    }
+}
+
+/// A decommissioned station whose stock moves to the next of the player's
+/// working stations they dock at
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Decommissioned;
+
+// =============================================================================
+// Outpost Trading
+// =============================================================================
+
+/// Fuel the player can buy from an Outpost per purchase
+pub const OUTPOST_FUEL_LOTS: [u32; 3] = [10, 25, 50];
+
+/// Ore the player can sell to an Outpost per sale
+pub const OUTPOST_ORE_LOTS: [u32; 2] = [5, 10];
+
+/// Credits per unit of fuel at an Outpost holding the stock it wants
+pub const FUEL_BASE_PRICE: f32 = 0.5;
+
+/// Bounds on how far scarcity or a glut moves a price from its list price
+const MARKET_MIN_SCARCITY: f32 = 0.5;
+const MARKET_MAX_SCARCITY: f32 = 2.5;
+
+/// Share of the gap between stock and demand an Outpost's own trade closes
+/// each minute
+const MARKET_RECOVERY_PER_MINUTE: f32 = 0.05;
+
+/// At full pirate control Outposts charge this much more, and pay as much less
+const PIRATE_CONTROL_PRICE_PREMIUM: f32 = 0.5;
+
+/// Ore prices in zones with rich ore veins
+const RICH_ORE_PRICE_FACTOR: f32 = 0.7;
+
+/// Something an Outpost buys or sells: fuel by the tank unit, or a commodity
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum MarketGood {
+    Fuel,
+    Goods(Commodity),
+}
+
+impl MarketGood {
+    pub fn base_price(self) -> f32 {
+        match self {
+            MarketGood::Fuel => FUEL_BASE_PRICE,
+            MarketGood::Goods(commodity) => commodity.spec().base_price as f32,
+        }
+    }
+
+    /// Price shift a zone's conditions put on this good
+    pub fn zone_factor(self, modifier: Option<ZoneModifier>) -> f32 {
+        match (modifier, self) {
+            (
+                Some(ZoneModifier::RichOreVeins),
+                MarketGood::Goods(Commodity::RawOre | Commodity::FuelOre),
+            ) => RICH_ORE_PRICE_FACTOR,
+            _ => 1.0,
+        }
+    }
+}
+
+/// An Outpost's holding of one good against the amount it wants on hand
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MarketStock {
+    pub stock: f32,
+    pub demand: f32,
+}
+
+impl MarketStock {
+    /// A good held at exactly the level the Outpost wants
+    pub fn balanced(demand: f32) -> Self {
+        Self {
+            stock: demand,
+            demand,
+        }
+    }
+
+    /// Price multiplier from supply: short stock raises prices, a glut lowers them
+    pub fn scarcity(&self) -> f32 {
+        (self.demand / self.stock.max(1.0))
+            .sqrt()
+            .clamp(MARKET_MIN_SCARCITY, MARKET_MAX_SCARCITY)
+    }
+}
+
+/// What moves an Outpost's prices besides its own stock
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarketConditions {
+    pub modifier: Option<ZoneModifier>,
+    /// Pirate control of the Outpost's zone, in [0, 1]
+    pub pirate_control: f32,
+    /// Pirate business and protests, from the Outpost's trade terms
+    pub price_multiplier: f32,
+}
+
+impl Default for MarketConditions {
+    fn default() -> Self {
+        Self {
+            modifier: None,
+            pirate_control: 0.0,
+            price_multiplier: 1.0,
+        }
+    }
+}
+
+impl MarketConditions {
+    pub fn new(modifier: Option<ZoneModifier>, pirate_control: f32, trade: &OutpostTrade) -> Self {
+        Self {
+            modifier,
+            pirate_control,
+            price_multiplier: trade.price_multiplier,
+        }
+    }
+
+    fn risk(&self) -> f32 {
+        1.0 + self.pirate_control.clamp(0.0, 1.0) * PIRATE_CONTROL_PRICE_PREMIUM
+    }
+}
+
+/// An independent Outpost's stock and demand per good; prices follow them
+#[derive(Component, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OutpostMarket {
+    pub goods: BTreeMap<MarketGood, MarketStock>,
+}
+
+impl Default for OutpostMarket {
+    fn default() -> Self {
+        let demand = |commodity| match commodity {
+            Commodity::RawOre => 60.0,
+            Commodity::FuelOre => 40.0,
+            Commodity::Alloys => 20.0,
+            Commodity::Components => 15.0,
+            Commodity::RareIsotopes => 5.0,
+        };
+        let mut goods = BTreeMap::new();
+        goods.insert(MarketGood::Fuel, MarketStock::balanced(200.0));
+        for commodity in COMMODITIES {
+            goods.insert(
+                MarketGood::Goods(commodity),
+                MarketStock::balanced(demand(commodity)),
+            );
+        }
+        Self { goods }
+    }
+}
+
+impl OutpostMarket {
+    pub fn stock(&self, good: MarketGood) -> f32 {
+        self.goods.get(&good).map_or(0.0, |entry| entry.stock)
+    }
+
+    /// Price per unit before the Outpost's margin
+    fn price(&self, good: MarketGood, conditions: &MarketConditions) -> f32 {
+        let scarcity = self.goods.get(&good).map_or(1.0, MarketStock::scarcity);
+        good.base_price() * scarcity * good.zone_factor(conditions.modifier)
+    }
+
+    /// Credits per unit the Outpost charges
+    pub fn ask(&self, good: MarketGood, conditions: &MarketConditions) -> f32 {
+        self.price(good, conditions) * conditions.risk() * conditions.price_multiplier
+    }
+
+    /// Credits per unit the Outpost pays; the markup it charges comes off what it pays too
+    pub fn bid(&self, good: MarketGood, conditions: &MarketConditions) -> f32 {
+        self.price(good, conditions) / (conditions.risk() * conditions.price_multiplier.max(1.0))
+    }
+
+    /// Credits the Outpost charges for `amount` units, each priced at the
+    /// stock left once the units before it are sold
+    pub fn ask_total(&self, good: MarketGood, amount: u32, conditions: &MarketConditions) -> u32 {
+        self.walk_total(good, amount, -1.0, |market| market.ask(good, conditions))
+    }
+
+    /// Credits the Outpost pays for `amount` units, each priced at the stock
+    /// it holds once the units before it are bought
+    pub fn bid_total(&self, good: MarketGood, amount: u32, conditions: &MarketConditions) -> u32 {
+        self.walk_total(good, amount, 1.0, |market| market.bid(good, conditions))
+    }
+
+    /// Sum a unit price over `amount` units as stock moves by `step` per unit
+    fn walk_total(
+        &self,
+        good: MarketGood,
+        amount: u32,
+        step: f32,
+        unit_price: impl Fn(&Self) -> f32,
+    ) -> u32 {
+        let mut market = self.clone();
+        let mut total = 0.0;
+        for _ in 0..amount {
+            total += unit_price(&market);
+            if let Some(entry) = market.goods.get_mut(&good) {
+                entry.stock = (entry.stock + step).max(0.0);
+            }
+        }
+        total.round() as u32
+    }
+
+    /// Take `amount` out of stock for a buyer. Returns false, leaving stock
+    /// alone, if the Outpost doesn't hold that much.
+    pub fn sell(&mut self, good: MarketGood, amount: f32) -> bool {
+        match self.goods.get_mut(&good) {
+            Some(entry) if entry.stock >= amount => {
+                entry.stock -= amount;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Add goods bought from a seller to stock
+    pub fn buy(&mut self, good: MarketGood, amount: f32) {
+        self.goods
+            .entry(good)
+            .or_insert(MarketStock {
+                stock: 0.0,
+                demand: 0.0,
+            })
+            .stock += amount.max(0.0);
+    }
+
+    /// Local consumption and supply pull every stock back toward demand
+    pub fn recover(&mut self, minutes: f32) {
+        let share = (MARKET_RECOVERY_PER_MINUTE * minutes).min(1.0);
+        for entry in self.goods.values_mut() {
+            entry.stock += (entry.demand - entry.stock) * share;
+        }
+    }
+
+    /// What the player would pay for fuel and be paid for each commodity now
+    pub fn quotes(&self, conditions: &MarketConditions) -> BTreeMap<MarketGood, f32> {
+        self.goods
+            .keys()
+            .map(|good| {
+                let price = match good {
+                    MarketGood::Fuel => self.ask(*good, conditions),
+                    MarketGood::Goods(_) => self.bid(*good, conditions),
+                };
+                (*good, price)
+            })
+            .collect()
+    }
+}
+
+/// Credits for `amount` units at `unit_price`, to the nearest credit
+pub fn trade_total(unit_price: f32, amount: u32) -> u32 {
+    (unit_price * amount as f32).round() as u32
+}
+
+/// Prices the player saw at an Outpost, trusted less the older they get
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PriceReport {
+    pub seen_tick: u64,
+    pub confidence: f32,
+    /// Fuel at the Outpost's asking price, commodities at what it pays
+    pub prices: BTreeMap<MarketGood, f32>,
+}
+
+/// Known Outpost prices by zone. Like system intel, they fade once the
+/// player stops checking them.
+#[derive(Resource, Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MarketIntel {
+    pub reports: BTreeMap<u32, PriceReport>,
+}
+
+impl MarketIntel {
+    pub fn record(&mut self, zone: u32, tick: u64, prices: BTreeMap<MarketGood, f32>) {
+        self.reports.insert(
+            zone,
+            PriceReport {
+                seen_tick: tick,
+                confidence: 1.0,
+                prices,
+            },
+        );
+    }
+
+    /// Lose `base_decay` confidence per tick, faster as reports age; reports
+    /// with nothing left are forgotten
+    pub fn decay(&mut self, tick: u64, base_decay: f32) {
+        for report in self.reports.values_mut() {
+            let age = tick.saturating_sub(report.seen_tick);
+            let age_factor = (age as f32 / 1000.0).clamp(0.0, 1.0);
+            report.confidence -= base_decay * (1.0 + age_factor);
+        }
+        self.reports.retain(|_, report| report.confidence > 0.0);
+    }
+}
+
+/// Cap on how far pirate business can inflate an Outpost's prices
+pub const OUTPOST_MAX_PRICE_MULTIPLIER: f32 = 2.0;
+
+/// Price increase per unit of stolen ore pirates sell at an Outpost
+const PRICE_PER_PIRATE_ORE: f32 = 0.02;
+
+/// Standing lost with the player per unit of stolen ore an Outpost buys
+const STANDING_PER_PIRATE_ORE: f32 = 0.01;
+
+/// How far prices and standing drift back toward normal per recovery pulse
+const OUTPOST_RECOVERY_STEP: f32 = 0.02;
+
+/// Standing at or below which an Outpost refuses to trade with the player
+pub const OUTPOST_REFUSAL_STANDING: f32 = -0.6;
+
+/// Standing lost per trade update, per stage of an open political flashpoint
+const FLASHPOINT_STANDING_LOSS: f32 = 0.04;
+
+/// Lowest standing a protest alone can push the player to, short of refusal
+const FLASHPOINT_STANDING_FLOOR: f32 = -0.5;
+
+/// Price increase per trade update, per stage of an open political flashpoint
+const FLASHPOINT_PRICE_STEP: f32 = 0.03;
 
-// =============================================================================
-// Outpost Trading
-// =============================================================================
+/// Credits charged for pirate base intel on the black market
+pub const BLACK_MARKET_INTEL_COST: u32 = 30;
 
-/// Outpost trading option for buying fuel
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-#[allow(dead_code)]
-pub struct OutpostBuyFuelOption {
-    pub fuel_amount: u32,
-    pub credit_cost: u32,
+/// Standing lost each time the player buys from the black market
+pub const BLACK_MARKET_STANDING_COST: f32 = 0.15;
+
+/// Pirate influence on an independent Outpost's trade terms. Anything that
+/// trades keeps a market, so its stock always moves with trade.
+#[derive(Component, Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[require(OutpostMarket)]
+pub struct OutpostTrade {
+    /// Markup on everything the Outpost sells, and the discount on what it buys
+    pub price_multiplier: f32,
+    /// Player standing in [-1, 1]
+    pub player_standing: f32,
+    /// Pirates currently docked, recounted every trade update
+    #[serde(skip)]
+    pub docked_pirates: u32,
 }
 
-/// Outpost trading option for selling ore
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-#[allow(dead_code)]
-pub struct OutpostSellOreOption {
-    pub ore_amount: u32,
-    pub credit_reward: u32,
+impl Default for OutpostTrade {
+    fn default() -> Self {
+        Self {
+            price_multiplier: 1.0,
+            player_standing: 0.0,
+            docked_pirates: 0,
+        }
+    }
 }
 
-/// Available fuel purchase options at Outposts.
-/// Price ratio: 5 credits → 10 fuel (2 fuel per credit)
-#[allow(dead_code)]
-pub const OUTPOST_BUY_FUEL_OPTIONS: [OutpostBuyFuelOption; 3] = [
-    OutpostBuyFuelOption {
-        fuel_amount: 10,
-        credit_cost: 5,
-    },
-    OutpostBuyFuelOption {
-        fuel_amount: 25,
-        credit_cost: 12,
-    },
-    OutpostBuyFuelOption {
-        fuel_amount: 50,
-        credit_cost: 25,
-    },
-];
+impl OutpostTrade {
+    /// Pirates unload stolen ore: prices climb and the Outpost cools on the player.
+    pub fn record_pirate_sale(&mut self, ore: f32) {
+        let ore = ore.max(0.0);
+        self.price_multiplier =
+            (self.price_multiplier + ore * PRICE_PER_PIRATE_ORE).min(OUTPOST_MAX_PRICE_MULTIPLIER);
+        self.player_standing = (self.player_standing - ore * STANDING_PER_PIRATE_ORE).max(-1.0);
+    }
+
+    /// Buying from the black market costs standing
+    pub fn record_black_market_deal(&mut self) {
+        self.player_standing = (self.player_standing - BLACK_MARKET_STANDING_COST).max(-1.0);
+    }
+
+    /// Protest against nearby player expansion: prices climb and standing sours
+    /// faster than it recovers, but never as far as refusing trade. Standing
+    /// already below the floor is left to recover.
+    pub fn record_protest(&mut self, stage: CrisisStage) {
+        let severity = stage.severity() as f32;
+        self.price_multiplier = (self.price_multiplier + FLASHPOINT_PRICE_STEP * severity)
+            .min(OUTPOST_MAX_PRICE_MULTIPLIER);
+        let floor = FLASHPOINT_STANDING_FLOOR.min(self.player_standing);
+        self.player_standing =
+            (self.player_standing - FLASHPOINT_STANDING_LOSS * severity).max(floor);
+    }
+
+    /// Drift prices and standing back toward normal
+    pub fn recover(&mut self) {
+        self.price_multiplier = (self.price_multiplier - OUTPOST_RECOVERY_STEP).max(1.0);
+        if self.player_standing < 0.0 {
+            self.player_standing = (self.player_standing + OUTPOST_RECOVERY_STEP).min(0.0);
+        }
+    }
 
-/// Available ore selling options at Outposts.
-/// Price ratio: 5 ore → 10 credits (2 credits per ore)
-#[allow(dead_code)]
-pub const OUTPOST_SELL_ORE_OPTIONS: [OutpostSellOreOption; 2] = [
-    OutpostSellOreOption {
-        ore_amount: 5,
-        credit_reward: 10,
-    },
-    OutpostSellOreOption {
-        ore_amount: 10,
-        credit_reward: 20,
-    },
-];
+    pub fn refuses_trade(&self) -> bool {
+        self.player_standing <= OUTPOST_REFUSAL_STANDING
+    }
 
-/// Calculate credit reward for selling a specific amount of ore.
-/// Rate: 2 credits per ore
-#[allow(dead_code)]
-pub fn outpost_ore_to_credits(ore: u32) -> u32 {
-    ore * 2
+    pub fn black_market_open(&self) -> bool {
+        self.docked_pirates > 0
+    }
+
+    pub fn standing_label(&self) -> &'static str {
+        if self.refuses_trade() {
+            "Hostile"
+        } else if self.player_standing < -0.2 {
+            "Wary"
+        } else if self.player_standing > 0.2 {
+            "Friendly"
+        } else {
+            "Neutral"
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{station_build_time_seconds, StationKind};
+    use super::{
+        station_build_time_seconds, ActiveCrisis, CascadePressure, CascadeSource, CrisisStage,
+        CrisisType, FuelDepotPolicy, RefineryStorage, Station, StationCrises, StationKind,
+        StationProduction, StationState, StationStock,
+    };
+    use crate::commodities::Commodity;
+    use crate::world::ZoneModifier;
+    use bevy::prelude::Entity;
+    use std::collections::BTreeMap;
+
+    fn tracked(stage: CrisisStage) -> ActiveCrisis {
+        ActiveCrisis {
+            entity: Entity::PLACEHOLDER,
+            stage,
+            elapsed_seconds: 5.0,
+            stage_seconds: 0.0,
+        }
+    }
+
+    #[test]
+    fn station_crises_track_each_type_independently() {
+        let mut crises = StationCrises::default();
+        assert!(crises.set(CrisisType::FuelShortage, CrisisStage::Strained));
+        assert!(crises.set(CrisisType::PirateHarassment, CrisisStage::Failing));
+        assert!(!crises.set(CrisisType::FuelShortage, CrisisStage::Strained));
+        crises.tick(5.0);
+
+        assert_eq!(crises.len(), 2);
+        assert_eq!(crises.derived_state(), StationState::Failing);
+
+        assert!(crises.clear(CrisisType::PirateHarassment));
+        let fuel = crises.get(CrisisType::FuelShortage).unwrap();
+        assert_eq!(fuel.stage, CrisisStage::Strained);
+        assert_eq!(fuel.elapsed_seconds, 5.0);
+        assert_eq!(crises.derived_state(), StationState::Strained);
+    }
+
+    #[test]
+    fn restaging_a_crisis_keeps_its_age() {
+        let mut crises = StationCrises::default();
+        crises.set(CrisisType::HullBreach, CrisisStage::Strained);
+        crises.tick(3.0);
+        assert!(crises.set(CrisisType::HullBreach, CrisisStage::Failing));
+
+        let breach = crises.get(CrisisType::HullBreach).unwrap();
+        assert_eq!(breach.elapsed_seconds, 3.0);
+        assert_eq!(breach.stage_seconds, 0.0);
+        assert!(!crises.clear(CrisisType::FuelShortage));
+        assert_eq!(
+            StationCrises::default().derived_state(),
+            StationState::Operational
+        );
+    }
+
+    #[test]
+    fn tracking_an_unchanged_stage_keeps_its_clock() {
+        let mut crises = StationCrises::default();
+        crises.track(CrisisType::FuelShortage, tracked(CrisisStage::Strained));
+        crises.tick(4.0);
+        crises.track(CrisisType::FuelShortage, tracked(CrisisStage::Strained));
+        assert_eq!(
+            crises.get(CrisisType::FuelShortage).unwrap().stage_seconds,
+            4.0
+        );
+
+        crises.track(CrisisType::FuelShortage, tracked(CrisisStage::Failing));
+        assert_eq!(
+            crises.get(CrisisType::FuelShortage).unwrap().stage_seconds,
+            0.0
+        );
+    }
+
+    #[test]
+    fn station_crises_derive_state_from_open_crises() {
+        let mut crises = StationCrises::default();
+        crises.track(CrisisType::FuelShortage, tracked(CrisisStage::Strained));
+        crises.track(CrisisType::PirateHarassment, tracked(CrisisStage::Failing));
+
+        assert_eq!(crises.len(), 2);
+        assert_eq!(crises.derived_state(), StationState::Failing);
+
+        crises.clear_tracked();
+        crises.track(CrisisType::FuelShortage, tracked(CrisisStage::Stable));
+        assert_eq!(crises.derived_state(), StationState::Operational);
+        assert_eq!(
+            StationCrises::default().derived_state(),
+            StationState::Operational
+        );
+    }
+
+    #[test]
+    fn reported_pressure_is_separate_from_open_crises() {
+        let mut crises = StationCrises::default();
+        assert!(crises.report(CrisisType::HullBreach, CrisisStage::Strained));
+        assert!(!crises.report(CrisisType::HullBreach, CrisisStage::Strained));
+        assert!(crises.report(CrisisType::HullBreach, CrisisStage::Failing));
+
+        assert_eq!(
+            crises.pressure(CrisisType::HullBreach),
+            Some(CrisisStage::Failing)
+        );
+        assert!(!crises.contains(CrisisType::HullBreach));
+        assert!(crises.relieve(CrisisType::HullBreach));
+        assert!(!crises.relieve(CrisisType::FuelShortage));
+    }
+
+    #[test]
+    fn cascaded_pressure_merges_with_direct_pressure() {
+        let mut crises = StationCrises::default();
+        crises.report(CrisisType::FuelShortage, CrisisStage::Strained);
+        let source = CascadeSource {
+            station: Entity::PLACEHOLDER,
+            station_kind: StationKind::FuelDepot,
+            zone: Some(2),
+            crisis_type: None,
+        };
+        crises.set_cascades(BTreeMap::from([
+            (
+                CrisisType::FuelShortage,
+                CascadePressure {
+                    stage: CrisisStage::Failing,
+                    source,
+                },
+            ),
+            (
+                CrisisType::SupplyShortage,
+                CascadePressure {
+                    stage: CrisisStage::Strained,
+                    source,
+                },
+            ),
+        ]));
+
+        assert_eq!(
+            crises.pressure(CrisisType::FuelShortage),
+            Some(CrisisStage::Failing)
+        );
+        assert_eq!(
+            crises.direct_pressure(CrisisType::FuelShortage),
+            Some(CrisisStage::Strained)
+        );
+        assert_eq!(crises.pressures().count(), 2);
+        assert_eq!(
+            crises.cascade_source(CrisisType::SupplyShortage),
+            Some(&source)
+        );
+    }
 
     #[test]
     fn station_build_time_values() {
@@ -759,54 +2381,150 @@ mod tests {
     fn refinery_job_new_calculates_fuel_output() {
         let job = super::RefineryJob::new(20);
         assert_eq!(job.ore_in, 20);
-        assert!((job.fuel_out - 10.0).abs() < f32::EPSILON);
-        assert!((job.remaining_seconds - 60.0).abs() < f32::EPSILON);
+        assert!((job.fuel_out - 40.0).abs() < f32::EPSILON);
+        assert_eq!(job.unrefined_ore(), 20);
     }
 
     #[test]
-    fn refinery_job_tick_decreases_time() {
+    fn refinery_job_process_works_through_the_batch() {
         let mut job = super::RefineryJob::new(10);
-        let complete = job.tick(30.0);
-        assert!(!complete);
-        assert!((job.remaining_seconds - 30.0).abs() < f32::EPSILON);
+        assert!((job.process(4.0) - 4.0).abs() < f32::EPSILON);
+        assert!((job.progress() - 0.4).abs() < f32::EPSILON);
+        assert!((job.process(20.0) - 6.0).abs() < f32::EPSILON);
+        assert!(job.is_complete());
     }
 
     #[test]
-    fn refinery_job_tick_returns_true_when_complete() {
+    fn cancelled_batches_hand_back_only_unrefined_ore() {
         let mut job = super::RefineryJob::new(10);
-        let complete = job.tick(60.0);
-        assert!(complete);
+        assert_eq!(job.unrefined_ore(), 10);
+        job.process(7.5);
+        assert_eq!(job.unrefined_ore(), 2);
+        job.process(5.0);
+        assert_eq!(job.unrefined_ore(), 0);
+    }
+
+    #[test]
+    fn refinery_rate_follows_station_state() {
+        use super::StationState;
+        let operational = super::refinery_ore_per_minute(StationState::Operational);
+        assert!(super::refinery_ore_per_minute(StationState::Strained) < operational);
+        assert!(
+            super::refinery_ore_per_minute(StationState::Failing)
+                < super::refinery_ore_per_minute(StationState::Strained)
+        );
+        assert_eq!(super::refinery_ore_per_minute(StationState::Failed), 0.0);
+    }
+
+    #[test]
+    fn refinery_queue_works_batches_before_the_hold() {
+        let mut queue = super::RefineryQueue::default();
+        assert!(queue.push(super::RefineryJob::new(5)));
+        assert!(queue.push(super::RefineryJob::new(5)));
+        let mut hold = super::StationProduction::new(80.0).with_ore(20.0);
+        let mut storage = super::RefineryStorage::new();
+
+        let (finished, fuel) = queue.refine(7.0, Some(&mut hold), &mut storage);
+        assert_eq!(finished.len(), 1);
+        assert!((fuel - 14.0).abs() < f32::EPSILON);
+        assert_eq!(queue.jobs.len(), 1);
+        assert!((hold.ore() - 20.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn refinery_queue_draws_on_the_hold_up_to_target() {
+        let mut queue = super::RefineryQueue::default();
+        let mut hold = super::StationProduction::new(80.0).with_ore(20.0);
+        let mut storage = super::RefineryStorage::new();
+        storage.fuel = 20.0;
+
+        // Target is half of 50: only 5 fuel, so 2.5 ore, is wanted
+        let (_, fuel) = queue.refine(10.0, Some(&mut hold), &mut storage);
+        assert!((fuel - 5.0).abs() < f32::EPSILON);
+        assert!((hold.ore() - 17.5).abs() < f32::EPSILON);
+        assert!((storage.fuel - 25.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn refinery_queue_stops_when_storage_is_full() {
+        let mut queue = super::RefineryQueue::default();
+        queue.push(super::RefineryJob::new(10));
+        let mut storage = super::RefineryStorage::new();
+        storage.fuel = 46.0;
+
+        let (finished, fuel) = queue.refine(10.0, None, &mut storage);
+        assert!(finished.is_empty());
+        assert!((fuel - 4.0).abs() < f32::EPSILON);
+        assert!((storage.fuel - 50.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn refinery_target_cycles_and_wraps() {
+        let mut queue = super::RefineryQueue::default();
+        queue.cycle_target();
+        assert!((queue.target_fraction - 0.75).abs() < f32::EPSILON);
+        queue.cycle_target();
+        queue.cycle_target();
+        assert!((queue.target_fraction - 0.25).abs() < f32::EPSILON);
     }
 
     #[test]
     fn shipyard_job_new_has_correct_values() {
-        let job = super::ShipyardJob::new();
-        assert_eq!(job.ore_in, 30);
-        assert!((job.fuel_in - 15.0).abs() < f32::EPSILON);
+        let job = super::ShipyardJob::new(crate::ships::ShipKind::Scout);
+        assert_eq!(job.ore_in, 15);
+        assert_eq!(job.credits_in, 0);
         assert!((job.remaining_seconds - 120.0).abs() < f32::EPSILON);
+        assert!(!job.is_started());
     }
 
     #[test]
     fn shipyard_job_tick_decreases_time() {
-        let mut job = super::ShipyardJob::new();
+        let mut job = super::ShipyardJob::new(crate::ships::ShipKind::Scout);
         let complete = job.tick(60.0);
         assert!(!complete);
         assert!((job.remaining_seconds - 60.0).abs() < f32::EPSILON);
+        assert!((job.progress() - 0.5).abs() < f32::EPSILON);
     }
 
     #[test]
     fn shipyard_job_tick_returns_true_when_complete() {
-        let mut job = super::ShipyardJob::new();
+        let mut job = super::ShipyardJob::new(crate::ships::ShipKind::Scout);
         let complete = job.tick(120.0);
         assert!(complete);
     }
 
     #[test]
     fn shipyard_job_default_same_as_new() {
-        let job1 = super::ShipyardJob::new();
+        let job1 = super::ShipyardJob::new(crate::ships::ShipKind::Scout);
         let job2 = super::ShipyardJob::default();
         assert_eq!(job1.ore_in, job2.ore_in);
-        assert!((job1.fuel_in - job2.fuel_in).abs() < f32::EPSILON);
+        assert_eq!(job1.kind, job2.kind);
+    }
+
+    #[test]
+    fn bigger_hulls_cost_more_and_take_longer() {
+        use crate::ships::ShipKind;
+        let scout = super::ship_build_cost(ShipKind::Scout);
+        let miner = super::ship_build_cost(ShipKind::Miner);
+        let security = super::ship_build_cost(ShipKind::Security);
+        assert!(scout.ore < miner.ore && miner.ore < security.ore);
+        assert!(scout.credits < miner.credits && miner.credits < security.credits);
+        assert!(scout.seconds < miner.seconds && miner.seconds < security.seconds);
+    }
+
+    #[test]
+    fn shipyard_queue_is_first_in_first_out_and_bounded() {
+        use crate::ships::ShipKind;
+        let mut queue = super::ShipyardQueue::default();
+        assert!(queue.push(super::ShipyardJob::new(ShipKind::Miner)));
+        for _ in 1..super::SHIPYARD_QUEUE_LIMIT {
+            assert!(queue.push(super::ShipyardJob::new(ShipKind::Scout)));
+        }
+        assert!(!queue.push(super::ShipyardJob::new(ShipKind::Security)));
+        assert_eq!(
+            queue.jobs.front().map(|job| job.kind),
+            Some(ShipKind::Miner)
+        );
     }
 
     // =============================================================================
@@ -857,48 +2575,67 @@ mod tests {
     fn shipyard_storage_new_has_correct_capacity() {
         let storage = super::ShipyardStorage::new();
         assert_eq!(storage.capacity, 3);
-        assert_eq!(storage.ready_scouts, 0);
+        assert!(storage.hangar.is_empty());
+    }
+
+    #[test]
+    fn shipyard_storage_add_ship() {
+        let mut storage = super::ShipyardStorage::new();
+        assert!(storage.add_ship(crate::ships::ShipKind::Miner));
+        assert_eq!(storage.hangar, vec![crate::ships::ShipKind::Miner]);
     }
 
     #[test]
-    fn shipyard_storage_add_scout() {
+    fn shipyard_storage_add_ship_respects_capacity() {
+        use crate::ships::ShipKind;
         let mut storage = super::ShipyardStorage::new();
-        assert!(storage.add_scout());
-        assert_eq!(storage.ready_scouts, 1);
+        assert!(storage.add_ship(ShipKind::Scout));
+        assert!(storage.add_ship(ShipKind::Scout));
+        assert!(storage.add_ship(ShipKind::Scout));
+        assert!(!storage.add_ship(ShipKind::Scout)); // Full
+        assert_eq!(storage.hangar.len(), 3);
     }
 
     #[test]
-    fn shipyard_storage_add_scout_respects_capacity() {
+    fn shipyard_storage_launches_in_arrival_order() {
+        use crate::ships::ShipKind;
         let mut storage = super::ShipyardStorage::new();
-        assert!(storage.add_scout());
-        assert!(storage.add_scout());
-        assert!(storage.add_scout());
-        assert!(!storage.add_scout()); // Full
-        assert_eq!(storage.ready_scouts, 3);
+        storage.add_ship(ShipKind::Security);
+        storage.add_ship(ShipKind::Scout);
+        assert_eq!(storage.next_launch(), Some(ShipKind::Security));
+        assert_eq!(storage.launch(), Some(ShipKind::Security));
+        assert_eq!(storage.hangar, vec![ShipKind::Scout]);
     }
 
     #[test]
-    fn shipyard_storage_remove_scout() {
+    fn replacement_hulls_wait_in_the_hangar_for_the_player() {
+        use crate::ships::ShipKind;
         let mut storage = super::ShipyardStorage::new();
-        storage.add_scout();
-        storage.add_scout();
-        assert!(storage.remove_scout());
-        assert_eq!(storage.ready_scouts, 1);
+        storage.add_ship(ShipKind::PlayerShip);
+        storage.add_ship(ShipKind::Miner);
+        assert_eq!(storage.next_launch(), Some(ShipKind::Miner));
+        assert_eq!(storage.launch(), Some(ShipKind::Miner));
+        assert_eq!(storage.launch(), None);
+
+        assert!(storage.take_replacement_hull());
+        assert!(!storage.take_replacement_hull());
+        assert!(storage.hangar.is_empty());
     }
 
     #[test]
-    fn shipyard_storage_remove_scout_empty() {
+    fn shipyard_storage_launch_empty() {
         let mut storage = super::ShipyardStorage::new();
-        assert!(!storage.remove_scout());
+        assert_eq!(storage.launch(), None);
     }
 
     #[test]
     fn shipyard_storage_is_full() {
+        use crate::ships::ShipKind;
         let mut storage = super::ShipyardStorage::new();
         assert!(!storage.is_full());
-        storage.add_scout();
-        storage.add_scout();
-        storage.add_scout();
+        storage.add_ship(ShipKind::Scout);
+        storage.add_ship(ShipKind::Miner);
+        storage.add_ship(ShipKind::Security);
         assert!(storage.is_full());
     }
 
@@ -906,10 +2643,28 @@ mod tests {
     fn shipyard_storage_free_slots() {
         let mut storage = super::ShipyardStorage::new();
         assert_eq!(storage.free_slots(), 3);
-        storage.add_scout();
+        storage.add_ship(crate::ships::ShipKind::Scout);
         assert_eq!(storage.free_slots(), 2);
     }
 
+    #[test]
+    fn shipyard_launches_only_above_its_reserve() {
+        use crate::ships::ShipKind;
+        let mut station = super::Station {
+            kind: StationKind::Shipyard,
+            state: super::StationState::Operational,
+            fuel: 30.0,
+            fuel_capacity: 50.0,
+        };
+        // A scout takes 15 fuel and the yard keeps back 12.5
+        assert_eq!(
+            super::shipyard_launch_fuel(&station, ShipKind::Scout),
+            Some(15.0)
+        );
+        station.fuel = 27.0;
+        assert_eq!(super::shipyard_launch_fuel(&station, ShipKind::Scout), None);
+    }
+
     // =============================================================================
     // Outpost tests (NPC station)
     // =============================================================================
@@ -964,33 +2719,529 @@ mod tests {
     // =============================================================================
 
     #[test]
-    fn outpost_buy_fuel_has_three_options() {
-        assert_eq!(super::OUTPOST_BUY_FUEL_OPTIONS.len(), 3);
+    fn outpost_market_opens_at_list_prices() {
+        let market = super::OutpostMarket::default();
+        let conditions = super::MarketConditions::default();
+        let fuel = market.ask(super::MarketGood::Fuel, &conditions);
+        assert_eq!(super::trade_total(fuel, super::OUTPOST_FUEL_LOTS[0]), 5);
+        let ore = market.bid(super::MarketGood::Goods(Commodity::RawOre), &conditions);
+        assert_eq!(super::trade_total(ore, super::OUTPOST_ORE_LOTS[0]), 10);
+    }
+
+    #[test]
+    fn large_lots_are_priced_unit_by_unit() {
+        let market = super::OutpostMarket::default();
+        let conditions = super::MarketConditions::default();
+        let fuel = super::MarketGood::Fuel;
+        let opening_ask = market.ask(fuel, &conditions);
+        assert!(market.ask_total(fuel, 150, &conditions) > super::trade_total(opening_ask, 150));
+
+        let ore = super::MarketGood::Goods(Commodity::RawOre);
+        let opening_bid = market.bid(ore, &conditions);
+        assert!(market.bid_total(ore, 60, &conditions) < super::trade_total(opening_bid, 60));
+        assert_eq!(market.bid_total(ore, 1, &conditions), 2);
+        // Pricing a lot leaves the stock alone
+        assert_eq!(market, super::OutpostMarket::default());
+    }
+
+    #[test]
+    fn outpost_market_prices_follow_stock() {
+        let mut market = super::OutpostMarket::default();
+        let conditions = super::MarketConditions::default();
+        let ore = super::MarketGood::Goods(Commodity::RawOre);
+        let before = market.bid(ore, &conditions);
+        market.buy(ore, 60.0);
+        assert!(market.bid(ore, &conditions) < before);
+
+        let before = market.ask(super::MarketGood::Fuel, &conditions);
+        assert!(market.sell(super::MarketGood::Fuel, 100.0));
+        assert!(market.ask(super::MarketGood::Fuel, &conditions) > before);
+    }
+
+    #[test]
+    fn outpost_market_cannot_sell_more_than_it_holds() {
+        let mut market = super::OutpostMarket::default();
+        assert!(!market.sell(super::MarketGood::Fuel, 500.0));
+        assert_eq!(market.stock(super::MarketGood::Fuel), 200.0);
     }
 
     #[test]
-    fn outpost_buy_fuel_first_option_is_10_fuel_5_credits() {
-        let option = super::OUTPOST_BUY_FUEL_OPTIONS[0];
-        assert_eq!(option.fuel_amount, 10);
-        assert_eq!(option.credit_cost, 5);
+    fn outpost_market_recovers_toward_demand() {
+        let mut market = super::OutpostMarket::default();
+        let ore = super::MarketGood::Goods(Commodity::RawOre);
+        market.buy(ore, 60.0);
+        market.recover(10.0);
+        assert!((market.stock(ore) - 90.0).abs() < 1e-4);
+        market.recover(100.0);
+        assert!((market.stock(ore) - 60.0).abs() < 1e-4);
     }
 
     #[test]
-    fn outpost_sell_ore_has_two_options() {
-        assert_eq!(super::OUTPOST_SELL_ORE_OPTIONS.len(), 2);
+    fn rich_ore_veins_lower_ore_prices_only() {
+        let market = super::OutpostMarket::default();
+        let normal = super::MarketConditions::default();
+        let rich = super::MarketConditions {
+            modifier: Some(ZoneModifier::RichOreVeins),
+            ..normal
+        };
+        let ore = super::MarketGood::Goods(Commodity::RawOre);
+        assert!(market.bid(ore, &rich) < market.bid(ore, &normal));
+        let alloys = super::MarketGood::Goods(Commodity::Alloys);
+        assert_eq!(market.bid(alloys, &rich), market.bid(alloys, &normal));
     }
 
     #[test]
-    fn outpost_sell_ore_first_option_is_5_ore_10_credits() {
-        let option = super::OUTPOST_SELL_ORE_OPTIONS[0];
-        assert_eq!(option.ore_amount, 5);
-        assert_eq!(option.credit_reward, 10);
+    fn pirate_control_widens_the_spread() {
+        let market = super::OutpostMarket::default();
+        let normal = super::MarketConditions::default();
+        let pirate = super::MarketConditions {
+            pirate_control: 1.0,
+            ..normal
+        };
+        let fuel = super::MarketGood::Fuel;
+        assert!((market.ask(fuel, &pirate) - 0.75).abs() < 1e-6);
+        let ore = super::MarketGood::Goods(Commodity::RawOre);
+        assert!(market.bid(ore, &pirate) < market.bid(ore, &normal));
     }
 
     #[test]
-    fn outpost_ore_to_credits_rate_is_2x() {
-        assert_eq!(super::outpost_ore_to_credits(5), 10);
-        assert_eq!(super::outpost_ore_to_credits(10), 20);
-        assert_eq!(super::outpost_ore_to_credits(23), 46);
+    fn market_intel_fades_and_is_forgotten() {
+        let mut intel = super::MarketIntel::default();
+        let market = super::OutpostMarket::default();
+        intel.record(3, 10, market.quotes(&super::MarketConditions::default()));
+        intel.decay(11, 0.25);
+        assert!((intel.reports[&3].confidence - 0.75).abs() < 1e-3);
+        for tick in 12..20 {
+            intel.decay(tick, 0.25);
+        }
+        assert!(intel.reports.is_empty());
+    }
+
+    #[test]
+    fn outpost_trade_pirate_sales_raise_prices_and_sour_standing() {
+        let mut trade = super::OutpostTrade::default();
+        trade.record_pirate_sale(10.0);
+        assert!((trade.price_multiplier - 1.2).abs() < 1e-6);
+        assert!((trade.player_standing + 0.1).abs() < 1e-6);
+        let conditions = super::MarketConditions {
+            price_multiplier: trade.price_multiplier,
+            ..Default::default()
+        };
+        let market = super::OutpostMarket::default();
+        let fuel = market.ask(super::MarketGood::Fuel, &conditions);
+        assert_eq!(super::trade_total(fuel, 10), 6);
+        let ore = super::MarketGood::Goods(Commodity::RawOre);
+        assert!(
+            market.bid(ore, &conditions) < market.bid(ore, &super::MarketConditions::default())
+        );
+    }
+
+    #[test]
+    fn outpost_trade_price_multiplier_is_capped() {
+        let mut trade = super::OutpostTrade::default();
+        trade.record_pirate_sale(500.0);
+        assert_eq!(trade.price_multiplier, super::OUTPOST_MAX_PRICE_MULTIPLIER);
+        assert_eq!(trade.player_standing, -1.0);
+        assert!(trade.refuses_trade());
+        assert_eq!(trade.standing_label(), "Hostile");
+    }
+
+    #[test]
+    fn outpost_trade_recovers_toward_normal() {
+        let mut trade = super::OutpostTrade {
+            price_multiplier: 1.01,
+            player_standing: -0.01,
+            docked_pirates: 0,
+        };
+        trade.recover();
+        assert_eq!(trade.price_multiplier, 1.0);
+        assert_eq!(trade.player_standing, 0.0);
+    }
+
+    #[test]
+    fn outpost_trade_black_market_costs_standing() {
+        let mut trade = super::OutpostTrade::default();
+        assert!(!trade.black_market_open());
+        trade.docked_pirates = 1;
+        assert!(trade.black_market_open());
+
+        trade.record_black_market_deal();
+        assert!((trade.player_standing + super::BLACK_MARKET_STANDING_COST).abs() < 1e-6);
+    }
+
+    #[test]
+    fn outpost_protest_outpaces_recovery() {
+        let mut trade = super::OutpostTrade::default();
+        for _ in 0..10 {
+            trade.recover();
+            trade.record_protest(CrisisStage::Failing);
+        }
+        assert!(trade.player_standing < -0.4);
+        assert!(trade.price_multiplier > 1.0);
+
+        // However long it festers, a protest alone never ends trade
+        for _ in 0..100 {
+            trade.recover();
+            trade.record_protest(CrisisStage::Failing);
+        }
+        assert!(!trade.refuses_trade());
+    }
+
+    #[test]
+    fn refusing_outpost_recovers_through_a_protest() {
+        let mut trade = super::OutpostTrade::default();
+        trade.record_pirate_sale(500.0);
+        assert!(trade.refuses_trade());
+
+        for _ in 0..200 {
+            trade.recover();
+            trade.record_protest(CrisisStage::Failing);
+        }
+        assert!(!trade.refuses_trade());
+    }
+
+    #[test]
+    fn abandoning_salvages_half_the_stock() {
+        let station = Station {
+            kind: StationKind::Refinery,
+            state: StationState::Failed,
+            fuel: 6.0,
+            fuel_capacity: 60.0,
+        };
+        let production = StationProduction::new(80.0).with_ore(15.0);
+        let storage = RefineryStorage {
+            fuel: 14.0,
+            fuel_capacity: 50.0,
+        };
+
+        let stock = StationStock::of(&station, Some(&production), Some(&storage));
+        assert_eq!(
+            stock,
+            StationStock {
+                ore: 15.0,
+                fuel: 20.0
+            }
+        );
+        assert_eq!(
+            stock.salvage(),
+            StationStock {
+                ore: 7.0,
+                fuel: 10.0
+            }
+        );
+    }
+
+    #[test]
+    fn decommissioned_stock_fills_tank_then_storage() {
+        let mut station = Station {
+            kind: StationKind::Refinery,
+            state: StationState::Operational,
+            fuel: 55.0,
+            fuel_capacity: 60.0,
+        };
+        let mut production = StationProduction::new(80.0).with_ore(75.0);
+        let mut storage = RefineryStorage::new();
+
+        let stock = StationStock {
+            ore: 20.0,
+            fuel: 12.0,
+        };
+        let moved = stock.deliver(&mut station, Some(&mut production), Some(&mut storage));
+
+        assert_eq!(
+            moved,
+            StationStock {
+                ore: 5.0,
+                fuel: 12.0
+            }
+        );
+        assert_eq!(station.fuel, 60.0);
+        assert_eq!(storage.fuel, 7.0);
+        assert_eq!(production.ore(), 80.0);
+    }
+
+    #[test]
+    fn decommissioned_stock_without_storage_is_capped_by_the_tank() {
+        let mut station = Station {
+            kind: StationKind::FuelDepot,
+            state: StationState::Operational,
+            fuel: 110.0,
+            fuel_capacity: 120.0,
+        };
+
+        let stock = StationStock {
+            ore: 20.0,
+            fuel: 30.0,
+        };
+        let moved = stock.deliver(&mut station, None, None);
+
+        assert_eq!(
+            moved,
+            StationStock {
+                ore: 0.0,
+                fuel: 10.0
+            }
+        );
+        assert_eq!(station.fuel, 120.0);
+    }
+
+    #[test]
+    fn depot_keeps_its_reserve() {
+        let mut station = Station {
+            kind: StationKind::FuelDepot,
+            state: StationState::Operational,
+            fuel: 50.0,
+            fuel_capacity: 120.0,
+        };
+        let policy = FuelDepotPolicy::default();
+
+        assert_eq!(policy.available(&station), 20.0);
+        assert_eq!(policy.dispense(&mut station, 25.0), 20.0);
+        assert_eq!(station.fuel, 30.0);
+        assert_eq!(policy.dispense(&mut station, 5.0), 0.0);
+
+        station.fuel = 100.0;
+        station.state = StationState::Failed;
+        assert_eq!(policy.available(&station), 0.0);
+    }
+
+    #[test]
+    fn depot_reserve_cycles_and_wraps() {
+        let mut policy = FuelDepotPolicy {
+            reserve_fraction: 0.5,
+        };
+        policy.cycle_reserve();
+        assert_eq!(policy.reserve_fraction, 0.75);
+        policy.cycle_reserve();
+        assert_eq!(policy.reserve_fraction, 0.0);
+        policy.cycle_reserve();
+        assert_eq!(policy.reserve_fraction, 0.25);
+    }
+
+    #[test]
+    fn sensor_strength_follows_zone_signals() {
+        use crate::world::ZoneModifier;
+
+        let nebula = super::sensor_strength(Some(ZoneModifier::NebulaInterference));
+        let plain = super::sensor_strength(None);
+        let clear = super::sensor_strength(Some(ZoneModifier::ClearSignals));
+        assert!(nebula < plain && plain < clear);
+
+        // Stronger sweeps hold neighbouring intel longer
+        assert!(super::sensor_neighbour_decay(clear) < super::sensor_neighbour_decay(plain));
+        assert!(super::sensor_neighbour_decay(nebula) < 1.0);
+    }
+
+    #[test]
+    fn production_hold_withdraws_and_deposits_within_bounds() {
+        let mut production = StationProduction::new(20.0).with_ore(12.0);
+
+        assert_eq!(production.withdraw(5.0), 5.0);
+        assert_eq!(production.withdraw(50.0), 7.0);
+        assert_eq!(production.ore(), 0.0);
+
+        assert_eq!(production.deposit(15.0), 15.0);
+        assert_eq!(production.deposit(15.0), 5.0);
+        assert_eq!(production.free_space(), 0.0);
+    }
+
+    #[test]
+    fn mining_outposts_feed_refineries_and_shipyards() {
+        assert!(super::is_ore_producer(StationKind::MiningOutpost));
+        assert!(!super::is_ore_producer(StationKind::Refinery));
+        assert!(super::is_ore_consumer(StationKind::Refinery));
+        assert!(super::is_ore_consumer(StationKind::Shipyard));
+        assert!(!super::is_ore_consumer(StationKind::MiningOutpost));
+
+        assert!(super::handles_ore(StationState::Strained));
+        assert!(!super::handles_ore(StationState::Deploying));
+        assert!(!super::handles_ore(StationState::Failed));
+    }
+
+    #[test]
+    fn build_costs_cover_every_buildable_kind() {
+        let mine = super::station_build_cost(StationKind::MiningOutpost);
+        assert_eq!(mine.ore, 18);
+        assert_eq!(mine.fuel, 15.0);
+
+        for kind in super::BUILDABLE_STATIONS {
+            assert!(super::station_build_cost(kind).ore > 0);
+            assert!(station_build_time_seconds(kind) > 0.0);
+        }
+        assert!(!super::BUILDABLE_STATIONS.contains(&StationKind::Outpost));
+    }
+
+    #[test]
+    fn build_cost_shortfall_names_the_first_missing_resource() {
+        let cost = super::station_build_cost(StationKind::Refinery);
+        assert_eq!(cost.shortfall(30, 30.0, 40), None);
+        assert_eq!(
+            cost.shortfall(25, 0.0, 0),
+            Some("need 5 more ore".to_string())
+        );
+        assert_eq!(
+            cost.shortfall(30, 29.5, 40),
+            Some("need 1 more fuel".to_string())
+        );
+        assert_eq!(
+            cost.shortfall(30, 30.0, 10),
+            Some("need 30 more credits".to_string())
+        );
+    }
+
+    #[test]
+    fn construction_site_checks_modifier_pirates_and_spacing() {
+        use crate::world::ZoneModifier;
+        use bevy::prelude::Vec2;
+
+        let site = Vec2::new(100.0, 0.0);
+        let problem = |kind, modifier, pirate_held, stations: &[Vec2]| {
+            super::construction_site_problem(
+                kind,
+                site,
+                modifier,
+                super::zone_claim_problem(false, pirate_held),
+                stations.iter().copied(),
+            )
+        };
+
+        assert_eq!(problem(StationKind::Refinery, None, false, &[]), None);
+        assert!(problem(
+            StationKind::SensorStation,
+            Some(ZoneModifier::NebulaInterference),
+            false,
+            &[]
+        )
+        .is_some_and(|reason| reason.contains("nebula")));
+        assert_eq!(
+            problem(
+                StationKind::Refinery,
+                Some(ZoneModifier::NebulaInterference),
+                false,
+                &[]
+            ),
+            None
+        );
+        assert_eq!(
+            problem(StationKind::Refinery, None, true, &[]),
+            Some("pirates hold this zone".to_string())
+        );
+        assert_eq!(
+            problem(StationKind::Refinery, None, false, &[Vec2::new(110.0, 0.0)]),
+            Some("site is occupied".to_string())
+        );
+    }
+
+    #[test]
+    fn pirate_bases_and_pirate_control_block_a_zone_claim() {
+        assert_eq!(super::zone_claim_problem(false, false), None);
+        assert_eq!(
+            super::zone_claim_problem(false, true),
+            Some("pirates hold this zone")
+        );
+        assert!(
+            super::zone_claim_problem(true, false).is_some_and(|reason| reason.contains("base"))
+        );
+    }
+
+    #[test]
+    fn construction_sites_ring_the_node_clear_of_each_other() {
+        use bevy::prelude::Vec2;
+
+        let sites: Vec<Vec2> = (0..super::CONSTRUCTION_SITE_SLOTS)
+            .map(super::construction_site_offset)
+            .collect();
+        assert!((sites[0] - Vec2::new(super::CONSTRUCTION_SITE_RADIUS, 0.0)).length() < 1e-3);
+        for (i, a) in sites.iter().enumerate() {
+            assert!((a.length() - super::CONSTRUCTION_SITE_RADIUS).abs() < 1e-3);
+            for b in &sites[i + 1..] {
+                assert!(a.distance(*b) > super::STATION_SITE_SPACING);
+            }
+        }
+        assert_eq!(
+            super::construction_site_offset(super::CONSTRUCTION_SITE_SLOTS),
+            sites[0]
+        );
+    }
+
+    #[test]
+    fn cancelled_construction_refunds_the_time_left() {
+        let mut site = super::ConstructionSite::new(StationKind::Shipyard);
+        assert_eq!(site.ore.stock, 10.0);
+        site.ore.stock = 6.4;
+        site.fuel.stock = 3.0;
+
+        let half = site.refund(site.build_seconds * 0.5, 12.0);
+        assert_eq!(half.ore, 6);
+        assert_eq!(half.credits, 30);
+        assert_eq!(half.fuel, 15.0);
+
+        site.ore.stock = 0.0;
+        site.fuel.stock = 0.0;
+        let finished = site.refund(0.0, 0.0);
+        assert_eq!(finished, super::StationCost::default());
+    }
+
+    #[test]
+    fn construction_stalls_until_materials_are_delivered() {
+        let mut site = super::ConstructionSite::new(StationKind::MiningOutpost);
+        let order = super::ConstructionSite::order_cost(StationKind::MiningOutpost);
+        assert_eq!(order.ore, 4);
+        assert_eq!(order.fuel, 3.75);
+
+        // The foundation's 4 ore covers 40s of the 180s build
+        let (built, fuel) = site.build(60.0);
+        assert!((built - 40.0).abs() < 0.01);
+        assert!((fuel - 40.0 / 12.0).abs() < 0.01);
+        assert_eq!(site.waiting_on(), Some("ore"));
+        assert_eq!(site.build(10.0).0, 0.0);
+
+        assert!((site.ore.deliver(100.0) - 14.0).abs() < 0.01);
+        site.fuel.deliver(100.0);
+        assert!(site.fuel.wanted() < 0.01);
+        let (built, _) = site.build(140.0);
+        assert!((built - 140.0).abs() < 0.01);
+        assert!(site.progress_line().starts_with("Ore 100% | Fuel 100%"));
+        assert_eq!(site.waiting_on(), None);
+    }
+
+    #[test]
+    fn recipe_outputs_weigh_less_than_inputs() {
+        for recipe in super::RECIPES {
+            let spec = recipe.spec();
+            let (input, input_amount) = spec.input;
+            let (output, output_amount) = spec.output;
+            assert!(output.mass() * output_amount < input.mass() * input_amount);
+        }
+    }
+
+    #[test]
+    fn recipe_cycle_steps_through_station_recipes_then_idles() {
+        let mut recipe = super::StationRecipe::default();
+        recipe.cycle(StationKind::Refinery);
+        assert_eq!(recipe.active, Some(super::RecipeKind::AlloySmelting));
+        recipe.cycle(StationKind::Refinery);
+        assert_eq!(recipe.active, Some(super::RecipeKind::IsotopeExtraction));
+        recipe.cycle(StationKind::Refinery);
+        assert_eq!(recipe.active, None);
+        recipe.cycle(StationKind::MiningOutpost);
+        assert_eq!(recipe.active, None);
+    }
+
+    #[test]
+    fn recipe_work_consumes_inputs_and_stalls_without_them() {
+        let mut production = StationProduction::new(80.0).with_ore(10.0);
+        let mut recipe = super::StationRecipe {
+            active: Some(super::RecipeKind::AlloySmelting),
+            elapsed_seconds: 0.0,
+        };
+        assert_eq!(recipe.work(30.0, &mut production.hold), 0);
+        assert_eq!(recipe.work(100.0, &mut production.hold), 2);
+        assert_eq!(production.ore(), 2.0);
+        assert_eq!(production.hold.amount(Commodity::Alloys), 2.0);
+
+        // Two ore is short of a batch, so time spent waiting is not banked
+        assert_eq!(recipe.work(120.0, &mut production.hold), 0);
+        assert_eq!(recipe.elapsed_seconds, 0.0);
     }
 }
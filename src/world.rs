@@ -117,6 +117,102 @@ pub fn zone_modifier_effect(modifier: Option<ZoneModifier>) -> ZoneModifierEffec
     }
 }
 
+/// Fuel lost when a jump runs into turbulence
+pub const JUMP_FUEL_LOSS: f32 = 5.0;
+
+/// Distance from the arrival gate when a jump drifts off-gate
+pub const JUMP_OFF_GATE_DISTANCE: f32 = 180.0;
+
+/// Highest chance that any single jump goes wrong
+const JUMP_RISK_CAP: f32 = 0.85;
+
+/// How a jump through a gate turned out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JumpOutcome {
+    Clean,
+    /// Arrived in the destination zone, but away from the arrival gate
+    OffGate,
+    /// Pirates were waiting at the arrival gate
+    Intercepted,
+    /// Turbulence burned extra fuel
+    FuelLoss,
+}
+
+/// Chance that a jump goes wrong, from the route's effective risk and the
+/// destination zone's modifier.
+pub fn jump_risk(route_risk: f32, destination: Option<ZoneModifier>) -> f32 {
+    let effect = zone_modifier_effect(destination);
+    (route_risk + (effect.fuel_risk + effect.pirate_risk) * 0.5).clamp(0.0, JUMP_RISK_CAP)
+}
+
+/// Two rolls in [0, 1) for a single jump. The same seed, tick, ship and route
+/// always produce the same rolls, so a replayed run sees the same ambushes.
+pub fn jump_rolls(seed: u64, tick: u64, ship: u64, from: u32, to: u32) -> (f32, f32) {
+    let mut state = seed
+        ^ tick.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ ship.wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+        ^ ((u64::from(from) << 32) | u64::from(to));
+    let hazard = mix_unit(&mut state);
+    let kind = mix_unit(&mut state);
+    (hazard, kind)
+}
+
+/// Decide a jump's outcome. `hazard_roll` below `risk` means something went
+/// wrong; `kind_roll` picks what, weighted by the destination's modifier and
+/// how firmly pirates control it.
+pub fn resolve_jump(
+    risk: f32,
+    destination: Option<ZoneModifier>,
+    pirate_control: f32,
+    hazard_roll: f32,
+    kind_roll: f32,
+) -> JumpOutcome {
+    if hazard_roll >= risk {
+        return JumpOutcome::Clean;
+    }
+
+    let effect = zone_modifier_effect(destination);
+    let off_gate = 1.0;
+    let intercepted = 0.5 + (effect.pirate_risk + pirate_control) * 2.0;
+    let fuel_loss = 0.5 + effect.fuel_risk * 2.0;
+
+    let pick = kind_roll * (off_gate + intercepted + fuel_loss);
+    if pick < off_gate {
+        JumpOutcome::OffGate
+    } else if pick < off_gate + intercepted {
+        JumpOutcome::Intercepted
+    } else {
+        JumpOutcome::FuelLoss
+    }
+}
+
+/// Find the route connecting two zones, in either direction.
+pub fn route_between(sector: &Sector, a: u32, b: u32) -> Option<&RouteEdge> {
+    sector
+        .routes
+        .iter()
+        .find(|route| (route.from == a && route.to == b) || (route.from == b && route.to == a))
+}
+
+/// The modifier of the zone with the given id, if it has one.
+pub fn zone_modifier_of(sector: &Sector, zone: u32) -> Option<ZoneModifier> {
+    sector
+        .nodes
+        .iter()
+        .find(|node| node.id == zone)
+        .and_then(|node| node.modifier)
+}
+
+// splitmix64 step mapped to [0, 1)
+fn mix_unit(state: &mut u64) -> f32 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    ((z >> 40) as f32) / ((1u64 << 24) as f32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::zone_modifier_effect;
@@ -460,4 +556,71 @@ mod tests {
     fn jump_transition_duration_is_positive() {
         assert!(super::JUMP_TRANSITION_SECONDS > 0.0);
     }
+
+    #[test]
+    fn jump_risk_adds_destination_hazards() {
+        let plain = super::jump_risk(0.2, None);
+        let radiation = super::jump_risk(0.2, Some(super::ZoneModifier::HighRadiation));
+        assert!((plain - 0.2).abs() < 1e-6);
+        assert!(radiation > plain);
+    }
+
+    #[test]
+    fn jump_risk_is_capped() {
+        assert!(super::jump_risk(5.0, None) < 1.0);
+        assert_eq!(super::jump_risk(-1.0, None), 0.0);
+    }
+
+    #[test]
+    fn jump_rolls_are_deterministic_and_in_range() {
+        let first = super::jump_rolls(12345, 600, 7, 1, 2);
+        let again = super::jump_rolls(12345, 600, 7, 1, 2);
+        assert_eq!(first, again);
+        assert!((0.0..1.0).contains(&first.0));
+        assert!((0.0..1.0).contains(&first.1));
+
+        let other_seed = super::jump_rolls(54321, 600, 7, 1, 2);
+        assert_ne!(first, other_seed);
+    }
+
+    #[test]
+    fn resolve_jump_is_clean_above_risk() {
+        let outcome = super::resolve_jump(0.3, None, 0.0, 0.5, 0.0);
+        assert_eq!(outcome, super::JumpOutcome::Clean);
+    }
+
+    #[test]
+    fn resolve_jump_picks_failure_by_kind_roll() {
+        assert_eq!(
+            super::resolve_jump(0.5, None, 0.0, 0.1, 0.0),
+            super::JumpOutcome::OffGate
+        );
+        assert_eq!(
+            super::resolve_jump(0.5, None, 0.0, 0.1, 0.99),
+            super::JumpOutcome::FuelLoss
+        );
+    }
+
+    #[test]
+    fn resolve_jump_pirate_control_favors_interception() {
+        // Full control widens the interception band over most of the roll
+        let outcome = super::resolve_jump(0.5, None, 1.0, 0.1, 0.8);
+        assert_eq!(outcome, super::JumpOutcome::Intercepted);
+    }
+
+    #[test]
+    fn route_between_matches_either_direction() {
+        let sector = super::Sector {
+            nodes: Vec::new(),
+            routes: vec![super::RouteEdge {
+                from: 1,
+                to: 2,
+                distance: 100.0,
+                risk: 0.3,
+            }],
+        };
+        assert!(super::route_between(&sector, 1, 2).is_some());
+        assert!(super::route_between(&sector, 2, 1).is_some());
+        assert!(super::route_between(&sector, 1, 3).is_none());
+    }
 }